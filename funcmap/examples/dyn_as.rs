@@ -0,0 +1,76 @@
+/// Usage of [`FuncMap`] for types containing trait objects, routing them
+/// through a wrapper type via `#[funcmap(dyn_as = "...")]`
+use funcmap::FuncMap;
+use std::fmt::Debug;
+
+/// Object-safe trait for a source of readings of type `T`
+trait Readings<T>: Debug {
+    fn into_vec(self: Box<Self>) -> Vec<T>;
+}
+
+impl<T> Readings<T> for Vec<T>
+where
+    T: Debug,
+{
+    fn into_vec(self: Box<Self>) -> Vec<T> {
+        *self
+    }
+}
+
+/// Wrapper around a boxed [`Readings`] trait object
+/// The derive macro cannot map over trait objects by itself, so it routes
+/// fields of type `Box<dyn Readings<T>>` through this wrapper
+struct ReadingsBox<T>(Box<dyn Readings<T>>);
+
+impl<A, B> FuncMap<A, B> for ReadingsBox<A>
+where
+    B: Debug + 'static,
+{
+    type Output = ReadingsBox<B>;
+
+    fn func_map<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+    {
+        // the mapped readings are stored in a `Vec`, which again implements
+        // `Readings`
+        ReadingsBox(Box::new(
+            self.0.into_vec().into_iter().map(f).collect::<Vec<_>>(),
+        ))
+    }
+}
+
+/// Example data structure illustrating the use of [`FuncMap`]
+/// `T` is meant to be a unit of temperature from the [units] module below
+#[derive(FuncMap, Debug)]
+#[funcmap(dyn_as = "ReadingsBox")]
+struct Station<T> {
+    name: String,
+    readings: Box<dyn Readings<T>>,
+}
+
+fn main() {
+    let station = Station {
+        name: String::from("Example Station"),
+        readings: Box::new(vec![units::Celsius(20.0), units::Celsius(25.0)]),
+    };
+
+    println!("{:?}", station.func_map(units::Fahrenheit::from));
+}
+
+/// Helpers dealing with units
+mod units {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    pub struct Celsius(pub f64);
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    pub struct Fahrenheit(pub f64);
+
+    impl From<Celsius> for Fahrenheit {
+        fn from(Celsius(value): Celsius) -> Self {
+            Self(value * 1.8 + 32.0)
+        }
+    }
+}
@@ -0,0 +1,46 @@
+/// Usage of [`FuncMap`] for types containing dynamic JSON data, converting
+/// units both in typed fields and in the JSON data
+use funcmap::{DynLeaf, FuncMap};
+
+use serde_json::{json, Value};
+
+/// Weather report with a fixed structure for the main readings and dynamic
+/// JSON data for station-specific details
+/// `T` is meant to be the type of a single temperature reading
+#[derive(FuncMap, Debug)]
+struct Report<T> {
+    station: String,
+    current: T,
+    history: Vec<T>,
+    details: DynLeaf<T>,
+}
+
+fn main() {
+    let report = Report {
+        station: String::from("hilltop"),
+        current: json!(21.5),
+        history: vec![json!(19.0), json!(20.5)],
+        details: DynLeaf::from_value(json!({
+            "min": { "value": 12.5, "at": "03:00" },
+            "max": { "value": 24.0, "at": "15:00" },
+            "sensors": ["north", "south"],
+        })),
+    };
+
+    // all temperatures, including those within the dynamic details, are
+    // converted in one go
+    let report = report.func_map(to_fahrenheit);
+
+    println!("{}: {} °F", report.station, report.current);
+    println!("history: {:?}", report.history);
+    println!("details: {}", report.details.into_value());
+}
+
+/// Converts a JSON number from degrees Celsius to degrees Fahrenheit, leaving
+/// all other JSON values unchanged
+fn to_fahrenheit(value: Value) -> Value {
+    match value.as_f64() {
+        Some(celsius) => json!(celsius * 9.0 / 5.0 + 32.0),
+        None => value,
+    }
+}
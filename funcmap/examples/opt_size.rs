@@ -0,0 +1,55 @@
+/// Usage of [`FuncMap`] and [`TryFuncMap`] with `#[funcmap(dyn_body)]` to keep
+/// the binary size small when mapping with many different closures, e.g. for
+/// WebAssembly
+///
+/// This example only requires the `alloc` feature of `funcmap`, so it can be
+/// built for `wasm32-unknown-unknown` with `--no-default-features --features
+/// alloc`.
+use funcmap::{FuncMap, TryFuncMap};
+
+/// Shape drawn on a canvas
+/// `T` is meant to be the type of a single coordinate
+/// With `dyn_body`, the code for mapping all the variants is instantiated once
+/// per pair of coordinate types rather than once per closure
+#[derive(FuncMap, TryFuncMap, Debug)]
+#[funcmap(dyn_body)]
+enum Shape<T> {
+    Point(T, T),
+    Line { from: (T, T), to: (T, T) },
+    Polygon(Vec<(T, T)>),
+}
+
+/// Drawing consisting of multiple shapes
+#[derive(FuncMap, TryFuncMap, Debug)]
+#[funcmap(dyn_body)]
+struct Drawing<T> {
+    name: String,
+    shapes: Vec<Shape<T>>,
+}
+
+fn main() {
+    let drawing = Drawing {
+        name: String::from("house"),
+        shapes: vec![
+            Shape::Polygon(vec![(0, 0), (4, 0), (4, 3), (0, 3)]),
+            Shape::Line {
+                from: (0, 3),
+                to: (2, 5),
+            },
+            Shape::Point(2, 1),
+        ],
+    };
+
+    // each of these closures has its own type, but they all share the same
+    // mapping code for `Drawing<i32>` to `Drawing<i32>`
+    let scaled = drawing.func_map(|c| c * 10);
+    let shifted = scaled.func_map(|c| c + 5);
+    let mirrored = shifted.func_map(|c| -c);
+
+    let unsigned: Result<Drawing<u32>, _> = mirrored.try_func_map(u32::try_from);
+
+    match unsigned {
+        Ok(drawing) => println!("{:?}", drawing),
+        Err(err) => println!("drawing has negative coordinates: {}", err),
+    }
+}
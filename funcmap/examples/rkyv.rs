@@ -0,0 +1,65 @@
+/// Usage of [`FuncMap`] to convert archived data from `rkyv` into a working
+/// representation in stages
+use funcmap::FuncMap;
+
+use rkyv::{Archive, Archived, Serialize};
+
+/// Data structure as it is stored, e.g. in a file
+#[derive(Archive, Serialize)]
+struct StoredSeries {
+    name: String,
+    readings: Vec<u32>,
+    threshold: Option<u32>,
+}
+
+/// Working representation of a series of readings
+/// `T` is meant to be the type of a single reading
+#[derive(FuncMap, Debug)]
+struct Series<T> {
+    name: String,
+    readings: Vec<T>,
+    threshold: Option<T>,
+}
+
+/// Temperature in tenths of a degree Celsius, as stored
+#[derive(Debug)]
+struct Decicelsius(u32);
+
+/// Temperature in degrees Fahrenheit
+#[derive(Debug)]
+struct Fahrenheit(f64);
+
+fn main() {
+    let bytes = rkyv::to_bytes::<_, 256>(&StoredSeries {
+        name: String::from("greenhouse"),
+        readings: vec![215, 230, 198],
+        threshold: Some(250),
+    })
+    .unwrap();
+
+    // SAFETY: `bytes` has just been produced by serializing a `StoredSeries`
+    let archived = unsafe { rkyv::archived_root::<StoredSeries>(&bytes) };
+
+    let series = load_series(archived).func_map(to_fahrenheit);
+
+    println!("{}:", series.name);
+    for Fahrenheit(value) in series.readings {
+        println!("{value:.1} °F");
+    }
+}
+
+/// Loads a [`Series`] from archived data without deserializing it first
+fn load_series(archived: &Archived<StoredSeries>) -> Series<Decicelsius> {
+    // use `func_map` on references to archived types to obtain owned data,
+    // reading each value in place
+    Series {
+        name: archived.name.to_string(),
+        readings: archived.readings.func_map(|&value| Decicelsius(value)),
+        threshold: archived.threshold.func_map(|&value| Decicelsius(value)),
+    }
+}
+
+/// Converts a [`Decicelsius`] value into [`Fahrenheit`]
+fn to_fahrenheit(Decicelsius(value): Decicelsius) -> Fahrenheit {
+    Fahrenheit(f64::from(value) / 10.0 * 9.0 / 5.0 + 32.0)
+}
@@ -0,0 +1,75 @@
+//! Closures for common conversions of mapped values
+//!
+//! The functions in this module return function pointers that can be passed
+//! directly to [`func_map`](crate::FuncMap::func_map) or
+//! [`try_func_map`](crate::TryFuncMap::try_func_map), so common numeric and
+//! string conversions don't need a hand-written closure at every call site:
+//! ```
+//! use funcmap::convert::{parse, truncate_checked, widen};
+//! use funcmap::{FuncMap, TryFuncMap};
+//!
+//! #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+//! struct Foo<T> {
+//!     value: T,
+//!     more_values: Vec<T>,
+//! }
+//!
+//! let foo = Foo {
+//!     value: "1",
+//!     more_values: vec!["2", "300"],
+//! };
+//!
+//! let foo: Foo<u16> = foo.try_func_map(parse()).unwrap();
+//! let foo: Foo<u64> = foo.func_map(widen());
+//!
+//! assert_eq!(
+//!     foo.try_func_map(truncate_checked::<u8, _>()).map_err(|_| ()),
+//!     Err(())
+//! );
+//! ```
+//!
+//! The target type comes first among the generic parameters, so it can be
+//! named via turbofish while the source type is inferred, as with
+//! `truncate_checked::<u8, _>()` above.
+
+use core::str::FromStr;
+
+/// Returns a closure converting values losslessly via [`From`]
+///
+/// This is meant for numeric widening such as `u32` to `u64`, but works for any
+/// pair of types related by [`From`].
+#[must_use]
+pub fn widen<B, A>() -> fn(A) -> B
+where
+    B: From<A>,
+{
+    B::from
+}
+
+/// Returns a closure converting values via [`TryFrom`], failing if a value
+/// doesn't fit into the target type
+///
+/// This is meant for numeric narrowing such as `u64` to `u8` within
+/// [`try_func_map`](crate::TryFuncMap::try_func_map), where the first value
+/// that would have to be truncated yields an error instead.
+#[must_use]
+pub fn truncate_checked<B, A>() -> fn(A) -> Result<B, B::Error>
+where
+    B: TryFrom<A>,
+{
+    B::try_from
+}
+
+/// Returns a closure parsing string values via [`FromStr`]
+///
+/// This accepts anything that can be viewed as a string slice, e.g. `&str` or
+/// `String`, and is meant to be used within
+/// [`try_func_map`](crate::TryFuncMap::try_func_map).
+#[must_use]
+pub fn parse<B, A>() -> fn(A) -> Result<B, B::Err>
+where
+    B: FromStr,
+    A: AsRef<str>,
+{
+    |value| value.as_ref().parse()
+}
@@ -0,0 +1,77 @@
+//! Helpers for manually implementing [`TryFuncMap`](crate::TryFuncMap)
+//!
+//! When implementing [`TryFuncMap`](crate::TryFuncMap) manually for a
+//! collection type, the mapped elements usually have to be collected into a
+//! new collection, stopping at the first error. The functions in this module
+//! do exactly that and are available on `no_std` targets with the `alloc`
+//! feature.
+//!
+//! All of them consume the given iterator in its iteration order and stop at
+//! the first [`Err`], which is then returned. Elements after the first error
+//! are not consumed, so a mapping closure inside the iterator is not invoked
+//! for them:
+//! ```
+//! use funcmap::helpers::try_collect_vec;
+//!
+//! let mut calls = 0;
+//!
+//! let result = try_collect_vec([1, 2, 3].into_iter().map(|v| {
+//!     calls += 1;
+//!     if v < 2 { Ok(v) } else { Err(v) }
+//! }));
+//!
+//! assert_eq!(result, Err(2));
+//! assert_eq!(calls, 2);
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// Collects an iterator of [`Result`]s into a [`Vec`], stopping at the first
+/// error
+///
+/// The elements are kept in iteration order.
+///
+/// # Errors
+/// Fails if and only if `iter` yields an [`Err`], returning the first one
+///
+/// # Example
+/// ```
+/// use funcmap::helpers::try_collect_vec;
+///
+/// let result: Result<_, ()> = try_collect_vec([Ok(1), Ok(2)]);
+///
+/// assert_eq!(result, Ok(vec![1, 2]));
+/// ```
+pub fn try_collect_vec<T, E, I>(iter: I) -> Result<Vec<T>, E>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    iter.into_iter().collect()
+}
+
+/// Collects an iterator of [`Result`]s of key-value pairs into a [`BTreeMap`],
+/// stopping at the first error
+///
+/// If the iterator yields multiple pairs with equal keys, the value of the last
+/// one is kept, just like with [`BTreeMap::insert`].
+///
+/// # Errors
+/// Fails if and only if `iter` yields an [`Err`], returning the first one
+///
+/// # Example
+/// ```
+/// use funcmap::helpers::try_collect_btreemap;
+/// use std::collections::BTreeMap;
+///
+/// let result: Result<_, ()> = try_collect_btreemap([Ok((1, "a")), Ok((1, "b"))]);
+///
+/// assert_eq!(result, Ok(BTreeMap::from([(1, "b")])));
+/// ```
+pub fn try_collect_btreemap<K, V, E, I>(iter: I) -> Result<BTreeMap<K, V>, E>
+where
+    K: Ord,
+    I: IntoIterator<Item = Result<(K, V), E>>,
+{
+    iter.into_iter().collect()
+}
@@ -5,37 +5,67 @@
 
 /// Implementations for types in [`alloc::collections::binary_heap`]
 mod binary_heap {
-    extern crate alloc;
-
-    use crate::{FuncMap, TryFuncMap};
+    use crate::{DrainFuncMap, FuncCount, FuncFilterMap, FuncMap, RefFuncMap, TryFuncMap};
 
     use alloc::collections::{binary_heap, BinaryHeap};
 
-    impl<A, B> FuncMap<A, B> for BinaryHeap<A>
+    // `into_iter` yields the values in the internal layout of the heap and
+    // `FromIterator` rebuilds the heap from them in `O(n)` time, so for an
+    // order-preserving closure no value is moved while rebuilding and there is
+    // nothing to be gained from a dedicated mapping keeping the layout
+    crate::impl_collect_map!(BinaryHeap where B: Ord);
+
+    impl<A> FuncCount<A> for BinaryHeap<A> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.iter().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            self.len()
+        }
+    }
+
+    impl<'a, A, B> RefFuncMap<'a, A, B> for BinaryHeap<A>
     where
         B: Ord,
     {
         type Output = BinaryHeap<B>;
 
-        fn func_map<F>(self, f: F) -> Self::Output
+        fn ref_func_map<F>(&'a self, f: F) -> Self::Output
         where
-            F: FnMut(A) -> B,
+            A: 'a,
+            F: FnMut(&'a A) -> B,
         {
-            self.into_iter().map(f).collect()
+            self.iter().map(f).collect()
         }
     }
 
-    impl<A, B> TryFuncMap<A, B> for BinaryHeap<A>
+    impl<A, B> FuncFilterMap<A, B> for BinaryHeap<A>
     where
         B: Ord,
     {
         type Output = BinaryHeap<B>;
 
-        fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+        fn func_filter_map<F>(self, f: F) -> Self::Output
         where
-            F: FnMut(A) -> Result<B, E>,
+            F: FnMut(A) -> Option<B>,
         {
-            self.into_iter().map(f).collect()
+            self.into_iter().filter_map(f).collect()
+        }
+    }
+
+    impl<A, B> DrainFuncMap<A, B> for BinaryHeap<A>
+    where
+        B: Ord,
+    {
+        fn drain_func_map<F>(&mut self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            self.drain().map(f).collect()
         }
     }
 
@@ -70,9 +100,93 @@ mod binary_heap {
     }
 }
 
+/// Implementations for types in [`alloc::borrow`]
+mod borrow {
+    use crate::{FuncMap, TryFuncMap};
+
+    use alloc::borrow::Cow;
+    use alloc::vec::Vec;
+
+    /// Maps the elements of a slice behind a [`Cow`]
+    ///
+    /// The output is always [`Cow::Owned`]: Borrowed elements are cloned before
+    /// being passed to the closure, while owned elements are moved. As it
+    /// doesn't borrow anything, it can be turned into a `Cow<'static, [B]>`
+    /// via [`Cow::into_owned`] and [`Cow::Owned`].
+    impl<'a, A, B> FuncMap<A, B> for Cow<'a, [A]>
+    where
+        A: Clone,
+        B: Clone + 'a,
+    {
+        type Output = Cow<'a, [B]>;
+
+        fn func_map<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            Cow::Owned(match self {
+                Cow::Borrowed(values) => values.iter().cloned().map(f).collect(),
+                Cow::Owned(values) => values.into_iter().map(f).collect(),
+            })
+        }
+    }
+
+    /// Tries to map the elements of a slice behind a [`Cow`]
+    ///
+    /// The output is always [`Cow::Owned`], see the implementation of
+    /// [`FuncMap`].
+    impl<'a, A, B> TryFuncMap<A, B> for Cow<'a, [A]>
+    where
+        A: Clone,
+        B: Clone + 'a,
+    {
+        type Output = Cow<'a, [B]>;
+
+        fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            match self {
+                Cow::Borrowed(values) => {
+                    values.iter().cloned().map(f).collect::<Result<Vec<_>, _>>()
+                }
+                Cow::Owned(values) => values.into_iter().map(f).collect::<Result<Vec<_>, _>>(),
+            }
+            .map(Cow::Owned)
+        }
+    }
+}
+
 /// Implementations for types in [`alloc::boxed`]
 mod boxed {
-    use crate::{FuncMap, TryFuncMap};
+    use crate::{FuncCount, FuncMap, RefFuncMap, TryFuncMap};
+
+    use alloc::boxed::Box;
+
+    impl<A> FuncCount<A> for Box<A> {
+        fn func_count<F>(&self, mut f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            f(self)
+        }
+
+        fn count_leaves(&self) -> usize {
+            1
+        }
+    }
+
+    impl<'a, A, B> RefFuncMap<'a, A, B> for Box<A> {
+        type Output = Box<B>;
+
+        fn ref_func_map<F>(&'a self, mut f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            Box::new(f(self))
+        }
+    }
 
     impl<A, B> FuncMap<A, B> for Box<A> {
         type Output = Box<B>;
@@ -99,11 +213,114 @@ mod boxed {
 
 /// Implementations for types in [`alloc::collections::btree_map`]
 mod btree_map {
-    extern crate alloc;
-
-    use crate::{FuncMap, TryFuncMap, TypeParam};
+    use crate::{
+        DrainFuncMap, FuncCount, FuncFilterMap, FuncMap, FuncMapEntries, RefFuncMap, TryFuncMap,
+        TryFuncMapEntries, TryFuncMapReserve, TypeParam,
+    };
 
     use alloc::collections::{btree_map, BTreeMap};
+    use core::mem;
+
+    impl<A, V> FuncCount<A, TypeParam<0>> for BTreeMap<A, V> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.keys().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            self.len()
+        }
+    }
+
+    impl<K, A> FuncCount<A, TypeParam<1>> for BTreeMap<K, A> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.values().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            self.len()
+        }
+    }
+
+    impl<'a, A, B, V> RefFuncMap<'a, A, B, TypeParam<0>> for BTreeMap<A, V>
+    where
+        B: Ord,
+        V: Clone,
+    {
+        type Output = BTreeMap<B, V>;
+
+        fn ref_func_map<F>(&'a self, mut f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            self.iter().map(|(k, v)| (f(k), v.clone())).collect()
+        }
+    }
+
+    impl<'a, K, A, B> RefFuncMap<'a, A, B, TypeParam<1>> for BTreeMap<K, A>
+    where
+        K: Clone + Ord,
+    {
+        type Output = BTreeMap<K, B>;
+
+        fn ref_func_map<F>(&'a self, mut f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            self.iter().map(|(k, v)| (k.clone(), f(v))).collect()
+        }
+    }
+
+    impl<K, A, B> FuncFilterMap<A, B> for BTreeMap<K, A>
+    where
+        K: Ord,
+    {
+        type Output = BTreeMap<K, B>;
+
+        fn func_filter_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> Option<B>,
+        {
+            self.into_iter()
+                .filter_map(|(k, v)| f(v).map(|v| (k, v)))
+                .collect()
+        }
+    }
+
+    impl<K, L, V, W> FuncMapEntries<K, L, V, W> for BTreeMap<K, V>
+    where
+        L: Ord,
+    {
+        type Output = BTreeMap<L, W>;
+
+        fn func_map_entries<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut((K, V)) -> (L, W),
+        {
+            self.into_iter().map(f).collect()
+        }
+    }
+
+    impl<K, L, V, W> TryFuncMapEntries<K, L, V, W> for BTreeMap<K, V>
+    where
+        L: Ord,
+    {
+        type Output = BTreeMap<L, W>;
+
+        fn try_func_map_entries<E, F>(self, f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut((K, V)) -> Result<(L, W), E>,
+        {
+            self.into_iter().map(f).collect()
+        }
+    }
 
     impl<A, B, V> FuncMap<A, B, TypeParam<0>> for BTreeMap<A, V>
     where
@@ -133,6 +350,31 @@ mod btree_map {
         }
     }
 
+    impl<A, B, V> DrainFuncMap<A, B, TypeParam<0>> for BTreeMap<A, V>
+    where
+        B: Ord,
+    {
+        fn drain_func_map<F>(&mut self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            mem::take(self).func_map(f)
+        }
+    }
+
+    impl<A, B, V> TryFuncMapReserve<A, B, TypeParam<0>> for BTreeMap<A, V>
+    where
+        B: Ord,
+    {
+        fn try_func_map_reserve<E, F>(self, f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            // a `BTreeMap` has no notion of capacity
+            self.try_func_map(f)
+        }
+    }
+
     impl<K, A, B> FuncMap<A, B, TypeParam<1>> for BTreeMap<K, A>
     where
         K: Ord,
@@ -143,10 +385,40 @@ mod btree_map {
         where
             F: FnMut(A) -> B,
         {
+            // the keys arrive in order, so collecting only needs a linear pass to
+            // check this before bulk-building the tree
+            //
+            // reusing the nodes of `self` instead would require knowledge of
+            // their private layout, see the `btree_map` benchmark
             self.into_iter().map(|(k, v)| (k, f(v))).collect()
         }
     }
 
+    impl<K, A, B> DrainFuncMap<A, B, TypeParam<1>> for BTreeMap<K, A>
+    where
+        K: Ord,
+    {
+        fn drain_func_map<F>(&mut self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            mem::take(self).func_map(f)
+        }
+    }
+
+    impl<K, A, B> TryFuncMapReserve<A, B, TypeParam<1>> for BTreeMap<K, A>
+    where
+        K: Ord,
+    {
+        fn try_func_map_reserve<E, F>(self, f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            // a `BTreeMap` has no notion of capacity
+            self.try_func_map(f)
+        }
+    }
+
     impl<K, A, B> TryFuncMap<A, B, TypeParam<1>> for BTreeMap<K, A>
     where
         K: Ord,
@@ -228,37 +500,64 @@ mod btree_map {
 
 /// Implementations for types in [`alloc::collections::btree_set`]
 mod btree_set {
-    extern crate alloc;
-
-    use crate::{FuncMap, TryFuncMap};
+    use crate::{DrainFuncMap, FuncCount, FuncFilterMap, FuncMap, RefFuncMap, TryFuncMap};
 
     use alloc::collections::{btree_set, BTreeSet};
+    use core::mem;
+
+    crate::impl_collect_map!(BTreeSet where B: Ord);
 
-    impl<A, B> FuncMap<A, B> for BTreeSet<A>
+    impl<A> FuncCount<A> for BTreeSet<A> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.iter().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            self.len()
+        }
+    }
+
+    impl<'a, A, B> RefFuncMap<'a, A, B> for BTreeSet<A>
     where
         B: Ord,
     {
         type Output = BTreeSet<B>;
 
-        fn func_map<F>(self, f: F) -> Self::Output
+        fn ref_func_map<F>(&'a self, f: F) -> Self::Output
         where
-            F: FnMut(A) -> B,
+            A: 'a,
+            F: FnMut(&'a A) -> B,
         {
-            self.into_iter().map(f).collect()
+            self.iter().map(f).collect()
         }
     }
 
-    impl<A, B> TryFuncMap<A, B> for BTreeSet<A>
+    impl<A, B> FuncFilterMap<A, B> for BTreeSet<A>
     where
         B: Ord,
     {
         type Output = BTreeSet<B>;
 
-        fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+        fn func_filter_map<F>(self, f: F) -> Self::Output
         where
-            F: FnMut(A) -> Result<B, E>,
+            F: FnMut(A) -> Option<B>,
         {
-            self.into_iter().map(f).collect()
+            self.into_iter().filter_map(f).collect()
+        }
+    }
+
+    impl<A, B> DrainFuncMap<A, B> for BTreeSet<A>
+    where
+        B: Ord,
+    {
+        fn drain_func_map<F>(&mut self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            mem::take(self).func_map(f)
         }
     }
 
@@ -295,31 +594,55 @@ mod btree_set {
 
 /// Implementations for types in [`alloc::collections::linked_list`]
 mod linked_list {
-    extern crate alloc;
-
-    use crate::{FuncMap, TryFuncMap};
+    use crate::{DrainFuncMap, FuncCount, FuncFilterMap, FuncMap, RefFuncMap, TryFuncMap};
 
     use alloc::collections::{linked_list, LinkedList};
+    use core::mem;
+
+    crate::impl_collect_map!(LinkedList);
 
-    impl<A, B> FuncMap<A, B> for LinkedList<A> {
+    impl<A> FuncCount<A> for LinkedList<A> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.iter().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            self.len()
+        }
+    }
+
+    impl<'a, A, B> RefFuncMap<'a, A, B> for LinkedList<A> {
         type Output = LinkedList<B>;
 
-        fn func_map<F>(self, f: F) -> Self::Output
+        fn ref_func_map<F>(&'a self, f: F) -> Self::Output
         where
-            F: FnMut(A) -> B,
+            A: 'a,
+            F: FnMut(&'a A) -> B,
         {
-            self.into_iter().map(f).collect()
+            self.iter().map(f).collect()
         }
     }
 
-    impl<A, B> TryFuncMap<A, B> for LinkedList<A> {
+    impl<A, B> FuncFilterMap<A, B> for LinkedList<A> {
         type Output = LinkedList<B>;
 
-        fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+        fn func_filter_map<F>(self, f: F) -> Self::Output
         where
-            F: FnMut(A) -> Result<B, E>,
+            F: FnMut(A) -> Option<B>,
         {
-            self.into_iter().map(f).collect()
+            self.into_iter().filter_map(f).collect()
+        }
+    }
+
+    impl<A, B> DrainFuncMap<A, B> for LinkedList<A> {
+        fn drain_func_map<F>(&mut self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            mem::take(self).func_map(f)
         }
     }
 
@@ -350,31 +673,81 @@ mod linked_list {
 
 /// Implementations for types in [`alloc::vec`](mod@alloc::vec)
 mod vec {
-    extern crate alloc;
+    use crate::{
+        DrainFuncMap, FuncCount, FuncFilterMap, FuncMap, FuncMapChunked, RefFuncMap, TryFuncMap,
+        TryFuncMapReserve,
+    };
 
-    use crate::{FuncMap, TryFuncMap};
+    use alloc::vec::{self, Vec};
 
-    use alloc::vec;
+    crate::impl_collect_map!(Vec);
+
+    impl<A> FuncCount<A> for Vec<A> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.iter().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            self.len()
+        }
+    }
 
-    impl<A, B> FuncMap<A, B> for Vec<A> {
+    impl<'a, A, B> RefFuncMap<'a, A, B> for Vec<A> {
         type Output = Vec<B>;
 
-        fn func_map<F>(self, f: F) -> Self::Output
+        fn ref_func_map<F>(&'a self, f: F) -> Self::Output
         where
-            F: FnMut(A) -> B,
+            A: 'a,
+            F: FnMut(&'a A) -> B,
         {
-            self.into_iter().map(f).collect()
+            self.iter().map(f).collect()
         }
     }
 
-    impl<A, B> TryFuncMap<A, B> for Vec<A> {
+    impl<A, B> FuncFilterMap<A, B> for Vec<A> {
         type Output = Vec<B>;
 
-        fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+        fn func_filter_map<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> Option<B>,
+        {
+            self.into_iter().filter_map(f).collect()
+        }
+    }
+
+    impl<A, B> DrainFuncMap<A, B> for Vec<A> {
+        fn drain_func_map<F>(&mut self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            self.drain(..).map(f).collect()
+        }
+    }
+
+    impl<A, B> TryFuncMapReserve<A, B> for Vec<A> {
+        fn try_func_map_reserve<E, F>(self, mut f: F) -> Result<Self::Output, E>
         where
             F: FnMut(A) -> Result<B, E>,
         {
-            self.into_iter().map(f).collect()
+            let mut output = Vec::with_capacity(self.len());
+
+            for value in self {
+                output.push(f(value)?);
+            }
+
+            Ok(output)
+        }
+    }
+
+    impl<A, B> FuncMapChunked<A, B> for Vec<A> {
+        fn func_map_chunked<F>(self, chunk_size: usize, f: F) -> Self::Output
+        where
+            F: FnMut(&[A], &mut Vec<B>),
+        {
+            map_slice_chunked(&self, chunk_size, f)
         }
     }
 
@@ -401,35 +774,94 @@ mod vec {
                 .map(IntoIterator::into_iter)
         }
     }
+
+    /// Maps the values of a slice in chunks of `chunk_size` values, checking
+    /// that `f` pushes exactly one value for every value of a chunk
+    pub(super) fn map_slice_chunked<A, B, F>(values: &[A], chunk_size: usize, mut f: F) -> Vec<B>
+    where
+        F: FnMut(&[A], &mut Vec<B>),
+    {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        let mut output = Vec::with_capacity(values.len());
+
+        for chunk in values.chunks(chunk_size) {
+            let len = output.len();
+            f(chunk, &mut output);
+
+            assert!(
+                output.len().checked_sub(len) == Some(chunk.len()),
+                "closure passed to `func_map_chunked` must push exactly one value per value of the chunk"
+            );
+        }
+
+        output
+    }
 }
 
 /// Implementations for types in [`alloc::collections::vec_deque`]
 mod vec_deque {
-    extern crate alloc;
-
-    use crate::{FuncMap, TryFuncMap};
+    use super::vec::map_slice_chunked;
+    use crate::{
+        DrainFuncMap, FuncCount, FuncFilterMap, FuncMap, FuncMapChunked, RefFuncMap, TryFuncMap,
+    };
 
     use alloc::collections::{vec_deque, VecDeque};
+    use alloc::vec::Vec;
+
+    crate::impl_collect_map!(VecDeque);
+
+    impl<A> FuncCount<A> for VecDeque<A> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.iter().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            self.len()
+        }
+    }
 
-    impl<A, B> FuncMap<A, B> for VecDeque<A> {
+    impl<'a, A, B> RefFuncMap<'a, A, B> for VecDeque<A> {
         type Output = VecDeque<B>;
 
-        fn func_map<F>(self, f: F) -> Self::Output
+        fn ref_func_map<F>(&'a self, f: F) -> Self::Output
         where
-            F: FnMut(A) -> B,
+            A: 'a,
+            F: FnMut(&'a A) -> B,
         {
-            self.into_iter().map(f).collect()
+            self.iter().map(f).collect()
         }
     }
 
-    impl<A, B> TryFuncMap<A, B> for VecDeque<A> {
+    impl<A, B> FuncFilterMap<A, B> for VecDeque<A> {
         type Output = VecDeque<B>;
 
-        fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+        fn func_filter_map<F>(self, f: F) -> Self::Output
         where
-            F: FnMut(A) -> Result<B, E>,
+            F: FnMut(A) -> Option<B>,
         {
-            self.into_iter().map(f).collect()
+            self.into_iter().filter_map(f).collect()
+        }
+    }
+
+    impl<A, B> DrainFuncMap<A, B> for VecDeque<A> {
+        fn drain_func_map<F>(&mut self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            self.drain(..).map(f).collect()
+        }
+    }
+
+    impl<A, B> FuncMapChunked<A, B> for VecDeque<A> {
+        fn func_map_chunked<F>(mut self, chunk_size: usize, f: F) -> Self::Output
+        where
+            F: FnMut(&[A], &mut Vec<B>),
+        {
+            map_slice_chunked(self.make_contiguous(), chunk_size, f).into()
         }
     }
 
@@ -5,7 +5,20 @@
 
 /// Implementations for [arrays](prim@array)
 mod array {
-    use crate::{array, FuncMap, TryFuncMap};
+    use crate::{array, FuncCount, FuncMap, RefFuncMap, TryFuncMap};
+
+    impl<A, const N: usize> FuncCount<A> for [A; N] {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.iter().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            N
+        }
+    }
 
     impl<A, B, const N: usize> FuncMap<A, B> for [A; N] {
         type Output = [B; N];
@@ -18,6 +31,18 @@ mod array {
         }
     }
 
+    impl<'a, A, B, const N: usize> RefFuncMap<'a, A, B> for [A; N] {
+        type Output = [B; N];
+
+        fn ref_func_map<F>(&'a self, mut f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            core::array::from_fn(|idx| f(&self[idx]))
+        }
+    }
+
     impl<A, B, const N: usize> TryFuncMap<A, B> for [A; N] {
         type Output = [B; N];
 
@@ -32,10 +57,22 @@ mod array {
 
 /// Implementations for [`core::ops::Bound`]
 mod bound {
-    use crate::{FuncMap, TryFuncMap};
+    use crate::{FuncCount, FuncMap, RefFuncMap, TryFuncMap};
 
     use core::ops::Bound;
 
+    impl<A> FuncCount<A> for Bound<A> {
+        fn func_count<F>(&self, mut f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            match self {
+                Self::Included(bound) | Self::Excluded(bound) => f(bound),
+                Self::Unbounded => 0,
+            }
+        }
+    }
+
     impl<A, B> FuncMap<A, B> for Bound<A> {
         type Output = Bound<B>;
 
@@ -51,6 +88,22 @@ mod bound {
         }
     }
 
+    impl<'a, A, B> RefFuncMap<'a, A, B> for Bound<A> {
+        type Output = Bound<B>;
+
+        fn ref_func_map<F>(&'a self, mut f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            match self {
+                Self::Included(bound) => Bound::Included(f(bound)),
+                Self::Excluded(bound) => Bound::Excluded(f(bound)),
+                Self::Unbounded => Bound::Unbounded,
+            }
+        }
+    }
+
     impl<A, B> TryFuncMap<A, B> for Bound<A> {
         type Output = Bound<B>;
 
@@ -96,6 +149,16 @@ mod cell {
     }
 }
 
+/// Implementations for [`core::cmp::Reverse`]
+mod cmp {
+    use core::cmp::Reverse;
+
+    crate::impl_funcmap_wrapper!(Reverse {
+        unwrap: |reverse| reverse.0,
+        wrap: Reverse,
+    });
+}
+
 /// Implementations for [`core::ops::ControlFlow`]
 mod control_flow {
     use crate::{FuncMap, TryFuncMap, TypeParam};
@@ -159,12 +222,46 @@ mod control_flow {
     }
 }
 
+/// Implementations for [`core::num::Wrapping`]
+mod num {
+    use core::num::Wrapping;
+
+    crate::impl_funcmap_wrapper!(Wrapping {
+        unwrap: |wrapping| wrapping.0,
+        wrap: Wrapping,
+    });
+}
+
 /// Implementations for [`core::option::Option`]
 mod option {
-    use crate::{FuncMap, TryFuncMap};
+    use crate::{FuncCount, FuncFilterMap, FuncMap, RefFuncMap, TryFuncMap};
 
     use core::option;
 
+    impl<A> FuncCount<A> for Option<A> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.as_ref().map_or(0, f)
+        }
+
+        fn count_leaves(&self) -> usize {
+            usize::from(self.is_some())
+        }
+    }
+
+    impl<A, B> FuncFilterMap<A, B> for Option<A> {
+        type Output = Option<B>;
+
+        fn func_filter_map<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> Option<B>,
+        {
+            self.and_then(f)
+        }
+    }
+
     impl<A, B> FuncMap<A, B> for Option<A> {
         type Output = Option<B>;
 
@@ -176,6 +273,18 @@ mod option {
         }
     }
 
+    impl<'a, A, B> RefFuncMap<'a, A, B> for Option<A> {
+        type Output = Option<B>;
+
+        fn ref_func_map<F>(&'a self, f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            self.as_ref().map(f)
+        }
+    }
+
     impl<A, B> TryFuncMap<A, B> for Option<A> {
         type Output = Option<B>;
 
@@ -212,10 +321,19 @@ mod option {
 
 /// Implementations for [`core::marker::PhantomData`]
 mod phantom_data {
-    use crate::{FuncMap, TryFuncMap};
+    use crate::{FuncCount, FuncMap, RefFuncMap, TryFuncMap};
 
     use core::marker::PhantomData;
 
+    impl<A> FuncCount<A> for PhantomData<A> {
+        fn func_count<F>(&self, _: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            0
+        }
+    }
+
     impl<A, B> FuncMap<A, B> for PhantomData<A> {
         type Output = PhantomData<B>;
 
@@ -227,6 +345,18 @@ mod phantom_data {
         }
     }
 
+    impl<'a, A, B> RefFuncMap<'a, A, B> for PhantomData<A> {
+        type Output = PhantomData<B>;
+
+        fn ref_func_map<F>(&'a self, _: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            PhantomData
+        }
+    }
+
     impl<A, B> TryFuncMap<A, B> for PhantomData<A> {
         type Output = PhantomData<B>;
 
@@ -424,10 +554,36 @@ mod ref_cell {
 
 /// Implementations for [`core::result::Result`]
 mod result {
-    use crate::{FuncMap, TryFuncMap, TypeParam};
+    use crate::{FuncCount, FuncMap, RefFuncMap, TryFuncMap, TypeParam};
 
     use core::result;
 
+    impl<A, U> FuncCount<A, TypeParam<0>> for Result<A, U> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.as_ref().map_or(0, f)
+        }
+
+        fn count_leaves(&self) -> usize {
+            usize::from(self.is_ok())
+        }
+    }
+
+    impl<T, A> FuncCount<A, TypeParam<1>> for Result<T, A> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.as_ref().err().map_or(0, f)
+        }
+
+        fn count_leaves(&self) -> usize {
+            usize::from(self.is_err())
+        }
+    }
+
     impl<A, B, U> FuncMap<A, B, TypeParam<0>> for Result<A, U> {
         type Output = Result<B, U>;
 
@@ -442,6 +598,39 @@ mod result {
         }
     }
 
+    impl<'a, A, B, U> RefFuncMap<'a, A, B, TypeParam<0>> for Result<A, U>
+    where
+        U: Clone,
+    {
+        type Output = Result<B, U>;
+
+        fn ref_func_map<F>(&'a self, f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            self.as_ref().map(f).map_err(Clone::clone)
+        }
+    }
+
+    impl<'a, T, A, B> RefFuncMap<'a, A, B, TypeParam<1>> for Result<T, A>
+    where
+        T: Clone,
+    {
+        type Output = Result<T, B>;
+
+        fn ref_func_map<F>(&'a self, mut f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            match self {
+                Ok(value) => Ok(value.clone()),
+                Err(value) => Err(f(value)),
+            }
+        }
+    }
+
     impl<A, B, U> TryFuncMap<A, B, TypeParam<0>> for Result<A, U> {
         type Output = Result<B, U>;
 
@@ -0,0 +1,91 @@
+//! Implementations of [`FuncMap`](crate::FuncMap) and
+//! [`TryFuncMap`](crate::TryFuncMap) for types in [`crossbeam_queue`]
+//!
+//! These consume the queue and push the mapped elements into a new queue in
+//! the order in which they would have been popped.
+
+/// Implementations for [`crossbeam_queue::ArrayQueue`]
+///
+/// The mapped queue has the same capacity as the original one.
+mod array_queue {
+    use crate::{FuncMap, TryFuncMap};
+
+    use crossbeam_queue::ArrayQueue;
+
+    impl<A, B> FuncMap<A, B> for ArrayQueue<A> {
+        type Output = ArrayQueue<B>;
+
+        fn func_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            let mapped = ArrayQueue::new(self.capacity());
+
+            for value in self {
+                // cannot fail because both queues have the same capacity
+                let _ = mapped.push(f(value));
+            }
+
+            mapped
+        }
+    }
+
+    impl<A, B> TryFuncMap<A, B> for ArrayQueue<A> {
+        type Output = ArrayQueue<B>;
+
+        fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            let mapped = ArrayQueue::new(self.capacity());
+
+            for value in self {
+                // cannot fail because both queues have the same capacity
+                let _ = mapped.push(f(value)?);
+            }
+
+            Ok(mapped)
+        }
+    }
+}
+
+/// Implementations for [`crossbeam_queue::SegQueue`]
+mod seg_queue {
+    use crate::{FuncMap, TryFuncMap};
+
+    use crossbeam_queue::SegQueue;
+
+    impl<A, B> FuncMap<A, B> for SegQueue<A> {
+        type Output = SegQueue<B>;
+
+        fn func_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            let mapped = SegQueue::new();
+
+            for value in self {
+                mapped.push(f(value));
+            }
+
+            mapped
+        }
+    }
+
+    impl<A, B> TryFuncMap<A, B> for SegQueue<A> {
+        type Output = SegQueue<B>;
+
+        fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            let mapped = SegQueue::new();
+
+            for value in self {
+                mapped.push(f(value)?);
+            }
+
+            Ok(mapped)
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Implementations of [`FuncMap`](crate::FuncMap) and
+//! [`TryFuncMap`](crate::TryFuncMap) for types in [`futures`]
+
+/// Implementations for [`futures::stream::Empty`]
+mod empty {
+    use crate::{FuncMap, TryFuncMap};
+
+    use futures::stream::{self, Empty};
+
+    impl<A, B> FuncMap<A, B> for Empty<A> {
+        type Output = Empty<B>;
+
+        fn func_map<F>(self, _: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            stream::empty()
+        }
+    }
+
+    impl<A, B> TryFuncMap<A, B> for Empty<A> {
+        type Output = Empty<B>;
+
+        fn try_func_map<E, F>(self, _: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            Ok(stream::empty())
+        }
+    }
+}
+
+/// Implementations for [`futures::stream::Iter`]
+///
+/// These map the underlying iterator rather than its items, so a field of type
+/// `Iter<I>` where `I` depends on the mapped type parameter (e.g.
+/// `Iter<vec::IntoIter<T>>`) is mapped through the implementation for `I`.
+mod iter {
+    use crate::{FuncMap, TryFuncMap};
+
+    use futures::stream::{self, Iter};
+
+    impl<A, B> FuncMap<A, B> for Iter<A>
+    where
+        B: Iterator,
+    {
+        type Output = Iter<B>;
+
+        fn func_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            stream::iter(f(self.into_inner()))
+        }
+    }
+
+    impl<A, B> TryFuncMap<A, B> for Iter<A>
+    where
+        B: Iterator,
+    {
+        type Output = Iter<B>;
+
+        fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            f(self.into_inner()).map(stream::iter)
+        }
+    }
+}
+
+/// Implementations for [`futures::stream::Pending`]
+mod pending {
+    use crate::{FuncMap, TryFuncMap};
+
+    use futures::stream::{self, Pending};
+
+    impl<A, B> FuncMap<A, B> for Pending<A> {
+        type Output = Pending<B>;
+
+        fn func_map<F>(self, _: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            stream::pending()
+        }
+    }
+
+    impl<A, B> TryFuncMap<A, B> for Pending<A> {
+        type Output = Pending<B>;
+
+        fn try_func_map<E, F>(self, _: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            Ok(stream::pending())
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Implementations of [`FuncMap`](crate::FuncMap) and
+//! [`TryFuncMap`](crate::TryFuncMap) for types in [`generic_array`]
+
+#![allow(clippy::mismatching_type_param_order)]
+
+use crate::{FuncMap, TryFuncMap};
+
+use generic_array::{ArrayLength, GenericArray};
+
+impl<A, B, N> FuncMap<A, B> for GenericArray<A, N>
+where
+    N: ArrayLength,
+{
+    type Output = GenericArray<B, N>;
+
+    fn func_map<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+    {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<A, B, N> TryFuncMap<A, B> for GenericArray<A, N>
+where
+    N: ArrayLength,
+{
+    type Output = GenericArray<B, N>;
+
+    fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+    {
+        // Collecting into `GenericArray` panics unless the iterator yields
+        // exactly `N` items, so instead of stopping at the first error, we keep
+        // going without calling `f` again and only fail afterwards
+        let mut error = None;
+
+        let values: GenericArray<Option<B>, N> = self
+            .into_iter()
+            .map(|value| match error {
+                Some(_) => None,
+                None => f(value).map_err(|err| error = Some(err)).ok(),
+            })
+            .collect();
+
+        match error {
+            Some(err) => Err(err),
+            None => Ok(values.into_iter().map(Option::unwrap).collect()),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Implementations of [`FuncMap`](crate::FuncMap) and
+//! [`TryFuncMap`](crate::TryFuncMap) for types in [`heapless`]
+
+#![allow(clippy::mismatching_type_param_order)]
+
+/// Implementations for [`heapless::Vec`]
+mod vec {
+    use crate::{FuncMap, TryFuncMap};
+
+    use heapless::Vec;
+
+    impl<A, B, const N: usize> FuncMap<A, B> for Vec<A, N> {
+        type Output = Vec<B, N>;
+
+        fn func_map<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            self.into_iter().map(f).collect()
+        }
+    }
+
+    impl<A, B, const N: usize> TryFuncMap<A, B> for Vec<A, N> {
+        type Output = Vec<B, N>;
+
+        fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            self.into_iter().map(f).collect()
+        }
+    }
+}
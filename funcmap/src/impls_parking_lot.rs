@@ -0,0 +1,63 @@
+//! Implementations of [`FuncMap`](crate::FuncMap) and
+//! [`TryFuncMap`](crate::TryFuncMap) for types in [`parking_lot`]
+//!
+//! Unlike their counterparts in [`std::sync`], these locks cannot be poisoned,
+//! so they can be consumed via `into_inner` without any error.
+
+/// Implementations for [`parking_lot::Mutex`]
+mod mutex {
+    use crate::{FuncMap, TryFuncMap};
+
+    use parking_lot::Mutex;
+
+    impl<A, B> FuncMap<A, B> for Mutex<A> {
+        type Output = Mutex<B>;
+
+        fn func_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            Mutex::new(f(self.into_inner()))
+        }
+    }
+
+    impl<A, B> TryFuncMap<A, B> for Mutex<A> {
+        type Output = Mutex<B>;
+
+        fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            f(self.into_inner()).map(Mutex::new)
+        }
+    }
+}
+
+/// Implementations for [`parking_lot::RwLock`]
+mod rw_lock {
+    use crate::{FuncMap, TryFuncMap};
+
+    use parking_lot::RwLock;
+
+    impl<A, B> FuncMap<A, B> for RwLock<A> {
+        type Output = RwLock<B>;
+
+        fn func_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            RwLock::new(f(self.into_inner()))
+        }
+    }
+
+    impl<A, B> TryFuncMap<A, B> for RwLock<A> {
+        type Output = RwLock<B>;
+
+        fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            f(self.into_inner()).map(RwLock::new)
+        }
+    }
+}
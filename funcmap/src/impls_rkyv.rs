@@ -0,0 +1,101 @@
+//! Implementations of [`FuncMap`](crate::FuncMap) and
+//! [`TryFuncMap`](crate::TryFuncMap) for references to archived types in
+//! [`rkyv`]
+//!
+//! Archived data is read in place and cannot be moved out of, so these
+//! implementations map a *reference* to an archived type, passing references
+//! to the archived values to the closure and producing the corresponding owned
+//! type, e.g. `&ArchivedVec<A>` is mapped to `Vec<B>`.
+
+#![allow(clippy::mismatching_type_param_order)]
+
+/// Implementations for [`rkyv::option::ArchivedOption`]
+mod option {
+    use crate::{FuncMap, TryFuncMap};
+
+    use rkyv::option::ArchivedOption;
+
+    impl<'a, A, B> FuncMap<&'a A, B> for &'a ArchivedOption<A> {
+        type Output = Option<B>;
+
+        fn func_map<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut(&'a A) -> B,
+        {
+            self.as_ref().map(f)
+        }
+    }
+
+    impl<'a, A, B> TryFuncMap<&'a A, B> for &'a ArchivedOption<A> {
+        type Output = Option<B>;
+
+        fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(&'a A) -> Result<B, E>,
+        {
+            self.as_ref().map(f).transpose()
+        }
+    }
+}
+
+/// Implementations for [`rkyv::boxed::ArchivedBox`]
+#[cfg(feature = "alloc")]
+mod boxed {
+    use crate::{FuncMap, TryFuncMap};
+
+    use alloc::boxed::Box;
+    use rkyv::boxed::ArchivedBox;
+
+    impl<'a, A, B> FuncMap<&'a A, B> for &'a ArchivedBox<A> {
+        type Output = Box<B>;
+
+        fn func_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(&'a A) -> B,
+        {
+            Box::new(f(self.get()))
+        }
+    }
+
+    impl<'a, A, B> TryFuncMap<&'a A, B> for &'a ArchivedBox<A> {
+        type Output = Box<B>;
+
+        fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(&'a A) -> Result<B, E>,
+        {
+            f(self.get()).map(Box::new)
+        }
+    }
+}
+
+/// Implementations for [`rkyv::vec::ArchivedVec`]
+#[cfg(feature = "alloc")]
+mod vec {
+    use crate::{FuncMap, TryFuncMap};
+
+    use alloc::vec::Vec;
+    use rkyv::vec::ArchivedVec;
+
+    impl<'a, A, B> FuncMap<&'a A, B> for &'a ArchivedVec<A> {
+        type Output = Vec<B>;
+
+        fn func_map<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut(&'a A) -> B,
+        {
+            self.iter().map(f).collect()
+        }
+    }
+
+    impl<'a, A, B> TryFuncMap<&'a A, B> for &'a ArchivedVec<A> {
+        type Output = Vec<B>;
+
+        fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(&'a A) -> Result<B, E>,
+        {
+            self.iter().map(f).collect()
+        }
+    }
+}
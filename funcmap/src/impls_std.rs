@@ -5,11 +5,115 @@
 
 /// Implementations for types in [`std::collections::hash_map`]
 mod hash_map {
-    use crate::{FuncMap, TryFuncMap, TypeParam};
+    use crate::{
+        DrainFuncMap, FuncCount, FuncFilterMap, FuncMap, FuncMapEntries, RefFuncMap, TryFuncMap,
+        TryFuncMapEntries, TryFuncMapReserve, TypeParam,
+    };
 
     use core::hash::Hash;
     use std::collections::{hash_map, HashMap};
 
+    impl<A, V, S> FuncCount<A, TypeParam<0>> for HashMap<A, V, S> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.keys().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            self.len()
+        }
+    }
+
+    impl<K, A, S> FuncCount<A, TypeParam<1>> for HashMap<K, A, S> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.values().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            self.len()
+        }
+    }
+
+    impl<'a, A, B, V, S> RefFuncMap<'a, A, B, TypeParam<0>> for HashMap<A, V, S>
+    where
+        B: Eq + Hash,
+        V: Clone,
+    {
+        type Output = HashMap<B, V>;
+
+        fn ref_func_map<F>(&'a self, mut f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            self.iter().map(|(k, v)| (f(k), v.clone())).collect()
+        }
+    }
+
+    impl<'a, K, A, B, S> RefFuncMap<'a, A, B, TypeParam<1>> for HashMap<K, A, S>
+    where
+        K: Clone + Eq + Hash,
+    {
+        type Output = HashMap<K, B>;
+
+        fn ref_func_map<F>(&'a self, mut f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            self.iter().map(|(k, v)| (k.clone(), f(v))).collect()
+        }
+    }
+
+    impl<K, A, B, S> FuncFilterMap<A, B> for HashMap<K, A, S>
+    where
+        K: Eq + Hash,
+    {
+        type Output = HashMap<K, B>;
+
+        fn func_filter_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> Option<B>,
+        {
+            self.into_iter()
+                .filter_map(|(k, v)| f(v).map(|v| (k, v)))
+                .collect()
+        }
+    }
+
+    impl<K, L, V, W, S> FuncMapEntries<K, L, V, W> for HashMap<K, V, S>
+    where
+        L: Eq + Hash,
+    {
+        type Output = HashMap<L, W>;
+
+        fn func_map_entries<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut((K, V)) -> (L, W),
+        {
+            self.into_iter().map(f).collect()
+        }
+    }
+
+    impl<K, L, V, W, S> TryFuncMapEntries<K, L, V, W> for HashMap<K, V, S>
+    where
+        L: Eq + Hash,
+    {
+        type Output = HashMap<L, W>;
+
+        fn try_func_map_entries<E, F>(self, f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut((K, V)) -> Result<(L, W), E>,
+        {
+            self.into_iter().map(f).collect()
+        }
+    }
+
     impl<A, B, V, S> FuncMap<A, B, TypeParam<0>> for HashMap<A, V, S>
     where
         B: Eq + Hash,
@@ -38,6 +142,36 @@ mod hash_map {
         }
     }
 
+    impl<A, B, V, S> DrainFuncMap<A, B, TypeParam<0>> for HashMap<A, V, S>
+    where
+        B: Eq + Hash,
+    {
+        fn drain_func_map<F>(&mut self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            self.drain().map(|(k, v)| (f(k), v)).collect()
+        }
+    }
+
+    impl<A, B, V, S> TryFuncMapReserve<A, B, TypeParam<0>> for HashMap<A, V, S>
+    where
+        B: Eq + Hash,
+    {
+        fn try_func_map_reserve<E, F>(self, mut f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            let mut output = HashMap::with_capacity(self.len());
+
+            for (k, v) in self {
+                output.insert(f(k)?, v);
+            }
+
+            Ok(output)
+        }
+    }
+
     impl<K, A, B, S> FuncMap<A, B, TypeParam<1>> for HashMap<K, A, S>
     where
         K: Eq + Hash,
@@ -52,6 +186,18 @@ mod hash_map {
         }
     }
 
+    impl<K, A, B, S> DrainFuncMap<A, B, TypeParam<1>> for HashMap<K, A, S>
+    where
+        K: Eq + Hash,
+    {
+        fn drain_func_map<F>(&mut self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            self.drain().map(|(k, v)| (k, f(v))).collect()
+        }
+    }
+
     impl<K, A, B, S> TryFuncMap<A, B, TypeParam<1>> for HashMap<K, A, S>
     where
         K: Eq + Hash,
@@ -66,6 +212,24 @@ mod hash_map {
         }
     }
 
+    impl<K, A, B, S> TryFuncMapReserve<A, B, TypeParam<1>> for HashMap<K, A, S>
+    where
+        K: Eq + Hash,
+    {
+        fn try_func_map_reserve<E, F>(self, mut f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            let mut output = HashMap::with_capacity(self.len());
+
+            for (k, v) in self {
+                output.insert(k, f(v)?);
+            }
+
+            Ok(output)
+        }
+    }
+
     impl<A, B, V> FuncMap<A, B, TypeParam<0>> for hash_map::IntoIter<A, V>
     where
         B: Eq + Hash,
@@ -133,11 +297,65 @@ mod hash_map {
 
 /// Implementations for types in [`std::collections::hash_set`]
 mod hash_set {
-    use crate::{FuncMap, TryFuncMap};
+    use crate::{DrainFuncMap, FuncCount, FuncFilterMap, FuncMap, RefFuncMap, TryFuncMap};
 
     use core::hash::Hash;
     use std::collections::{hash_set, HashSet};
 
+    impl<A, S> FuncCount<A> for HashSet<A, S> {
+        fn func_count<F>(&self, f: F) -> usize
+        where
+            F: FnMut(&A) -> usize,
+        {
+            self.iter().map(f).sum()
+        }
+
+        fn count_leaves(&self) -> usize {
+            self.len()
+        }
+    }
+
+    impl<'a, A, B, S> RefFuncMap<'a, A, B> for HashSet<A, S>
+    where
+        B: Eq + Hash,
+    {
+        type Output = HashSet<B>;
+
+        fn ref_func_map<F>(&'a self, f: F) -> Self::Output
+        where
+            A: 'a,
+            F: FnMut(&'a A) -> B,
+        {
+            self.iter().map(f).collect()
+        }
+    }
+
+    impl<A, B, S> FuncFilterMap<A, B> for HashSet<A, S>
+    where
+        B: Eq + Hash,
+    {
+        type Output = HashSet<B>;
+
+        fn func_filter_map<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> Option<B>,
+        {
+            self.into_iter().filter_map(f).collect()
+        }
+    }
+
+    impl<A, B, S> DrainFuncMap<A, B> for HashSet<A, S>
+    where
+        B: Eq + Hash,
+    {
+        fn drain_func_map<F>(&mut self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            self.drain().map(f).collect()
+        }
+    }
+
     impl<A, B, S> FuncMap<A, B> for HashSet<A, S>
     where
         B: Eq + Hash,
@@ -0,0 +1,127 @@
+//! A generic interval given by a pair of bounds
+//!
+//! Types parameterized over the endpoints of an interval often store them as a
+//! pair of [`Bound`]s. Using [`Interval`] instead of a tuple
+//! `(Bound<T>, Bound<T>)` keeps the type parameter at a single position, so
+//! deriving [`FuncMap`] or [`TryFuncMap`] for an enclosing type maps both
+//! endpoints through one implementation rather than through the nested
+//! implementations for tuples and [`Bound`]:
+//! ```
+//! use funcmap::interval::Interval;
+//! use funcmap::FuncMap;
+//!
+//! use std::ops::Bound;
+//!
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! struct Schedule<T> {
+//!     name: &'static str,
+//!     active: Interval<T>,
+//! }
+//!
+//! let schedule = Schedule {
+//!     name: "night",
+//!     active: Interval::from_range_bounds(&(22..)),
+//! };
+//!
+//! assert_eq!(
+//!     schedule.func_map(|hour| hour * 60),
+//!     Schedule {
+//!         name: "night",
+//!         active: Interval(Bound::Included(1320), Bound::Unbounded),
+//!     }
+//! );
+//! ```
+//!
+//! As [`Interval`] implements [`RangeBounds`], it can be used wherever a range
+//! is expected, e.g. for [`RangeBounds::contains`] or for slicing a
+//! [`BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.range).
+
+use crate::{FuncMap, TryFuncMap};
+
+use core::ops::{Bound, RangeBounds};
+
+/// An interval given by its start bound and its end bound
+///
+/// This is equivalent to the tuple `(Bound<T>, Bound<T>)` but implements
+/// [`FuncMap`] and [`TryFuncMap`] directly, mapping the start bound before the
+/// end bound.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Interval<T>(pub Bound<T>, pub Bound<T>);
+
+impl<T> Interval<T> {
+    /// Creates an interval from its start bound and its end bound
+    #[must_use]
+    pub fn new(start: Bound<T>, end: Bound<T>) -> Self {
+        Self(start, end)
+    }
+
+    /// Creates an interval with the same bounds as the given range, cloning
+    /// its endpoints
+    #[must_use]
+    pub fn from_range_bounds<R>(range: &R) -> Self
+    where
+        R: RangeBounds<T> + ?Sized,
+        T: Clone,
+    {
+        Self(range.start_bound().cloned(), range.end_bound().cloned())
+    }
+
+    /// Returns the bounds of this interval as references
+    #[must_use]
+    pub fn as_ref(&self) -> Interval<&T> {
+        Interval(self.0.as_ref(), self.1.as_ref())
+    }
+
+    /// Turns this interval into its start bound and its end bound
+    #[must_use]
+    pub fn into_bounds(self) -> (Bound<T>, Bound<T>) {
+        (self.0, self.1)
+    }
+}
+
+impl<T> RangeBounds<T> for Interval<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        self.0.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.1.as_ref()
+    }
+}
+
+impl<T> From<(Bound<T>, Bound<T>)> for Interval<T> {
+    fn from((start, end): (Bound<T>, Bound<T>)) -> Self {
+        Self(start, end)
+    }
+}
+
+impl<T> From<Interval<T>> for (Bound<T>, Bound<T>) {
+    fn from(interval: Interval<T>) -> Self {
+        interval.into_bounds()
+    }
+}
+
+impl<A, B> FuncMap<A, B> for Interval<A> {
+    type Output = Interval<B>;
+
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+    {
+        Interval(self.0.func_map(&mut f), self.1.func_map(f))
+    }
+}
+
+impl<A, B> TryFuncMap<A, B> for Interval<A> {
+    type Output = Interval<B>;
+
+    fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+    {
+        Ok(Interval(
+            self.0.try_func_map(&mut f)?,
+            self.1.try_func_map(f)?,
+        ))
+    }
+}
@@ -0,0 +1,180 @@
+//! Dynamic JSON trees with mappable scalar values
+
+use crate::{FuncMap, TryFuncMap};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde_json::{Map, Value};
+
+/// A dynamic JSON tree whose scalar values have type `T`
+///
+/// A [`Value`] from [`serde_json`] cannot implement [`FuncMap`] because it is
+/// not generic. [`DynLeaf<Value>`] has the same shape as a [`Value`] but keeps
+/// all scalar values (`null`, booleans, numbers and strings) as
+/// [`Leaf`](DynLeaf::Leaf)s of type `T`, so it can be mapped over `T`. This
+/// allows embedding a dynamic JSON tree into a generic type that derives
+/// [`FuncMap`] or [`TryFuncMap`], mapping its scalar values along with the
+/// other values of the type parameter:
+/// ```
+/// use funcmap::{DynLeaf, FuncMap};
+/// use serde_json::{json, Value};
+///
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Setting<T> {
+///     name: &'static str,
+///     default: T,
+///     extra: DynLeaf<T>,
+/// }
+///
+/// let setting = Setting {
+///     name: "timeout",
+///     default: json!(30),
+///     extra: DynLeaf::from_value(json!({ "min": 1, "max": [60, null] })),
+/// };
+///
+/// let setting = setting.func_map(|value| match value {
+///     Value::Number(n) => Value::from(n.as_u64().unwrap() * 1000),
+///     value => value,
+/// });
+///
+/// assert_eq!(setting.default, json!(30000));
+/// assert_eq!(
+///     setting.extra.into_value(),
+///     json!({ "min": 1000, "max": [60000, null] })
+/// );
+/// ```
+///
+/// The implementations of [`FuncMap`] and [`TryFuncMap`] visit the leaves in
+/// document order, i.e. the elements of an array in their order and the
+/// entries of an object in the order of the underlying [`Map`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DynLeaf<T> {
+    /// A scalar value
+    Leaf(T),
+
+    /// An array of trees
+    Array(Vec<DynLeaf<T>>),
+
+    /// An object, given by its entries in order
+    Object(Vec<(String, DynLeaf<T>)>),
+}
+
+impl DynLeaf<Value> {
+    /// Creates a tree from a [`Value`], keeping all of its scalar values as
+    /// [`Leaf`](DynLeaf::Leaf)s
+    #[must_use]
+    pub fn from_value(value: Value) -> Self {
+        match value {
+            Value::Array(values) => Self::Array(values.into_iter().map(Self::from_value).collect()),
+            Value::Object(map) => Self::Object(
+                map.into_iter()
+                    .map(|(key, value)| (key, Self::from_value(value)))
+                    .collect(),
+            ),
+            value => Self::Leaf(value),
+        }
+    }
+}
+
+impl<T> DynLeaf<T> {
+    /// Turns this tree into a [`Value`], converting all of its
+    /// [`Leaf`](DynLeaf::Leaf)s into [`Value`]s
+    ///
+    /// If an object contains the same key more than once, the last entry with
+    /// this key wins.
+    #[must_use]
+    pub fn into_value(self) -> Value
+    where
+        T: Into<Value>,
+    {
+        match self {
+            Self::Leaf(leaf) => leaf.into(),
+            Self::Array(trees) => Value::Array(trees.into_iter().map(Self::into_value).collect()),
+            Self::Object(entries) => Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, tree)| (key, tree.into_value()))
+                    .collect::<Map<_, _>>(),
+            ),
+        }
+    }
+}
+
+impl From<Value> for DynLeaf<Value> {
+    fn from(value: Value) -> Self {
+        Self::from_value(value)
+    }
+}
+
+impl<T> From<DynLeaf<T>> for Value
+where
+    T: Into<Value>,
+{
+    fn from(tree: DynLeaf<T>) -> Self {
+        tree.into_value()
+    }
+}
+
+impl<A, B> FuncMap<A, B> for DynLeaf<A> {
+    type Output = DynLeaf<B>;
+
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+    {
+        map_leaves(self, &mut f)
+    }
+}
+
+impl<A, B> TryFuncMap<A, B> for DynLeaf<A> {
+    type Output = DynLeaf<B>;
+
+    fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+    {
+        try_map_leaves(self, &mut f)
+    }
+}
+
+/// Maps the leaves of a tree, recursing with the same closure type to avoid
+/// instantiating the mapping for ever deeper references to the closure
+fn map_leaves<A, B, F>(tree: DynLeaf<A>, f: &mut F) -> DynLeaf<B>
+where
+    F: FnMut(A) -> B,
+{
+    match tree {
+        DynLeaf::Leaf(leaf) => DynLeaf::Leaf(f(leaf)),
+        DynLeaf::Array(trees) => {
+            DynLeaf::Array(trees.into_iter().map(|tree| map_leaves(tree, f)).collect())
+        }
+        DynLeaf::Object(entries) => DynLeaf::Object(
+            entries
+                .into_iter()
+                .map(|(key, tree)| (key, map_leaves(tree, f)))
+                .collect(),
+        ),
+    }
+}
+
+/// Fallible counterpart of [`map_leaves`]
+fn try_map_leaves<A, B, E, F>(tree: DynLeaf<A>, f: &mut F) -> Result<DynLeaf<B>, E>
+where
+    F: FnMut(A) -> Result<B, E>,
+{
+    Ok(match tree {
+        DynLeaf::Leaf(leaf) => DynLeaf::Leaf(f(leaf)?),
+        DynLeaf::Array(trees) => DynLeaf::Array(
+            trees
+                .into_iter()
+                .map(|tree| try_map_leaves(tree, f))
+                .collect::<Result<_, _>>()?,
+        ),
+        DynLeaf::Object(entries) => DynLeaf::Object(
+            entries
+                .into_iter()
+                .map(|(key, tree)| Ok((key, try_map_leaves(tree, f)?)))
+                .collect::<Result<_, _>>()?,
+        ),
+    })
+}
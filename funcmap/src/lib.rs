@@ -86,6 +86,38 @@
 //! your type such as arrays, tuples and many types from the standard library as
 //! well as your own types as long as they implement [`FuncMap`] themselves.
 //!
+//! The closure may return values borrowing from data outside of the type, such
+//! as string slices allocated in an arena or [`Cow`](std::borrow::Cow) values
+//! borrowing from a default. Derived implementations don't require the
+//! destination type to be `'static`, so the mapped value can live exactly as
+//! long as the borrowed data:
+//! ```
+//! # use funcmap::FuncMap;
+//! # use std::borrow::Cow;
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! struct Foo<T> {
+//!     value: T,
+//!     more_values: Vec<T>,
+//! }
+//!
+//! let default = String::from("default");
+//!
+//! let foo = Foo {
+//!     value: String::new(),
+//!     more_values: vec![String::from("a")],
+//! };
+//!
+//! let bar: Foo<Cow<'_, str>> = foo.func_map(|v| {
+//!     if v.is_empty() {
+//!         Cow::Borrowed(default.as_str())
+//!     } else {
+//!         Cow::Owned(v)
+//!     }
+//! });
+//!
+//! assert_eq!(bar.value, "default");
+//! ```
+//!
 //! Note that the purpose of the `funcmap` crate is just to provide utility
 //! functionality, so
 //! - you shouldn't depend on any of the items it exports in your public API,
@@ -170,11 +202,53 @@
 //! * It is a type defined in your crate for which you implement [`FuncMap`]
 //!   manually.
 //!
+//! The trait bounds for [`Vec<T0>`], `Box<T0>` and [`Option<T0>`] always hold,
+//! so they are left out if these types are referred to through fully qualified
+//! paths such as `std::vec::Vec` or `::core::option::Option`, which keeps the
+//! `where` clause short for deeply nested types. A type merely named `Vec` may
+//! be any type, so the trait bounds are kept in this case.
+//!
+//! As an exception, fields of types `BTreeMap<K, V>` or `HashMap<K, V>` where
+//! both `K` and `V` depend on `T` have their keys and values mapped together in
+//! a single pass, so the map is rebuilt only once. This only applies if the
+//! type is referred to through a fully qualified path such as
+//! `std::collections::BTreeMap` or `::alloc::collections::BTreeMap`, as a type
+//! merely named `BTreeMap` may be any type. Otherwise, the map is mapped over
+//! its keys and then over its values through its implementations of
+//! [`FuncMap`].
+//!
+//! Likewise, fields of types `Option<Box<U>>` or `Box<Option<U>>` where both
+//! [`Option`] and `Box` are referred to through fully qualified paths such as
+//! `std::option::Option` and `std::boxed::Box` are mapped by matching on the
+//! option directly rather than going through the implementations for both
+//! [`Option`] and `Box`, which keeps the generated code and its trait bounds
+//! small.
+//!
+//! Similarly, fields of types named `PhantomData<T0>` are assumed to be
+//! [`PhantomData`](core::marker::PhantomData) and are mapped without looking
+//! at `T0`, which may then be any type depending on `T`, including the
+//! unsupported ones below (e.g. `PhantomData<&'a T>`).
+//!
 //! Other types depending on `T` such as references (e.g. `&'a T`) or function
 //! pointers (e.g. `fn() -> T`) are not supported. This doesn't mean that `T`
 //! itself cannot be a reference type (it can), but just that it cannot occur
 //! behind a reference within `Foo<T>`.
 //!
+//! Iterator adapters from the standard library such as
+//! [`Peekable<I>`](core::iter::Peekable) and [`Fuse<I>`](core::iter::Fuse) are
+//! not supported either, as they provide no way of taking out the underlying
+//! iterator (respectively the peeked item), so there is no way of mapping them.
+//! Instead of such an adapter, you can store the underlying iterator in a field
+//! of `Foo<T>` itself, e.g. `vec::IntoIter<T>` together with an `Option<T>` for
+//! the peeked item.
+//!
+//! The same goes for [`array::IntoIter<T, N>`](core::array::IntoIter): once it
+//! has yielded some of its items, it holds fewer than `N` of them, and an
+//! `array::IntoIter<U, N>` with fewer than `N` items cannot be constructed on
+//! stable Rust, so there is no way of mapping it without panicking. Instead,
+//! you can store the remaining items in a `Vec<T>`, or an array `[T; N]`
+//! together with the index of the next item.
+//!
 //! You can have a look at the code generated by the [`FuncMap`] derive macro
 //! by using [`cargo-expand`](https://github.com/dtolnay/cargo-expand).
 //!
@@ -219,7 +293,9 @@
 //! where `Bar<A>` also implements [`FuncMap<A, B>`]. If `Bar<T>` doesn't
 //! implement [`FuncMap`] *at all*, this condition is never satisfied. In this
 //! case, the derived implementation still compiles but doesn't add any
-//! functionality.
+//! functionality. For field types defined in the same crate, this can be
+//! turned into a compile error through
+//! [`#[funcmap(strict)]`](#funcmapstrict).
 //!
 //! **Note:** If your crate's public API contains types deriving [`FuncMap`],
 //! this creates a
@@ -238,8 +314,11 @@
 //! the fields of `Foo<A>` and `Foo<B>` to be [`Sized`]. In the case of a type
 //! depending on `A` (in `Foo<A>`) or `B` (in the output), this is implicit in
 //! the [`FuncMap`] trait bounds mentioned in the previous section. For types
-//! that don't depend on `A` or `B`, the [`FuncMap`] derive macro adds an
-//! explicit [`Sized`] bound to the derived implementation.
+//! that don't depend on `A` or `B` but on other type parameters, the
+//! [`FuncMap`] derive macro adds an explicit [`Sized`] bound to the derived
+//! implementation. Types not depending on any type parameter, such as `u64`,
+//! are [`Sized`] or not regardless of the mapping, so no bound is added for
+//! them.
 //!
 //! This is again because a field could have a type `Bar<T>` that is generic
 //! over another type parameter `T` different from `A` and `B` and `Bar<T>`
@@ -256,7 +335,23 @@
 //! [`Copy`], but in this case it would very likely make no sense anyway for the
 //! reasons described
 //! [here](https://doc.rust-lang.org/std/marker/trait.Copy.html#when-cant-my-type-be-copy),
-//! so it is still disallowed.)
+//! so it is still disallowed.) If the type doesn't actually implement [`Drop`]
+//! where the implementation is derived, or its fields are accessed only
+//! through custom functions, the check can be skipped using
+//! [`#[funcmap(assume_no_drop)]`](#funcmapassume_no_drop).
+//!
+//! The check works by implementing a hidden marker trait for the type, which
+//! conflicts with a blanket implementation of the same trait for all types
+//! implementing [`Drop`]. The name of the marker trait is chosen to explain
+//! the problem, so the compile error reads like this:
+//! ```text
+//! error[E0119]: conflicting implementations of trait `funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop` for type `Foo<_>`
+//! ```
+//! (with `TryFuncMap` in place of `FuncMap` when deriving [`TryFuncMap`]). It
+//! is usually followed by errors about moving out of a type that implements
+//! the [`Drop`] trait (`E0509`), which have the same cause. The marker traits
+//! are an implementation detail of the derive macros and are only meant to be
+//! read in error messages, not to be used directly.
 //!
 //! However, if a type `Foo<T>` implements [`Drop`], you can still implement
 //!  [`FuncMap`] for `Foo<T>` manually. For instance, in the case where all the
@@ -379,6 +474,73 @@
 //! [`try_func_map`](TryFuncMap::try_func_map) returns the first one according
 //! to the order of the fields in the definition of `Foo<T>`.
 //!
+//! # Traversal Order
+//!
+//! The closure passed to [`func_map`](FuncMap::func_map) is invoked exactly
+//! once for every value of the mapped type, in a well-defined order, so you
+//! can rely on this order for stateful closures such as counters or interners:
+//! ```
+//! # use funcmap::FuncMap;
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! struct Foo<T> {
+//!     value: T,
+//!     more_values: Vec<(T, Option<T>)>,
+//!     last_value: T,
+//! }
+//!
+//! let foo = Foo {
+//!     value: "a",
+//!     more_values: vec![("b", None), ("c", Some("d"))],
+//!     last_value: "e",
+//! };
+//!
+//! let mut count = 0;
+//!
+//! let bar = foo.func_map(|v| {
+//!     count += 1;
+//!     (count, v)
+//! });
+//!
+//! assert_eq!(
+//!     bar,
+//!     Foo {
+//!         value: (1, "a"),
+//!         more_values: vec![((2, "b"), None), ((3, "c"), Some((4, "d")))],
+//!         last_value: (5, "e"),
+//!     }
+//! );
+//! ```
+//!
+//! The order is depth-first and left-to-right:
+//! - The derive macros visit the fields in the order of their definition,
+//!   visiting all values within a field before moving on to the next field.
+//!   The same holds for the components of tuples and for the elements of
+//!   arrays within the types of fields.
+//! - The provided implementations for sequences and iterators, e.g. for
+//!   [`Vec`](std::vec::Vec), [`VecDeque`](std::collections::VecDeque),
+//!   [`Option`] or [`vec::IntoIter`](std::vec::IntoIter), visit the values
+//!   in iteration order.
+//! - The provided implementations for ranges, e.g. [`Range`](core::ops::Range),
+//!   and for [`Interval`](interval::Interval) visit the start before the end.
+//! - The provided implementations for [`BTreeMap`](std::collections::BTreeMap)
+//!   and [`BTreeSet`](std::collections::BTreeSet) visit the values in
+//!   ascending order of the keys, both when mapping over the keys and when
+//!   mapping over the values.
+//! - For fields of map types whose keys and values both depend on the mapped
+//!   type parameter, the derive macros visit the key and then the value of one
+//!   entry after another if the map is referred to through a fully qualified
+//!   path such as `std::collections::BTreeMap`, as it is mapped in a single
+//!   pass. Otherwise, they visit all keys before all values.
+//! - The provided implementations for
+//!   [`BinaryHeap`](std::collections::BinaryHeap),
+//!   [`HashMap`](std::collections::HashMap) and
+//!   [`HashSet`](std::collections::HashSet) visit the values in their
+//!   iteration order, which is unspecified.
+//!
+//! For [`try_func_map`](TryFuncMap::try_func_map), the closure is invoked in
+//! the same order until it fails for the first time, after which it is not
+//! invoked anymore and the error is returned.
+//!
 //! # Multiple Type Parameters
 //!
 //! When a type is generic over multiple type parameters, then the [`FuncMap`]
@@ -487,6 +649,12 @@
 //! [`func_map_over`](FuncMap::func_map_over) has a second parameter that is the
 //! type of the given closure.
 //!
+//! If you prefer to pass the type parameter as a value, e.g. from generic
+//! helper functions, you can use [`func_map_at`](FuncMap::func_map_at) with a
+//! value of type [`Param<N>`] instead, such as `foo.func_map_at(P1, |x| x + 1)`
+//! where [`P1`](const@markers::P1) comes from the [`markers`] module and is
+//! also available as `funcmap::P1`.
+//!
 //! To improve readability and make your code more robust to changes, it is
 //! recommended to define type aliases for the markers that convey the meaning
 //! of the corresponding types and abstract away their concrete indices:
@@ -510,6 +678,99 @@
 //! assert_eq!(skewed, Size { width: 200, height: 300 });
 //! ```
 //!
+//! Alternatively, the [`func_map_over!`] macro lets you refer to a type
+//! parameter by its name, so you don't need to keep track of indices at all:
+//! ```
+//! # use funcmap::{func_map_over, FuncMap};
+//! #
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! struct Size<W, H> {
+//!     width: W,
+//!     height: H
+//! }
+//!
+//! let normal = Size { width: 100, height: 100 };
+//! let skewed = func_map_over!(normal, H, |h| h * 3);
+//!
+//! assert_eq!(skewed, Size { width: 100, height: 300 });
+//! ```
+//!
+//! If you map over type parameters by name a lot, the [`MapBuilders`] derive
+//! macro generates an inherent method `map_<param>` for every mapped type
+//! parameter, named after the parameter in snake case and delegating to the
+//! derived implementation of [`FuncMap`]:
+//! ```
+//! # use funcmap::{FuncMap, MapBuilders};
+//! #
+//! #[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+//! struct Size<Width, Height> {
+//!     width: Width,
+//!     height: Height
+//! }
+//!
+//! let normal = Size { width: 100, height: 100 };
+//! let skewed = normal
+//!     .map_width(|w| w * 2)
+//!     .map_height(|h| f64::from(h) * 1.5);
+//!
+//! assert_eq!(skewed, Size { width: 200, height: 150.0 });
+//! ```
+//!
+//! Similarly, the [`func_map_variant!`] macro maps a value of an enum only if
+//! it is a given variant, leaving values of all other variants untouched:
+//! ```
+//! # use funcmap::{func_map_variant, FuncMap};
+//! #
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! enum Length<T> {
+//!     Meters(T),
+//!     Feet(T),
+//! }
+//!
+//! let feet = Length::Feet(2.0);
+//! let feet = func_map_variant!(feet, Meters, |m| m * 100.0);
+//!
+//! assert_eq!(feet, Length::Feet(2.0));
+//! ```
+//!
+//! If two type parameters are instantiated with the same type, the
+//! [`JoinParams`] derive macro generates a mapping over both of them at once
+//! with a single closure, see the documentation of the [`JoinParams`] trait.
+//!
+//! Mapping values one at a time doesn't work if mapping the values of one type
+//! parameter requires values of another one, e.g. converting an amount
+//! depending on its currency. For such cases, the [`FuncMapFields`] derive
+//! macro generates an inherent method `func_map_fields` for a struct. It
+//! passes all fields to a single closure as a tuple, which returns a tuple of
+//! the mapped fields, while the method takes care of taking the struct apart
+//! and putting it back together with the new type parameters:
+//! ```
+//! # use funcmap::{FuncMap, FuncMapFields};
+//! #
+//! #[derive(FuncMapFields, Debug, PartialEq)]
+//! struct Prices<V, C> {
+//!     values: Vec<V>,
+//!     currency: C,
+//! }
+//!
+//! let prices = Prices { values: vec![100, 250], currency: "EUR" };
+//! let prices = prices.func_map_fields(|(values, currency)| {
+//!     let rate = if currency == "EUR" { 160 } else { 1 };
+//!     (values.func_map(|v| v * rate), String::from("JPY"))
+//! });
+//!
+//! assert_eq!(
+//!     prices,
+//!     Prices { values: vec![16000, 40000], currency: String::from("JPY") }
+//! );
+//! ```
+//!
+//! Within a field type, every mapped type parameter `T` is replaced with a type
+//! parameter named `T2` unless this name is already in use. Fields whose types
+//! don't depend on any mapped type parameter keep their types. Since the
+//! fields are moved out of the struct, this requires that the struct doesn't
+//! implement [`Drop`].
+//!
 //! By default, implementations for all type parameters are generated. You can
 //! restrict this to only a subset of the type parameters by configuration as
 //! described in the next section. This becomes necessary if any of the type
@@ -563,12 +824,41 @@
 //! type Bar<T> = Baz<'static, T>;
 //! ```
 //!
+//! If the type the alias stands for has a derived implementation of
+//! [`FuncMap`], then violations of this rule are detected at compile time,
+//! resulting in an error like "expected `PhantomData<A>`, found
+//! `PhantomData<i32>`" pointing to the offending field type.
+//!
+//! ## Caveat: Defaults of Type Parameters
+//!
+//! Defaults of type parameters are only a shorthand for writing a type and
+//! play no role in mapping. In particular, a default referring to an earlier
+//! type parameter is not recomputed when mapping over that parameter:
+//! ```
+//! # use funcmap::{FuncMap, TypeParam};
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! struct Foo<S, T = Vec<S>>(S, T);
+//!
+//! let foo: Foo<i32> = Foo(1, vec![2, 3]);
+//! let bar: Foo<String, Vec<i32>> = foo.func_map_over::<TypeParam<0>, _>(|v| v.to_string());
+//!
+//! assert_eq!(bar, Foo(String::from("1"), vec![2, 3]));
+//! ```
+//!
+//! Here, the result is a `Foo<String, Vec<i32>>` and not a `Foo<String>`,
+//! i.e. `Foo<String, Vec<String>>`, because mapping over `S` leaves the values
+//! of type `T` untouched. To map over both, map over `S` and `T` separately.
+//!
 //! # Customizing Derive Behavior
 //!
 //! When deriving [`FuncMap`] or [`TryFuncMap`] for a type, you can change the
 //! default behavior of the derive macro through the optional `#[funcmap]`
 //! helper attribute. This attribute may only be applied to the type itself, not
-//! to its fields or variants:
+//! to its fields or variants, except for the
+//! [`deref`](#funcmapderef-rebuild--) and
+//! [`opaque_via`](#funcmapopaque_via--) options. Any other attributes, e.g.
+//! helper attributes of other derive macros such as `#[serde(...)]`, are
+//! ignored wherever they occur:
 //! ```
 //! # use funcmap as my_funcmap;
 //! # use funcmap::{FuncMap, TryFuncMap};
@@ -597,6 +887,13 @@
 //! }
 //! ```
 //!
+//! `#[funcmap]` attributes can also be put into `#[cfg_attr(...)]`, e.g.
+//! `#[cfg_attr(feature = "x", funcmap(params(T)))]`. Since the compiler
+//! evaluates `cfg_attr` before invoking the derive macros, the options are used
+//! exactly if the condition holds. Otherwise the derive macros don't get to see
+//! them at all, so they cannot report errors within them either, and e.g. all
+//! type parameters are mapped as if there were no `params` option.
+//!
 //! Note that this way of customizing the derive macro doesn't distinguish
 //! between [`FuncMap`] and [`TryFuncMap`]. The options are always the same for
 //! both.
@@ -611,6 +908,11 @@
 //! of your `Cargo.toml` or invoke a re-exported `funcmap` derive in a public
 //! macro.
 //!
+//! The path is resolved in the same way as a path to a type at the position of
+//! the derived type, so it can be relative (`reexports::funcmap`), start with
+//! `crate::`, `self::` or `super::`, or have a leading colon (`::funcmap`), which
+//! in edition 2015 refers to the crate root.
+//!
 //! ## `#[funcmap(params(...))]`
 //!
 //! If a type has [multiple type parameters](#multiple-type-parameters), this
@@ -640,680 +942,4191 @@
 //! The `params` option can also be used to decrease compile time when a
 //! `FuncMap` implementation for some type parameter is not needed.
 //!
-//! # Manually Implementing [`FuncMap`] and [`TryFuncMap`]
+//! ## `#[funcmap(skip_params(...))]`
 //!
-//! Even though implementations of the traits in this crate are usually meant to
-//! be derived automatically, it can become necessary for you to implement the
-//! traits manually in some cases, for instance
-//! - when a type in your crate has a field depending on a type parameter in a
-//!   way that isn't supported by the [`FuncMap`] and [`TryFuncMap`] derive
-//!   macros, e.g. when you implement a low-level primitive such as your custom
-//!   version of [`Vec<T>`],
-//! - when you need a [`FuncMap`] or [`TryFuncMap`] implementation for a type in
-//!   a third-party crate that doesn't provide one.
+//! This is the opposite of [`params`](#funcmapparams), generating
+//! implementations for all type parameters *except* the listed ones. For types
+//! with many type parameters of which only a few need to be excluded, this is
+//! shorter than listing all others:
+//! ```
+//! # use funcmap::FuncMap;
+//! #[derive(FuncMap)]
+//! #[funcmap(skip_params(U))]
+//! struct Foo<'a, S, T, U, V> {
+//!     value: S,
+//!     more_values: Vec<T>,
+//!     reference: &'a U,
+//!     other_value: Option<V>,
+//! }
+//! ```
 //!
-//! In the latter case, since you cannot implement nor derive the trait for a
-//! third-party type due to the orphan rule, you can provide your own wrapper
-//! around it (following the *newtype* pattern) and implement the trait manually
-//! for the wrapper type:
+//! Here, implementations are generated for `S`, `T` and `V`. The
+//! `skip_params` option cannot be combined with the `params` option, and every
+//! listed parameter has to be a type parameter of the type. At least one type
+//! parameter has to remain.
+//!
+//! ## `#[funcmap(extra_bounds = "...")]`
+//!
+//! This adds a comma-separated list of `where` predicates to every generated
+//! implementation. It is meant as an escape hatch for cases where the bounds
+//! inferred by the derive macro are not what you need. Within the predicates,
+//! `A` and `B` refer to the source and destination type of the mapping,
+//! respectively, i.e. to the type parameter being mapped over before and after
+//! the mapping:
 //! ```
 //! # use funcmap::FuncMap;
-//! #
-//! // Pretend that this is an external crate, not a module
-//! mod third_party {
-//!     # #[derive(Debug, PartialEq)]
-//!     pub struct List<T> {
-//!         // ...
-//!         # pub value: Option<T>,
+//! #[derive(FuncMap)]
+//! #[funcmap(extra_bounds = "A: Clone, B: Default")]
+//! struct Foo<T> {
+//!     value: T,
+//! }
+//! ```
+//! Here, `Foo<A>` only implements `FuncMap<A, B>` if `A: Clone` and
+//! `B: Default`.
+//!
+//! Consequently, a type parameter of the type itself that is named `A` or `B`
+//! cannot be referred to within the predicates. Mentioning it is reported as
+//! an error, so you have to rename the type parameter in this case.
+//!
+//! ## `#[funcmap(drop_bounds(...))]`
+//!
+//! This is the counterpart of [`extra_bounds`](#funcmapextra_bounds--),
+//! omitting the given bounds from every generated implementation, where again
+//! `A` and `B` refer to the source and destination type of the mapping. It is
+//! meant for bounds that the derive macro copies from the type or infers from
+//! its fields but that are not needed for mapping, e.g. because they are
+//! implied by other bounds. For instance, a bound on a sealed trait of another
+//! crate that is a supertrait of a public trait can be left to the public
+//! trait:
+//! ```
+//! # use funcmap::FuncMap;
+//! mod other {
+//!     pub mod sealed {
+//!         pub trait Marker {}
 //!     }
 //!
-//!     impl<A> List<A> {
-//!         pub fn map<B>(self, f: impl FnMut(A) -> B) -> List<B> {
-//!             // ...
-//!             # List { value: self.value.map(f) }
-//!         }
+//!     pub trait Scalar: sealed::Marker {}
+//! }
+//!
+//! #[derive(FuncMap)]
+//! #[funcmap(drop_bounds(A: other::sealed::Marker, B: other::sealed::Marker))]
+//! struct Foo<T: other::Scalar + other::sealed::Marker> {
+//!     value: T,
+//! }
+//! ```
+//! Here, `Foo<A>` implements `FuncMap<A, B>` if `A: other::Scalar` and
+//! `B: other::Scalar`, without mentioning the sealed trait.
+//!
+//! Every given bound has to occur in at least one generated implementation,
+//! otherwise it is reported as an error. Note that bounds needed for the
+//! source or destination type to be well-formed, such as bounds declared on
+//! the type that are not implied by other bounds, cannot be dropped, as the
+//! generated implementation would not compile. This option is ignored by
+//! derive macros other than [`FuncMap`] and [`TryFuncMap`].
+//!
+//! ## `#[funcmap(idents(...))]`
+//!
+//! The generated implementations introduce type parameters for the source and
+//! destination type of the mapping. By default, these are named `A` and `B`
+//! unless these names are already used within the type, in which case the
+//! first unused name among the letters following `A` (respectively `B`) in
+//! the alphabet, wrapping around from `Z` to `A`, is used, and only if all
+//! letters are used, a prefix `__FUNCMAP_` and then a numeric suffix are
+//! added. The names only depend on the names used within the type, so the
+//! same type always leads to the same generated code. With this option, you
+//! can choose the names yourself, e.g. to make the generated code more
+//! readable in the output of `cargo expand`:
+//! ```
+//! # use funcmap::FuncMap;
+//! #[derive(FuncMap)]
+//! #[funcmap(idents(src = "Src", dst = "Dst"))]
+//! struct Foo<T> {
+//!     value: T,
+//! }
+//! ```
+//! Here, the implementation is `impl<Src, Dst> FuncMap<Src, Dst> for Foo<Src>`.
+//! The given names must not be used within the type. Within
+//! [`#[funcmap(extra_bounds = "...")]`](#funcmapextra_bounds--), the source and
+//! destination type are still referred to as `A` and `B`.
+//!
+//! ## `#[funcmap(dyn_as = "...")]`
+//!
+//! Trait objects such as `dyn Trait<T>` are not supported by the derive macros
+//! because there is no general way to map over them. Using this option, you can
+//! instead provide a wrapper type through which every field type containing a
+//! trait object, such as `Box<dyn Trait<T>>`, is mapped. The wrapper must be a
+//! tuple struct generic over a single type parameter whose only field is of
+//! the type to be mapped, and it must implement [`FuncMap`] (respectively
+//! [`TryFuncMap`]) itself:
+//! ```
+//! # use funcmap::FuncMap;
+//! trait Values<T> {
+//!     fn into_vec(self: Box<Self>) -> Vec<T>;
+//! }
+//!
+//! impl<T> Values<T> for Vec<T> {
+//!     fn into_vec(self: Box<Self>) -> Vec<T> {
+//!         *self
 //!     }
 //! }
 //!
-//! // In your crate:
-//! # #[derive(Debug, PartialEq)]
-//! struct MyList<T>(third_party::List<T>);
+//! struct ValuesBox<T>(Box<dyn Values<T>>);
 //!
-//! impl<A, B> FuncMap<A, B> for MyList<A> {
-//!     type Output = MyList<B>;
+//! impl<A, B: 'static> FuncMap<A, B> for ValuesBox<A> {
+//!     type Output = ValuesBox<B>;
 //!
 //!     fn func_map<F>(self, f: F) -> Self::Output
 //!     where
 //!         F: FnMut(A) -> B,
 //!     {
-//!         MyList(self.0.map(f))
+//!         ValuesBox(Box::new(
+//!             self.0.into_vec().into_iter().map(f).collect::<Vec<_>>(),
+//!         ))
 //!     }
 //! }
 //!
-//! // Now you can derive `FuncMap` for types containing a `MyList<T>`:
-//! # #[derive(Debug, PartialEq)]
 //! #[derive(FuncMap)]
+//! #[funcmap(dyn_as = "ValuesBox")]
 //! struct Foo<T> {
-//!     list: MyList<T>,
+//!     values: Box<dyn Values<T>>,
 //! }
-//! #
-//! # let list = Foo {
-//! #     list: MyList(third_party::List {
-//! #         value: Some(1)
-//! #     })
-//! # };
-//! #
-//! # assert_eq!(
-//! #     list.func_map(|v| v.to_string()),
-//! #     Foo {
-//! #         list: MyList(third_party::List {
-//! #             value: Some(String::from("1"))
-//! #         })
-//! #     }
-//! # );
+//!
+//! let foo = Foo {
+//!     values: Box::new(vec![1, 2]),
+//! };
+//!
+//! assert_eq!(foo.func_map(|v| v + 1).values.into_vec(), [2, 3]);
 //! ```
 //!
-//! For details on the exact contract to uphold when writing manual
-//! implementations, see the API documentations of [`FuncMap`] and
-//! [`TryFuncMap`].
+//! The wrapper is used for all fields whose type is a generic type with a
+//! trait object among its generic arguments, e.g. also within `Vec<Box<dyn
+//! Values<T>>>`, so it can only be used for one kind of trait object per type.
 //!
-//! Note that if you have already implemented [`TryFuncMap`] for a type, you can
-//! then always implement [`FuncMap`] like this:
+//! ## `#[funcmap(free_fn = "...")]`
+//!
+//! This makes the [`FuncMap`] derive macro additionally generate a free
+//! function with the given name that maps the type in the same way as the
+//! generated implementation, but can be called without the [`FuncMap`] trait
+//! in scope:
 //! ```
-//! use funcmap::{FuncMap, TryFuncMap};
+//! # use funcmap::FuncMap;
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! #[funcmap(free_fn = "map_foo")]
+//! struct Foo<T> {
+//!     value: T,
+//!     more_values: Vec<T>,
+//! }
 //!
-//! # #[derive(Debug, PartialEq)]
+//! let foo = Foo {
+//!     value: 1,
+//!     more_values: vec![2, 3],
+//! };
+//!
+//! assert_eq!(
+//!     map_foo(foo, |v| v + 1),
+//!     Foo {
+//!         value: 2,
+//!         more_values: vec![3, 4],
+//!     }
+//! );
+//! ```
+//!
+//! The function is `pub(crate)` and has the signature
+//! `fn map_foo<A, B, F>(value: Foo<A>, f: F) -> Foo<B> where F: FnMut(A) -> B`
+//! plus the bounds of the generated implementation. It doesn't make use of the
+//! implementation for `Foo`, but fields of nested types such as `Vec<T>` are
+//! still mapped through their implementations of [`FuncMap`]. This option
+//! requires that exactly one type parameter is mapped, see
+//! [`#[funcmap(params(...))]`](#funcmapparams). It is ignored by the
+//! [`TryFuncMap`] derive macro.
+//!
+//! ## `#[funcmap(validate = "...")]`
+//!
+//! This passes the fully mapped value through the function at the given path
+//! before it is returned, e.g. to normalize it in a way that only makes sense
+//! after the mapping. The function takes the mapped value and
+//! returns a [`Result`] of the same type, where the error type is the one of
+//! the mapping closure:
+//! ```
+//! # use funcmap::{FuncMap, TryFuncMap};
+//! #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+//! #[funcmap(validate = "sorted", extra_bounds = "B: Ord")]
 //! struct Foo<T> {
-//!     // ...
-//! #   value: T,
+//!     values: Vec<T>,
 //! }
 //!
-//! impl<A, B> TryFuncMap<A, B> for Foo<A> {
-//!     type Output = Foo<B>;
+//! fn sorted<T: Ord, E>(mut foo: Foo<T>) -> Result<Foo<T>, E> {
+//!     foo.values.sort();
+//!     Ok(foo)
+//! }
 //!
-//!     // ...
-//! #
-//! #   fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
-//! #   where
-//! #       F: FnMut(A) -> Result<B, E>,
-//! #   {
-//! #       Ok(Foo {
-//! #           value: f(self.value)?
-//! #       })
-//! #   }
+//! let foo = Foo {
+//!     values: vec!["3", "1", "2"],
+//! };
+//!
+//! assert_eq!(
+//!     foo.try_func_map(|v| v.parse::<i32>()),
+//!     Ok(Foo {
+//!         values: vec![1, 2, 3],
+//!     })
+//! );
+//! ```
+//!
+//! For [`TryFuncMap`], the result of the function is returned as is, so no
+//! second pass over the mapped value is needed. Since the error type is chosen
+//! by the caller of [`try_func_map`](TryFuncMap::try_func_map), the function
+//! has to be generic over it. For [`FuncMap`], the function is called with the
+//! error type [`Infallible`](core::convert::Infallible), so it cannot fail.
+//! Bounds needed by the function,
+//! such as `B: Ord` above, can be added through
+//! [`#[funcmap(extra_bounds = "...")]`](#funcmapextra_bounds--).
+//!
+//! Note that the function cannot fail with an error of its own, not even for
+//! [`TryFuncMap`]: the error type `E` is a type parameter of the method
+//! [`try_func_map`](TryFuncMap::try_func_map) rather than of the
+//! implementation, and as the trait doesn't put any bounds on it, the
+//! generated implementation cannot require bounds such as `E: From<MyError>`
+//! either. The predicates given through `extra_bounds` apply to the
+//! implementation as a whole, where `E` is not in scope. So this option is
+//! meant for normalizing the mapped value. Validation that may fail needs to be
+//! done after the mapping, where the error type is known:
+//! ```
+//! # use funcmap::TryFuncMap;
+//! #[derive(TryFuncMap, Debug, PartialEq)]
+//! struct Range<T> {
+//!     start: T,
+//!     end: T,
 //! }
 //!
-//! impl<A, B> FuncMap<A, B> for Foo<A> {
-//!     type Output = Foo<B>;
+//! #[derive(Debug, PartialEq)]
+//! enum Error {
+//!     Parse(std::num::ParseIntError),
+//!     Empty,
+//! }
 //!
-//!     fn func_map<F>(self, mut f: F) -> Self::Output
-//!     where
-//!         F: FnMut(A) -> B,
-//!     {
-//!         self.try_func_map::<core::convert::Infallible, _>(|x| Ok(f(x))).unwrap()
+//! fn non_empty(range: Range<i32>) -> Result<Range<i32>, Error> {
+//!     if range.start < range.end {
+//!         Ok(range)
+//!     } else {
+//!         Err(Error::Empty)
 //!     }
 //! }
-//! #
-//! # let foo = Foo { value: 42 };
-//! # assert_eq!(foo.func_map(|x| x + 1), Foo { value: 43 });
-//! ```
 //!
-//! # `no_std` Support
+//! let range = Range {
+//!     start: "2",
+//!     end: "1",
+//! };
 //!
-//! `funcmap` has a Cargo feature named `std` that is enabled by default and
-//! provides implementations of [`FuncMap`] and [`TryFuncMap`] for many types
-//! from the [standard library](`std`). In order to use `funcmap` in a `no_std`
-//! context, modify your dependency on `funcmap` in `Cargo.toml` to opt out of
-//! default features:
-//! ```toml
-//! [dependencies]
-//! funcmap = { version = "...", default-features = false }
+//! assert_eq!(
+//!     range
+//!         .try_func_map(|v| v.parse().map_err(Error::Parse))
+//!         .and_then(non_empty),
+//!     Err(Error::Empty)
+//! );
 //! ```
 //!
-//! In this case, only implementations for types in the [`core`] library are
-//! provided. Note that this excludes implementations for all standard library
-//! types that involve heap memory allocation, such as [`Box<T>`] or [`Vec<T>`].
-//! In order to opt back in to these implementations, you can enable the `alloc`
-//! Cargo feature:
-//! ```toml
-//! [dependencies]
-//! funcmap = { version = "...", default-features = false, features = ["alloc"] }
+//! ## `#[funcmap(with = "...", rebuild = "...")]`
+//!
+//! This makes the derive macros access the fields of a struct only through the
+//! given functions, which is useful for types upholding invariants that must be
+//! re-established after the mapping. The function given by `with` decomposes
+//! the value into a tuple of its fields in the order of their definition and
+//! the function given by `rebuild` builds the mapped value from the tuple of
+//! the mapped fields. Within these paths, `Self` refers to the type with its
+//! parameters inferred:
 //! ```
+//! # use funcmap::FuncMap;
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! #[funcmap(
+//!     with = "Self::into_parts",
+//!     rebuild = "Self::from_parts",
+//!     extra_bounds = "B: Ord"
+//! )]
+//! struct SortedVec<T> {
+//!     values: Vec<T>,
+//! }
 //!
-//! This will provide implementations for many types in the [`alloc`] library.
+//! impl<T: Ord> SortedVec<T> {
+//!     fn new(mut values: Vec<T>) -> Self {
+//!         values.sort();
+//!         Self { values }
+//!     }
 //!
-//! # Functional Programming Background
+//!     fn from_parts((values,): (Vec<T>,)) -> Self {
+//!         Self::new(values)
+//!     }
+//! }
 //!
-//! The idea of `funcmap` is based on the *functor* design pattern from
-//! functional programming, which in turn is inspired from the notion of a
-//! functor in category theory.
+//! impl<T> SortedVec<T> {
+//!     fn into_parts(self) -> (Vec<T>,) {
+//!         (self.values,)
+//!     }
+//! }
 //!
-//! Basically, `F` is a functor if
-//! 1. it associates each type `T` with a new type `F(T)`
-//! 2. it associates each function
-//!     ```plain
-//!     f: A -> B
-//!     ```
-//!     with a function
-//!     ```plain
-//!     F(f): F(A) -> F(B)
-//!     ```
-//!     such that the following *functor laws* are satisfied:
-//!     - `F(id) = id` where `id` is the identity function on `A`, respectively
-//!       `F(A)`
-//!     - `F(g . f) = F(g) . F(f)` for any two functions `f: A -> B` and
-//!       `g: B -> C`, where `g . f` denotes function composition
+//! let foo = SortedVec::new(vec![1, 2, 3]);
 //!
-//! In languages with higher-kinded types such as Haskell, this property of
-//! being a functor is expressed as a *type class* (similar to a trait) called
-//! [Functor](https://wiki.haskell.org/Functor) that the higher-kinded type `F`
-//! is an instance of.
+//! assert_eq!(foo.func_map(|v| -v), SortedVec::new(vec![-3, -2, -1]));
+//! ```
 //!
-//! In Rust, property 1. is satisfied for every type `Foo<T>` that is generic
-//! over a type parameter `T` because it associates each type `T` with a new
-//! type `Foo<T>`, at least for those types `T` that satisfy all trait bounds
-//! that `Foo<T>` imposes on `T`.
+//! Both options must be configured together and are only supported for
+//! structs. With [`#[funcmap(validate = "...")]`](#funcmapvalidate--), the
+//! rebuilt value is passed through the validation function.
 //!
-//! Property 2. is where the [`FuncMap`] trait comes into play. As there are no
-//! higher-kinded types in Rust as of now, it cannot be expressed by `Foo`
-//! itself implementing a trait, because while `Foo<T>` is a type for every `T`,
-//! `Foo` itself (without the `<T>`) isn't something one can reason about within
-//! the Rust type system. However, one can say that `Foo` is a functor if and
-//! only if
+//! ## `#[funcmap(assume_no_drop)]`
+//!
+//! This skips the check that the type doesn't implement [`Drop`] (see
+//! [Types Implementing `Drop`](#types-implementing-drop)). Ensuring that the
+//! derived implementation compiles nonetheless is then your responsibility.
+//! This is useful if the check fails in a confusing way, e.g. because of a
+//! [`Drop`] implementation that only exists under a `cfg` under which the
+//! derive macros are not used, or if the fields are only accessed through
+//! [`#[funcmap(with = "...", rebuild = "...")]`](#funcmapwith---rebuild--),
+//! which works for types implementing [`Drop`]:
 //! ```
 //! # use funcmap::FuncMap;
-//! #
-//! # #[derive(FuncMap)]
-//! # struct Foo<T>(T);
-//! #
-//! # enum A {}
-//! # enum B {}
-//! #
-//! # fn test() where
-//! Foo<A>: FuncMap<A, B, Output = Foo<B>>
-//! # {}
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! #[funcmap(assume_no_drop, with = "Self::into_parts", rebuild = "Self::from_parts")]
+//! struct Handle<T> {
+//!     value: Option<T>,
+//! }
+//!
+//! impl<T> Drop for Handle<T> {
+//!     fn drop(&mut self) {
+//!         // apply some cleanup logic
+//!     }
+//! }
+//!
+//! impl<T> Handle<T> {
+//!     fn into_parts(mut self) -> (Option<T>,) {
+//!         (self.value.take(),)
+//!     }
+//!
+//!     fn from_parts((value,): (Option<T>,)) -> Self {
+//!         Self { value }
+//!     }
+//! }
+//!
+//! let handle = Handle { value: Some(1) };
+//!
+//! assert_eq!(handle.func_map(|v| v + 1), Handle { value: Some(2) });
 //! ```
-//! holds for all types `A` and `B` for which `Foo<T>` exists. The function
-//! `Foo<A> -> Foo<B>` associated with a function `f: A -> B` (in Rust:
-//! `f: impl FnMut(A) -> B`) by property 2. is then provided by the
-//! [`func_map`](FuncMap::func_map) method as the function
+//!
+//! ## `#[funcmap(infallible_via_try)]`
+//!
+//! This makes the [`TryFuncMap`] derive macro also generate implementations of
+//! [`FuncMap`] that delegate to the ones of [`TryFuncMap`] with the error type
+//! [`Infallible`](core::convert::Infallible), as described in
+//! [Manually Implementing `FuncMap` and `TryFuncMap`](#manually-implementing-funcmap-and-tryfuncmap).
+//! This keeps the generated code small compared to deriving both traits, where
+//! the traversal of the fields is generated twice:
+//! ```
+//! # use funcmap::{FuncMap, TryFuncMap};
+//! #[derive(TryFuncMap, Debug, PartialEq)]
+//! #[funcmap(infallible_via_try)]
+//! struct Foo<T> {
+//!     value: T,
+//!     more_values: Vec<T>,
+//! }
+//!
+//! let foo = Foo {
+//!     value: 1,
+//!     more_values: vec![2, 3],
+//! };
+//!
+//! assert_eq!(
+//!     foo.func_map(|v| v + 1),
+//!     Foo {
+//!         value: 2,
+//!         more_values: vec![3, 4],
+//!     }
+//! );
+//! ```
+//!
+//! With this option, the [`FuncMap`] derive macro must not be used on the same
+//! type. Options only affecting the [`FuncMap`] derive macro, such as
+//! [`#[funcmap(free_fn = "...")]`](#funcmapfree_fn--), have no effect.
+//!
+//! ## `#[funcmap(inline = "...")]`
+//!
+//! This puts an inline hint on the mapping functions generated by the derive
+//! macros, i.e. on [`func_map`](FuncMap::func_map),
+//! [`try_func_map`](TryFuncMap::try_func_map) and the function generated via
+//! [`#[funcmap(free_fn = "...")]`](#funcmapfree_fn--). The value `"always"`
+//! generates `#[inline(always)]`, `"hint"` generates `#[inline]` and `"never"`
+//! generates `#[inline(never)]`:
 //! ```
 //! # use funcmap::FuncMap;
-//! #
-//! # #[derive(FuncMap)]
-//! # struct Foo<T>(T);
-//! #
-//! # enum A {}
-//! # enum B {}
-//! #
-//! # fn test(f: impl FnMut(A) -> B) -> impl FnOnce(Foo<A>) -> Foo<B> {
-//! |x: Foo<A>| x.func_map(f)
-//! # }
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! #[funcmap(inline = "always")]
+//! struct Meters<T>(T);
+//!
+//! assert_eq!(Meters(1).func_map(|v| v * 1000), Meters(1000));
 //! ```
-//! So deriving the [`FuncMap`] trait for `Foo<T>` can be viewed as deriving
-//! Property 2. from Property 1. or equivalently, deriving a (hypothetical)
-//! *Functor*  trait for the (hypothetical) higher-kinded type `Foo`.
 //!
-//! In fact, the name of the [`func_map`](FuncMap::func_map) method is inspired
-//! from the
-//! [`fmap`](https://hackage.haskell.org/package/base-4.16.0.0/docs/Data-Functor.html#v:fmap)
-//! function of Haskell's `Functor` type class.
+//! Without this option, no hint is generated and the compiler decides on its
+//! own. Since the generated functions are generic, they can already be inlined
+//! across crates, so the option mostly helps with deeply nested graphs of small
+//! wrapper types where the compiler's heuristics give up too early.
+//!
+//! ## `#[funcmap(dyn_body)]`
+//!
+//! The generated mapping functions are generic over the type of the closure,
+//! so their code, including the code for mapping all fields, is instantiated
+//! once for every distinct closure the type is mapped with. For types that are
+//! mapped with many different closures across a large codebase, this can add
+//! up to a considerable amount of code. This option generates the body of
+//! [`func_map`](FuncMap::func_map) and [`try_func_map`](TryFuncMap::try_func_map)
+//! as an inner function taking the closure as a `&mut dyn FnMut`, so only a
+//! thin wrapper is instantiated per closure type:
+//! ```
+//! # use funcmap::FuncMap;
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! #[funcmap(dyn_body)]
+//! struct Readings<T> {
+//!     current: T,
+//!     history: Vec<T>,
+//! }
 //!
-//! # Edition support
+//! let readings = Readings {
+//!     current: 3,
+//!     history: vec![1, 2],
+//! };
 //!
-//! This crate supports all Rust editions. There is one caveat, however: When deriving [`FuncMap`] for a type in edition
-//! 2015 code that contains an identifier that is a keyword from edition 2018 onwards, i.e. one of `async`, `await`,
-//! `dyn` and `try`, the identifier has to be written as a raw identifier in the type definition:
-//! ```edition2015
+//! assert_eq!(
+//!     readings.func_map(|v| v * 10),
+//!     Readings {
+//!         current: 30,
+//!         history: vec![10, 20],
+//!     }
+//! );
+//! ```
+//!
+//! The trade-off is that the closure is called through dynamic dispatch, which
+//! prevents it from being inlined into the mapping code. The inner function is
+//! still instantiated once for every combination of generic arguments. A free
+//! function generated via [`#[funcmap(free_fn = "...")]`](#funcmapfree_fn--)
+//! delegates to the implementation rather than duplicating its body.
+//!
+//! ## `#[funcmap(allow(...))]`
+//!
+//! The code generated by the derive macros allows a number of lints that it
+//! could otherwise trigger in your crate, so that e.g. `#![deny(warnings)]`
+//! doesn't break your build. If a lint added in a future version of Rust fires
+//! within the generated code before `funcmap` allows it by default, you can
+//! allow it yourself by listing its path within this option:
+//! ```
+//! # use funcmap::FuncMap;
+//! #[derive(FuncMap)]
+//! #[funcmap(allow(unused_results, clippy::used_underscore_binding))]
+//! struct Foo<T>(T);
+//! ```
+//!
+//! The lints are allowed on all items generated by the derive macros, but not
+//! on the type itself.
+//!
+//! ## `#[funcmap(cfg(...))]`
+//!
+//! This puts a `#[cfg(...)]` attribute with the given predicate on all items
+//! generated by the derive macros, so they are only generated if the predicate
+//! holds. This lets you provide implementations of [`FuncMap`] only under an
+//! optional feature of your crate while the type itself is always available:
+//! ```
 //! # use funcmap::FuncMap;
-//! #
 //! #[derive(FuncMap)]
+//! #[funcmap(cfg(feature = "conversion"))]
+//! pub struct Foo<T>(T);
+//! ```
+//!
+//! Unlike with `#[cfg_attr(feature = "conversion", derive(FuncMap))]`, the
+//! `funcmap` options of the type are still validated if the predicate doesn't
+//! hold. If the option is given multiple times, the items are only generated if
+//! all of the predicates hold.
+//!
+//! ## `#[funcmap(assert_auto_traits(...))]`
+//!
+//! This makes the [`FuncMap`] derive macro additionally generate compile-time
+//! assertions that mapping preserves the given auto traits, i.e. that
+//! `Foo<B>` implements an auto trait whenever `Foo<A>` and `B` do:
+//! ```
+//! # use funcmap::FuncMap;
+//! # use std::sync::Arc;
+//! #[derive(FuncMap)]
+//! #[funcmap(assert_auto_traits(Send, Sync))]
 //! struct Foo<T> {
-//!     r#async: T, // this would fail with `async` instead of `r#async`
+//!     value: T,
+//!     shared: Arc<str>,
+//! }
+//! ```
+//!
+//! This way, changing a field type such that the type is no longer [`Send`]
+//! or [`Sync`] for some mapped values, e.g. from `Arc<T>` to `Rc<T>` or from
+//! `T` to `Cell<T>`, causes a compilation error at the definition of the type
+//! rather than wherever a mapped value is sent between threads. The assertion
+//! has to be provable from the bounds of fields not depending on the type
+//! parameter, so it also fails for types that never implement the auto trait.
+//! This option is ignored by the [`TryFuncMap`] derive macro.
+//!
+//! ## `#[funcmap(strict)]`
+//!
+//! This makes the derive macros additionally generate compile-time assertions
+//! that the types of the fields that are defined in the same crate derive the
+//! same trait. Without this option, a missing derive on such a type only makes
+//! the derived implementation unusable, as described in
+//! [`FuncMap` Trait Bounds](#funcmap-trait-bounds), which shows up as a
+//! confusing unsatisfied trait bound wherever the mapping is used. With this
+//! option, it causes a compilation error pointing to the field type instead:
+//! ```compile_fail
+//! # use funcmap::FuncMap;
+//! struct Bar<T>(T);
+//!
+//! #[derive(FuncMap)]
+//! #[funcmap(strict)]
+//! struct Foo<T> {
+//!     bar: Bar<T>, // error: `Bar<T>` doesn't derive `FuncMap`
+//! }
+//! ```
+//!
+//! As the derive macros cannot resolve paths, the types are selected by their
+//! syntax: a field type, or a type nested within it through generic arguments,
+//! tuples, arrays or slices, is considered to be defined in the same crate if
+//! it depends on a mapped type parameter and its path either starts with
+//! `crate`, `self` or `super` or consists of a single identifier that doesn't
+//! name one of the standard library types `funcmap` implements the traits for,
+//! such as `Option` or `Vec`. A type from another crate that is referred to by
+//! a single identifier can be excluded by referring to it through a longer
+//! path instead. Whether a type derives the trait is determined through the
+//! [`FuncMapParams`] or [`TryFuncMapParams`] trait, which the derive macros
+//! implement alongside, so a type implementing [`FuncMap`] manually can
+//! implement [`FuncMapParams`] as well to pass the assertion.
+//!
+//! ## `#[funcmap(canonical)]`
+//!
+//! The output of the derive macros is deterministic, i.e. the same input
+//! always produces the same code. However, the `where` clauses of the
+//! generated implementations list their predicates in the order in which the
+//! fields contributing them appear, so reordering the fields of a type or
+//! adding a field in the middle changes large parts of the generated code.
+//! This option brings the predicates, and the bounds within each predicate and
+//! on each generic parameter, into a canonical order, sorted by their textual
+//! representation with lifetime predicates first. This keeps diffs small when
+//! snapshotting the expanded code, e.g. with
+//! [`cargo expand`](https://github.com/dtolnay/cargo-expand) or
+//! [`macrotest`](https://docs.rs/macrotest):
+//! ```
+//! # use funcmap::FuncMap;
+//! #[derive(FuncMap)]
+//! #[funcmap(canonical)]
+//! struct Foo<T> {
+//!     values: Vec<T>,
+//!     value: Option<T>,
+//! }
+//! ```
+//!
+//! Generic parameters keep their order of declaration, since it determines the
+//! meaning of the type's generic arguments, and the names of generated helper
+//! items and parameters only depend on the input. The canonical order of the
+//! generated code for a given input is stable across patch releases of
+//! `funcmap`, so any change to it is a breaking change of the code generation.
+//! Formatting the code is left to the tool displaying it, as the derive macros
+//! emit a token stream without any layout.
+//!
+//! ## `#[funcmap(traced)]`
+//!
+//! This option is only available with the `tracing` Cargo feature and only
+//! used by the [`TryFuncMap`] derive macro. It makes
+//! [`try_func_map`](TryFuncMap::try_func_map) map every field depending on the
+//! type parameter within a [`tracing`](https://docs.rs/tracing) span named
+//! `try_func_map`, whose field `path` holds the static path of the field, e.g.
+//! `Foo.values` or `Foo::Bar.0`. As the mapping of nested types deriving
+//! [`TryFuncMap`] with this option happens within the span of the enclosing
+//! field, the spans active when the closure fails lead from the outermost type
+//! to the failing value:
+#![cfg_attr(feature = "tracing", doc = "```")]
+#![cfg_attr(not(feature = "tracing"), doc = "```ignore")]
+//! use funcmap::{traced, TryFuncMap};
+//!
+//! #[derive(TryFuncMap)]
+//! #[funcmap(traced)]
+//! struct Foo<T> {
+//!     bar: Bar<T>,
+//! }
+//!
+//! #[derive(TryFuncMap)]
+//! #[funcmap(traced)]
+//! enum Bar<T> {
+//!     Values(Vec<T>),
 //! }
 //!
 //! let foo = Foo {
-//!     async: 1,
+//!     bar: Bar::Values(vec!["1", "x"]),
 //! };
 //!
-//! let bar = foo.func_map(|v| v.to_string());
+//! // emits an error event within the spans for `Foo.bar` and `Bar::Values.0`
+//! let result = foo.try_func_map(traced(str::parse::<u32>));
 //!
-//! assert_eq!(bar.async, "1");
+//! assert!(result.is_err());
 //! ```
 //!
-//! # Minimum Supported Rust Version (MSRV) Policy
+//! The function `traced` used here wraps a closure so that it emits an error
+//! event for every error it returns, which then shows up in the output of your
+//! `tracing` subscriber along with the spans. The spans have level `ERROR`, so
+//! they are enabled whenever the error events are. Entering a span for every
+//! field has a runtime cost even if no subscriber is interested in it, so this
+//! option is meant for fallible mappings where the context of failures matters
+//! more than performance, such as data migrations.
 //!
-//! The current MSRV of this crate is `1.65`.
+//! ## `#[funcmap(deref, rebuild = "...")]`
 //!
-//! Increasing the MSRV of this crate is *not* considered a breaking change.
-//! However, in such cases there will be at least a minor version bump.
+//! This option is applied to a field rather than to the type itself. It is
+//! meant for fields whose type is a wrapper around a mappable type that
+//! implements [`DerefMut`](core::ops::DerefMut) but not [`FuncMap`], so you
+//! don't need to implement [`FuncMap`] manually for every such wrapper:
+//! ```
+//! # use funcmap::FuncMap;
+//! # use std::ops::{Deref, DerefMut};
+//! #[derive(Debug, PartialEq)]
+//! struct Tracked<T> {
+//!     value: T,
+//!     changed: bool,
+//! }
 //!
-//! Each version of this crate will support at least the four latest stable Rust
-//! versions at the time it is published.
-
-#![cfg_attr(not(feature = "std"), no_std)]
-#![deny(missing_copy_implementations)]
-#![deny(missing_debug_implementations)]
-#![deny(missing_docs)]
-#![deny(unreachable_pub)]
-#![deny(unused_crate_dependencies)]
-#![deny(unused_extern_crates)]
-#![deny(unused_lifetimes)]
-#![deny(unused_qualifications)]
-#![deny(clippy::all)]
-#![deny(clippy::pedantic)]
-#![deny(clippy::cargo_common_metadata)]
-#![deny(clippy::multiple_crate_versions)]
-#![deny(clippy::rest_pat_in_fully_bound_structs)]
-#![deny(clippy::use_debug)]
-#![allow(clippy::module_name_repetitions)]
-#![deny(rustdoc::broken_intra_doc_links)]
-#![deny(rustdoc::private_intra_doc_links)]
-#![deny(rustdoc::invalid_codeblock_attributes)]
-#![deny(rustdoc::invalid_rust_codeblocks)]
-#![deny(rustdoc::bare_urls)]
-
-mod array;
-mod impls_core;
-
-#[cfg(feature = "alloc")]
-extern crate alloc;
-
-#[cfg(feature = "alloc")]
-mod impls_alloc;
-
-#[cfg(feature = "std")]
-mod impls_std;
-
-use core::fmt::{self, Display, Formatter};
-
-/// Functorial mapping of a generic type over any of its type parameters
-///
-/// # Deriving [`FuncMap`]
-///
-/// In most cases, implementations of this trait can and should be derived
-/// automatically:
-/// ```
-/// # use funcmap::FuncMap;
-/// #
-/// #[derive(FuncMap)]
-/// struct Foo<T> {
-///     // ...
-///     # value: T,
-/// }
-/// ```
-///
-/// See the [crate-level documentation](crate) for details.
-///
-/// # Manually Implementing [`FuncMap`]
-///
-/// If you need to implement [`FuncMap`] manually, make sure to uphold the
-/// following contract:
-///
-/// Let `Foo` be a type that is generic over the type or const parameters
-/// `T0, ..., Tn`.
-///
-/// If `Foo` implements [`FuncMap<A, B, TypeParam<N>>`], then
-/// - `N` must be in the range `0..=n`.
-/// - The parameter of `Foo` at index `N` (not counting lifetime parameters)
-///   must be `A`. In particular, it must be a type parameter, not a const
-///   generic.
-/// - `Foo::Output` must be `Foo` with the parameter at index `N` replaced with
+//! impl<T> Tracked<T> {
+//!     fn new(value: T) -> Self {
+//!         Self { value, changed: false }
+//!     }
+//! }
+//!
+//! impl<T> Deref for Tracked<T> {
+//!     // ...
+//! #   type Target = T;
+//! #
+//! #   fn deref(&self) -> &T {
+//! #       &self.value
+//! #   }
+//! }
+//!
+//! impl<T> DerefMut for Tracked<T> {
+//!     // ...
+//! #   fn deref_mut(&mut self) -> &mut T {
+//! #       self.changed = true;
+//! #       &mut self.value
+//! #   }
+//! }
+//!
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! struct Foo<T> {
+//!     #[funcmap(deref, rebuild = "Tracked::new")]
+//!     values: Tracked<Vec<T>>,
+//! }
+//!
+//! let foo = Foo {
+//!     values: Tracked::new(vec![1, 2]),
+//! };
+//!
+//! assert_eq!(
+//!     foo.func_map(|v| v * 2),
+//!     Foo {
+//!         values: Tracked::new(vec![2, 4]),
+//!     }
+//! );
+//! ```
+//!
+//! As the derive macros cannot resolve the target of
+//! [`Deref`](core::ops::Deref), it is taken to be the only type argument of the
+//! type of the field, e.g. `Vec<T>` for `Tracked<Vec<T>>`, which is then mapped
+//! like any other field. The target is taken out of the field via
+//! [`mem::take`](core::mem::take), so it needs to implement [`Default`], and
+//! the field is rebuilt by passing the mapped target to the function given by
+//! `rebuild`. Note that this doesn't preserve any other state of the wrapper.
+//!
+//! ## `#[funcmap(opaque_via = "...")]`
+//!
+//! This option is applied to a field rather than to the type itself and is
+//! only available with the `nightly` Cargo feature. It is meant for fields
+//! whose type is an opaque type the derive macros cannot see through, most
+//! notably a type alias for `impl Trait` mentioning the type parameter (which
+//! requires the unstable `type_alias_impl_trait` language feature). In this
+//! example, `Values<T>` stands in for such a type, as it can only be mapped
+//! over through the function `map_values`:
+#![cfg_attr(feature = "nightly", doc = "```")]
+#![cfg_attr(not(feature = "nightly"), doc = "```ignore")]
+//! use funcmap::FuncMap;
+//!
+//! mod opaque {
+//!     #[derive(Debug, PartialEq)]
+//!     pub struct Values<T>(Vec<T>);
+//!
+//!     pub fn values<T>(values: Vec<T>) -> Values<T> {
+//!         Values(values)
+//!     }
+//!
+//!     pub fn map_values<A, B>(values: Values<A>, f: impl FnMut(A) -> B) -> Values<B> {
+//!         Values(values.0.into_iter().map(f).collect())
+//!     }
+//! }
+//!
+//! use opaque::{map_values, values, Values};
+//!
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! struct Foo<T> {
+//!     #[funcmap(opaque_via = "map_values")]
+//!     values: Values<T>,
+//! }
+//!
+//! let foo = Foo {
+//!     values: values(vec![1, 2]),
+//! };
+//!
+//! assert_eq!(
+//!     foo.func_map(|v| v * 2),
+//!     Foo {
+//!         values: values(vec![2, 4]),
+//!     }
+//! );
+//! ```
+//!
+//! Instead of deriving a mapping from the type of the field, the field is then
+//! passed to the given function along with a mutable reference to the mapping
+//! closure. For [`TryFuncMap`], the function must return a [`Result`] and the
+//! closure returns a [`Result`] as well.
+//!
+//! ## `#[funcmap(from = "...")]`
+//!
+//! This is only used by the [`StructuralFrom`] derive macro, see
+//! [Converting Between Types of the Same Shape](#converting-between-types-of-the-same-shape).
+//! It is ignored by the [`FuncMap`] and [`TryFuncMap`] derive macros.
+//!
+//! # Converting Between Types of the Same Shape
+//!
+//! When a generic type evolves, e.g. between versions of a schema, you
+//! sometimes end up with two types that have the same fields (respectively
+//! variants) with the same types. Instead of writing the conversion by hand,
+//! you can derive an implementation of [`From`] through the [`StructuralFrom`]
+//! derive macro, configuring the source type through
+//! `#[funcmap(from = "...")]`:
+//! ```
+//! # use funcmap::StructuralFrom;
+//! mod v1 {
+//!     pub struct Foo<T> {
+//!         pub value: T,
+//!         pub more_values: Vec<T>,
+//!     }
+//! }
+//!
+//! #[derive(StructuralFrom, Debug, PartialEq)]
+//! #[funcmap(from = "v1::Foo")]
+//! struct Foo<T> {
+//!     value: T,
+//!     more_values: Vec<T>,
+//! }
+//!
+//! let foo = v1::Foo {
+//!     value: 1,
+//!     more_values: vec![2, 3],
+//! };
+//!
+//! assert_eq!(
+//!     Foo::from(foo),
+//!     Foo {
+//!         value: 1,
+//!         more_values: vec![2, 3],
+//!     }
+//! );
+//! ```
+//!
+//! The source type is instantiated with the same generic arguments as the
+//! target type, so the path given in `from` must not contain generic
+//! arguments. Fields are matched by name (respectively by index for tuple
+//! structs and variants) and variants of enums are matched by name. The `from`
+//! option can be given multiple times to generate multiple implementations.
+//!
+//! Both types must have the same shape, i.e. the same fields and variants,
+//! but the types of the fields may differ: every field is converted through
+//! [`Into`], so a field of type `v1::Bar<T>` can be converted into a field of
+//! type `Bar<T>` as long as `Bar<T>` implements `From<v1::Bar<T>>`, e.g. by
+//! deriving [`StructuralFrom`] itself:
+//! ```
+//! # use funcmap::StructuralFrom;
+//! mod v1 {
+//!     pub struct Bar<T>(pub T);
+//!
+//!     pub struct Foo<T> {
+//!         pub bar: Bar<T>,
+//!         pub count: u8,
+//!     }
+//! }
+//!
+//! #[derive(StructuralFrom, Debug, PartialEq)]
+//! #[funcmap(from = "v1::Bar")]
+//! struct Bar<T>(T);
+//!
+//! #[derive(StructuralFrom, Debug, PartialEq)]
+//! #[funcmap(from = "v1::Foo")]
+//! struct Foo<T> {
+//!     bar: Bar<T>,
+//!     count: u32,
+//! }
+//!
+//! let foo = v1::Foo {
+//!     bar: v1::Bar(1),
+//!     count: 2,
+//! };
+//!
+//! assert_eq!(
+//!     Foo::from(foo),
+//!     Foo {
+//!         bar: Bar(1),
+//!         count: 2,
+//!     }
+//! );
+//! ```
+//!
+//! The derive macro cannot see the types of the fields of the source type, so
+//! the generated implementation doesn't carry any bounds for these
+//! conversions. Each of them has to hold for all generic arguments satisfying
+//! the bounds declared on the target type. If a conversion needs further
+//! bounds, e.g. `T: Clone`, you have to declare them on the target type.
+//!
+//! # Mapping Between Families of Types
+//!
+//! Some types are generic not over the values they contain, but over the
+//! containers holding them, e.g. a configuration that is partial (`Option`)
+//! while being assembled and complete afterwards. Such a container can be
+//! modeled as a [`Family`] of types, and the [`FamilyMap`] derive macro
+//! generates a conversion from one family to another, given a
+//! [`NaturalTransformation`] between them. This reuses the same logic as the
+//! [`FuncMap`] derive macro for traversing the fields, see the documentation of
+//! the [`FamilyMap`] trait.
+//!
+//! # Manually Implementing [`FuncMap`] and [`TryFuncMap`]
+//!
+//! Even though implementations of the traits in this crate are usually meant to
+//! be derived automatically, it can become necessary for you to implement the
+//! traits manually in some cases, for instance
+//! - when a type in your crate has a field depending on a type parameter in a
+//!   way that isn't supported by the [`FuncMap`] and [`TryFuncMap`] derive
+//!   macros, e.g. when you implement a low-level primitive such as your custom
+//!   version of [`Vec<T>`],
+//! - when you need a [`FuncMap`] or [`TryFuncMap`] implementation for a type in
+//!   a third-party crate that doesn't provide one.
+//!
+//! In the latter case, since you cannot implement nor derive the trait for a
+//! third-party type due to the orphan rule, you can provide your own wrapper
+//! around it (following the *newtype* pattern) and implement the trait manually
+//! for the wrapper type:
+//! ```
+//! # use funcmap::FuncMap;
+//! #
+//! // Pretend that this is an external crate, not a module
+//! mod third_party {
+//!     # #[derive(Debug, PartialEq)]
+//!     pub struct List<T> {
+//!         // ...
+//!         # pub value: Option<T>,
+//!     }
+//!
+//!     impl<A> List<A> {
+//!         pub fn map<B>(self, f: impl FnMut(A) -> B) -> List<B> {
+//!             // ...
+//!             # List { value: self.value.map(f) }
+//!         }
+//!     }
+//! }
+//!
+//! // In your crate:
+//! # #[derive(Debug, PartialEq)]
+//! struct MyList<T>(third_party::List<T>);
+//!
+//! impl<A, B> FuncMap<A, B> for MyList<A> {
+//!     type Output = MyList<B>;
+//!
+//!     fn func_map<F>(self, f: F) -> Self::Output
+//!     where
+//!         F: FnMut(A) -> B,
+//!     {
+//!         MyList(self.0.map(f))
+//!     }
+//! }
+//!
+//! // Now you can derive `FuncMap` for types containing a `MyList<T>`:
+//! # #[derive(Debug, PartialEq)]
+//! #[derive(FuncMap)]
+//! struct Foo<T> {
+//!     list: MyList<T>,
+//! }
+//! #
+//! # let list = Foo {
+//! #     list: MyList(third_party::List {
+//! #         value: Some(1)
+//! #     })
+//! # };
+//! #
+//! # assert_eq!(
+//! #     list.func_map(|v| v.to_string()),
+//! #     Foo {
+//! #         list: MyList(third_party::List {
+//! #             value: Some(String::from("1"))
+//! #         })
+//! #     }
+//! # );
+//! ```
+//!
+//! For details on the exact contract to uphold when writing manual
+//! implementations, see the API documentations of [`FuncMap`] and
+//! [`TryFuncMap`].
+//!
+//! When implementing [`TryFuncMap`] for a collection type, the `helpers`
+//! module (available with the `alloc` feature) provides functions for
+//! collecting mapped elements that stop at the first error.
+//!
+//! If a collection type in your crate implements [`IntoIterator`] and
+//! [`FromIterator`], you can implement both traits in a single line through
+//! the [`impl_collect_map`] macro, see [`CollectMap`]. Similarly, newtype
+//! wrappers around a single mappable field can be covered through the
+//! [`impl_funcmap_newtype`] macro, and wrappers around a single value that is
+//! only accessible through functions, such as units of measurement generic
+//! over a numeric type, through the [`impl_funcmap_wrapper`] macro.
+//!
+//! When your type contains another type whose generic arguments are in a
+//! different order, such as a field of type `Bar<T, S>` within `Foo<S, T>`, the
+//! [`param_of!`] macro computes the marker type to delegate to, e.g.
+//! `param_of!(A in Bar<A, S>)` for `TypeParam<0>`.
+//!
+//! Note that if you have already implemented [`TryFuncMap`] for a type, you can
+//! then always implement [`FuncMap`] like this:
+//! ```
+//! use funcmap::{FuncMap, TryFuncMap};
+//!
+//! # #[derive(Debug, PartialEq)]
+//! struct Foo<T> {
+//!     // ...
+//! #   value: T,
+//! }
+//!
+//! impl<A, B> TryFuncMap<A, B> for Foo<A> {
+//!     type Output = Foo<B>;
+//!
+//!     // ...
+//! #
+//! #   fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+//! #   where
+//! #       F: FnMut(A) -> Result<B, E>,
+//! #   {
+//! #       Ok(Foo {
+//! #           value: f(self.value)?
+//! #       })
+//! #   }
+//! }
+//!
+//! impl<A, B> FuncMap<A, B> for Foo<A> {
+//!     type Output = Foo<B>;
+//!
+//!     fn func_map<F>(self, mut f: F) -> Self::Output
+//!     where
+//!         F: FnMut(A) -> B,
+//!     {
+//!         self.try_func_map::<core::convert::Infallible, _>(|x| Ok(f(x))).unwrap()
+//!     }
+//! }
+//! #
+//! # let foo = Foo { value: 42 };
+//! # assert_eq!(foo.func_map(|x| x + 1), Foo { value: 43 });
+//! ```
+//!
+//! # `no_std` Support
+//!
+//! `funcmap` has a Cargo feature named `std` that is enabled by default and
+//! provides implementations of [`FuncMap`] and [`TryFuncMap`] for many types
+//! from the [standard library](`std`). In order to use `funcmap` in a `no_std`
+//! context, modify your dependency on `funcmap` in `Cargo.toml` to opt out of
+//! default features:
+//! ```toml
+//! [dependencies]
+//! funcmap = { version = "...", default-features = false }
+//! ```
+//!
+//! In this case, only implementations for types in the [`core`] library are
+//! provided. Note that this excludes implementations for all standard library
+//! types that involve heap memory allocation, such as [`Box<T>`] or [`Vec<T>`].
+//! In order to opt back in to these implementations, you can enable the `alloc`
+//! Cargo feature:
+//! ```toml
+//! [dependencies]
+//! funcmap = { version = "...", default-features = false, features = ["alloc"] }
+//! ```
+//!
+//! This will provide implementations for many types in the [`alloc`] library.
+//!
+//! Both configurations also build for WebAssembly targets such as
+//! `wasm32-unknown-unknown`. Since binary size often matters on such targets,
+//! note that the code generated by the derive macros is instantiated once for
+//! every closure a type is mapped with. Deriving with
+//! [`#[funcmap(dyn_body)]`](#funcmapdyn_body) reduces this to a thin wrapper
+//! per closure, see the
+//! [`opt_size` example](https://github.com/matthias-stemmler/funcmap/blob/main/funcmap/examples/opt_size.rs).
+//! Combining this with `opt-level = "z"` in the release profile of your
+//! crate typically yields the smallest binaries.
+//!
+//! For fixed-capacity collections that work without heap memory allocation,
+//! `funcmap` provides implementations for types from the following crates,
+//! each behind a Cargo feature of the same name:
+//! - [`generic-array`](https://docs.rs/generic-array): `GenericArray<T, N>`
+//! - [`heapless`](https://docs.rs/heapless): `heapless::Vec<T, N>`
+//!
+//! ```toml
+//! [dependencies]
+//! funcmap = { version = "...", default-features = false, features = ["heapless"] }
+//! ```
+//!
+//! Similarly, the `futures` Cargo feature provides implementations for the
+//! stream primitives [`Iter<I>`](https://docs.rs/futures/0.3/futures/stream/struct.Iter.html),
+//! `Empty<T>` and `Pending<T>` from [`futures::stream`](https://docs.rs/futures/0.3/futures/stream/),
+//! so types holding these can derive [`FuncMap`] and [`TryFuncMap`]. Since the
+//! type parameter of `Iter<I>` is the type of the underlying iterator, a field
+//! of type `Iter<vec::IntoIter<T>>` is mapped by mapping the iterator, which
+//! collects its remaining items.
+//!
+//! For shared state in server applications, the `parking_lot` Cargo feature
+//! provides implementations for [`parking_lot::Mutex<T>`](https://docs.rs/parking_lot/0.12/parking_lot/type.Mutex.html)
+//! and [`parking_lot::RwLock<T>`](https://docs.rs/parking_lot/0.12/parking_lot/type.RwLock.html),
+//! which are mapped by consuming the lock, and the `crossbeam-queue` Cargo
+//! feature provides implementations for the owned queues
+//! [`ArrayQueue<T>`](https://docs.rs/crossbeam-queue/0.3/crossbeam_queue/struct.ArrayQueue.html)
+//! and [`SegQueue<T>`](https://docs.rs/crossbeam-queue/0.3/crossbeam_queue/struct.SegQueue.html)
+//! from [`crossbeam_queue`](https://docs.rs/crossbeam-queue/0.3/crossbeam_queue/),
+//! keeping the order of their elements.
+//!
+//! For zero-copy deserialization, the `rkyv` Cargo feature provides
+//! implementations for references to the archived types
+//! [`ArchivedVec<T>`](https://docs.rs/rkyv/0.7/rkyv/vec/struct.ArchivedVec.html),
+//! [`ArchivedOption<T>`](https://docs.rs/rkyv/0.7/rkyv/option/enum.ArchivedOption.html)
+//! and [`ArchivedBox<T>`](https://docs.rs/rkyv/0.7/rkyv/boxed/struct.ArchivedBox.html)
+//! from [`rkyv`](https://docs.rs/rkyv/0.7/rkyv/). As archived data cannot be
+//! moved out of, the closure receives references to the archived values and
+//! the output is the corresponding owned type, e.g. `&ArchivedVec<A>` is mapped
+//! to `Vec<B>`. This allows building a working representation from archived
+//! data in a single step and then mapping it further, see the
+//! [`rkyv` example](https://github.com/matthias-stemmler/funcmap/blob/main/funcmap/examples/rkyv.rs).
+//!
+//! A [`serde_json::Value`](https://docs.rs/serde_json/1/serde_json/enum.Value.html)
+//! is not generic, so it cannot be mapped over a type parameter. For embedding
+//! dynamic JSON data into types deriving [`FuncMap`] or [`TryFuncMap`], the
+//! `json` Cargo feature provides the type `DynLeaf<T>`, a JSON tree whose
+//! scalar values have type `T`. It can be created from a `Value`, mapped along
+//! with the rest of the enclosing type and turned back into a `Value`, see the
+//! [`json` example](https://github.com/matthias-stemmler/funcmap/blob/main/funcmap/examples/json.rs).
+//!
+//! For teams used to the naming of functional programming languages, the
+//! `fp-names` Cargo feature provides the extension trait `Fmap` with the
+//! methods `fmap` and `try_fmap` as aliases for [`func_map`](FuncMap::func_map)
+//! and [`try_func_map`](TryFuncMap::try_func_map), as well as `void` and
+//! `replace`.
+//!
+//! For reporting failures of [`try_func_map`](TryFuncMap::try_func_map) along
+//! with the fields leading to the failing value, the `tracing` Cargo feature
+//! provides the function `traced` and the option
+//! [`#[funcmap(traced)]`](#funcmaptraced) integrating with
+//! [`tracing`](https://docs.rs/tracing).
+//!
+//! # Functional Programming Background
+//!
+//! The idea of `funcmap` is based on the *functor* design pattern from
+//! functional programming, which in turn is inspired from the notion of a
+//! functor in category theory.
+//!
+//! Basically, `F` is a functor if
+//! 1. it associates each type `T` with a new type `F(T)`
+//! 2. it associates each function
+//!     ```plain
+//!     f: A -> B
+//!     ```
+//!     with a function
+//!     ```plain
+//!     F(f): F(A) -> F(B)
+//!     ```
+//!     such that the following *functor laws* are satisfied:
+//!     - `F(id) = id` where `id` is the identity function on `A`, respectively
+//!       `F(A)`
+//!     - `F(g . f) = F(g) . F(f)` for any two functions `f: A -> B` and
+//!       `g: B -> C`, where `g . f` denotes function composition
+//!
+//! In languages with higher-kinded types such as Haskell, this property of
+//! being a functor is expressed as a *type class* (similar to a trait) called
+//! [Functor](https://wiki.haskell.org/Functor) that the higher-kinded type `F`
+//! is an instance of.
+//!
+//! In Rust, property 1. is satisfied for every type `Foo<T>` that is generic
+//! over a type parameter `T` because it associates each type `T` with a new
+//! type `Foo<T>`, at least for those types `T` that satisfy all trait bounds
+//! that `Foo<T>` imposes on `T`.
+//!
+//! Property 2. is where the [`FuncMap`] trait comes into play. As there are no
+//! higher-kinded types in Rust as of now, it cannot be expressed by `Foo`
+//! itself implementing a trait, because while `Foo<T>` is a type for every `T`,
+//! `Foo` itself (without the `<T>`) isn't something one can reason about within
+//! the Rust type system. However, one can say that `Foo` is a functor if and
+//! only if
+//! ```
+//! # use funcmap::FuncMap;
+//! #
+//! # #[derive(FuncMap)]
+//! # struct Foo<T>(T);
+//! #
+//! # enum A {}
+//! # enum B {}
+//! #
+//! # fn test() where
+//! Foo<A>: FuncMap<A, B, Output = Foo<B>>
+//! # {}
+//! ```
+//! holds for all types `A` and `B` for which `Foo<T>` exists. The function
+//! `Foo<A> -> Foo<B>` associated with a function `f: A -> B` (in Rust:
+//! `f: impl FnMut(A) -> B`) by property 2. is then provided by the
+//! [`func_map`](FuncMap::func_map) method as the function
+//! ```
+//! # use funcmap::FuncMap;
+//! #
+//! # #[derive(FuncMap)]
+//! # struct Foo<T>(T);
+//! #
+//! # enum A {}
+//! # enum B {}
+//! #
+//! # fn test(f: impl FnMut(A) -> B) -> impl FnOnce(Foo<A>) -> Foo<B> {
+//! |x: Foo<A>| x.func_map(f)
+//! # }
+//! ```
+//! So deriving the [`FuncMap`] trait for `Foo<T>` can be viewed as deriving
+//! Property 2. from Property 1. or equivalently, deriving a (hypothetical)
+//! *Functor*  trait for the (hypothetical) higher-kinded type `Foo`.
+//!
+//! In fact, the name of the [`func_map`](FuncMap::func_map) method is inspired
+//! from the
+//! [`fmap`](https://hackage.haskell.org/package/base-4.16.0.0/docs/Data-Functor.html#v:fmap)
+//! function of Haskell's `Functor` type class.
+//!
+//! # Edition support
+//!
+//! This crate supports all Rust editions. There is one caveat, however: When deriving [`FuncMap`] for a type in edition
+//! 2015 code that contains an identifier that is a keyword from edition 2018 onwards, i.e. one of `async`, `await`,
+//! `dyn` and `try`, the identifier has to be written as a raw identifier in the type definition:
+//! ```edition2015
+//! # use funcmap::FuncMap;
+//! #
+//! #[derive(FuncMap)]
+//! struct Foo<T> {
+//!     r#async: T, // this would fail with `async` instead of `r#async`
+//! }
+//!
+//! let foo = Foo {
+//!     async: 1,
+//! };
+//!
+//! let bar = foo.func_map(|v| v.to_string());
+//!
+//! assert_eq!(bar.async, "1");
+//! ```
+//!
+//! # Minimum Supported Rust Version (MSRV) Policy
+//!
+//! The current MSRV of this crate is `1.65`.
+//!
+//! Increasing the MSRV of this crate is *not* considered a breaking change.
+//! However, in such cases there will be at least a minor version bump.
+//!
+//! Each version of this crate will support at least the four latest stable Rust
+//! versions at the time it is published.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![deny(missing_copy_implementations)]
+#![deny(missing_debug_implementations)]
+#![deny(missing_docs)]
+#![deny(unreachable_pub)]
+#![deny(unused_crate_dependencies)]
+#![deny(unused_extern_crates)]
+#![deny(unused_lifetimes)]
+#![deny(unused_qualifications)]
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::cargo_common_metadata)]
+#![deny(clippy::multiple_crate_versions)]
+// `rkyv` 0.7 depends on older versions of `hashbrown` and `syn`
+#![cfg_attr(feature = "rkyv", allow(clippy::multiple_crate_versions))]
+#![deny(clippy::rest_pat_in_fully_bound_structs)]
+#![deny(clippy::use_debug)]
+#![allow(clippy::module_name_repetitions)]
+#![deny(rustdoc::broken_intra_doc_links)]
+#![deny(rustdoc::private_intra_doc_links)]
+#![deny(rustdoc::invalid_codeblock_attributes)]
+#![deny(rustdoc::invalid_rust_codeblocks)]
+#![deny(rustdoc::bare_urls)]
+
+mod array;
+mod impls_core;
+
+pub mod convert;
+pub mod interval;
+pub mod tagged;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod helpers;
+
+pub mod markers;
+
+#[doc(no_inline)]
+#[cfg(feature = "json")]
+pub use json::DynLeaf;
+
+pub use markers::{P0, P1, P2, P3, P4, P5, P6, P7, P8, P9};
+
+#[cfg(feature = "alloc")]
+mod impls_alloc;
+
+#[cfg(feature = "std")]
+mod impls_std;
+
+#[cfg(feature = "crossbeam-queue")]
+mod impls_crossbeam_queue;
+
+#[cfg(feature = "futures")]
+mod impls_futures;
+
+#[cfg(feature = "generic-array")]
+mod impls_generic_array;
+
+#[cfg(feature = "heapless")]
+mod impls_heapless;
+
+#[cfg(feature = "json")]
+mod json;
+
+#[cfg(feature = "parking_lot")]
+mod impls_parking_lot;
+
+#[cfg(feature = "rkyv")]
+mod impls_rkyv;
+
+#[cfg(feature = "tracing")]
+mod trace;
+
+#[cfg(feature = "tracing")]
+pub use trace::traced;
+
+#[doc(hidden)]
+#[cfg(feature = "tracing")]
+pub use trace::enter_field_span;
+
+use core::fmt::{self, Display, Formatter};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Functorial mapping of a generic type over any of its type parameters
+///
+/// # Deriving [`FuncMap`]
+///
+/// In most cases, implementations of this trait can and should be derived
+/// automatically:
+/// ```
+/// # use funcmap::FuncMap;
+/// #
+/// #[derive(FuncMap)]
+/// struct Foo<T> {
+///     // ...
+///     # value: T,
+/// }
+/// ```
+///
+/// See the [crate-level documentation](crate) for details.
+///
+/// # Manually Implementing [`FuncMap`]
+///
+/// If you need to implement [`FuncMap`] manually, make sure to uphold the
+/// following contract:
+///
+/// Let `Foo` be a type that is generic over the type or const parameters
+/// `T0, ..., Tn`.
+///
+/// If `Foo` implements [`FuncMap<A, B, TypeParam<N>>`], then
+/// - `N` must be in the range `0..=n`.
+/// - The parameter of `Foo` at index `N` (not counting lifetime parameters)
+///   must be `A`. In particular, it must be a type parameter, not a const
+///   generic.
+/// - `Foo::Output` must be `Foo` with the parameter at index `N` replaced with
+///   `B`.
+///
+/// Furthermore:
+/// - [`func_map_over`](Self::func_map_over),
+///   [`func_map_at`](Self::func_map_at) and
+///   [`func_map_by_ref`](Self::func_map_by_ref) must behave in exactly the same
+///   way as [`func_map`](Self::func_map). This is the default behavior and must
+///   not be changed.
+/// - [`func_map`](Self::func_map) must invoke the closure exactly once for
+///   every value of type `A` within `self`, in the order described in
+///   [Traversal Order](crate#traversal-order). Implementations for types
+///   without a meaningful order must still invoke it exactly once per value.
+/// - When implementing [`FuncMap`] for different marker types [`TypeParam<N>`]
+///   and [`TypeParam<M>`], the result of mapping over both type parameters in
+///   sequence must not depend on the order of the two mappings, i.e.
+///   ```
+///   # use funcmap::{FuncMap, TypeParam};
+///   #
+///   # #[derive(FuncMap, Copy, Clone, Debug, PartialEq)]
+///   # struct Foo<T, U>(T, U);
+///   #
+///   # const N: usize = 0;
+///   # const M: usize = 1;
+///   #
+///   # let foo = Foo(42, 43);
+///   # let f = |x| x + 1;
+///   # let g = |x| x * 2;
+///   #
+///   # assert!(
+///   foo.func_map_over::<TypeParam<N>, _>(f)
+///      .func_map_over::<TypeParam<M>, _>(g)
+///
+///   // must be equivalent to
+///   # ==
+///
+///   foo.func_map_over::<TypeParam<M>, _>(g)
+///      .func_map_over::<TypeParam<N>, _>(f)  
+///   # );
+///   ```
+pub trait FuncMap<A, B, P = TypeParam<0>>: Sized
+where
+    P: FuncMarker<P>,
+{
+    /// The output type of the functorial mapping
+    ///
+    /// This is `Self` with the parameter at index `N` replaced with `B`, where
+    /// `N` is such that `P` is `TypeParam<N>`.
+    ///
+    /// In the simplest case of a type with just a single type parameter, if
+    /// `Self` is `Foo<A>`, then this is `Foo<B>`.
+    type Output;
+
+    /// Applies the closure `f` to `self` in a functorial way
+    #[must_use]
+    fn func_map<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B;
+
+    /// Applies the closure `f` to `self` in a functorial way, allowing explicit
+    /// specification of the marker type `P`
+    ///
+    /// This is a convenience method that has the exact same functionality as
+    /// [`func_map`](Self::func_map) but can be used to specify the marker type
+    /// `P` in a convenient way in cases where it is ambiguous.
+    ///
+    /// So if you have
+    /// ```
+    /// # use funcmap::FuncMap;
+    /// #
+    /// #[derive(FuncMap, Debug, PartialEq)]
+    /// struct Foo<S, T> {
+    ///     s: S,
+    ///     t: T,
+    /// }
+    ///
+    /// let foo = Foo {
+    ///     s: 42,
+    ///     t: 42,
+    /// };
+    /// ```
+    /// then instead of writing
+    /// ```
+    /// # use funcmap::{FuncMap, TypeParam};
+    /// #
+    /// # #[derive(FuncMap, Debug, PartialEq)]
+    /// # struct Foo<S, T> {
+    /// #     s: S,
+    /// #     t: T,
+    /// # }
+    /// #
+    /// # let foo = Foo {
+    /// #     s: 42,
+    /// #     t: 42,
+    /// # };
+    /// #
+    /// let bar = FuncMap::<_, _, TypeParam<1>>::func_map(foo, |v| v + 1);
+    /// assert_eq!(bar, Foo { s: 42, t: 43 });
+    /// ```
+    ///
+    /// you can more conveniently write
+    ///
+    /// ```
+    /// # use funcmap::{FuncMap, TypeParam};
+    /// #
+    /// # #[derive(FuncMap, Debug, PartialEq)]
+    /// # struct Foo<S, T> {
+    /// #     s: S,
+    /// #     t: T,
+    /// # }
+    /// #
+    /// # let foo = Foo {
+    /// #     s: 42,
+    /// #     t: 42,
+    /// # };
+    /// #
+    /// let bar = foo.func_map_over::<TypeParam<1>, _>(|v| v + 1);
+    /// assert_eq!(bar, Foo { s: 42, t: 43 });
+    /// ```
+    ///
+    /// This lets you chain method calls more easily as in
+    /// ```
+    /// # use funcmap::{FuncMap, TypeParam};
+    /// #
+    /// # #[derive(FuncMap, Debug, PartialEq)]
+    /// # struct Foo<S, T> {
+    /// #     s: S,
+    /// #     t: T,
+    /// # }
+    /// #
+    /// # let foo = Foo {
+    /// #     s: 42,
+    /// #     t: 42,
+    /// # };
+    /// #
+    /// foo.func_map_over::<TypeParam<0>, _>(|v| v + 1)
+    ///    .func_map_over::<TypeParam<1>, _>(|v| v + 1)
+    /// # ;
+    /// ```
+    ///
+    /// Note that you still need to specify the inferred type `_` for the
+    /// closure type `F`.
+    #[must_use]
+    fn func_map_over<Q, F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+        Q: FuncMarker<P>,
+    {
+        self.func_map(f)
+    }
+
+    /// Applies the closure `f` to `self` in a functorial way, specifying the
+    /// type parameter to map over by a value
+    ///
+    /// This has the same functionality as
+    /// [`func_map_over`](Self::func_map_over), but the type parameter is given
+    /// by a value of type [`Param<N>`] rather than a type argument, so it can
+    /// be passed around like any other value, e.g. by generic helpers in tests:
+    /// ```
+    /// # use funcmap::{FuncMap, Param};
+    /// # use funcmap::markers::P1;
+    /// #
+    /// #[derive(FuncMap, Debug, PartialEq)]
+    /// struct Foo<S, T> {
+    ///     s: S,
+    ///     t: T,
+    /// }
+    ///
+    /// let foo = Foo { s: 42, t: 42 };
+    ///
+    /// let bar = foo.func_map_at(P1, |v| v + 1);
+    /// // Equivalent to: let bar = foo.func_map_at(Param::<1>, |v| v + 1);
+    ///
+    /// assert_eq!(bar, Foo { s: 42, t: 43 });
+    /// ```
+    #[must_use]
+    fn func_map_at<const N: usize, F>(self, param: Param<N>, f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+        TypeParam<N>: FuncMarker<P>,
+    {
+        let _ = param;
+        self.func_map(f)
+    }
+
+    /// Applies the closure `f` to `self` in a functorial way, borrowing the
+    /// closure mutably instead of taking ownership of it
+    ///
+    /// This has the same functionality as [`func_map`](Self::func_map), but
+    /// lets you reuse a single closure across multiple calls, e.g. to keep
+    /// track of state across all of them:
+    /// ```
+    /// # use funcmap::FuncMap;
+    /// #
+    /// #[derive(FuncMap, Debug, PartialEq)]
+    /// struct Foo<T>(T, T);
+    ///
+    /// let mut next_id = 0;
+    /// let mut f = |_| {
+    ///     next_id += 1;
+    ///     next_id
+    /// };
+    ///
+    /// let foo = Foo("a", "b").func_map_by_ref(&mut f);
+    /// let bar = Foo("c", "d").func_map_by_ref(&mut f);
+    ///
+    /// assert_eq!(foo, Foo(1, 2));
+    /// assert_eq!(bar, Foo(3, 4));
+    /// ```
+    ///
+    /// Since `f` may be unsized, this also accepts trait objects such as
+    /// `&mut dyn FnMut(A) -> B`.
+    #[must_use]
+    fn func_map_by_ref<F>(self, f: &mut F) -> Self::Output
+    where
+        F: FnMut(A) -> B + ?Sized,
+    {
+        self.func_map(f)
+    }
+}
+
+/// Fallible functorial mapping of a generic type over any of its type
+/// parameters
+///
+/// # Deriving [`TryFuncMap`]
+///
+/// In most cases, implementations of this trait can and should be derived
+/// automatically:
+/// ```
+/// # use funcmap::TryFuncMap;
+/// #
+/// #[derive(TryFuncMap)]
+/// struct Foo<T> {
+///     // ...
+///     # value: T,
+/// }
+/// ```
+///
+/// See the [crate-level documentation](crate) for details.
+///
+/// # Manually Implementing [`TryFuncMap`]
+///
+/// If you need to implement [`TryFuncMap`] manually, make sure to uphold the
+/// following contract:
+///
+/// Let `Foo` be a type that is generic over the type or const parameters
+/// `T0, ..., Tn`.
+///
+/// If `Foo` implements [`TryFuncMap<A, B, TypeParam<N>>`], then
+/// - `N` must be in the range `0..=n`.
+/// - The parameter of `Foo` at index `N` (not counting lifetime parameters)
+///   must be `A`. In particular, it must be a type parameter, not a const
+///   generic.
+/// - `Foo::Output` must be `Foo` with the parameter at index `N` replaced with
 ///   `B`.
 ///
-/// Furthermore:
-/// - [`func_map_over`](Self::func_map_over) must behave in exactly the same way
-///   as [`func_map`](Self::func_map). This is the default behavior and must not
-///   be changed.
-/// - When implementing [`FuncMap`] for different marker types [`TypeParam<N>`]
-///   and [`TypeParam<M>`], the result of mapping over both type parameters in
-///   sequence must not depend on the order of the two mappings, i.e.
-///   ```
-///   # use funcmap::{FuncMap, TypeParam};
-///   #
-///   # #[derive(FuncMap, Copy, Clone, Debug, PartialEq)]
-///   # struct Foo<T, U>(T, U);
-///   #
-///   # const N: usize = 0;
-///   # const M: usize = 1;
-///   #
-///   # let foo = Foo(42, 43);
-///   # let f = |x| x + 1;
-///   # let g = |x| x * 2;
-///   #
-///   # assert!(
-///   foo.func_map_over::<TypeParam<N>, _>(f)
-///      .func_map_over::<TypeParam<M>, _>(g)
+/// Furthermore:
+/// - [`try_func_map_over`](Self::try_func_map_over),
+///   [`try_func_map_at`](Self::try_func_map_at) and
+///   [`try_func_map_by_ref`](Self::try_func_map_by_ref) must behave in exactly
+///   the same way as [`try_func_map`](Self::try_func_map). This is the default
+///   behavior and must not be changed.
+/// - [`try_func_map`](Self::try_func_map) must invoke the closure in the same
+///   order as described for [`FuncMap`], stopping right after it fails for the
+///   first time.
+/// - If the closure provided to [`try_func_map`](Self::try_func_map) fails,
+///   then the result must be the first error according to the order of the
+///   fields in the definition of `Foo`:
+///   ```
+///   # use funcmap::TryFuncMap;
+///   # use std::num::{IntErrorKind, ParseIntError};
+///   #
+///   #[derive(TryFuncMap, Copy, Clone, Debug, PartialEq)]
+///   struct Foo<T> {
+///       value1: T,
+///       value2: T,
+///   }
+///   
+///   let foo = Foo {
+///       value1: "1a",
+///       value2: ""
+///   };
+///
+///   let result: Result<Foo<i32>, ParseIntError> = foo.try_func_map(|v| v.parse());
+///   
+///   assert!(result.is_err());
+///   assert_eq!(*result.unwrap_err().kind(), IntErrorKind::InvalidDigit);
+///   ```
+/// - When implementing [`TryFuncMap`] for different marker types
+///   [`TypeParam<N>`] and [`TypeParam<M>`], the result of mapping over both
+///   type parameters in sequence must not depend on the order of the two
+///   mappings, i.e.
+///   ```
+///   # use funcmap::{TryFuncMap, TypeParam};
+///   #
+///   # #[derive(TryFuncMap, Copy, Clone, Debug, PartialEq)]
+///   # struct Foo<T, U>(T, U);
+///   #
+///   # const N: usize = 0;
+///   # const M: usize = 1;
+///   #
+///   # let foo = Foo(42, 43);
+///   # let f = |x| Ok::<_, ()>(x + 1);
+///   # let g = |x| Ok::<_, ()>(x * 2);
+///   #
+///   # assert!(
+///   foo.try_func_map_over::<TypeParam<N>, _, _>(f)
+///      .and_then(|x| x.try_func_map_over::<TypeParam<M>, _, _>(g))
+///
+///   // must be equivalent to
+///   # ==
+///
+///   foo.try_func_map_over::<TypeParam<M>, _, _>(g)
+///      .and_then(|x| x.try_func_map_over::<TypeParam<N>, _, _>(f))
+///   # );
+///   ```
+pub trait TryFuncMap<A, B, P = TypeParam<0>>: Sized
+where
+    P: FuncMarker<P>,
+{
+    /// The output type of the functorial mapping
+    ///
+    /// This is `Self` with the parameter at index `N` replaced with `B`, where
+    /// `N` is such that `P` is `TypeParam<N>`.
+    ///
+    /// In the simplest case of a type with just a single type parameter, if
+    /// `Self` is `Foo<A>`, then this is `Foo<B>`.
+    type Output;
+
+    /// Tries to apply the closure `f` to `self` in a functorial way
+    ///
+    /// # Errors
+    /// Fails if and only if `f` fails, returning the first error according to
+    /// the order of the fields in the definition of `Self`
+    fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(A) -> Result<B, E>;
+
+    /// Tries to apply the closure `f` to `self` in a functorial way, allowing
+    /// explicit specification of the marker type `P`
+    ///
+    /// This is a convenience method that has the exact same functionality as
+    /// [`try_func_map`](Self::try_func_map) but can be used to specify the
+    /// marker type `P` in a convenient way in cases where it is ambiguous.
+    ///
+    /// So if you have
+    /// ```
+    /// # use funcmap::TryFuncMap;
+    /// #
+    /// #[derive(TryFuncMap, Debug, PartialEq)]
+    /// struct Foo<S, T> {
+    ///     s: S,
+    ///     t: T,
+    /// }
+    ///
+    /// let foo = Foo {
+    ///     s: "42",
+    ///     t: "42",
+    /// };
+    /// ```
+    /// then instead of writing
+    /// ```
+    /// # use funcmap::{TryFuncMap, TypeParam};
+    /// #
+    /// # #[derive(TryFuncMap, Debug, PartialEq)]
+    /// # struct Foo<S, T> {
+    /// #     s: S,
+    /// #     t: T,
+    /// # }
+    /// #
+    /// # let foo = Foo {
+    /// #     s: "42",
+    /// #     t: "42",
+    /// # };
+    /// #
+    /// let bar = TryFuncMap::<_, _, TypeParam<1>>::try_func_map(foo, |v| v.parse::<i32>());
+    /// assert_eq!(bar, Ok(Foo { s: "42", t: 42 }));
+    /// ```
+    ///
+    /// you can more conveniently write
+    ///
+    /// ```
+    /// # use funcmap::{TryFuncMap, TypeParam};
+    /// #
+    /// # #[derive(TryFuncMap, Debug, PartialEq)]
+    /// # struct Foo<S, T> {
+    /// #     s: S,
+    /// #     t: T,
+    /// # }
+    /// #
+    /// # let foo = Foo {
+    /// #     s: "42",
+    /// #     t: "42",
+    /// # };
+    /// #
+    /// let bar = foo.try_func_map_over::<TypeParam<1>, _, _>(|v| v.parse::<i32>());
+    /// assert_eq!(bar, Ok(Foo { s: "42", t: 42 }));
+    /// ```
+    ///
+    /// This lets you chain method calls more easily as in
+    /// ```
+    /// # use funcmap::{TryFuncMap, TypeParam};
+    /// #
+    /// # #[derive(TryFuncMap, Debug, PartialEq)]
+    /// # struct Foo<S, T> {
+    /// #     s: S,
+    /// #     t: T,
+    /// # }
+    /// #
+    /// # let foo = Foo {
+    /// #     s: "42",
+    /// #     t: "42",
+    /// # };
+    /// #
+    /// foo.try_func_map_over::<TypeParam<0>, _, _>(|v| v.parse::<i32>())
+    ///     .and_then(|foo| foo.try_func_map_over::<TypeParam<1>, _, _>(|v| v.parse::<i32>()))
+    /// # ;
+    /// ```
+    ///
+    /// Note that you still need to specify the inferred type `_` for the
+    /// error type `E` and the closure type `F`.
+    ///
+    /// # Errors
+    /// Fails if and only if `f` fails, returning the first error according to
+    /// the order of the fields in the definition of `Self`
+    fn try_func_map_over<Q, E, F>(self, f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+        Q: FuncMarker<P>,
+    {
+        self.try_func_map(f)
+    }
+
+    /// Tries to apply the closure `f` to `self` in a functorial way, specifying
+    /// the type parameter to map over by a value
+    ///
+    /// This has the same functionality as
+    /// [`try_func_map_over`](Self::try_func_map_over), but the type parameter
+    /// is given by a value of type [`Param<N>`] rather than a type argument:
+    /// ```
+    /// # use funcmap::TryFuncMap;
+    /// # use funcmap::markers::P1;
+    /// #
+    /// #[derive(TryFuncMap, Debug, PartialEq)]
+    /// struct Foo<S, T> {
+    ///     s: S,
+    ///     t: T,
+    /// }
+    ///
+    /// let foo = Foo { s: "42", t: "42" };
+    ///
+    /// let bar = foo.try_func_map_at(P1, |v| v.parse::<i32>());
+    ///
+    /// assert_eq!(bar, Ok(Foo { s: "42", t: 42 }));
+    /// ```
+    ///
+    /// # Errors
+    /// Fails if and only if `f` fails, returning the first error according to
+    /// the order of the fields in the definition of `Self`
+    fn try_func_map_at<const N: usize, E, F>(self, param: Param<N>, f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+        TypeParam<N>: FuncMarker<P>,
+    {
+        let _ = param;
+        self.try_func_map(f)
+    }
+
+    /// Tries to apply the closure `f` to `self` in a functorial way, borrowing
+    /// the closure mutably instead of taking ownership of it
+    ///
+    /// This has the same functionality as [`try_func_map`](Self::try_func_map),
+    /// but lets you reuse a single closure across multiple calls, see
+    /// [`FuncMap::func_map_by_ref`].
+    ///
+    /// # Errors
+    /// Fails if and only if `f` fails, returning the first error according to
+    /// the order of the fields in the definition of `Self`
+    fn try_func_map_by_ref<E, F>(self, f: &mut F) -> Result<Self::Output, E>
+    where
+        F: FnMut(A) -> Result<B, E> + ?Sized,
+    {
+        self.try_func_map(f)
+    }
+}
+
+/// Functorial mapping of a generic type over one of its type parameters
+/// without changing the type
+///
+/// This is an extension trait with a blanket implementation for all types
+/// implementing [`FuncMap<A, A, P>`] with `Output = Self`, which in particular
+/// includes all types for which [`FuncMap`] is derived.
+///
+/// Its method [`func_map_same`](Self::func_map_same) has the same functionality
+/// as [`FuncMap::func_map`], but the closure is required to map `A` to `A`, so
+/// the output type is known to be `Self` and doesn't need to be inferred:
+/// ```
+/// # use funcmap::{FuncMap, FuncMapSame};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     value: T,
+///     more_values: Vec<T>,
+/// }
+///
+/// let foo = Foo {
+///     value: 1,
+///     more_values: vec![2, 3],
+/// };
+///
+/// let bar = foo.func_map_same(|v| v * 10);
+///
+/// assert_eq!(
+///     bar,
+///     Foo {
+///         value: 10,
+///         more_values: vec![20, 30],
+///     }
+/// );
+/// ```
+pub trait FuncMapSame<A, P = TypeParam<0>>: FuncMap<A, A, P, Output = Self>
+where
+    P: FuncMarker<P>,
+{
+    /// Applies the closure `f` to `self` in a functorial way, keeping the type
+    /// of `self`
+    #[must_use]
+    fn func_map_same<F>(self, f: F) -> Self
+    where
+        F: FnMut(A) -> A,
+    {
+        self.func_map(f)
+    }
+}
+
+impl<T, A, P> FuncMapSame<A, P> for T
+where
+    T: FuncMap<A, A, P, Output = T>,
+    P: FuncMarker<P>,
+{
+}
+
+/// Functorial mapping of a [`Copy`] type over one of its type parameters by
+/// reference
+///
+/// This is an extension trait with a blanket implementation for all types
+/// implementing [`FuncMap<A, B, P>`] and [`Copy`], which in particular includes
+/// all [`Copy`] types for which [`FuncMap`] is derived.
+///
+/// Its method [`func_map_copy`](Self::func_map_copy) has the same
+/// functionality as [`FuncMap::func_map`], but takes `self` by reference and
+/// maps a copy of it, so small value types such as points or vectors can be
+/// mapped without being consumed:
+/// ```
+/// # use funcmap::{CopyFuncMap, FuncMap};
+/// #
+/// #[derive(FuncMap, Copy, Clone, Debug, PartialEq)]
+/// struct Point<T> {
+///     x: T,
+///     y: T,
+/// }
+///
+/// let point = Point { x: 1, y: 2 };
+///
+/// let scaled = point.func_map_copy(|v| v * 10);
+/// let shifted = point.func_map_copy(|v| f64::from(v) + 0.5);
+///
+/// assert_eq!(scaled, Point { x: 10, y: 20 });
+/// assert_eq!(shifted, Point { x: 1.5, y: 2.5 });
+/// ```
+pub trait CopyFuncMap<A, B, P = TypeParam<0>>: FuncMap<A, B, P> + Copy
+where
+    P: FuncMarker<P>,
+{
+    /// Applies the closure `f` to a copy of `self` in a functorial way
+    #[must_use]
+    fn func_map_copy<F>(&self, f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+    {
+        (*self).func_map(f)
+    }
+}
+
+impl<T, A, B, P> CopyFuncMap<A, B, P> for T
+where
+    T: FuncMap<A, B, P> + Copy,
+    P: FuncMarker<P>,
+{
+}
+
+/// Functorial mapping of a generic type over one of its type parameters via
+/// [`From`]
+///
+/// This is an extension trait with a blanket implementation for all types, so
+/// its method [`func_map_into`](Self::func_map_into) is available whenever
+/// [`FuncMap<A, B, P>`] is implemented, which in particular includes all types
+/// for which [`FuncMap`] is derived.
+///
+/// Its method [`func_map_into`](Self::func_map_into) is a shorthand for
+/// [`func_map`](FuncMap::func_map) with the closure [`Into::into`], where the
+/// target type `B` can be specified directly:
+/// ```
+/// # use funcmap::{FuncMap, FuncMapInto};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     value: T,
+///     more_values: Vec<T>,
+/// }
+///
+/// let foo = Foo {
+///     value: 1_u8,
+///     more_values: vec![2, 3],
+/// };
+///
+/// let bar = foo.func_map_into::<u64>();
+///
+/// assert_eq!(
+///     bar,
+///     Foo {
+///         value: 1_u64,
+///         more_values: vec![2, 3],
+///     }
+/// );
+/// ```
+pub trait FuncMapInto<A, P = TypeParam<0>>
+where
+    P: FuncMarker<P>,
+{
+    /// Converts every value of type `A` within `self` into `B` via [`From`]
+    /// in a functorial way
+    #[must_use]
+    fn func_map_into<B>(self) -> <Self as FuncMap<A, B, P>>::Output
+    where
+        Self: FuncMap<A, B, P> + Sized,
+        B: From<A>,
+    {
+        self.func_map(B::from)
+    }
+}
+
+impl<T, A, P> FuncMapInto<A, P> for T where P: FuncMarker<P> {}
+
+/// Fallible functorial mapping of a generic type over one of its type
+/// parameters via [`TryFrom`]
+///
+/// This is an extension trait with a blanket implementation for all types, so
+/// its method [`try_func_map_into`](Self::try_func_map_into) is available
+/// whenever [`TryFuncMap<A, B, P>`] is implemented, which in particular
+/// includes all types for which [`TryFuncMap`] is derived.
+///
+/// Its method [`try_func_map_into`](Self::try_func_map_into) is a shorthand for
+/// [`try_func_map`](TryFuncMap::try_func_map) with the closure
+/// [`TryInto::try_into`], where the target type `B` can be specified directly:
+/// ```
+/// # use funcmap::{TryFuncMap, TryFuncMapInto};
+/// #
+/// #[derive(TryFuncMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     value: T,
+///     more_values: Vec<T>,
+/// }
+///
+/// let foo = Foo {
+///     value: 1_u64,
+///     more_values: vec![2, 3],
+/// };
+///
+/// let bar = foo.try_func_map_into::<u8>();
+///
+/// assert_eq!(
+///     bar,
+///     Ok(Foo {
+///         value: 1_u8,
+///         more_values: vec![2, 3],
+///     })
+/// );
+/// ```
+///
+/// Note that a derived implementation of `TryFrom<Foo<A>> for Foo<B>` is not
+/// possible, as it would conflict with the blanket implementation of
+/// [`TryFrom`] in the standard library for the case `A = B`.
+pub trait TryFuncMapInto<A, P = TypeParam<0>>
+where
+    P: FuncMarker<P>,
+{
+    /// Tries to convert every value of type `A` within `self` into `B` via
+    /// [`TryFrom`] in a functorial way
+    ///
+    /// # Errors
+    /// Fails if and only if converting one of the values fails, returning the
+    /// first error according to the order of the fields in the definition of
+    /// `Self`
+    fn try_func_map_into<B>(self) -> Result<<Self as TryFuncMap<A, B, P>>::Output, B::Error>
+    where
+        Self: TryFuncMap<A, B, P> + Sized,
+        B: TryFrom<A>,
+    {
+        self.try_func_map(B::try_from)
+    }
+}
+
+impl<T, A, P> TryFuncMapInto<A, P> for T where P: FuncMarker<P> {}
+
+/// Functorial mapping of a generic type over one of its type parameters with
+/// closures taking the values by reference
+///
+/// This is an extension trait with a blanket implementation for all types, so
+/// its methods are available whenever [`FuncMap<A, B, P>`] is implemented,
+/// which in particular includes all types for which [`FuncMap`] is derived.
+///
+/// Its method [`func_map_as_ref`](Self::func_map_as_ref) has the same
+/// functionality as [`FuncMap::func_map`], but passes every value of type `A`
+/// to the closure via [`AsRef<U>`]. This way, closures that only inspect the
+/// values don't need to take ownership of them, and functions taking a
+/// reference such as [`str::len`] can be passed directly. For the common case
+/// of [`String`] values, the method [`func_map_str`](Self::func_map_str) maps
+/// every value to a new [`String`] using a closure taking a [`&str`](str):
+/// ```
+/// # use funcmap::{FuncMap, FuncMapAsRef};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     value: T,
+///     more_values: Vec<T>,
+/// }
+///
+/// let foo = Foo {
+///     value: String::from("a"),
+///     more_values: vec![String::from("bc"), String::from("def")],
+/// };
+///
+/// let upper = foo.func_map_str(str::to_uppercase);
+///
+/// assert_eq!(
+///     upper,
+///     Foo {
+///         value: String::from("A"),
+///         more_values: vec![String::from("BC"), String::from("DEF")],
+///     }
+/// );
+///
+/// let lengths = upper.func_map_as_ref(str::len);
+///
+/// assert_eq!(
+///     lengths,
+///     Foo {
+///         value: 1,
+///         more_values: vec![2, 3],
+///     }
+/// );
+/// ```
+///
+/// As a type can implement [`AsRef<U>`] for many types `U`, e.g. [`String`]
+/// implements both [`AsRef<str>`] and [`AsRef<[u8]>`](AsRef), the type `U` is
+/// usually inferred from the argument type of the closure.
+pub trait FuncMapAsRef<A, P = TypeParam<0>>
+where
+    P: FuncMarker<P>,
+{
+    /// Applies the closure `f` to references of type `&U` to all values of
+    /// type `A` within `self` in a functorial way
+    #[must_use]
+    fn func_map_as_ref<U, B, F>(self, mut f: F) -> <Self as FuncMap<A, B, P>>::Output
+    where
+        Self: FuncMap<A, B, P> + Sized,
+        A: AsRef<U>,
+        U: ?Sized,
+        F: FnMut(&U) -> B,
+    {
+        self.func_map(|value| f(value.as_ref()))
+    }
+
+    /// Applies the closure `f` to string slices of all values of type `A`
+    /// within `self` in a functorial way, mapping them to [`String`]s
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    fn func_map_str<F>(self, f: F) -> <Self as FuncMap<A, String, P>>::Output
+    where
+        Self: FuncMap<A, String, P> + Sized,
+        A: AsRef<str>,
+        F: FnMut(&str) -> String,
+    {
+        self.func_map_as_ref(f)
+    }
+}
+
+impl<T, A, P> FuncMapAsRef<A, P> for T where P: FuncMarker<P> {}
+
+/// Functorial mapping under the names familiar from functional programming
+/// languages
+///
+/// This is an extension trait with a blanket implementation for all types, so
+/// its methods are available whenever [`FuncMap<A, B, P>`] (respectively
+/// [`TryFuncMap<A, B, P>`]) is implemented. It is only available with the
+/// `fp-names` Cargo feature.
+///
+/// Its methods [`fmap`](Self::fmap) and [`try_fmap`](Self::try_fmap) are
+/// aliases for [`func_map`](FuncMap::func_map) and
+/// [`try_func_map`](TryFuncMap::try_func_map), while [`void`](Self::void) and
+/// [`replace`](Self::replace) correspond to Haskell's `void` and `<$`:
+/// ```
+/// # use funcmap::{FuncMap, Fmap};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     value: T,
+///     more_values: Vec<T>,
+/// }
+///
+/// let foo = Foo {
+///     value: 1,
+///     more_values: vec![2, 3],
+/// };
+///
+/// let foo = foo.fmap(|v| v * 10);
+/// assert_eq!(foo.more_values, [20, 30]);
+///
+/// let foo = foo.replace("x");
+/// assert_eq!(foo.more_values, ["x", "x"]);
+///
+/// let foo = foo.void();
+/// assert_eq!(foo.more_values, [(), ()]);
+/// ```
+#[cfg(feature = "fp-names")]
+pub trait Fmap<A, P = TypeParam<0>>
+where
+    P: FuncMarker<P>,
+{
+    /// Applies the closure `f` to `self` in a functorial way, see
+    /// [`FuncMap::func_map`]
+    #[must_use]
+    fn fmap<B, F>(self, f: F) -> <Self as FuncMap<A, B, P>>::Output
+    where
+        Self: FuncMap<A, B, P> + Sized,
+        F: FnMut(A) -> B,
+    {
+        self.func_map(f)
+    }
+
+    /// Tries to apply the closure `f` to `self` in a functorial way, see
+    /// [`TryFuncMap::try_func_map`]
+    ///
+    /// # Errors
+    /// Fails if and only if `f` fails, returning the first error according to
+    /// the order of the fields in the definition of `Self`
+    fn try_fmap<B, E, F>(self, f: F) -> Result<<Self as TryFuncMap<A, B, P>>::Output, E>
+    where
+        Self: TryFuncMap<A, B, P> + Sized,
+        F: FnMut(A) -> Result<B, E>,
+    {
+        self.try_func_map(f)
+    }
+
+    /// Replaces every value of type `A` within `self` with `()`
+    #[must_use]
+    fn void(self) -> <Self as FuncMap<A, (), P>>::Output
+    where
+        Self: FuncMap<A, (), P> + Sized,
+    {
+        self.func_map(|_| ())
+    }
+
+    /// Replaces every value of type `A` within `self` with a clone of `b`
+    #[must_use]
+    fn replace<B>(self, b: B) -> <Self as FuncMap<A, B, P>>::Output
+    where
+        Self: FuncMap<A, B, P> + Sized,
+        B: Clone,
+    {
+        self.func_map(|_| b.clone())
+    }
+}
+
+#[cfg(feature = "fp-names")]
+impl<T, A, P> Fmap<A, P> for T where P: FuncMarker<P> {}
+
+/// Functorial mapping of a generic type over two of its type parameters
+/// instantiated with the same type
+///
+/// For a type `Foo<T, U>`, this is implemented for `Foo<A, A>`. Its method
+/// [`join_params`](Self::join_params) maps every value of type `A` within
+/// `self` through the same closure, regardless of whether it occurs in place of
+/// `T` or `U`, resulting in `Foo<B, B>`.
+///
+/// # Deriving [`JoinParams`]
+///
+/// Implementations of this trait can be derived for types with exactly two
+/// type parameters (or exactly two type parameters configured via
+/// `#[funcmap(params(...))]`):
+/// ```
+/// # use funcmap::JoinParams;
+/// #
+/// #[derive(JoinParams, Debug, PartialEq)]
+/// struct Foo<T, U> {
+///     value: T,
+///     more_values: Vec<U>,
+/// }
+///
+/// let foo = Foo {
+///     value: 1,
+///     more_values: vec![2, 3],
+/// };
+///
+/// assert_eq!(
+///     foo.join_params(|v| v * 10),
+///     Foo {
+///         value: 10,
+///         more_values: vec![20, 30],
+///     }
+/// );
+/// ```
+///
+/// This has the same effect as mapping over both type parameters in sequence
+/// via [`FuncMap`], but traverses `self` only once and requires only a single
+/// closure.
+///
+/// # Manually Implementing [`JoinParams`]
+///
+/// If `Foo` implements [`JoinParams<A, B, TypeParam<N>, TypeParam<M>>`], then
+/// `N` and `M` must be different, the parameters of `Foo` at index `N` and `M`
+/// must both be `A` and `Foo::Output` must be `Foo` with both of them replaced
+/// with `B`.
+pub trait JoinParams<A, B, P = TypeParam<0>, Q = TypeParam<1>>: Sized
+where
+    P: FuncMarker<P>,
+    Q: FuncMarker<Q>,
+{
+    /// The output type of the mapping
+    ///
+    /// This is `Self` with the parameters at index `N` and `M` replaced with
+    /// `B`, where `N` and `M` are such that `P` is `TypeParam<N>` and `Q` is
+    /// `TypeParam<M>`.
+    type Output;
+
+    /// Applies the closure `f` to every value of type `A` within `self`,
+    /// whether it occurs in place of the parameter at index `N` or `M`
+    #[must_use]
+    fn join_params<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B;
+}
+
+/// A family of types, i.e. a function mapping types to types
+///
+/// Implementors are typically uninhabited marker types, e.g.
+/// ```
+/// # use funcmap::Family;
+/// #
+/// enum OptionFamily {}
+///
+/// impl Family for OptionFamily {
+///     type Of<T> = Option<T>;
+/// }
+/// ```
+///
+/// Types generic over a family can be mapped from one family to another using
+/// the [`FamilyMap`] trait.
+pub trait Family {
+    /// The type this family assigns to the type `T`
+    type Of<T>;
+}
+
+/// A transformation from the family `FA` to the family `FB` that works the same
+/// for every type `T`
+///
+/// Its method [`transform`](Self::transform) turns a value of type
+/// `FA::Of<T>` into a value of type `FB::Of<T>` for every `T`. Since closures
+/// cannot be generic, this needs to be implemented on a dedicated type:
+/// ```
+/// # use funcmap::{Family, NaturalTransformation};
+/// #
+/// enum OptionFamily {}
+///
+/// impl Family for OptionFamily {
+///     type Of<T> = Option<T>;
+/// }
+///
+/// enum VecFamily {}
+///
+/// impl Family for VecFamily {
+///     type Of<T> = Vec<T>;
+/// }
+///
+/// struct OptionToVec;
+///
+/// impl NaturalTransformation<OptionFamily, VecFamily> for OptionToVec {
+///     fn transform<T>(&mut self, value: Option<T>) -> Vec<T> {
+///         value.into_iter().collect()
+///     }
+/// }
+/// ```
+pub trait NaturalTransformation<FA, FB>
+where
+    FA: Family,
+    FB: Family,
+{
+    /// Transforms a value of type `FA::Of<T>` into a value of type `FB::Of<T>`
+    fn transform<T>(&mut self, value: FA::Of<T>) -> FB::Of<T>;
+}
+
+impl<FA, FB, N> NaturalTransformation<FA, FB> for &mut N
+where
+    FA: Family,
+    FB: Family,
+    N: NaturalTransformation<FA, FB> + ?Sized,
+{
+    fn transform<T>(&mut self, value: FA::Of<T>) -> FB::Of<T> {
+        (**self).transform(value)
+    }
+}
+
+/// Mapping of a type generic over a [`Family`] from one family to another
+///
+/// For a type `Foo<F>`, where `F` is a family, this is implemented for
+/// `Foo<FA>`. Its method [`family_map`](Self::family_map) applies a
+/// [`NaturalTransformation`] from `FA` to `FB` to every value of type
+/// `FA::Of<X>` within `self`, for any `X`, resulting in `Foo<FB>`. So while
+/// [`FuncMap`] maps the values of a type parameter, this maps the containers
+/// given by a family.
+///
+/// # Deriving [`FamilyMap`]
+///
+/// Implementations of this trait can be derived for types with exactly one
+/// type parameter (or exactly one type parameter configured via
+/// `#[funcmap(params(...))]`), which needs to be bounded by [`Family`]:
+/// ```
+/// # use funcmap::{Family, FamilyMap, NaturalTransformation};
+/// #
+/// # enum OptionFamily {}
+/// #
+/// # impl Family for OptionFamily {
+/// #     type Of<T> = Option<T>;
+/// # }
+/// #
+/// # enum VecFamily {}
+/// #
+/// # impl Family for VecFamily {
+/// #     type Of<T> = Vec<T>;
+/// # }
+/// #
+/// # struct OptionToVec;
+/// #
+/// # impl NaturalTransformation<OptionFamily, VecFamily> for OptionToVec {
+/// #     fn transform<T>(&mut self, value: Option<T>) -> Vec<T> {
+/// #         value.into_iter().collect()
+/// #     }
+/// # }
+/// #
+/// #[derive(FamilyMap)]
+/// struct Config<F: Family> {
+///     name: F::Of<String>,
+///     ports: [F::Of<u16>; 2],
+///     verbose: bool,
+/// }
+///
+/// let config: Config<OptionFamily> = Config {
+///     name: Some(String::from("server")),
+///     ports: [Some(80), None],
+///     verbose: true,
+/// };
+///
+/// let config: Config<VecFamily> = config.family_map(OptionToVec);
+///
+/// assert_eq!(config.name, [String::from("server")]);
+/// assert_eq!(config.ports, [vec![80], vec![]]);
+/// assert!(config.verbose);
+/// ```
+///
+/// Projections of the family may occur anywhere within the types of the
+/// fields, as long as the surrounding types can be mapped via [`FuncMap`], but
+/// every field may contain projections onto only a single type `X`. Fields
+/// depending on the family in any other way, e.g. fields of type `Bar<F>`, are
+/// mapped via their own implementations of [`FamilyMap`].
+///
+/// # Manually Implementing [`FamilyMap`]
+///
+/// If `Foo` implements [`FamilyMap<FA, FB>`], then `Foo::Output` must be `Foo`
+/// with `FA` replaced with `FB`.
+pub trait FamilyMap<FA, FB>: Sized
+where
+    FA: Family,
+    FB: Family,
+{
+    /// The output type of the mapping
+    ///
+    /// This is `Self` with the family `FA` replaced with `FB`.
+    type Output;
+
+    /// Applies the natural transformation `n` to every value of type
+    /// `FA::Of<X>` within `self`, for any `X`
+    #[must_use]
+    fn family_map<N>(self, n: N) -> Self::Output
+    where
+        N: NaturalTransformation<FA, FB>;
+}
+
+/// Functorial mapping of a generic type with a closure that may drop values
+///
+/// Its method [`func_filter_map`](Self::func_filter_map) applies a closure
+/// returning [`Option<B>`] to all values of type `A` within `self`. Values for
+/// which the closure returns [`None`] are dropped from their collection, e.g.
+/// from a [`Vec`], a set or the values of a map (together with
+/// their keys), while an [`Option<A>`] becomes [`None`]:
+/// ```
+/// # use funcmap::FuncFilterMap;
+/// #
+/// let values = vec![1, -2, 3];
+///
+/// assert_eq!(
+///     values.func_filter_map(|v| u8::try_from(v).ok()),
+///     [1, 3]
+/// );
+/// assert_eq!(Some(-2).func_filter_map(|v| u8::try_from(v).ok()), None);
+/// ```
+///
+/// This is implemented for [`Option`] and, with the `alloc` feature, for the
+/// collections in [`alloc::collections`] and [`Vec`] and, with
+/// the `std` feature, for [`HashMap`](std::collections::HashMap) and
+/// [`HashSet`](std::collections::HashSet). The values are visited in the same
+/// order as by [`FuncMap::func_map`].
+///
+/// # Deriving [`FuncFilterMap`]
+///
+/// Implementations of this trait can be derived for types with exactly one type
+/// parameter (or exactly one type parameter configured via
+/// `#[funcmap(params(...))]`). Every field whose type depends on the type
+/// parameter is mapped via its own implementation of [`FuncFilterMap`], so it
+/// has to be a collection, an [`Option`] or another type deriving
+/// [`FuncFilterMap`], whereas a field of the type parameter itself cannot be
+/// dropped and is rejected. The other fields are moved. This is useful e.g.
+/// for pruning values during a migration:
+/// ```
+/// # use funcmap::FuncFilterMap;
+/// # use std::collections::BTreeMap;
+/// #
+/// #[derive(FuncFilterMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     name: &'static str,
+///     primary: Option<T>,
+///     secondary: Vec<T>,
+///     by_key: BTreeMap<char, T>,
+/// }
+///
+/// let foo = Foo {
+///     name: "foo",
+///     primary: Some("x"),
+///     secondary: vec!["1", "2"],
+///     by_key: [('a', "3"), ('b', "y")].into(),
+/// };
+///
+/// assert_eq!(
+///     foo.func_filter_map(|v| v.parse::<i32>().ok()),
+///     Foo {
+///         name: "foo",
+///         primary: None,
+///         secondary: vec![1, 2],
+///         by_key: [('a', 3)].into(),
+///     }
+/// );
+/// ```
+///
+/// # Manually Implementing [`FuncFilterMap`]
+///
+/// If `Foo` implements [`FuncFilterMap<A, B>`], then the type parameter of
+/// `Foo` that is filter-mapped must be `A` and `Foo::Output` must be `Foo` with
+/// it replaced with `B`.
+pub trait FuncFilterMap<A, B>: Sized {
+    /// The output type of the mapping
+    ///
+    /// This is `Self` with the filter-mapped type parameter replaced with `B`.
+    type Output;
+
+    /// Applies the closure `f` to all values of type `A` within `self`,
+    /// dropping the values for which it returns [`None`]
+    #[must_use]
+    fn func_filter_map<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(A) -> Option<B>;
+}
+
+/// Counting the values of a type parameter within a generic type without
+/// mapping them
+///
+/// Its method [`count_leaves`](Self::count_leaves) returns the number of
+/// values of type `A` within `self`, i.e. the number of times that
+/// [`FuncMap::func_map`] would invoke its closure. This is useful e.g. for
+/// reporting progress during an expensive mapping over a large value:
+/// ```
+/// # use funcmap::FuncCount;
+/// #
+/// let values = vec![Some(1), None, Some(3)];
+///
+/// assert_eq!(values.count_leaves(), 3);
+/// assert_eq!(values.func_count(|v| v.count_leaves()), 2);
+/// ```
+///
+/// The more general method [`func_count`](Self::func_count) sums up the results
+/// of applying a closure to references to all values of type `A` in the same
+/// order as [`FuncMap::func_map`] visits them, which allows counting values
+/// nested within other types, as in the example.
+///
+/// This is implemented for arrays, [`Bound`](core::ops::Bound), [`Option`],
+/// [`PhantomData`] and [`Result`] and, with the `alloc` feature, for [`Box`],
+/// [`Vec`] and the collections in [`alloc::collections`] and, with the `std`
+/// feature, for [`HashMap`](std::collections::HashMap) and
+/// [`HashSet`](std::collections::HashSet). For collections,
+/// [`count_leaves`](Self::count_leaves) returns their length without traversing
+/// them.
+///
+/// # Deriving [`FuncCount`]
+///
+/// Implementations of this trait can be derived in the same way and for the
+/// same type parameters as implementations of [`FuncMap`]. A derived
+/// implementation counts the values within every field depending on the type
+/// parameter through the implementations of [`FuncCount`] for the field types,
+/// using the shortcut for collections of values of the type parameter itself:
+/// ```
+/// # use funcmap::{FuncCount, FuncMap};
+/// #
+/// #[derive(FuncMap, FuncCount, Debug)]
+/// struct Foo<T> {
+///     name: &'static str,
+///     primary: T,
+///     batches: Vec<Vec<T>>,
+/// }
+///
+/// let foo = Foo {
+///     name: "foo",
+///     primary: 1,
+///     batches: vec![vec![2, 3], vec![4]],
+/// };
+///
+/// let total = foo.count_leaves();
+/// let mut done = 0;
+///
+/// let bar = foo.func_map(|v| {
+///     done += 1;
+///     println!("{done}/{total}");
+///     v * 10
+/// });
+///
+/// assert_eq!(done, 4);
+/// # assert_eq!(bar.primary, 10);
+/// ```
+///
+/// # Manually Implementing [`FuncCount`]
+///
+/// If `Foo` implements [`FuncCount<A, TypeParam<N>>`], then the `N`-th type
+/// parameter of `Foo` must be `A` and [`func_count`](Self::func_count) must
+/// visit the same values as [`FuncMap::func_map`] does for `TypeParam<N>`.
+/// Overriding [`count_leaves`](Self::count_leaves) is only an optimization and
+/// must be consistent with [`func_count`](Self::func_count).
+pub trait FuncCount<A, P = TypeParam<0>>
+where
+    P: FuncMarker<P>,
+{
+    /// Applies the closure `f` to references to all values of type `A` within
+    /// `self`, returning the sum of its results
+    fn func_count<F>(&self, f: F) -> usize
+    where
+        F: FnMut(&A) -> usize;
+
+    /// Returns the number of values of type `A` within `self`
+    fn count_leaves(&self) -> usize {
+        self.func_count(|_| 1)
+    }
+}
+
+/// Functorial mapping of a generic type by reference
+///
+/// Its method [`ref_func_map`](Self::ref_func_map) works like
+/// [`FuncMap::func_map`], but borrows `self` instead of consuming it and
+/// applies the closure to references to all values of type `A`. All other
+/// values are cloned into the output. This is useful for building a projection
+/// of a value without giving it up:
+/// ```
+/// # use funcmap::RefFuncMap;
+/// #
+/// let names = vec![String::from("a"), String::from("bc")];
+/// let lengths: Vec<usize> = names.ref_func_map(|name| name.len());
+///
+/// assert_eq!(lengths, [1, 2]);
+/// assert_eq!(names, ["a", "bc"]);
+/// ```
+///
+/// As the closure receives references with the lifetime `'a` of the borrow of
+/// `self`, it can also produce a value of the same type holding references
+/// into `self`, e.g. a `Vec<&str>` from a `&Vec<String>`.
+///
+/// This is implemented for arrays, [`Bound`](core::ops::Bound), [`Option`],
+/// [`PhantomData`] and [`Result`] and, with the `alloc` feature, for [`Box`],
+/// [`Vec`] and the collections in [`alloc::collections`] and, with the `std`
+/// feature, for [`HashMap`](std::collections::HashMap) and
+/// [`HashSet`](std::collections::HashSet). The values are visited in the same
+/// order as by [`FuncMap::func_map`].
+///
+/// # Deriving [`RefFuncMap`]
+///
+/// Implementations of this trait can be derived in the same way and for the
+/// same type parameters as implementations of [`FuncMap`]. A derived
+/// implementation maps every field depending on the type parameter through the
+/// implementation of [`RefFuncMap`] for its type and clones all other fields,
+/// so their types need to implement [`Clone`]:
+/// ```
+/// # use funcmap::RefFuncMap;
+/// #
+/// #[derive(RefFuncMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     name: String,
+///     primary: T,
+///     secondary: Vec<Option<T>>,
+/// }
+///
+/// let foo = Foo {
+///     name: String::from("foo"),
+///     primary: String::from("a"),
+///     secondary: vec![Some(String::from("bc")), None],
+/// };
+///
+/// let bar: Foo<&str> = foo.ref_func_map(String::as_str);
+///
+/// assert_eq!(
+///     bar,
+///     Foo {
+///         name: String::from("foo"),
+///         primary: "a",
+///         secondary: vec![Some("bc"), None],
+///     }
+/// );
+/// ```
+///
+/// Since a value can be borrowed only once, a derived implementation cannot
+/// map a field of a type with more than one type argument depending on the
+/// type parameter, such as `Result<T, Vec<T>>`.
+///
+/// # Manually Implementing [`RefFuncMap`]
+///
+/// If `Foo` implements [`RefFuncMap<'a, A, B, TypeParam<N>>`], then the `N`-th
+/// type parameter of `Foo` must be `A`, `Foo::Output` must be `Foo` with it
+/// replaced with `B` and [`ref_func_map`](Self::ref_func_map) must visit the
+/// same values as [`FuncMap::func_map`] does for `TypeParam<N>`.
+pub trait RefFuncMap<'a, A, B, P = TypeParam<0>>
+where
+    P: FuncMarker<P>,
+{
+    /// The output type of the mapping
+    ///
+    /// This is `Self` with the parameter at the index specified by `P` replaced
+    /// with `B`.
+    type Output;
+
+    /// Applies the closure `f` to references to all values of type `A` within
+    /// `self`, cloning all other values
+    #[must_use]
+    fn ref_func_map<F>(&'a self, f: F) -> Self::Output
+    where
+        A: 'a,
+        F: FnMut(&'a A) -> B;
+}
+
+/// Functorial mapping of a sequence in chunks
+///
+/// This is implemented for [`Vec<A>`](alloc::vec::Vec) and
+/// [`VecDeque<A>`](alloc::collections::VecDeque). Its method
+/// [`func_map_chunked`](Self::func_map_chunked) works like
+/// [`FuncMap::func_map`], but instead of being invoked for every single value,
+/// the closure is invoked for consecutive chunks of `chunk_size` values (except
+/// for the last chunk, which may be shorter) and pushes the mapped values onto
+/// the output. This allows the closure to do setup work once per chunk and to
+/// convert values in bulk, e.g. in a way the compiler can vectorize:
+/// ```
+/// # use funcmap::FuncMapChunked;
+/// #
+/// let values = vec![1_u8, 2, 3, 4, 5];
+///
+/// let mut chunk_lens = Vec::new();
+/// let values = values.func_map_chunked(2, |chunk, output| {
+///     chunk_lens.push(chunk.len());
+///     output.extend(chunk.iter().map(|&v| f32::from(v) / 2.0));
+/// });
+///
+/// assert_eq!(values, [0.5, 1.0, 1.5, 2.0, 2.5]);
+/// assert_eq!(chunk_lens, [2, 2, 1]);
+/// ```
+///
+/// The output is allocated once upfront, so the closure pushes directly into
+/// the memory of the result. The closure must push exactly one mapped value for
+/// every value of the chunk, in the same order, and must not remove values
+/// from the output.
+///
+/// # Panics
+/// Panics if `chunk_size` is zero or if the closure pushes a different number
+/// of values than the chunk contains.
+#[cfg(feature = "alloc")]
+pub trait FuncMapChunked<A, B>: FuncMap<A, B> {
+    /// Applies the closure `f` to `self` in a functorial way, one chunk of
+    /// `chunk_size` values at a time
+    #[must_use]
+    fn func_map_chunked<F>(self, chunk_size: usize, f: F) -> Self::Output
+    where
+        F: FnMut(&[A], &mut Vec<B>);
+}
+
+/// Functorial mapping of a collection by draining it
+///
+/// This is implemented for the collections in [`alloc`] and [`std`]. Its
+/// method [`drain_func_map`](Self::drain_func_map) works like
+/// [`FuncMap::func_map`], but takes `self` by mutable reference and moves its
+/// values out, leaving it empty. Where the collection supports draining (e.g.
+/// [`Vec::drain`]), the collection keeps its allocated memory, so it can be
+/// refilled without reallocating:
+/// ```
+/// # use funcmap::DrainFuncMap;
+/// #
+/// let mut values = Vec::with_capacity(16);
+/// values.extend([1, 2, 3]);
+///
+/// let mapped = values.drain_func_map(|v| v * 10);
+///
+/// assert_eq!(mapped, [10, 20, 30]);
+/// assert!(values.is_empty());
+/// assert!(values.capacity() >= 16);
+/// ```
+///
+/// For collections without a notion of capacity, such as
+/// [`BTreeMap`](alloc::collections::BTreeMap), the values are moved out by
+/// replacing `self` with an empty collection.
+#[cfg(feature = "alloc")]
+pub trait DrainFuncMap<A, B, P = TypeParam<0>>: FuncMap<A, B, P>
+where
+    P: FuncMarker<P>,
+{
+    /// Applies the closure `f` to the values drained from `self` in a
+    /// functorial way, leaving `self` empty
+    #[must_use]
+    fn drain_func_map<F>(&mut self, f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B;
+}
+
+/// Fallible functorial mapping of a collection into pre-reserved memory
+///
+/// This is implemented for [`Vec`],
+/// [`HashMap`](std::collections::HashMap) and
+/// [`BTreeMap`](alloc::collections::BTreeMap). Its method
+/// [`try_func_map_reserve`](Self::try_func_map_reserve) works like
+/// [`TryFuncMap::try_func_map`], but reserves memory for all values of the
+/// output upfront. For [`Vec`] and
+/// [`HashMap`](std::collections::HashMap), this means that the output is
+/// allocated exactly once in the success path, whereas
+/// [`try_func_map`](TryFuncMap::try_func_map) doesn't know the number of
+/// values in advance and grows the output as needed:
+/// ```
+/// # use funcmap::TryFuncMapReserve;
+/// #
+/// let values = vec![1, 2, 3];
+///
+/// let mapped: Result<Vec<u8>, _> = values.try_func_map_reserve(u8::try_from);
+///
+/// assert_eq!(mapped, Ok(vec![1, 2, 3]));
+/// ```
+///
+/// In the error path, both [`try_func_map`](TryFuncMap::try_func_map) and
+/// [`try_func_map_reserve`](Self::try_func_map_reserve) drop the partially
+/// built output together with the values of `self` that have not been mapped
+/// yet, so no memory beyond the output is allocated in either case. However,
+/// [`try_func_map_reserve`](Self::try_func_map_reserve) has already allocated
+/// memory for all values when the first error occurs, while
+/// [`try_func_map`](TryFuncMap::try_func_map) has only allocated memory for the
+/// values mapped so far. So prefer the former when errors are rare.
+///
+/// A [`BTreeMap`](alloc::collections::BTreeMap) has no notion of capacity, so
+/// its nodes are allocated one by one in either case.
+#[cfg(feature = "alloc")]
+pub trait TryFuncMapReserve<A, B, P = TypeParam<0>>: TryFuncMap<A, B, P>
+where
+    P: FuncMarker<P>,
+{
+    /// Tries to apply the closure `f` to `self` in a functorial way, reserving
+    /// memory for all values of the output upfront
+    ///
+    /// # Errors
+    /// Fails if and only if `f` fails, returning the first error according to
+    /// the iteration order of `self`
+    fn try_func_map_reserve<E, F>(self, f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(A) -> Result<B, E>;
+}
+
+/// Mapping of a collection by collecting its mapped items
+///
+/// This is implemented for collections `C<A>` that can be turned into an
+/// iterator over `A` and collected back from an iterator over `B` into a
+/// collection [`Output`](Self::Output), usually `C<B>`. Its provided methods
+/// map the collection through [`IntoIterator`], [`Iterator::map`] and
+/// [`Iterator::collect`], which is how [`FuncMap`] and [`TryFuncMap`] are
+/// implemented for most collections.
+///
+/// Because of the coherence rules of Rust, there cannot be a blanket
+/// implementation of [`FuncMap`] for all implementors of this trait. Instead,
+/// for a collection type in your crate generic over a single type parameter,
+/// you can implement [`CollectMap`], [`FuncMap`] and [`TryFuncMap`] at once
+/// through the [`impl_collect_map`] macro:
+/// ```
+/// # use funcmap::{FuncMap, TryFuncMap};
+/// #
+/// #[derive(Debug, PartialEq)]
+/// struct Stack<T>(Vec<T>);
+///
+/// impl<T> IntoIterator for Stack<T> {
+///     type Item = T;
+///     type IntoIter = std::vec::IntoIter<T>;
+///
+///     fn into_iter(self) -> Self::IntoIter {
+///         self.0.into_iter()
+///     }
+/// }
+///
+/// impl<T> FromIterator<T> for Stack<T> {
+///     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+///         Self(iter.into_iter().collect())
+///     }
+/// }
+///
+/// funcmap::impl_collect_map!(Stack);
+///
+/// assert_eq!(Stack(vec![1, 2]).func_map(|v| v * 10), Stack(vec![10, 20]));
+///
+/// #[derive(FuncMap)]
+/// struct Foo<T> {
+///     values: Stack<T>,
+/// }
+/// ```
+pub trait CollectMap<B>: IntoIterator + Sized {
+    /// The collection of mapped items
+    type Output: FromIterator<B>;
+
+    /// Maps every item of `self` through the closure `f` and collects the
+    /// results
+    #[must_use]
+    fn collect_map<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(Self::Item) -> B,
+    {
+        self.into_iter().map(f).collect()
+    }
+
+    /// Maps every item of `self` through the fallible closure `f` and collects
+    /// the results, stopping at the first error
+    ///
+    /// # Errors
+    /// Fails if and only if `f` fails, returning the first error
+    fn try_collect_map<E, F>(self, f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(Self::Item) -> Result<B, E>,
+    {
+        self.into_iter().map(f).collect()
+    }
+}
+
+/// Implements [`CollectMap`], [`FuncMap`] and [`TryFuncMap`] for a collection
+/// type generic over a single type parameter
+///
+/// The type must be given by its name, which has to be in scope, optionally
+/// followed by `where` and a list of predicates to add to the implementations.
+/// Within these, `A` and `B` refer to the type of the items before and after
+/// the mapping, respectively:
+/// ```
+/// # use std::collections::BTreeSet;
+/// #
+/// struct Set<T>(BTreeSet<T>);
+///
+/// # impl<T> IntoIterator for Set<T> {
+/// #     type Item = T;
+/// #     type IntoIter = std::collections::btree_set::IntoIter<T>;
+/// #
+/// #     fn into_iter(self) -> Self::IntoIter {
+/// #         self.0.into_iter()
+/// #     }
+/// # }
+/// #
+/// # impl<T: Ord> FromIterator<T> for Set<T> {
+/// #     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+/// #         Self(iter.into_iter().collect())
+/// #     }
+/// # }
+/// #
+/// // given implementations of `IntoIterator` for `Set<T>`
+/// // and of `FromIterator<T>` for `Set<T>` where `T: Ord`
+///
+/// funcmap::impl_collect_map!(Set where B: Ord);
+/// ```
+///
+/// See [`CollectMap`] for more information.
+#[macro_export]
+macro_rules! impl_collect_map {
+    ($ty:ident $(where $($bounds:tt)*)?) => {
+        impl<A, B> $crate::CollectMap<B> for $ty<A>
+        where
+            $ty<A>: ::core::iter::IntoIterator<Item = A>,
+            $ty<B>: ::core::iter::FromIterator<B>,
+            $($($bounds)*)?
+        {
+            type Output = $ty<B>;
+        }
+
+        impl<A, B> $crate::FuncMap<A, B> for $ty<A>
+        where
+            $ty<A>: ::core::iter::IntoIterator<Item = A>,
+            $ty<B>: ::core::iter::FromIterator<B>,
+            $($($bounds)*)?
+        {
+            type Output = $ty<B>;
+
+            fn func_map<F>(self, f: F) -> Self::Output
+            where
+                F: ::core::ops::FnMut(A) -> B,
+            {
+                $crate::CollectMap::collect_map(self, f)
+            }
+        }
+
+        impl<A, B> $crate::TryFuncMap<A, B> for $ty<A>
+        where
+            $ty<A>: ::core::iter::IntoIterator<Item = A>,
+            $ty<B>: ::core::iter::FromIterator<B>,
+            $($($bounds)*)?
+        {
+            type Output = $ty<B>;
+
+            fn try_func_map<E, F>(self, f: F) -> ::core::result::Result<Self::Output, E>
+            where
+                F: ::core::ops::FnMut(A) -> ::core::result::Result<B, E>,
+            {
+                $crate::CollectMap::try_collect_map(self, f)
+            }
+        }
+    };
+}
+
+/// Implements [`FuncMap`] and [`TryFuncMap`] for a newtype wrapper generic
+/// over a single type parameter
+///
+/// The newtype must have a single field whose type is generic over the same
+/// type parameter and implements [`FuncMap`] respectively [`TryFuncMap`]. It
+/// is given by its name, which has to be in scope, followed by the field and
+/// the name of the type of the field in braces. For tuple structs, the field is
+/// given by its index:
+/// ```
+/// # use funcmap::FuncMap;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// struct Stack<T>(Vec<T>);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Queue<T> {
+///     items: std::collections::VecDeque<T>,
+/// }
+///
+/// funcmap::impl_funcmap_newtype!(Stack { 0: Vec });
+/// funcmap::impl_funcmap_newtype!(Queue { items: std::collections::VecDeque });
+///
+/// let stack = Stack(vec![1, 2]);
+/// assert_eq!(stack.func_map(|x| x * 2), Stack(vec![2, 4]));
+/// ```
+///
+/// Optionally, the field can be followed by `where` and a list of predicates
+/// to add to the implementations. Within these, `A` and `B` refer to the type
+/// of the values before and after the mapping, respectively:
+/// ```
+/// # use std::collections::BTreeSet;
+/// #
+/// struct Set<T>(BTreeSet<T>);
+///
+/// funcmap::impl_funcmap_newtype!(Set { 0: BTreeSet } where B: Ord);
+/// ```
+///
+/// This is an alternative to deriving [`FuncMap`] and [`TryFuncMap`] for
+/// wrappers that cannot use the derive macros, e.g. because they are
+/// themselves generated by a declarative macro.
+#[macro_export]
+macro_rules! impl_funcmap_newtype {
+    (
+        $ty:ident { $field:tt : $($inner:ident)::+ }
+        $(where $($bounds:tt)*)?
+    ) => {
+        impl<A, B> $crate::FuncMap<A, B> for $ty<A>
+        where
+            $($inner)::+<A>: $crate::FuncMap<A, B, Output = $($inner)::+<B>>,
+            $($($bounds)*)?
+        {
+            type Output = $ty<B>;
+
+            fn func_map<F>(self, f: F) -> Self::Output
+            where
+                F: ::core::ops::FnMut(A) -> B,
+            {
+                $ty {
+                    $field: $crate::FuncMap::func_map(self.$field, f),
+                }
+            }
+        }
+
+        impl<A, B> $crate::TryFuncMap<A, B> for $ty<A>
+        where
+            $($inner)::+<A>: $crate::TryFuncMap<A, B, Output = $($inner)::+<B>>,
+            $($($bounds)*)?
+        {
+            type Output = $ty<B>;
+
+            fn try_func_map<E, F>(self, f: F) -> ::core::result::Result<Self::Output, E>
+            where
+                F: ::core::ops::FnMut(A) -> ::core::result::Result<B, E>,
+            {
+                ::core::result::Result::Ok($ty {
+                    $field: $crate::TryFuncMap::try_func_map(self.$field, f)?,
+                })
+            }
+        }
+    };
+}
+
+/// Implements [`FuncMap`] and [`TryFuncMap`] for a wrapper type generic over a
+/// single type parameter through functions unwrapping and wrapping its value
+///
+/// This is meant for wrappers such as units of measurement or durations that
+/// are generic over a numeric type, but keep their value in a private field
+/// and provide functions for accessing it instead. The type is given by its
+/// path, followed by the unwrapping and wrapping functions in braces, which
+/// can be paths to functions or closures:
+/// ```
+/// # use funcmap::FuncMap;
+/// #
+/// mod units {
+///     #[derive(Debug, PartialEq)]
+///     pub struct Seconds<T>(T);
 ///
-///   // must be equivalent to
-///   # ==
+///     impl<T> Seconds<T> {
+///         pub fn new(value: T) -> Self {
+///             Self(value)
+///         }
 ///
-///   foo.func_map_over::<TypeParam<M>, _>(g)
-///      .func_map_over::<TypeParam<N>, _>(f)  
-///   # );
-///   ```
-pub trait FuncMap<A, B, P = TypeParam<0>>: Sized
+///         pub fn get(self) -> T {
+///             self.0
+///         }
+///     }
+/// }
+///
+/// funcmap::impl_funcmap_wrapper!(units::Seconds {
+///     unwrap: units::Seconds::get,
+///     wrap: units::Seconds::new,
+/// });
+///
+/// let seconds = units::Seconds::new(90_u32);
+/// assert_eq!(seconds.func_map(f64::from), units::Seconds::new(90.0));
+/// ```
+///
+/// Optionally, the functions can be followed by `where` and a list of
+/// predicates to add to the implementations. Within these, `A` and `B` refer
+/// to the type of the values before and after the mapping, respectively:
+/// ```
+/// # use funcmap::TryFuncMap;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// struct Ratio<T>(T);
+///
+/// impl<T: Copy> Ratio<T> {
+///     fn new(value: T) -> Self {
+///         Self(value)
+///     }
+/// }
+///
+/// funcmap::impl_funcmap_wrapper!(Ratio {
+///     unwrap: |ratio| ratio.0,
+///     wrap: Ratio::new,
+/// } where B: Copy);
+///
+/// let ratio = Ratio::new(42_u64);
+/// assert_eq!(ratio.try_func_map(u8::try_from), Ok(Ratio::new(42)));
+/// ```
+///
+/// Note that, due to the orphan rules, this can only be used within the crate
+/// defining the wrapper type. For wrapper types from other crates, consider
+/// putting them into a newtype within your crate and using
+/// [`impl_funcmap_newtype`].
+#[macro_export]
+macro_rules! impl_funcmap_wrapper {
+    (
+        $($ty:ident)::+ { unwrap: $unwrap:expr, wrap: $wrap:expr $(,)? }
+        $(where $($bounds:tt)*)?
+    ) => {
+        impl<A, B> $crate::FuncMap<A, B> for $($ty)::+<A>
+        where
+            $($($bounds)*)?
+        {
+            type Output = $($ty)::+<B>;
+
+            fn func_map<F>(self, mut f: F) -> Self::Output
+            where
+                F: ::core::ops::FnMut(A) -> B,
+            {
+                let unwrap: fn(Self) -> A = $unwrap;
+                let wrap: fn(B) -> Self::Output = $wrap;
+
+                wrap(f(unwrap(self)))
+            }
+        }
+
+        impl<A, B> $crate::TryFuncMap<A, B> for $($ty)::+<A>
+        where
+            $($($bounds)*)?
+        {
+            type Output = $($ty)::+<B>;
+
+            fn try_func_map<E, F>(self, mut f: F) -> ::core::result::Result<Self::Output, E>
+            where
+                F: ::core::ops::FnMut(A) -> ::core::result::Result<B, E>,
+            {
+                let unwrap: fn(Self) -> A = $unwrap;
+                let wrap: fn(B) -> Self::Output = $wrap;
+
+                ::core::result::Result::Ok(wrap(f(unwrap(self))?))
+            }
+        }
+    };
+}
+
+/// Applies a closure to a value in a functorial way, specifying the type
+/// parameter to map over by its name
+///
+/// `func_map_over!(value, T, f)` is equivalent to
+/// `value.func_map_over::<TypeParam<N>, _>(f)`, where `N` is the index of the
+/// type parameter named `T` in the definition of the type of `value`. This only
+/// works for types for which [`FuncMap`] is derived over `T`, as the index is
+/// looked up via an implementation generated by the derive macro.
+///
+/// Naming the type parameter rather than its index keeps working when the
+/// index changes, e.g. because a const generic is inserted in front of it:
+/// ```
+/// # use funcmap::{func_map_over, FuncMap};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<const N: usize, S, T> {
+///     s: [S; N],
+///     t: T,
+/// }
+///
+/// let foo = Foo { s: [1, 2], t: 3 };
+///
+/// let bar = func_map_over!(foo, T, |x| x + 1);
+/// // Equivalent to: let bar = foo.func_map_over::<TypeParam<2>, _>(|x| x + 1);
+///
+/// assert_eq!(bar, Foo { s: [1, 2], t: 4 });
+/// ```
+///
+/// If the type has no type parameter of the given name for which [`FuncMap`] is
+/// derived, this fails to compile.
+#[macro_export]
+macro_rules! func_map_over {
+    ($value:expr, $param:ident, $f:expr $(,)?) => {
+        $crate::func_map_over_named::<
+            { $crate::param_name_hash(::core::stringify!($param)) },
+            _,
+            _,
+            _,
+            _,
+        >($value, $f)
+    };
+}
+
+/// Tries to apply a closure to a value in a functorial way, specifying the
+/// type parameter to map over by its name
+///
+/// This is the counterpart of [`func_map_over!`] for [`TryFuncMap`]:
+/// `try_func_map_over!(value, T, f)` is equivalent to
+/// `value.try_func_map_over::<TypeParam<N>, _, _>(f)`, where `N` is the index
+/// of the type parameter named `T` in the definition of the type of `value`.
+/// ```
+/// # use funcmap::{try_func_map_over, TryFuncMap};
+/// #
+/// #[derive(TryFuncMap, Debug, PartialEq)]
+/// struct Foo<const N: usize, S, T> {
+///     s: [S; N],
+///     t: T,
+/// }
+///
+/// let foo = Foo { s: ["1", "2"], t: "3" };
+///
+/// let bar = try_func_map_over!(foo, T, |x| x.parse::<i32>());
+///
+/// assert_eq!(bar, Ok(Foo { s: ["1", "2"], t: 3 }));
+/// ```
+#[macro_export]
+macro_rules! try_func_map_over {
+    ($value:expr, $param:ident, $f:expr $(,)?) => {
+        $crate::try_func_map_over_named::<
+            { $crate::param_name_hash(::core::stringify!($param)) },
+            _,
+            _,
+            _,
+            _,
+            _,
+        >($value, $f)
+    };
+}
+
+/// Expands to the marker type [`TypeParam<N>`] for mapping a given type over
+/// one of its generic arguments
+///
+/// `param_of!(A in Bar<X, A, Y>)` expands to `TypeParam<1>`, i.e. to the
+/// marker for the position at which the type parameter `A` occurs among the
+/// generic arguments of `Bar<X, A, Y>`. Lifetime arguments are skipped, while
+/// all other arguments count, in accordance with how [`TypeParam<N>`] is
+/// indexed.
+///
+/// This is meant for manual implementations of [`FuncMap`] and [`TryFuncMap`]
+/// for types containing other types whose generic arguments are in a different
+/// order, where working out the index by hand is error-prone:
+/// ```
+/// use funcmap::{param_of, FuncMap, TypeParam};
+///
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Bar<S, T>(S, T);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Foo<S, T> {
+///     bar: Bar<T, S>,
+/// }
+///
+/// impl<S, A, B> FuncMap<A, B, TypeParam<1>> for Foo<S, A> {
+///     type Output = Foo<S, B>;
+///
+///     fn func_map<F>(self, f: F) -> Self::Output
+///     where
+///         F: FnMut(A) -> B,
+///     {
+///         Foo {
+///             // Equivalent to: self.bar.func_map_over::<TypeParam<0>, _>(f)
+///             bar: self.bar.func_map_over::<param_of!(A in Bar<A, S>), _>(f),
+///         }
+///     }
+/// }
+///
+/// let foo = Foo { bar: Bar(1, 2) };
+///
+/// assert_eq!(
+///     foo.func_map_over::<TypeParam<1>, _>(|x| x * 10),
+///     Foo { bar: Bar(10, 2) }
+/// );
+/// ```
+///
+/// The type parameter has to occur as a generic argument on its own, exactly
+/// once, otherwise this fails to compile.
+///
+/// Alternatively, `param_of!(Foo<X, Y>, T)` expands to the marker type for the
+/// type parameter named `T` in the definition of `Foo`, looked up via an
+/// implementation generated by the [`FuncMap`] derive macro just like for
+/// [`func_map_over!`]. This keeps call sites readable and correct when the type
+/// parameters of `Foo` are reordered:
+/// ```
+/// use funcmap::{param_of, FuncMap};
+///
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<S, T> {
+///     s: S,
+///     t: T,
+/// }
+///
+/// let foo = Foo { s: 1, t: 2 };
+///
+/// // Equivalent to: foo.func_map_over::<TypeParam<1>, _>(|x| x * 10)
+/// let bar = foo.func_map_over::<param_of!(Foo<i32, i32>, T), _>(|x| x * 10);
+///
+/// assert_eq!(bar, Foo { s: 1, t: 20 });
+/// ```
+#[macro_export]
+macro_rules! param_of {
+    ($param:ident in $(::)? $($seg:ident)::+ < $($args:tt)+) => {
+        $crate::param_of!(@args $param [] $($args)+)
+    };
+
+    (@args $param:ident [$($acc:tt)*] $arg:lifetime , $($rest:tt)+) => {
+        $crate::param_of!(@args $param [$($acc)*] $($rest)+)
+    };
+
+    (@args $param:ident [$($acc:tt)*] $arg:literal $($rest:tt)+) => {
+        $crate::param_of!(@args $param [$($acc)* (),] $($rest)+)
+    };
+
+    (@args $param:ident [$($acc:tt)*] { $($arg:tt)* } $($rest:tt)+) => {
+        $crate::param_of!(@args $param [$($acc)* (),] $($rest)+)
+    };
+
+    (@args $param:ident [$($acc:tt)*] , $($rest:tt)+) => {
+        $crate::param_of!(@args $param [$($acc)*] $($rest)+)
+    };
+
+    (@args $param:ident [$($acc:tt)*] $arg:ty , $($rest:tt)+) => {
+        $crate::param_of!(@args $param [$($acc)* $arg,] $($rest)+)
+    };
+
+    (@args $param:ident [$($acc:tt)*] $arg:ty >) => {
+        $crate::param_of!(@args $param [$($acc)* $arg,] >)
+    };
+
+    (@args $param:ident [$($acc:ty,)*] $(,)? >) => {
+        $crate::TypeParam<
+            {
+                $crate::param_position(
+                    &[$($crate::param_name_hash(::core::stringify!($acc))),*],
+                    $crate::param_name_hash(::core::stringify!($param)),
+                )
+            },
+        >
+    };
+
+    ($ty:ty, $param:ident $(,)?) => {
+        <$ty as $crate::FuncMapNamedParam<
+            { $crate::param_name_hash(::core::stringify!($param)) },
+        >>::Marker
+    };
+}
+
+/// Applies a closure to a value of an enum in a functorial way, but only if it
+/// is a given variant
+///
+/// `func_map_variant!(value, Variant, f)` is equivalent to `value.func_map(f)`
+/// if `value` is the variant named `Variant` and returns `value` unchanged
+/// otherwise. As values of the other variants are left untouched, `f` must map
+/// the type parameter to itself, so the type of `value` stays the same. This is
+/// useful when different variants hold semantically different values of the
+/// type parameter that must be migrated separately:
+/// ```
+/// # use funcmap::{func_map_variant, FuncMap};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// enum Length<T> {
+///     Meters(T),
+///     Feet(T),
+/// }
+///
+/// let meters = Length::Meters(2.0);
+/// let feet = Length::Feet(2.0);
+///
+/// let to_cm = |m| m * 100.0;
+/// assert_eq!(func_map_variant!(meters, Meters, to_cm), Length::Meters(200.0));
+/// assert_eq!(func_map_variant!(feet, Meters, to_cm), Length::Feet(2.0));
+/// ```
+///
+/// This only works for enums for which [`FuncMap`] is derived, as the variant
+/// is identified via an implementation generated by the derive macro. If the
+/// enum has no variant of the given name, this fails to compile.
+#[macro_export]
+macro_rules! func_map_variant {
+    ($value:expr, $variant:ident, $f:expr $(,)?) => {
+        $crate::func_map_variant_named::<
+            { $crate::param_name_hash(::core::stringify!($variant)) },
+            _,
+            _,
+            _,
+            _,
+        >($value, $f)
+    };
+}
+
+/// Tries to apply a closure to a value of an enum in a functorial way, but
+/// only if it is a given variant
+///
+/// This is the counterpart of [`func_map_variant!`] for [`TryFuncMap`]:
+/// `try_func_map_variant!(value, Variant, f)` is equivalent to
+/// `value.try_func_map(f)` if `value` is the variant named `Variant` and
+/// returns `Ok(value)` otherwise.
+/// ```
+/// # use funcmap::{try_func_map_variant, TryFuncMap};
+/// #
+/// #[derive(TryFuncMap, Debug, PartialEq)]
+/// enum Input<T> {
+///     Checked(T),
+///     Unchecked(T),
+/// }
+///
+/// let checked = Input::Checked("x");
+/// let unchecked = Input::Unchecked("x");
+///
+/// let validate = |s: &'static str| if s.is_empty() { Err("empty") } else { Ok(s) };
+/// assert_eq!(try_func_map_variant!(checked, Unchecked, validate), Ok(Input::Checked("x")));
+/// assert_eq!(try_func_map_variant!(unchecked, Unchecked, validate), Ok(Input::Unchecked("x")));
+/// ```
+#[macro_export]
+macro_rules! try_func_map_variant {
+    ($value:expr, $variant:ident, $f:expr $(,)?) => {
+        $crate::try_func_map_variant_named::<
+            { $crate::param_name_hash(::core::stringify!($variant)) },
+            _,
+            _,
+            _,
+            _,
+            _,
+        >($value, $f)
+    };
+}
+
+/// Functorial mapping of a generic type over one of its type parameters while
+/// accumulating a value
+///
+/// This is an extension trait with a blanket implementation for all types
+/// implementing [`FuncMap<A, B, P>`], which in particular includes all types
+/// for which [`FuncMap`] is derived.
+///
+/// Its method [`func_map_fold`](Self::func_map_fold) works like
+/// [`FuncMap::func_map`], but additionally threads an accumulator through all
+/// invocations of the closure, so you can e.g. renumber values and collect a
+/// lookup table in a single traversal without resorting to interior
+/// mutability:
+/// ```
+/// # use funcmap::{FuncMap, FuncMapFold};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     value: T,
+///     more_values: Vec<T>,
+/// }
+///
+/// let foo = Foo {
+///     value: "a",
+///     more_values: vec!["b", "c"],
+/// };
+///
+/// let (names, bar) = foo.func_map_fold(Vec::new(), |mut names, name| {
+///     names.push(name);
+///     let id = names.len() - 1;
+///     (names, id)
+/// });
+///
+/// assert_eq!(names, ["a", "b", "c"]);
+/// assert_eq!(
+///     bar,
+///     Foo {
+///         value: 0,
+///         more_values: vec![1, 2],
+///     }
+/// );
+/// ```
+///
+/// The closure is invoked in the same order as for [`FuncMap::func_map`], i.e.
+/// according to the order of the fields in the definition of `Self`.
+pub trait FuncMapFold<A, B, P = TypeParam<0>>: FuncMap<A, B, P>
 where
     P: FuncMarker<P>,
 {
-    /// The output type of the functorial mapping
-    ///
-    /// This is `Self` with the parameter at index `N` replaced with `B`, where
-    /// `N` is such that `P` is `TypeParam<N>`.
+    /// Applies the closure `f` to `self` in a functorial way, threading an
+    /// accumulator starting at `init` through all invocations of `f`
     ///
-    /// In the simplest case of a type with just a single type parameter, if
-    /// `Self` is `Foo<A>`, then this is `Foo<B>`.
-    type Output;
-
-    /// Applies the closure `f` to `self` in a functorial way
-    fn func_map<F>(self, f: F) -> Self::Output
+    /// Returns the final value of the accumulator together with the output of
+    /// the mapping.
+    #[must_use]
+    fn func_map_fold<Acc, F>(self, init: Acc, mut f: F) -> (Acc, Self::Output)
     where
-        F: FnMut(A) -> B;
+        F: FnMut(Acc, A) -> (Acc, B),
+    {
+        let mut acc = Some(init);
 
-    /// Applies the closure `f` to `self` in a functorial way, allowing explicit
-    /// specification of the marker type `P`
-    ///
-    /// This is a convenience method that has the exact same functionality as
-    /// [`func_map`](Self::func_map) but can be used to specify the marker type
-    /// `P` in a convenient way in cases where it is ambiguous.
-    ///
-    /// So if you have
-    /// ```
-    /// # use funcmap::FuncMap;
-    /// #
-    /// #[derive(FuncMap, Debug, PartialEq)]
-    /// struct Foo<S, T> {
-    ///     s: S,
-    ///     t: T,
-    /// }
-    ///
-    /// let foo = Foo {
-    ///     s: 42,
-    ///     t: 42,
-    /// };
-    /// ```
-    /// then instead of writing
-    /// ```
-    /// # use funcmap::{FuncMap, TypeParam};
-    /// #
-    /// # #[derive(FuncMap, Debug, PartialEq)]
-    /// # struct Foo<S, T> {
-    /// #     s: S,
-    /// #     t: T,
-    /// # }
-    /// #
-    /// # let foo = Foo {
-    /// #     s: 42,
-    /// #     t: 42,
-    /// # };
-    /// #
-    /// let bar = FuncMap::<_, _, TypeParam<1>>::func_map(foo, |v| v + 1);
-    /// assert_eq!(bar, Foo { s: 42, t: 43 });
-    /// ```
-    ///
-    /// you can more conveniently write
-    ///
-    /// ```
-    /// # use funcmap::{FuncMap, TypeParam};
-    /// #
-    /// # #[derive(FuncMap, Debug, PartialEq)]
-    /// # struct Foo<S, T> {
-    /// #     s: S,
-    /// #     t: T,
-    /// # }
-    /// #
-    /// # let foo = Foo {
-    /// #     s: 42,
-    /// #     t: 42,
-    /// # };
-    /// #
-    /// let bar = foo.func_map_over::<TypeParam<1>, _>(|v| v + 1);
-    /// assert_eq!(bar, Foo { s: 42, t: 43 });
-    /// ```
-    ///
-    /// This lets you chain method calls more easily as in
-    /// ```
-    /// # use funcmap::{FuncMap, TypeParam};
-    /// #
-    /// # #[derive(FuncMap, Debug, PartialEq)]
-    /// # struct Foo<S, T> {
-    /// #     s: S,
-    /// #     t: T,
-    /// # }
-    /// #
-    /// # let foo = Foo {
-    /// #     s: 42,
-    /// #     t: 42,
-    /// # };
-    /// #
-    /// foo.func_map_over::<TypeParam<0>, _>(|v| v + 1)
-    ///    .func_map_over::<TypeParam<1>, _>(|v| v + 1)
-    /// # ;
-    /// ```
-    ///
-    /// Note that you still need to specify the inferred type `_` for the
-    /// closure type `F`.
-    fn func_map_over<Q, F>(self, f: F) -> Self::Output
+        let output = self.func_map(|value| {
+            // `acc` is always `Some` in between invocations of the closure
+            let (next_acc, mapped) = match acc.take() {
+                Some(acc) => f(acc, value),
+                None => unreachable!(),
+            };
+
+            acc = Some(next_acc);
+            mapped
+        });
+
+        match acc {
+            Some(acc) => (acc, output),
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T, A, B, P> FuncMapFold<A, B, P> for T
+where
+    T: FuncMap<A, B, P>,
+    P: FuncMarker<P>,
+{
+}
+
+/// Functorial mapping of a generic type over one of its type parameters with
+/// the positions of the values
+///
+/// This is an extension trait with a blanket implementation for all types
+/// implementing [`FuncMap<A, B, P>`], which in particular includes all types
+/// for which [`FuncMap`] is derived.
+///
+/// Its method [`func_map_enumerated`](Self::func_map_enumerated) works like
+/// [`FuncMap::func_map`], but additionally passes the position of every value
+/// to the closure, so you can e.g. assign stable IDs to the values without
+/// keeping a separate counter:
+/// ```
+/// # use funcmap::{FuncMap, FuncMapEnumerated};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     value: T,
+///     more_values: Vec<Option<T>>,
+/// }
+///
+/// let foo = Foo {
+///     value: "a",
+///     more_values: vec![Some("b"), None, Some("c")],
+/// };
+///
+/// let bar = foo.func_map_enumerated(|idx, name| format!("{name}#{idx}"));
+///
+/// assert_eq!(
+///     bar,
+///     Foo {
+///         value: String::from("a#0"),
+///         more_values: vec![Some(String::from("b#1")), None, Some(String::from("c#2"))],
+///     }
+/// );
+/// ```
+///
+/// The position of a value is the number of values visited before it in the
+/// [traversal order](crate#traversal-order) of [`FuncMap::func_map`], i.e.
+/// positions are assigned depth-first according to the order of the fields in
+/// the definition of `Self`, starting at `0`. As this order is part of the
+/// contract of [`FuncMap`], values at the same place within values of the same
+/// shape always get the same position.
+pub trait FuncMapEnumerated<A, B, P = TypeParam<0>>: FuncMap<A, B, P>
+where
+    P: FuncMarker<P>,
+{
+    /// Applies the closure `f` to `self` in a functorial way, passing the
+    /// position of every value along with the value
+    #[must_use]
+    fn func_map_enumerated<F>(self, mut f: F) -> Self::Output
     where
-        F: FnMut(A) -> B,
-        Q: FuncMarker<P>,
+        F: FnMut(usize, A) -> B,
     {
-        self.func_map(f)
+        let mut idx = 0;
+
+        self.func_map(|value| {
+            let mapped = f(idx, value);
+            idx += 1;
+            mapped
+        })
     }
 }
 
-/// Fallible functorial mapping of a generic type over any of its type
-/// parameters
+impl<T, A, B, P> FuncMapEnumerated<A, B, P> for T
+where
+    T: FuncMap<A, B, P>,
+    P: FuncMarker<P>,
+{
+}
+
+/// Fallible functorial mapping of a generic type over one of its type
+/// parameters that keeps the successfully mapped values
 ///
-/// # Deriving [`TryFuncMap`]
+/// This is an extension trait with a blanket implementation for all types
+/// implementing [`FuncMap<A, Option<B>, P>`], which in particular includes all
+/// types for which [`FuncMap`] is derived.
 ///
-/// In most cases, implementations of this trait can and should be derived
-/// automatically:
+/// Its method [`try_func_map_partition`](Self::try_func_map_partition) applies
+/// a fallible closure to all values of type `A`, but unlike
+/// [`TryFuncMap::try_func_map`] it doesn't stop at the first error. Instead,
+/// every value is replaced with [`Some`] of its mapped value if the closure
+/// succeeds and with [`None`] if it fails, while the errors are collected
+/// separately. This is useful for lossy migrations that should keep the parts
+/// that could be converted and report the ones that couldn't:
 /// ```
-/// # use funcmap::TryFuncMap;
+/// # use funcmap::{FuncMap, TryFuncMapPartition};
 /// #
-/// #[derive(TryFuncMap)]
+/// #[derive(FuncMap, Debug, PartialEq)]
 /// struct Foo<T> {
-///     // ...
-///     # value: T,
+///     value: T,
+///     more_values: Vec<T>,
 /// }
+///
+/// let foo = Foo {
+///     value: "1",
+///     more_values: vec!["2", "x", "4"],
+/// };
+///
+/// let (bar, errors) = foo.try_func_map_partition(|v| v.parse::<i32>().map_err(|_| v));
+///
+/// assert_eq!(errors, ["x"]);
+/// assert_eq!(
+///     bar,
+///     Foo {
+///         value: Some(1),
+///         more_values: vec![Some(2), None, Some(4)],
+///     }
+/// );
 /// ```
 ///
-/// See the [crate-level documentation](crate) for details.
+/// The closure is invoked for every value, in the same order as for
+/// [`FuncMap::func_map`], so the errors are collected in this order as well.
+#[cfg(feature = "alloc")]
+pub trait TryFuncMapPartition<A, B, P = TypeParam<0>>: FuncMap<A, Option<B>, P>
+where
+    P: FuncMarker<P>,
+{
+    /// Applies the fallible closure `f` to all values of type `A` within `self`
+    /// in a functorial way, keeping the successfully mapped values and
+    /// collecting the errors
+    ///
+    /// Returns the output of the mapping, where every value is [`Some`] if `f`
+    /// succeeded for it and [`None`] otherwise, together with all errors
+    /// returned by `f`.
+    #[must_use]
+    fn try_func_map_partition<E, F>(self, mut f: F) -> (Self::Output, Vec<E>)
+    where
+        F: FnMut(A) -> Result<B, E>,
+    {
+        let mut errors = Vec::new();
+
+        let output = self.func_map(|value| match f(value) {
+            Ok(mapped) => Some(mapped),
+            Err(err) => {
+                errors.push(err);
+                None
+            }
+        });
+
+        (output, errors)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, A, B, P> TryFuncMapPartition<A, B, P> for T
+where
+    T: FuncMap<A, Option<B>, P>,
+    P: FuncMarker<P>,
+{
+}
+
+/// Functorial mapping of a generic type over one of its type parameters
+/// alongside an iterator
 ///
-/// # Manually Implementing [`TryFuncMap`]
+/// This is an extension trait with a blanket implementation for all types
+/// implementing [`FuncMap<A, B, P>`], which in particular includes all types
+/// for which [`FuncMap`] is derived.
 ///
-/// If you need to implement [`TryFuncMap`] manually, make sure to uphold the
-/// following contract:
+/// Its method [`func_map_zip_with`](Self::func_map_zip_with) consumes one item
+/// of an iterator for every value of type `A`, in the order in which
+/// [`FuncMap::func_map`] visits the values, and passes both to the closure.
+/// Its method [`func_map_zip`](Self::func_map_zip) replaces every value with
+/// the next item of the iterator:
+/// ```
+/// # use funcmap::{FuncMap, FuncMapZip};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     value: T,
+///     more_values: Vec<T>,
+/// }
 ///
-/// Let `Foo` be a type that is generic over the type or const parameters
-/// `T0, ..., Tn`.
+/// let foo = Foo {
+///     value: "a",
+///     more_values: vec!["b", "c"],
+/// };
 ///
-/// If `Foo` implements [`TryFuncMap<A, B, TypeParam<N>>`], then
-/// - `N` must be in the range `0..=n`.
-/// - The parameter of `Foo` at index `N` (not counting lifetime parameters)
-///   must be `A`. In particular, it must be a type parameter, not a const
-///   generic.
-/// - `Foo::Output` must be `Foo` with the parameter at index `N` replaced with
-///   `B`.
+/// let bar = foo.func_map_zip_with(1.., |name, id| (id, name));
 ///
-/// Furthermore:
-/// - [`try_func_map_over`](Self::try_func_map_over) must behave in exactly the
-///   same way as [`try_func_map`](Self::try_func_map). This is the default
-///   behavior and must not be changed.
-/// - If the closure provided to [`try_func_map`](Self::try_func_map) fails,
-///   then the result must be the first error according to the order of the
-///   fields in the definition of `Foo`:
-///   ```
-///   # use funcmap::TryFuncMap;
-///   # use std::num::{IntErrorKind, ParseIntError};
-///   #
-///   #[derive(TryFuncMap, Copy, Clone, Debug, PartialEq)]
-///   struct Foo<T> {
-///       value1: T,
-///       value2: T,
-///   }
-///   
-///   let foo = Foo {
-///       value1: "1a",
-///       value2: ""
-///   };
+/// assert_eq!(
+///     bar,
+///     Foo {
+///         value: (1, "a"),
+///         more_values: vec![(2, "b"), (3, "c")],
+///     }
+/// );
 ///
-///   let result: Result<Foo<i32>, ParseIntError> = foo.try_func_map(|v| v.parse());
-///   
-///   assert!(result.is_err());
-///   assert_eq!(*result.unwrap_err().kind(), IntErrorKind::InvalidDigit);
-///   ```
-/// - When implementing [`TryFuncMap`] for different marker types
-///   [`TypeParam<N>`] and [`TypeParam<M>`], the result of mapping over both
-///   type parameters in sequence must not depend on the order of the two
-///   mappings, i.e.
-///   ```
-///   # use funcmap::{TryFuncMap, TypeParam};
-///   #
-///   # #[derive(TryFuncMap, Copy, Clone, Debug, PartialEq)]
-///   # struct Foo<T, U>(T, U);
-///   #
-///   # const N: usize = 0;
-///   # const M: usize = 1;
-///   #
-///   # let foo = Foo(42, 43);
-///   # let f = |x| Ok::<_, ()>(x + 1);
-///   # let g = |x| Ok::<_, ()>(x * 2);
-///   #
-///   # assert!(
-///   foo.try_func_map_over::<TypeParam<N>, _, _>(f)
-///      .and_then(|x| x.try_func_map_over::<TypeParam<M>, _, _>(g))
+/// let baz = bar.func_map_zip(vec!['x', 'y', 'z']);
 ///
-///   // must be equivalent to
-///   # ==
+/// assert_eq!(
+///     baz,
+///     Foo {
+///         value: 'x',
+///         more_values: vec!['y', 'z'],
+///     }
+/// );
+/// ```
 ///
-///   foo.try_func_map_over::<TypeParam<M>, _, _>(g)
-///      .and_then(|x| x.try_func_map_over::<TypeParam<N>, _, _>(f))
-///   # );
-///   ```
-pub trait TryFuncMap<A, B, P = TypeParam<0>>: Sized
+/// As the items are moved into the closure, this allows moving a resource
+/// into exactly one value without resorting to interior mutability, see also
+/// [`once_per_leaf`] for an iterator of closures. Surplus items of the
+/// iterator are left unconsumed.
+///
+/// # Panics
+/// The methods panic if the iterator yields fewer items than there are values
+/// of type `A` within `self`.
+pub trait FuncMapZip<A, B, P = TypeParam<0>>: FuncMap<A, B, P>
 where
     P: FuncMarker<P>,
 {
-    /// The output type of the functorial mapping
-    ///
-    /// This is `Self` with the parameter at index `N` replaced with `B`, where
-    /// `N` is such that `P` is `TypeParam<N>`.
-    ///
-    /// In the simplest case of a type with just a single type parameter, if
-    /// `Self` is `Foo<A>`, then this is `Foo<B>`.
-    type Output;
+    /// Applies the closure `f` to every value of type `A` within `self`
+    /// together with the next item of `iter` in a functorial way
+    #[must_use]
+    fn func_map_zip_with<I, F>(self, iter: I, mut f: F) -> Self::Output
+    where
+        I: IntoIterator,
+        F: FnMut(A, I::Item) -> B,
+    {
+        let mut iter = iter.into_iter();
 
-    /// Tries to apply the closure `f` to `self` in a functorial way
-    ///
-    /// # Errors
-    /// Fails if and only if `f` fails, returning the first error according to
-    /// the order of the fields in the definition of `Self`
-    fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+        self.func_map(|value| match iter.next() {
+            Some(item) => f(value, item),
+            None => panic!("iterator yielded fewer items than there are values to map"),
+        })
+    }
+
+    /// Replaces every value of type `A` within `self` with the next item of
+    /// `iter`
+    #[must_use]
+    fn func_map_zip<I>(self, iter: I) -> Self::Output
     where
-        F: FnMut(A) -> Result<B, E>;
+        I: IntoIterator<Item = B>,
+    {
+        self.func_map_zip_with(iter, |_, item| item)
+    }
+}
 
-    /// Tries to apply the closure `f` to `self` in a functorial way, allowing
-    /// explicit specification of the marker type `P`
-    ///
-    /// This is a convenience method that has the exact same functionality as
-    /// [`try_func_map`](Self::try_func_map) but can be used to specify the
-    /// marker type `P` in a convenient way in cases where it is ambiguous.
-    ///
-    /// So if you have
-    /// ```
-    /// # use funcmap::TryFuncMap;
-    /// #
-    /// #[derive(TryFuncMap, Debug, PartialEq)]
-    /// struct Foo<S, T> {
-    ///     s: S,
-    ///     t: T,
-    /// }
-    ///
-    /// let foo = Foo {
-    ///     s: "42",
-    ///     t: "42",
-    /// };
-    /// ```
-    /// then instead of writing
-    /// ```
-    /// # use funcmap::{TryFuncMap, TypeParam};
-    /// #
-    /// # #[derive(TryFuncMap, Debug, PartialEq)]
-    /// # struct Foo<S, T> {
-    /// #     s: S,
-    /// #     t: T,
-    /// # }
-    /// #
-    /// # let foo = Foo {
-    /// #     s: "42",
-    /// #     t: "42",
-    /// # };
-    /// #
-    /// let bar = TryFuncMap::<_, _, TypeParam<1>>::try_func_map(foo, |v| v.parse::<i32>());
-    /// assert_eq!(bar, Ok(Foo { s: "42", t: 42 }));
-    /// ```
-    ///
-    /// you can more conveniently write
-    ///
-    /// ```
-    /// # use funcmap::{TryFuncMap, TypeParam};
-    /// #
-    /// # #[derive(TryFuncMap, Debug, PartialEq)]
-    /// # struct Foo<S, T> {
-    /// #     s: S,
-    /// #     t: T,
-    /// # }
-    /// #
-    /// # let foo = Foo {
-    /// #     s: "42",
-    /// #     t: "42",
-    /// # };
-    /// #
-    /// let bar = foo.try_func_map_over::<TypeParam<1>, _, _>(|v| v.parse::<i32>());
-    /// assert_eq!(bar, Ok(Foo { s: "42", t: 42 }));
-    /// ```
+impl<T, A, B, P> FuncMapZip<A, B, P> for T
+where
+    T: FuncMap<A, B, P>,
+    P: FuncMarker<P>,
+{
+}
+
+/// Returns a closure applying the next closure of `closures` to every value it
+/// is called with
+///
+/// This turns an iterator of [`FnOnce`] closures into a single [`FnMut`]
+/// closure that can be passed to [`FuncMap::func_map`], where each of the
+/// closures is invoked for exactly one value, in the order in which the values
+/// are visited. This way, each closure can move a captured resource into its
+/// value:
+/// ```
+/// # use funcmap::{once_per_leaf, FuncMap};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<T>(T, T);
+///
+/// let first = String::from("first");
+/// let second = String::from("second");
+///
+/// let closures: Vec<Box<dyn FnOnce(u8) -> (u8, String)>> = vec![
+///     Box::new(move |v| (v, first)),
+///     Box::new(move |v| (v, second)),
+/// ];
+///
+/// assert_eq!(
+///     Foo(1, 2).func_map(once_per_leaf(closures)),
+///     Foo((1, String::from("first")), (2, String::from("second")))
+/// );
+/// ```
+///
+/// # Panics
+/// The returned closure panics if it is called more often than `closures`
+/// yields items.
+pub fn once_per_leaf<I, A, B>(closures: I) -> impl FnMut(A) -> B
+where
+    I: IntoIterator,
+    I::Item: FnOnce(A) -> B,
+{
+    let mut closures = closures.into_iter();
+
+    move |value| match closures.next() {
+        Some(f) => f(value),
+        None => panic!("closure was called more often than there are closures to apply"),
+    }
+}
+
+/// Structural diffing of values of a generic type
+///
+/// This is an extension trait with a blanket implementation for all types
+/// implementing [`FuncMap<A, A, P>`] with `Output = Self`, which in particular
+/// includes all types for which [`FuncMap`] is derived.
+///
+/// Its method [`func_diff`](Self::func_diff) compares the values of type `A`
+/// within two values of `Self` position by position, where the positions are
+/// given by the order in which [`FuncMap::func_map`] visits the values, and
+/// collects the changed ones into a [`Diff`]. The method
+/// [`apply_diff`](Self::apply_diff) then applies such a [`Diff`] to another
+/// value of the same shape:
+/// ```
+/// # use funcmap::{FuncMap, FuncMapDiff};
+/// #
+/// #[derive(FuncMap, Clone, Debug, PartialEq)]
+/// struct Foo<T> {
+///     value: T,
+///     more_values: Vec<T>,
+/// }
+///
+/// let old = Foo {
+///     value: 1,
+///     more_values: vec![2, 3],
+/// };
+///
+/// let new = Foo {
+///     value: 1,
+///     more_values: vec![2, 4],
+/// };
+///
+/// let diff = old.func_diff(&new).unwrap();
+/// assert_eq!(diff.changes(), [(2, 4)]);
+///
+/// assert_eq!(old.apply_diff(&diff), new);
+/// ```
+///
+/// Values of different shapes, e.g. containing vectors of different lengths,
+/// cannot be compared position by position, so
+/// [`func_diff`](Self::func_diff) returns [`None`] for them.
+#[cfg(feature = "alloc")]
+pub trait FuncMapDiff<A, P = TypeParam<0>>: FuncMap<A, A, P, Output = Self> + Clone
+where
+    P: FuncMarker<P>,
+{
+    /// Returns the values of type `A` within `other` that differ from the ones
+    /// at the same positions within `self`
     ///
-    /// This lets you chain method calls more easily as in
-    /// ```
-    /// # use funcmap::{TryFuncMap, TypeParam};
-    /// #
-    /// # #[derive(TryFuncMap, Debug, PartialEq)]
-    /// # struct Foo<S, T> {
-    /// #     s: S,
-    /// #     t: T,
-    /// # }
-    /// #
-    /// # let foo = Foo {
-    /// #     s: "42",
-    /// #     t: "42",
-    /// # };
-    /// #
-    /// foo.try_func_map_over::<TypeParam<0>, _, _>(|v| v.parse::<i32>())
-    ///     .and_then(|foo| foo.try_func_map_over::<TypeParam<1>, _, _>(|v| v.parse::<i32>()))
-    /// # ;
-    /// ```
+    /// Returns [`None`] if `self` and `other` contain different numbers of
+    /// values of type `A`.
+    fn func_diff(&self, other: &Self) -> Option<Diff<A>>
+    where
+        A: Clone + PartialEq,
+    {
+        let old = collect_values(self.clone());
+        let new = collect_values(other.clone());
+
+        if old.len() != new.len() {
+            return None;
+        }
+
+        let len = new.len();
+        let changes = old
+            .into_iter()
+            .zip(new)
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(idx, (_, new))| (idx, new))
+            .collect();
+
+        Some(Diff {
+            changes,
+            num_values: len,
+        })
+    }
+
+    /// Replaces the values of type `A` within `self` at the positions changed
+    /// in `diff` with their new values
     ///
-    /// Note that you still need to specify the inferred type `_` for the
-    /// error type `E` and the closure type `F`.
+    /// # Panics
+    /// Panics if `self` contains a different number of values of type `A` than
+    /// the values `diff` was computed from
+    #[must_use]
+    fn apply_diff(self, diff: &Diff<A>) -> Self
+    where
+        A: Clone,
+    {
+        let mut changes = diff.changes.iter().peekable();
+        let mut idx = 0;
+
+        let output = self.func_map(|value| {
+            let value = match changes.next_if(|(change_idx, _)| *change_idx == idx) {
+                Some((_, new)) => new.clone(),
+                None => value,
+            };
+
+            idx += 1;
+            value
+        });
+
+        assert_eq!(
+            idx, diff.num_values,
+            "diff was computed from values of a different shape"
+        );
+
+        output
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, A, P> FuncMapDiff<A, P> for T
+where
+    T: FuncMap<A, A, P, Output = T> + Clone,
+    P: FuncMarker<P>,
+{
+}
+
+/// Collects clones of the values of type `A` within `value` in the order in
+/// which [`FuncMap::func_map`] visits them
+#[cfg(feature = "alloc")]
+fn collect_values<T, A, P>(value: T) -> Vec<A>
+where
+    T: FuncMap<A, A, P>,
+    A: Clone,
+    P: FuncMarker<P>,
+{
+    let mut values = Vec::new();
+
+    let _ = value.func_map(|value| {
+        values.push(value.clone());
+        value
+    });
+
+    values
+}
+
+/// Changed values of type `A` between two values of a generic type
+///
+/// This is produced by [`FuncMapDiff::func_diff`] and consumed by
+/// [`FuncMapDiff::apply_diff`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Diff<A> {
+    /// Positions and new values of the changed values, ordered by position
+    changes: Vec<(usize, A)>,
+
+    /// Total number of values of type `A` in the compared values
+    num_values: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<A> Diff<A> {
+    /// Returns the positions and new values of the changed values, ordered by
+    /// position
     ///
-    /// # Errors
-    /// Fails if and only if `f` fails, returning the first error according to
-    /// the order of the fields in the definition of `Self`
-    fn try_func_map_over<Q, E, F>(self, f: F) -> Result<Self::Output, E>
+    /// Positions are counted in the order in which [`FuncMap::func_map`]
+    /// visits the values of type `A`.
+    #[must_use]
+    pub fn changes(&self) -> &[(usize, A)] {
+        &self.changes
+    }
+
+    /// Returns the total number of values of type `A` in the compared values,
+    /// including unchanged ones
+    #[must_use]
+    pub fn num_values(&self) -> usize {
+        self.num_values
+    }
+
+    /// Returns `true` if no values have changed
+    #[must_use]
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Turns this diff into the positions and new values of the changed values
+    #[must_use]
+    pub fn into_changes(self) -> Vec<(usize, A)> {
+        self.changes
+    }
+}
+
+/// Functorial mapping of every item of an iterator
+///
+/// This is an extension trait with a blanket implementation for all iterators.
+/// Its method [`func_map_each`](Self::func_map_each) maps every item of type
+/// `Foo<A>` to `Foo<B>` via [`FuncMap::func_map`], where all items share the
+/// same closure:
+/// ```
+/// # use funcmap::{FuncMap, FuncMapEach};
+/// #
+/// #[derive(FuncMap, Debug, PartialEq)]
+/// struct Foo<T> {
+///     value: T,
+///     more_values: Vec<T>,
+/// }
+///
+/// let foos = vec![
+///     Foo {
+///         value: 1,
+///         more_values: vec![2],
+///     },
+///     Foo {
+///         value: 3,
+///         more_values: vec![],
+///     },
+/// ];
+///
+/// let mut count = 0;
+///
+/// let bars: Vec<_> = foos
+///     .into_iter()
+///     .func_map_each(|v| {
+///         count += 1;
+///         v * 10
+///     })
+///     .collect();
+///
+/// assert_eq!(count, 3);
+/// assert_eq!(
+///     bars,
+///     [
+///         Foo {
+///             value: 10,
+///             more_values: vec![20],
+///         },
+///         Foo {
+///             value: 30,
+///             more_values: vec![],
+///         },
+///     ]
+/// );
+/// ```
+///
+/// This is a shorthand for `.map(|x| x.func_map(&mut f))`. The resulting
+/// iterator preserves the size hint of the underlying iterator, so collecting
+/// it allocates only once whenever the size hint is exact. See also
+/// [`map_all`] for a free function version.
+pub trait FuncMapEach: Iterator + Sized {
+    /// Applies the closure `f` to every item of `self` in a functorial way
+    fn func_map_each<A, B, P, F>(self, f: F) -> MapAll<Self, F, A, B, P>
     where
-        F: FnMut(A) -> Result<B, E>,
-        Q: FuncMarker<P>,
+        Self::Item: FuncMap<A, B, P>,
+        P: FuncMarker<P>,
+        F: FnMut(A) -> B,
     {
-        self.try_func_map(f)
+        MapAll {
+            iter: self,
+            f,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<I> FuncMapEach for I where I: Iterator {}
+
+/// Maps every item of `iter` to its output via [`FuncMap::func_map`], where
+/// all items share the closure `f`
+///
+/// This is the same as `iter.into_iter().func_map_each(f)`, see
+/// [`FuncMapEach`]:
+/// ```
+/// # use funcmap::map_all;
+/// #
+/// let values = vec![Some(1), None, Some(2)];
+/// let values: Vec<_> = map_all(values, |v| v + 1).collect();
+///
+/// assert_eq!(values, [Some(2), None, Some(3)]);
+/// ```
+pub fn map_all<I, A, B, P, F>(iter: I, f: F) -> MapAll<I::IntoIter, F, A, B, P>
+where
+    I: IntoIterator,
+    I::Item: FuncMap<A, B, P>,
+    P: FuncMarker<P>,
+    F: FnMut(A) -> B,
+{
+    iter.into_iter().func_map_each(f)
+}
+
+/// Iterator mapping every item of an underlying iterator via
+/// [`FuncMap::func_map`]
+///
+/// This is produced by [`FuncMapEach::func_map_each`] and [`map_all`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapAll<I, F, A, B, P> {
+    /// Underlying iterator
+    iter: I,
+
+    /// Closure shared by all items
+    f: F,
+
+    /// Marker for the types and the type parameter of the mapping
+    marker: PhantomData<fn(A, P) -> B>,
+}
+
+impl<I, F, A, B, P> fmt::Debug for MapAll<I, F, A, B, P>
+where
+    I: fmt::Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapAll")
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, F, A, B, P> Clone for MapAll<I, F, A, B, P>
+where
+    I: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<I, F, A, B, P> Iterator for MapAll<I, F, A, B, P>
+where
+    I: Iterator,
+    I::Item: FuncMap<A, B, P>,
+    P: FuncMarker<P>,
+    F: FnMut(A) -> B,
+{
+    type Item = <I::Item as FuncMap<A, B, P>>::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter.next().map(|item| item.func_map(f))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, A, B, P> DoubleEndedIterator for MapAll<I, F, A, B, P>
+where
+    I: DoubleEndedIterator,
+    I::Item: FuncMap<A, B, P>,
+    P: FuncMarker<P>,
+    F: FnMut(A) -> B,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let f = &mut self.f;
+        self.iter.next_back().map(|item| item.func_map(f))
+    }
+}
+
+impl<I, F, A, B, P> ExactSizeIterator for MapAll<I, F, A, B, P>
+where
+    I: ExactSizeIterator,
+    I::Item: FuncMap<A, B, P>,
+    P: FuncMarker<P>,
+    F: FnMut(A) -> B,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
     }
 }
 
+impl<I, F, A, B, P> FusedIterator for MapAll<I, F, A, B, P>
+where
+    I: FusedIterator,
+    I::Item: FuncMap<A, B, P>,
+    P: FuncMarker<P>,
+    F: FnMut(A) -> B,
+{
+}
+
 pub use funcmap_derive::FuncMap;
 
 pub use funcmap_derive::TryFuncMap;
 
+pub use funcmap_derive::StructuralFrom;
+
+pub use funcmap_derive::JoinParams;
+
+pub use funcmap_derive::FamilyMap;
+
+pub use funcmap_derive::FuncFilterMap;
+
+pub use funcmap_derive::FuncCount;
+
+pub use funcmap_derive::RefFuncMap;
+
+pub use funcmap_derive::MapBuilders;
+
+pub use funcmap_derive::FuncMapFields;
+
 /// Marker type specifying one of multiple type parameters to map over
 ///
 /// The const generic `N` is the zero-based index of the type parameter, not
@@ -1335,6 +5148,27 @@ impl<const N: usize> Display for TypeParam<N> {
     }
 }
 
+/// Value specifying one of multiple type parameters to map over
+///
+/// This is the value-level counterpart of the marker type [`TypeParam<N>`],
+/// which is uninhabited and can only be used as a type. A value of type
+/// [`Param<N>`] can be passed to [`FuncMap::func_map_at`] and
+/// [`TryFuncMap::try_func_map_at`] to map over the type parameter at index `N`.
+///
+/// The [`markers`] module contains short names for the first few indices, so
+/// e.g. [`markers::P1`](type@markers::P1) is both the type [`TypeParam<1>`] and the value
+/// [`Param<1>`].
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Param<const N: usize>;
+
+impl<const N: usize> Param<N> {
+    /// Returns the index `N` of the type parameter specified by this value
+    #[must_use]
+    pub const fn index(self) -> usize {
+        N
+    }
+}
+
 /// Marker trait for marker types specifying what to map over
 ///
 /// This is only implemented by the marker types [`TypeParam<N>`] and is used to
@@ -1371,6 +5205,42 @@ mod private {
     impl<const N: usize> Sealed<TypeParam<N>> for TypeParam<N> {}
 }
 
+/// Reflection of the type parameters a type can be mapped over via [`FuncMap`]
+///
+/// The [`FuncMap`] derive macro produces an implementation of this trait, where
+/// [`PARAMS`](Self::PARAMS) lists the indices `N` for which it implements
+/// [`FuncMap`] with the marker type [`TypeParam<N>`], in ascending order. As
+/// with the marker types, lifetime parameters are not counted:
+/// ```
+/// # use funcmap::{FuncMap, FuncMapParams};
+/// #
+/// #[derive(FuncMap)]
+/// #[funcmap(params(S, U))]
+/// struct Foo<'a, S, T, U>(&'a T, S, U);
+///
+/// assert_eq!(<Foo<'_, (), (), ()> as FuncMapParams>::PARAMS, [0, 2]);
+/// ```
+///
+/// This allows discovering the mappable type parameters of a type at compile
+/// time, e.g. for debugging or for generic code, without resorting to trait
+/// bounds.
+pub trait FuncMapParams {
+    /// The indices `N` for which [`FuncMap`] is implemented with the marker
+    /// type [`TypeParam<N>`]
+    const PARAMS: &'static [usize];
+}
+
+/// Reflection of the type parameters a type can be mapped over via
+/// [`TryFuncMap`]
+///
+/// This is the counterpart of [`FuncMapParams`] for the [`TryFuncMap`] derive
+/// macro.
+pub trait TryFuncMapParams {
+    /// The indices `N` for which [`TryFuncMap`] is implemented with the marker
+    /// type [`TypeParam<N>`]
+    const PARAMS: &'static [usize];
+}
+
 /// Marker trait with a blanket implementation for all types that implement
 /// [`Drop`]
 ///
@@ -1379,8 +5249,10 @@ mod private {
 /// doesn't implement [`Drop`] because otherwise there would be conflicting
 /// implementations of this trait.
 ///
-/// This is necessary because derived implementation of [`FuncMap`] need to move
-/// out of fields, which isn't possible for types implementing [`Drop`].
+/// This is necessary because derived implementations of [`FuncMap`] need to
+/// move out of fields, which isn't possible for types implementing [`Drop`].
+/// The name of this trait appears in the resulting compile error, see
+/// [Types Implementing `Drop`](crate#types-implementing-drop).
 #[doc(hidden)]
 #[allow(non_camel_case_types)]
 pub trait FuncMap_cannot_be_derived_for_types_implementing_Drop {}
@@ -1396,11 +5268,337 @@ impl<T> FuncMap_cannot_be_derived_for_types_implementing_Drop for T where T: Dro
 /// doesn't implement [`Drop`] because otherwise there would be conflicting
 /// implementations of this trait.
 ///
-/// This is necessary because derived implementation of [`TryFuncMap`] need to
+/// This is necessary because derived implementations of [`TryFuncMap`] need to
 /// move out of fields, which isn't possible for types implementing [`Drop`].
+/// The name of this trait appears in the resulting compile error, see
+/// [Types Implementing `Drop`](crate#types-implementing-drop).
 #[doc(hidden)]
 #[allow(non_camel_case_types)]
 pub trait TryFuncMap_cannot_be_derived_for_types_implementing_Drop {}
 
 #[allow(drop_bounds)]
 impl<T> TryFuncMap_cannot_be_derived_for_types_implementing_Drop for T where T: Drop + ?Sized {}
+
+/// Trait exposing the type parameter of a type at a given index
+///
+/// The [`FuncMap`] derive macro produces an implementation of this trait for
+/// every type parameter it implements [`FuncMap`] for, where `P` is the marker
+/// type [`TypeParam<N>`] and [`Param`](Self::Param) is the type parameter at
+/// index `N`.
+///
+/// This is used to check at compile time that the marker types used for
+/// mapping over fields refer to the intended type parameters, which is not the
+/// case when the rule from [Caveat: Type Aliases](crate#caveat-type-aliases) is
+/// violated.
+#[doc(hidden)]
+pub trait FuncMapTypeParam<P> {
+    /// The type parameter at the index specified by `P`
+    type Param: ?Sized;
+}
+
+/// Trait exposing the type parameter of a type at a given index
+///
+/// This is the counterpart of [`FuncMapTypeParam`] for the [`TryFuncMap`]
+/// derive macro.
+#[doc(hidden)]
+pub trait TryFuncMapTypeParam<P> {
+    /// The type parameter at the index specified by `P`
+    type Param: ?Sized;
+}
+
+/// Trait exposing the marker type of the type parameter with a given name
+///
+/// The [`FuncMap`] derive macro produces an implementation of this trait for
+/// every type parameter it implements [`FuncMap`] for, where `NAME` is the
+/// [`param_name_hash`] of the name of the type parameter and
+/// [`Marker`](Self::Marker) is the corresponding marker type
+/// [`TypeParam<N>`].
+///
+/// This is used by the [`func_map_over!`] macro.
+#[doc(hidden)]
+pub trait FuncMapNamedParam<const NAME: u128> {
+    /// The marker type of the type parameter with the name specified by `NAME`
+    type Marker;
+}
+
+/// Trait exposing the marker type of the type parameter with a given name
+///
+/// This is the counterpart of [`FuncMapNamedParam`] for the [`TryFuncMap`]
+/// derive macro.
+#[doc(hidden)]
+pub trait TryFuncMapNamedParam<const NAME: u128> {
+    /// The marker type of the type parameter with the name specified by `NAME`
+    type Marker;
+}
+
+/// Trait identifying the variant of an enum with a given name
+///
+/// The [`FuncMap`] derive macro produces an implementation of this trait for
+/// every variant of an enum, where `NAME` is the [`param_name_hash`] of the
+/// name of the variant.
+///
+/// This is used by the [`func_map_variant!`] macro.
+#[doc(hidden)]
+pub trait FuncMapVariant<const NAME: u128> {
+    /// Returns whether `self` is the variant with the name specified by `NAME`
+    fn is_variant(&self) -> bool;
+}
+
+/// Trait identifying the variant of an enum with a given name
+///
+/// This is the counterpart of [`FuncMapVariant`] for the [`TryFuncMap`] derive
+/// macro.
+#[doc(hidden)]
+pub trait TryFuncMapVariant<const NAME: u128> {
+    /// Returns whether `self` is the variant with the name specified by `NAME`
+    fn is_variant(&self) -> bool;
+}
+
+/// Hashes the name of a type parameter or variant for use as the `NAME` in
+/// [`FuncMapNamedParam`], [`FuncMapVariant`] and their fallible counterparts
+///
+/// This is the 128-bit FNV-1a hash of `name` without a leading `r#`, so raw
+/// identifiers are hashed like their non-raw counterparts.
+#[doc(hidden)]
+#[must_use]
+pub const fn param_name_hash(name: &str) -> u128 {
+    const OFFSET_BASIS: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+    const PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+
+    let bytes = name.as_bytes();
+    let mut idx = if bytes.len() > 2 && bytes[0] == b'r' && bytes[1] == b'#' {
+        2
+    } else {
+        0
+    };
+    let mut hash = OFFSET_BASIS;
+
+    while idx < bytes.len() {
+        hash ^= bytes[idx] as u128;
+        hash = hash.wrapping_mul(PRIME);
+        idx += 1;
+    }
+
+    hash
+}
+
+/// Returns the position of the hash `param` within `args`, as used by the
+/// [`param_of!`] macro
+///
+/// # Panics
+/// Panics if `param` doesn't occur exactly once within `args`, which happens
+/// at compile time when used by [`param_of!`]
+#[doc(hidden)]
+#[must_use]
+pub const fn param_position(args: &[u128], param: u128) -> usize {
+    let mut position = None;
+    let mut idx = 0;
+
+    while idx < args.len() {
+        if args[idx] == param {
+            assert!(
+                position.is_none(),
+                "type parameter occurs multiple times among the generic arguments"
+            );
+            position = Some(idx);
+        }
+
+        idx += 1;
+    }
+
+    match position {
+        Some(position) => position,
+        None => panic!("type parameter doesn't occur among the generic arguments"),
+    }
+}
+
+/// Implementation of the [`func_map_over!`] macro
+#[doc(hidden)]
+pub fn func_map_over_named<const NAME: u128, T, A, B, F>(
+    value: T,
+    f: F,
+) -> <T as FuncMap<A, B, T::Marker>>::Output
+where
+    T: FuncMapNamedParam<NAME> + FuncMap<A, B, T::Marker>,
+    T::Marker: FuncMarker<T::Marker>,
+    F: FnMut(A) -> B,
+{
+    value.func_map(f)
+}
+
+/// Implementation of the [`try_func_map_over!`] macro
+#[doc(hidden)]
+pub fn try_func_map_over_named<const NAME: u128, T, A, B, E, F>(
+    value: T,
+    f: F,
+) -> Result<<T as TryFuncMap<A, B, T::Marker>>::Output, E>
+where
+    T: TryFuncMapNamedParam<NAME> + TryFuncMap<A, B, T::Marker>,
+    T::Marker: FuncMarker<T::Marker>,
+    F: FnMut(A) -> Result<B, E>,
+{
+    value.try_func_map(f)
+}
+
+/// Implementation of the [`func_map_variant!`] macro
+#[doc(hidden)]
+pub fn func_map_variant_named<const NAME: u128, T, A, P, F>(value: T, f: F) -> T
+where
+    T: FuncMapVariant<NAME> + FuncMap<A, A, P, Output = T>,
+    P: FuncMarker<P>,
+    F: FnMut(A) -> A,
+{
+    if value.is_variant() {
+        value.func_map(f)
+    } else {
+        value
+    }
+}
+
+/// Implementation of the [`try_func_map_variant!`] macro
+#[doc(hidden)]
+pub fn try_func_map_variant_named<const NAME: u128, T, A, P, E, F>(value: T, f: F) -> Result<T, E>
+where
+    T: TryFuncMapVariant<NAME> + TryFuncMap<A, A, P, Output = T>,
+    P: FuncMarker<P>,
+    F: FnMut(A) -> Result<A, E>,
+{
+    if value.is_variant() {
+        value.try_func_map(f)
+    } else {
+        Ok(value)
+    }
+}
+
+/// Helper type for checking that the type parameter of `T` at the index
+/// specified by `P` is `E`
+///
+/// Given a value `check` of this type, `(&check).check()` returns a
+/// [`PhantomData`] of
+/// - the type parameter of `T` at the index specified by `P` if `T` implements
+///   [`FuncMapTypeParam<P>`] (respectively [`TryFuncMapTypeParam<P>`]),
+/// - `E` otherwise,
+///
+/// so asserting that the result is a [`PhantomData<E>`] fails to compile if and
+/// only if `T` exposes a type parameter different from `E`. This relies on
+/// method resolution preferring methods that don't require auto-referencing, so
+/// [`FuncMapTypeParamCheck`] (respectively [`TryFuncMapTypeParamCheck`]) takes
+/// precedence over [`TypeParamCheckFallback`].
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct TypeParamCheck<T: ?Sized, P, E: ?Sized>(PhantomData<P>, PhantomData<T>, PhantomData<E>);
+
+impl<T: ?Sized, P, E: ?Sized> TypeParamCheck<T, P, E> {
+    /// Creates a new [`TypeParamCheck`]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData, PhantomData, PhantomData)
+    }
+}
+
+impl<T: ?Sized, P, E: ?Sized> Default for TypeParamCheck<T, P, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check for types implementing [`FuncMapTypeParam`], see [`TypeParamCheck`]
+#[doc(hidden)]
+pub trait FuncMapTypeParamCheck {
+    /// The type parameter exposed by the checked type
+    type Param: ?Sized;
+
+    /// Returns a [`PhantomData`] of the exposed type parameter
+    fn check(&self) -> PhantomData<Self::Param> {
+        PhantomData
+    }
+}
+
+impl<T, P, E> FuncMapTypeParamCheck for TypeParamCheck<T, P, E>
+where
+    T: FuncMapTypeParam<P> + ?Sized,
+    E: ?Sized,
+{
+    type Param = T::Param;
+}
+
+/// Check for types implementing [`TryFuncMapTypeParam`], see
+/// [`TypeParamCheck`]
+#[doc(hidden)]
+pub trait TryFuncMapTypeParamCheck {
+    /// The type parameter exposed by the checked type
+    type Param: ?Sized;
+
+    /// Returns a [`PhantomData`] of the exposed type parameter
+    fn check(&self) -> PhantomData<Self::Param> {
+        PhantomData
+    }
+}
+
+impl<T, P, E> TryFuncMapTypeParamCheck for TypeParamCheck<T, P, E>
+where
+    T: TryFuncMapTypeParam<P> + ?Sized,
+    E: ?Sized,
+{
+    type Param = T::Param;
+}
+
+/// Check for types not exposing their type parameters, see
+/// [`TypeParamCheck`]
+#[doc(hidden)]
+pub trait TypeParamCheckFallback {
+    /// The expected type parameter
+    type Param: ?Sized;
+
+    /// Returns a [`PhantomData`] of the expected type parameter
+    fn check(&self) -> PhantomData<Self::Param> {
+        PhantomData
+    }
+}
+
+impl<T, P, E> TypeParamCheckFallback for &TypeParamCheck<T, P, E>
+where
+    T: ?Sized,
+    E: ?Sized,
+{
+    type Param = E;
+}
+
+/// Trait for mapping over the keys and values of a map in a single pass
+///
+/// Derived implementations of [`FuncMap`] use this for fields of type
+/// `std::collections::BTreeMap<K, V>` or `std::collections::HashMap<K, V>`,
+/// referred to through fully qualified paths, where both `K` and `V` depend
+/// on the type parameter being mapped over. Compared to mapping over the keys
+/// and the values one after another, this rebuilds the map only once.
+#[doc(hidden)]
+pub trait FuncMapEntries<K, L, V, W>: Sized {
+    /// The output type of the mapping
+    type Output;
+
+    /// Applies the closure `f` to each entry, mapping keys of type `K` to `L`
+    /// and values of type `V` to `W`
+    #[must_use]
+    fn func_map_entries<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut((K, V)) -> (L, W);
+}
+
+/// Trait for mapping over the keys and values of a map in a single pass with a
+/// closure that can fail
+///
+/// This is the counterpart of [`FuncMapEntries`] for [`TryFuncMap`].
+#[doc(hidden)]
+pub trait TryFuncMapEntries<K, L, V, W>: Sized {
+    /// The output type of the mapping
+    type Output;
+
+    /// Tries to apply the closure `f` to each entry, mapping keys of type `K`
+    /// to `L` and values of type `V` to `W`
+    ///
+    /// # Errors
+    /// Fails if and only if `f` fails, returning the first error of `f`
+    fn try_func_map_entries<E, F>(self, f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut((K, V)) -> Result<(L, W), E>;
+}
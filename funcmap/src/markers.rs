@@ -0,0 +1,51 @@
+//! Short names for the first few type parameters to map over
+//!
+//! Each name `P<N>` refers to both the marker type [`TypeParam<N>`] and the
+//! value [`Param<N>`], so it can be used wherever either of them is expected:
+//! ```
+//! use funcmap::FuncMap;
+//! use funcmap::markers::{P0, P1};
+//!
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! struct Foo<S, T> {
+//!     s: S,
+//!     t: T,
+//! }
+//!
+//! let foo = Foo { s: 1, t: 2 };
+//!
+//! let bar = foo.func_map_over::<P0, _>(|v| v * 10).func_map_at(P1, |v| v * 100);
+//!
+//! assert_eq!(bar, Foo { s: 10, t: 200 });
+//! ```
+//!
+//! These names are also re-exported at the crate root, so e.g.
+//! `funcmap::P1` works as well. For type parameters at higher indices, use
+//! [`TypeParam<N>`] and [`Param<N>`] directly.
+
+use crate::{Param, TypeParam};
+
+macro_rules! markers {
+    ($($name:ident = $idx:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Marker type [`TypeParam<", $idx, ">`]")]
+            pub type $name = TypeParam<$idx>;
+
+            #[doc = concat!("Value [`Param<", $idx, ">`]")]
+            pub const $name: Param<$idx> = Param;
+        )*
+    };
+}
+
+markers! {
+    P0 = 0,
+    P1 = 1,
+    P2 = 2,
+    P3 = 3,
+    P4 = 4,
+    P5 = 5,
+    P6 = 6,
+    P7 = 7,
+    P8 = 8,
+    P9 = 9,
+}
@@ -0,0 +1,191 @@
+//! A value tagged with a phantom type
+//!
+//! Newtypes carrying an additional type parameter only within [`PhantomData`],
+//! e.g. to distinguish identifiers of different kinds of entities, are often
+//! mapped over their value while keeping the tag. [`Tagged`] implements
+//! [`FuncMap`] and [`TryFuncMap`] over the value only, so it can be used
+//! directly or within a type deriving them:
+//! ```
+//! use funcmap::tagged::Tagged;
+//! use funcmap::FuncMap;
+//!
+//! enum User {}
+//! enum Order {}
+//!
+//! #[derive(FuncMap, Debug, PartialEq)]
+//! struct Purchase<T> {
+//!     user: Tagged<T, User>,
+//!     order: Tagged<T, Order>,
+//! }
+//!
+//! let purchase = Purchase {
+//!     user: Tagged::new(1),
+//!     order: Tagged::new(2),
+//! };
+//!
+//! assert_eq!(
+//!     purchase.func_map(|id| id + 100),
+//!     Purchase {
+//!         user: Tagged::new(101),
+//!         order: Tagged::new(102),
+//!     }
+//! );
+//! ```
+//!
+//! None of the trait implementations of [`Tagged`] require any bounds on the
+//! tag, so tags can be uninhabited types like the ones above.
+//!
+//! For a user-defined equivalent of [`Tagged`], the same behavior can be
+//! derived by restricting the mapping to the value via
+//! [`#[funcmap(params(...))]`](crate#funcmapparams):
+//! ```
+//! use funcmap::FuncMap;
+//!
+//! use std::marker::PhantomData;
+//!
+//! #[derive(FuncMap)]
+//! #[funcmap(params(T))]
+//! struct Id<T, Tag>(T, PhantomData<Tag>);
+//!
+//! enum User {}
+//!
+//! let id: Id<_, User> = Id(1, PhantomData);
+//!
+//! assert_eq!(id.func_map(|v| v + 100).0, 101);
+//! ```
+
+use crate::{FuncMap, TryFuncMap};
+
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// A value of type `T` tagged with the type `Tag`
+///
+/// This implements [`FuncMap`] and [`TryFuncMap`] over `T`, keeping the tag.
+pub struct Tagged<T, Tag>(pub T, pub PhantomData<Tag>);
+
+impl<T, Tag> Tagged<T, Tag> {
+    /// Creates a tagged value
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns a reference to the value, tagged with the same tag
+    #[must_use]
+    pub fn as_ref(&self) -> Tagged<&T, Tag> {
+        Tagged(&self.0, PhantomData)
+    }
+
+    /// Turns this into the value, dropping the tag
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Replaces the tag with another one, keeping the value
+    #[must_use]
+    pub fn retag<U>(self) -> Tagged<T, U> {
+        Tagged(self.0, PhantomData)
+    }
+}
+
+impl<T, Tag> From<T> for Tagged<T, Tag> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, Tag> Clone for Tagged<T, Tag>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<T, Tag> Copy for Tagged<T, Tag> where T: Copy {}
+
+impl<T, Tag> Debug for Tagged<T, Tag>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Tagged").field(&self.0).finish()
+    }
+}
+
+impl<T, Tag> Default for Tagged<T, Tag>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T, Tag> PartialEq for Tagged<T, Tag>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T, Tag> Eq for Tagged<T, Tag> where T: Eq {}
+
+impl<T, Tag> PartialOrd for Tagged<T, Tag>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T, Tag> Ord for Tagged<T, Tag>
+where
+    T: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T, Tag> Hash for Tagged<T, Tag>
+where
+    T: Hash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.0.hash(state);
+    }
+}
+
+impl<A, B, Tag> FuncMap<A, B> for Tagged<A, Tag> {
+    type Output = Tagged<B, Tag>;
+
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+    {
+        Tagged::new(f(self.0))
+    }
+}
+
+impl<A, B, Tag> TryFuncMap<A, B> for Tagged<A, Tag> {
+    type Output = Tagged<B, Tag>;
+
+    fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+    {
+        f(self.0).map(Tagged::new)
+    }
+}
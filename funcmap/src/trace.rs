@@ -0,0 +1,60 @@
+//! Integration with [`tracing`](https://docs.rs/tracing)
+
+use core::fmt::Display;
+
+use tracing::span::EnteredSpan;
+
+/// Returns a closure applying `f` and emitting an error event for every error
+/// it returns
+///
+/// This is meant to be passed to [`try_func_map`](crate::TryFuncMap::try_func_map)
+/// for types deriving [`TryFuncMap`](crate::TryFuncMap) with
+/// [`#[funcmap(traced)]`](crate#funcmaptraced), so the event is emitted within
+/// the spans of the fields leading to the failing value:
+/// ```
+/// use funcmap::{traced, TryFuncMap};
+///
+/// #[derive(TryFuncMap, Debug)]
+/// #[funcmap(traced)]
+/// struct Record<T> {
+///     id: T,
+///     parent_ids: Vec<T>,
+/// }
+///
+/// let record = Record {
+///     id: "1",
+///     parent_ids: vec!["2", "x"],
+/// };
+///
+/// // emits an error event within the span for the field `Record.parent_ids`
+/// let result = record.try_func_map(traced(str::parse::<u32>));
+///
+/// assert!(result.is_err());
+/// ```
+///
+/// The event has level [`ERROR`](tracing::Level::ERROR) and records the
+/// error through its [`Display`] implementation in the field `error`. The error
+/// itself is returned unchanged.
+pub fn traced<A, B, E, F>(mut f: F) -> impl FnMut(A) -> Result<B, E>
+where
+    F: FnMut(A) -> Result<B, E>,
+    E: Display,
+{
+    move |value| {
+        f(value).map_err(|err| {
+            tracing::error!(error = %err, "mapping failed");
+            err
+        })
+    }
+}
+
+/// Creates and enters a span for mapping the field at the given path
+///
+/// This is called by the code generated through
+/// [`#[funcmap(traced)]`](crate#funcmaptraced). The span has level
+/// [`ERROR`](tracing::Level::ERROR), so it is enabled whenever the error
+/// events emitted through [`traced`] are.
+#[doc(hidden)]
+pub fn enter_field_span(path: &'static str) -> EnteredSpan {
+    tracing::error_span!("try_func_map", path).entered()
+}
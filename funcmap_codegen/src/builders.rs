@@ -0,0 +1,231 @@
+//! The derive logic for `MapBuilders`
+
+use crate::derive::{binds_assoc_type_to, impl_attrs, subs_type_in_bounds, AttrOpts};
+use crate::ident::{FN_IDENT, MARKER_TYPE_IDENT, OUTPUT_TYPE_IDENT, TRAIT_IDENT};
+use crate::input::{parse_derive_input, FuncMapInput};
+use crate::predicates::UniquePredicates;
+use crate::result::{self, Error, ResultExt};
+use crate::syn_ext::{
+    DependencyOnType, IntoGenericArgument, IntoType, SubsType, WithoutAttrs, WithoutMaybeBounds,
+};
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ext::IdentExt, parse_quote, GenericArgument, GenericParam, WherePredicate};
+
+/// Generates inherent `map_*` methods for a given item
+pub(crate) fn derive(item: TokenStream) -> TokenStream {
+    match try_derive(item) {
+        Ok(output) => output,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+/// Tries to generate inherent `map_*` methods for a given item
+///
+/// For every mapped type parameter `T`, this generates a method `map_t`
+/// (named after `T` in snake case) delegating to the implementation of
+/// `FuncMap` over `T`, which needs to be derived separately. Its destination
+/// type parameter is named `T2` unless this name is already in use.
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed
+/// - `item` is not a valid input for deriving `FuncMap`
+pub(crate) fn try_derive(item: TokenStream) -> Result<TokenStream, Error> {
+    let input = parse_derive_input(item)?;
+    let vis = input.vis.clone();
+    let input: FuncMapInput = input.try_into()?;
+    let mut ident_collector = input.meta.ident_collector;
+    let mut result_builder = result::Builder::new();
+
+    let crate_path = &input.meta.crate_path;
+    let inline = &input.meta.inline;
+    let ident = &input.ident;
+    let all_params = &input.generics.params;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let attrs = impl_attrs(AttrOpts {
+        allow: &input.meta.allow,
+        cfg: &input.meta.cfg,
+    });
+
+    let mut methods = Vec::with_capacity(input.mapped_type_params.len());
+
+    for mapped_type_param in &input.mapped_type_params {
+        let type_param_ident = &mapped_type_param.type_param.ident;
+        let method_ident = format_ident!(
+            "map_{}",
+            to_snake_case(&type_param_ident.unraw().to_string()),
+            span = type_param_ident.span()
+        );
+
+        // see the derive logic for `FuncMap` regarding bounds binding
+        // associated types to the type parameter
+        let dst_type_ident = if binds_assoc_type_to(&input.generics, type_param_ident) {
+            None
+        } else {
+            Some(
+                ident_collector
+                    .reserve(&format_ident!("{}2", type_param_ident), Span::mixed_site())
+                    .unwrap_or_else(|| {
+                        ident_collector.reserve_uppercase_letter('B', Span::mixed_site())
+                    }),
+            )
+        };
+
+        let dst_args: Vec<GenericArgument> = all_params
+            .iter()
+            .enumerate()
+            .map(|(param_idx, param)| match &dst_type_ident {
+                Some(dst_type_ident) if param_idx == mapped_type_param.param_idx => {
+                    GenericArgument::Type(dst_type_ident.clone().into_type())
+                }
+                _ => param.clone().into_generic_argument(),
+            })
+            .collect();
+
+        let dst_type_ident = dst_type_ident.as_ref().unwrap_or(type_param_ident);
+
+        // the destination type needs to satisfy the bounds of the original type
+        // for the output type to be well-formed
+        let mut unique_predicates = UniquePredicates::new();
+
+        if dst_type_ident != type_param_ident {
+            for param in all_params {
+                let GenericParam::Type(type_param) = param else {
+                    continue;
+                };
+
+                let (bounded_ident, bounds) = if type_param.ident == *type_param_ident {
+                    (
+                        dst_type_ident,
+                        subs_type_in_bounds(
+                            &type_param.bounds,
+                            type_param_ident,
+                            &[dst_type_ident],
+                        )?
+                        .without_maybe_bounds(),
+                    )
+                } else {
+                    (
+                        &type_param.ident,
+                        subs_type_in_bounds(
+                            type_param.bounds.iter().filter(|bound| {
+                                bound.dependency_on_type(type_param_ident).is_some()
+                            }),
+                            type_param_ident,
+                            &[dst_type_ident],
+                        )?,
+                    )
+                };
+
+                if !bounds.is_empty() {
+                    unique_predicates
+                        .add(parse_quote!(#bounded_ident: #bounds))
+                        .add_err_to(&mut result_builder);
+                }
+            }
+
+            for predicate in where_clause
+                .iter()
+                .flat_map(|clause| clause.predicates.iter())
+            {
+                let predicate = match predicate.clone().without_attrs() {
+                    WherePredicate::Type(predicate_type)
+                        if predicate_type.bounded_ty == type_param_ident.clone().into_type() =>
+                    {
+                        WherePredicate::Type(predicate_type.without_maybe_bounds())
+                    }
+                    predicate => predicate,
+                };
+
+                if predicate.dependency_on_type(type_param_ident).is_some() {
+                    unique_predicates
+                        .add(predicate.subs_type(type_param_ident, dst_type_ident))
+                        .add_err_to(&mut result_builder);
+                }
+            }
+        }
+
+        let marker_idx = mapped_type_param.marker_idx;
+        let output_type = quote!(#ident<#(#dst_args),*>);
+
+        unique_predicates
+            .add(parse_quote! {
+                Self: #crate_path::#TRAIT_IDENT<
+                    #type_param_ident,
+                    #dst_type_ident,
+                    #crate_path::#MARKER_TYPE_IDENT<#marker_idx>,
+                    #OUTPUT_TYPE_IDENT = #output_type
+                >
+            })
+            .add_err_to(&mut result_builder);
+
+        let method_where_clause = unique_predicates.into_where_clause();
+        let dst_param = (dst_type_ident != type_param_ident).then_some(dst_type_ident);
+        let doc = format!(
+            " Maps every value of type `{type_param_ident}` within `self` using the given closure"
+        );
+
+        methods.push(quote! {
+            #[doc = #doc]
+            #inline
+            #[must_use]
+            #vis fn #method_ident<#dst_param>(
+                self,
+                f: impl ::core::ops::FnMut(#type_param_ident) -> #dst_type_ident
+            ) -> #output_type
+            #method_where_clause
+            {
+                #crate_path::#TRAIT_IDENT::<
+                    #type_param_ident,
+                    #dst_type_ident,
+                    #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+                >::#FN_IDENT(self, f)
+            }
+        });
+    }
+
+    result_builder.err_or(quote! {
+        #attrs
+        impl #impl_generics #ident #type_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
+
+/// Converts an identifier in `CamelCase` into `snake_case`
+///
+/// An underscore is inserted before every uppercase letter that follows a
+/// lowercase letter or a digit, e.g. `KeyType` becomes `key_type` and `T0`
+/// becomes `t0`.
+fn to_snake_case(ident: &str) -> String {
+    let mut snake_case = String::with_capacity(ident.len() + 4);
+    let mut prev_is_lower_or_digit = false;
+
+    for c in ident.chars() {
+        if c.is_uppercase() && prev_is_lower_or_digit {
+            snake_case.push('_');
+        }
+
+        prev_is_lower_or_digit = c.is_lowercase() || c.is_ascii_digit();
+        snake_case.extend(c.to_lowercase());
+    }
+
+    snake_case
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_snake_case_converts_camel_case() {
+        assert_eq!(to_snake_case("T"), "t");
+        assert_eq!(to_snake_case("T0"), "t0");
+        assert_eq!(to_snake_case("Width"), "width");
+        assert_eq!(to_snake_case("KeyType"), "key_type");
+        assert_eq!(to_snake_case("Item2Value"), "item2_value");
+    }
+}
@@ -0,0 +1,383 @@
+//! The derive logic for `FuncCount`
+
+use crate::derive::{impl_attrs, AttrOpts};
+use crate::ident::{COUNT_FN_IDENT, COUNT_LEAVES_FN_IDENT, COUNT_TRAIT_IDENT, MARKER_TYPE_IDENT};
+use crate::input::{parse_derive_input, FuncMapInput, Structish};
+use crate::predicates::UniquePredicates;
+use crate::result::{self, Error, ResultExt};
+use crate::syn_ext::{DependencyOnType, IntoType, IsTypish};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse_quote, GenericArgument, Index, Path, PathArguments, QSelf, Type, TypeArray, TypeParen,
+    TypePath, TypeReference, TypeTuple,
+};
+
+/// Generates implementations of `FuncCount` for a given item
+pub(crate) fn derive(item: TokenStream) -> TokenStream {
+    match try_derive(item) {
+        Ok(output) => output,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+/// Tries to generate implementations of `FuncCount` for a given item
+///
+/// This generates one implementation for every mapped type parameter `T`,
+/// counting the values within every field depending on `T` through the
+/// implementations of `FuncCount` for the types of the fields. Unlike for
+/// `FuncMap`, the type itself is not changed, so the implementations keep the
+/// generics of the item.
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed
+/// - `item` is not a valid input for deriving `FuncMap`
+/// - any of the fields of `item` has an unsupported type
+pub(crate) fn try_derive(item: TokenStream) -> Result<TokenStream, Error> {
+    let input = parse_derive_input(item)?;
+    let input: FuncMapInput = input.try_into()?;
+    let mut ident_collector = input.meta.ident_collector;
+    let mut result_builder = result::Builder::new();
+
+    let fn_type_ident = ident_collector.reserve_uppercase_letter('F', Span::mixed_site());
+    let fn_var_ident = Ident::new("f", Span::mixed_site());
+
+    let crate_path = &input.meta.crate_path;
+    let inline = &input.meta.inline;
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let attrs = impl_attrs(AttrOpts {
+        allow: &input.meta.allow,
+        cfg: &input.meta.cfg,
+    });
+
+    let mut impls = Vec::with_capacity(input.mapped_type_params.len());
+
+    for mapped_type_param in &input.mapped_type_params {
+        let type_param_ident = &mapped_type_param.type_param.ident;
+        let marker_idx = mapped_type_param.marker_idx;
+
+        let mut counter = Counter {
+            type_ident: type_param_ident,
+            fn_ident: &fn_var_ident,
+            crate_path,
+            unique_predicates: UniquePredicates::new(),
+        };
+
+        for predicate in where_clause
+            .iter()
+            .flat_map(|clause| clause.predicates.iter())
+        {
+            counter
+                .unique_predicates
+                .add(predicate.clone())
+                .add_err_to(&mut result_builder);
+        }
+
+        let mut count_arms = Vec::new();
+        let mut count_leaves_arms = Vec::new();
+        let mut uses_fn = false;
+
+        for Structish {
+            variant_ident,
+            fields,
+        } in &input.variants
+        {
+            let mut patterns = Vec::new();
+            let mut counts = Vec::new();
+            let mut leaf_counts = Vec::new();
+
+            for (field_idx, field) in fields.iter().enumerate() {
+                let (member, field_ident) = field.binding(field_idx);
+
+                let result = counter
+                    .count(quote!(#field_ident), &field.ty, Leaf::Fn)
+                    .and_then(|count| {
+                        Ok((
+                            count,
+                            counter.count(quote!(#field_ident), &field.ty, Leaf::One)?,
+                        ))
+                    });
+
+                let Some((Some(count), Some(leaf_count))) = result
+                    .with_context(field.context(field_idx, &input.ident, variant_ident.as_ref()))
+                    .add_err_to(&mut result_builder)
+                else {
+                    continue;
+                };
+
+                uses_fn = true;
+                patterns.push(quote!(#member: #field_ident));
+                counts.push(count);
+                leaf_counts.push(leaf_count);
+            }
+
+            let pat_path = variant_ident
+                .as_ref()
+                .map_or_else(|| quote!(Self), |ident| quote!(Self::#ident));
+
+            let count = sum(&counts);
+            let leaf_count = sum(&leaf_counts);
+
+            count_arms.push(quote! {
+                #pat_path { #(#patterns,)* .. } => #count
+            });
+
+            count_leaves_arms.push(quote! {
+                #pat_path { #(#patterns,)* .. } => #leaf_count
+            });
+        }
+
+        let impl_where_clause = counter.unique_predicates.into_where_clause();
+
+        let fn_pat = if uses_fn {
+            quote!(mut #fn_var_ident)
+        } else {
+            quote!(_)
+        };
+
+        impls.push(quote! {
+            #attrs
+            impl #impl_generics #crate_path::#COUNT_TRAIT_IDENT<
+                #type_param_ident,
+                #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+            > for #ident #type_generics
+                #impl_where_clause
+            {
+                #inline
+                fn #COUNT_FN_IDENT<#fn_type_ident>(
+                    &self,
+                    #fn_pat: #fn_type_ident
+                ) -> ::core::primitive::usize
+                where
+                    #fn_type_ident: ::core::ops::FnMut(
+                        &#type_param_ident
+                    ) -> ::core::primitive::usize
+                {
+                    match self {
+                        #(#count_arms,)*
+                    }
+                }
+
+                #inline
+                fn #COUNT_LEAVES_FN_IDENT(&self) -> ::core::primitive::usize {
+                    match self {
+                        #(#count_leaves_arms,)*
+                    }
+                }
+            }
+        });
+    }
+
+    result_builder.err_or(quote!(#(#impls)*))
+}
+
+/// How to count a value of the type parameter itself
+#[derive(Clone, Copy, Debug)]
+enum Leaf {
+    /// Apply the closure
+    Fn,
+
+    /// Count it as `1`, using `count_leaves` shortcuts for nested types
+    One,
+}
+
+/// Helper used for generating counting expressions and collecting predicates
+#[derive(Debug)]
+struct Counter<'ast> {
+    /// Identifier of the type parameter whose values are counted
+    type_ident: &'ast Ident,
+
+    /// Identifier of the closure passed to `func_count`
+    fn_ident: &'ast Ident,
+
+    /// Path to the `funcmap` crate
+    crate_path: &'ast Path,
+
+    /// Collected predicates
+    unique_predicates: UniquePredicates,
+}
+
+impl Counter<'_> {
+    /// Returns an expression counting the values of the type parameter within
+    /// `countable`, which is a reference to a value of type `ty`
+    ///
+    /// Returns [`None`] if `ty` doesn't depend on the type parameter, in which
+    /// case there is nothing to count.
+    fn count(
+        &mut self,
+        countable: TokenStream,
+        ty: &Type,
+        leaf: Leaf,
+    ) -> Result<Option<TokenStream>, Error> {
+        if let Type::Macro(..) = ty {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "`derive` cannot be used on items with type macros",
+            )
+            .into());
+        }
+
+        if ty.dependency_on_type(self.type_ident).is_none() {
+            return Ok(None);
+        }
+
+        if *ty == self.type_ident.clone().into_type() {
+            let fn_ident = self.fn_ident;
+
+            return Ok(Some(match leaf {
+                Leaf::Fn => quote!(#fn_ident(#countable)),
+                Leaf::One => quote!(1),
+            }));
+        }
+
+        match ty {
+            Type::Array(TypeArray { elem, .. }) => {
+                self.count_args(&countable, ty, [(0, &**elem)], leaf)
+            }
+
+            Type::Paren(TypeParen { elem, .. }) => self.count(countable, elem, leaf),
+
+            Type::Reference(TypeReference { elem, .. }) => {
+                self.count(quote!(*#countable), elem, leaf)
+            }
+
+            Type::Tuple(TypeTuple { elems, .. }) => {
+                let mut counts = Vec::new();
+
+                for (idx, elem) in elems.iter().enumerate() {
+                    let idx = Index::from(idx);
+
+                    if let Some(count) = self.count(quote!(&(#countable).#idx), elem, leaf)? {
+                        counts.push(count);
+                    }
+                }
+
+                Ok(Some(sum(&counts)))
+            }
+
+            Type::Path(TypePath { qself, path }) => {
+                let unsupported = || -> Error {
+                    syn::Error::new_spanned(
+                        ty,
+                        "counting over type with associated item is not supported",
+                    )
+                    .into()
+                };
+
+                if let Some(QSelf { ty: inner_ty, .. }) = qself {
+                    if inner_ty.dependency_on_type(self.type_ident).is_some() {
+                        return Err(unsupported());
+                    }
+                }
+
+                let Some(last) = path.segments.last() else {
+                    return Err(unsupported());
+                };
+
+                let mut prefix = path.clone();
+                prefix.segments.pop();
+
+                if Type::Path(TypePath {
+                    qself: None,
+                    path: prefix,
+                })
+                .dependency_on_type(self.type_ident)
+                .is_some()
+                {
+                    return Err(unsupported());
+                }
+
+                let PathArguments::AngleBracketed(angle_bracketed) = &last.arguments else {
+                    return Err(syn::Error::new_spanned(
+                        ty,
+                        "counting over this type is not supported",
+                    )
+                    .into());
+                };
+
+                // the type argument of `PhantomData` is never instantiated
+                if last.ident == "PhantomData" {
+                    return Ok(None);
+                }
+
+                let arg_types = angle_bracketed
+                    .args
+                    .iter()
+                    .filter(|arg| arg.is_typish())
+                    .enumerate()
+                    .filter_map(|(marker_idx, arg)| match arg {
+                        GenericArgument::Type(ty) => Some((marker_idx, ty)),
+                        _ => None,
+                    });
+
+                self.count_args(&countable, ty, arg_types, leaf)
+            }
+
+            _ => {
+                Err(syn::Error::new_spanned(ty, "counting over this type is not supported").into())
+            }
+        }
+    }
+
+    /// Returns an expression counting the values of the type parameter within
+    /// those of the given type arguments of `ty` that depend on it, via the
+    /// implementations of `FuncCount` for `ty`
+    fn count_args<'a>(
+        &mut self,
+        countable: &TokenStream,
+        ty: &Type,
+        arg_types: impl IntoIterator<Item = (usize, &'a Type)>,
+        leaf: Leaf,
+    ) -> Result<Option<TokenStream>, Error> {
+        let crate_path = self.crate_path;
+        let value_ident = Ident::new("value", Span::mixed_site());
+        let mut counts = Vec::new();
+
+        for (marker_idx, arg_type) in arg_types {
+            if arg_type.dependency_on_type(self.type_ident).is_none() {
+                continue;
+            }
+
+            self.unique_predicates.add(parse_quote! {
+                #ty: #crate_path::#COUNT_TRAIT_IDENT<
+                    #arg_type,
+                    #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+                >
+            })?;
+
+            let trait_path = quote! {
+                #crate_path::#COUNT_TRAIT_IDENT::<
+                    _,
+                    #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+                >
+            };
+
+            counts.push(
+                if matches!(leaf, Leaf::One) && *arg_type == self.type_ident.clone().into_type() {
+                    quote!(#trait_path::#COUNT_LEAVES_FN_IDENT(#countable))
+                } else {
+                    let inner = self
+                        .count(quote!(#value_ident), arg_type, leaf)?
+                        .unwrap_or_else(|| quote!(0));
+
+                    quote!(#trait_path::#COUNT_FN_IDENT(#countable, |#value_ident| #inner))
+                },
+            );
+        }
+
+        Ok(Some(sum(&counts)))
+    }
+}
+
+/// Returns an expression for the sum of the given expressions
+fn sum(counts: &[TokenStream]) -> TokenStream {
+    match counts {
+        [] => quote!(0),
+        [count] => count.clone(),
+        counts => quote!((#(#counts)+*)),
+    }
+}
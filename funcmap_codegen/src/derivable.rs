@@ -1,16 +1,22 @@
 //! [`Derivable`] type for managing different derivable traits
 
 use crate::ident::{
-    StaticIdent, FALLIBLE_FN_IDENT, FALLIBLE_TRAIT_IDENT, FN_IDENT,
-    NO_DROP_MARKER_FALLIBLE_TRAIT_IDENT, NO_DROP_MARKER_TRAIT_IDENT, TRAIT_IDENT,
+    StaticIdent, ENTRIES_FALLIBLE_FN_IDENT, ENTRIES_FALLIBLE_TRAIT_IDENT, ENTRIES_FN_IDENT,
+    ENTRIES_TRAIT_IDENT, FALLIBLE_FN_IDENT, FALLIBLE_TRAIT_IDENT, FN_IDENT,
+    NAMED_PARAM_FALLIBLE_TRAIT_IDENT, NAMED_PARAM_TRAIT_IDENT, NO_DROP_MARKER_FALLIBLE_TRAIT_IDENT,
+    NO_DROP_MARKER_TRAIT_IDENT, PARAMS_FALLIBLE_TRAIT_IDENT, PARAMS_TRAIT_IDENT, TRAIT_IDENT,
+    TYPE_PARAM_CHECK_FALLIBLE_TRAIT_IDENT, TYPE_PARAM_CHECK_TRAIT_IDENT,
+    TYPE_PARAM_FALLIBLE_TRAIT_IDENT, TYPE_PARAM_TRAIT_IDENT, VARIANT_FALLIBLE_TRAIT_IDENT,
+    VARIANT_TRAIT_IDENT,
 };
 
 use proc_macro2::TokenStream;
 use quote::quote;
 
 /// A derivable trait
-#[derive(Copy, Clone, Debug)]
-pub(crate) enum Derivable {
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Derivable {
     /// The derivable trait `FuncMap`
     Standard,
 
@@ -36,6 +42,69 @@ impl Derivable {
         }
     }
 
+    /// Returns the identifier of the trait exposing type parameters
+    /// corresponding to this derivable trait
+    pub(crate) fn type_param_trait_ident(self) -> StaticIdent {
+        match self {
+            Self::Standard => TYPE_PARAM_TRAIT_IDENT,
+            Self::Fallible => TYPE_PARAM_FALLIBLE_TRAIT_IDENT,
+        }
+    }
+
+    /// Returns the identifier of the trait exposing marker types of type
+    /// parameters by name corresponding to this derivable trait
+    pub(crate) fn named_param_trait_ident(self) -> StaticIdent {
+        match self {
+            Self::Standard => NAMED_PARAM_TRAIT_IDENT,
+            Self::Fallible => NAMED_PARAM_FALLIBLE_TRAIT_IDENT,
+        }
+    }
+
+    /// Returns the identifier of the trait listing the mapped type parameters
+    /// corresponding to this derivable trait
+    pub(crate) fn params_trait_ident(self) -> StaticIdent {
+        match self {
+            Self::Standard => PARAMS_TRAIT_IDENT,
+            Self::Fallible => PARAMS_FALLIBLE_TRAIT_IDENT,
+        }
+    }
+
+    /// Returns the identifier of the trait identifying variants by name
+    /// corresponding to this derivable trait
+    pub(crate) fn variant_trait_ident(self) -> StaticIdent {
+        match self {
+            Self::Standard => VARIANT_TRAIT_IDENT,
+            Self::Fallible => VARIANT_FALLIBLE_TRAIT_IDENT,
+        }
+    }
+
+    /// Returns the identifier of the trait checking type parameters
+    /// corresponding to this derivable trait
+    pub(crate) fn type_param_check_trait_ident(self) -> StaticIdent {
+        match self {
+            Self::Standard => TYPE_PARAM_CHECK_TRAIT_IDENT,
+            Self::Fallible => TYPE_PARAM_CHECK_FALLIBLE_TRAIT_IDENT,
+        }
+    }
+
+    /// Returns the identifier of the trait for mapping over the keys and values
+    /// of a map corresponding to this derivable trait
+    pub(crate) fn entries_trait_ident(self) -> StaticIdent {
+        match self {
+            Self::Standard => ENTRIES_TRAIT_IDENT,
+            Self::Fallible => ENTRIES_FALLIBLE_TRAIT_IDENT,
+        }
+    }
+
+    /// Returns the identifier of the method for mapping over the keys and
+    /// values of a map corresponding to this derivable trait
+    pub(crate) fn entries_fn_ident(self) -> StaticIdent {
+        match self {
+            Self::Standard => ENTRIES_FN_IDENT,
+            Self::Fallible => ENTRIES_FALLIBLE_FN_IDENT,
+        }
+    }
+
     /// Returns the identifier of the required method of this derivable trait
     pub(crate) fn fn_ident(self) -> StaticIdent {
         match self {
@@ -0,0 +1,1897 @@
+//! The core derive logic
+
+use crate::derivable::Derivable;
+use crate::ident::{
+    FALLIBLE_FN_IDENT, FALLIBLE_TRAIT_IDENT, FN_IDENT, MARKER_TYPE_IDENT, OUTPUT_TYPE_IDENT,
+    PARAM_NAME_HASH_FN_IDENT, TRAIT_IDENT, TYPE_PARAM_CHECK_FALLBACK_TRAIT_IDENT,
+    TYPE_PARAM_CHECK_TYPE_IDENT,
+};
+use crate::input::{parse_derive_input, FuncMapInput, Structish};
+use crate::map::{is_phantom_data_type, Mapping, TypeParamCheck};
+use crate::predicates::{Canonicalizer, UniquePredicates, UniqueTypeBounds};
+use crate::result::{self, Error, IteratorExt, ResultExt};
+use crate::syn_ext::{
+    DependencyOnType, IntoGenericArgument, IntoType, SubsType, WithoutAttrs, WithoutDefault,
+    WithoutMaybeBounds,
+};
+
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+use indexmap::{IndexMap, IndexSet};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::fold::Fold;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{
+    ext::IdentExt, parse_quote, GenericArgument, GenericParam, Generics, Member, Meta, Path,
+    PathArguments, PredicateType, Token, Type, TypeParam, TypeParamBound, TypePath, WhereClause,
+    WherePredicate,
+};
+
+/// Generates an implementation of `FuncMap` or `TryFuncMap` for a given item
+pub(crate) fn derive(item: TokenStream, derivable: Derivable) -> TokenStream {
+    match try_derive(item, derivable) {
+        Ok(output) => output,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+/// Tries to generate an implementation of `FuncMap` or `TryFuncMap` for a given
+/// item
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed
+/// - `item` is not a valid input for deriving `FuncMap` or `TryFuncMap`
+/// - any of the fields of `item` has an unsupported type
+pub(crate) fn try_derive(item: TokenStream, derivable: Derivable) -> Result<TokenStream, Error> {
+    let input = parse_derive_input(item)?;
+    let input: FuncMapInput = input.try_into()?;
+    let mut ident_collector = input.meta.ident_collector;
+    let mut result_builder = result::Builder::new();
+
+    // identifiers configured via `#[funcmap(idents(...))]` are reserved first,
+    // so the remaining ones are reserved around them
+    let mut reserve_configured = |ident: &Option<Ident>| {
+        let ident = ident.as_ref()?;
+        let reserved = ident_collector.reserve(ident, Span::mixed_site());
+
+        if reserved.is_none() {
+            result_builder.add_err(syn::Error::new_spanned(
+                ident,
+                format!("identifier `{ident}` is already in use"),
+            ));
+        }
+
+        reserved
+    };
+
+    let src_type_ident = reserve_configured(&input.meta.src_type_ident);
+    let dst_type_ident = reserve_configured(&input.meta.dst_type_ident);
+    result_builder.err_or(())?;
+
+    let src_type_ident = src_type_ident
+        .unwrap_or_else(|| ident_collector.reserve_uppercase_letter('A', Span::mixed_site()));
+    let dst_type_ident = dst_type_ident
+        .unwrap_or_else(|| ident_collector.reserve_uppercase_letter('B', Span::mixed_site()));
+    let fn_type_ident = ident_collector.reserve_uppercase_letter('F', Span::mixed_site());
+    let err_type_ident = ident_collector.reserve_uppercase_letter('E', Span::mixed_site());
+    let tmp_type_ident = ident_collector.reserve_uppercase_letter('X', Span::mixed_site());
+    let fn_var_ident = Ident::new("f", Span::mixed_site());
+
+    let crate_path = &input.meta.crate_path;
+    let inline = &input.meta.inline;
+    let ident = &input.ident;
+    let all_params = &input.generics.params;
+    let where_clause = &input.generics.where_clause;
+
+    let attr_opts = AttrOpts {
+        allow: &input.meta.allow,
+        cfg: &input.meta.cfg,
+    };
+    let attrs = impl_attrs(attr_opts);
+
+    let orig_impl_params: Vec<_> = all_params
+        .iter()
+        .cloned()
+        .map(|param| param.without_attrs().without_default())
+        .collect();
+
+    let orig_args: Vec<_> = all_params
+        .iter()
+        .cloned()
+        .map(IntoGenericArgument::into_generic_argument)
+        .collect();
+
+    // with `#[funcmap(assume_no_drop)]`, the user takes responsibility for the
+    // type not implementing `Drop`, e.g. because an implementation only exists
+    // under a `cfg` under which the derive is not used, or because moving out
+    // of the fields is delegated to `#[funcmap(with = "...")]`
+    let assert_not_drop = (!input.meta.assume_no_drop).then(|| {
+        let impl_params = &orig_impl_params;
+        let args = &orig_args;
+        let trait_ident = derivable.no_drop_marker_trait_ident();
+
+        // use `ident.span()` instead of `Span::call_site()` to avoid error
+        // message "this error originates in the derive macro ..."
+        quote_spanned! { ident.span() =>
+            #attrs
+            impl<#(#impl_params),*>
+                #crate_path::#trait_ident
+                for #ident<#(#args),*>
+                #where_clause
+            {}
+        }
+    });
+
+    if let Some(dyn_as) = &input.meta.dyn_as {
+        if dyn_as
+            .segments
+            .iter()
+            .any(|segment| !matches!(segment.arguments, PathArguments::None))
+        {
+            return Err(syn::Error::new_spanned(
+                dyn_as,
+                "expected path to wrapper type without generic arguments",
+            )
+            .into());
+        }
+    }
+
+    if derivable == Derivable::Standard && input.meta.infallible_via_try {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "`FuncMap` cannot be derived when it is derived via `TryFuncMap` through `#[funcmap(infallible_via_try)]`",
+        )
+        .into());
+    }
+
+    // with `#[funcmap(infallible_via_try)]`, deriving `TryFuncMap` also
+    // generates the implementations that deriving `FuncMap` would generate,
+    // but with `FuncMap` delegating to `TryFuncMap`
+    let derivables: &[Derivable] = if input.meta.infallible_via_try {
+        &[Derivable::Fallible, Derivable::Standard]
+    } else {
+        &[derivable]
+    };
+
+    if let (Derivable::Standard, Some(free_fn)) = (derivable, &input.meta.free_fn) {
+        if input.mapped_type_params.len() != 1 {
+            return Err(syn::Error::new_spanned(
+                free_fn,
+                "expected exactly one type parameter to be mapped for generating a free function",
+            )
+            .into());
+        }
+    }
+
+    // with `#[funcmap(strict)]`, field types that look like they are defined in
+    // the same crate are asserted to derive the same trait, so that a missing
+    // derive is reported at the field rather than as an unsatisfied bound
+    // wherever the mapping is used
+    let strict_checks = if input.meta.strict {
+        let mapped_type_idents: Vec<_> = input
+            .mapped_type_params
+            .iter()
+            .map(|mapped_type_param| &mapped_type_param.type_param.ident)
+            .collect();
+
+        let mut local_types = IndexSet::new();
+
+        for field in input.variants.iter().flat_map(|variant| &variant.fields) {
+            if field.opaque_via.is_none() {
+                local_types.extend(local_types_in(&field.ty, &mapped_type_idents));
+            }
+        }
+
+        (!local_types.is_empty()).then(|| {
+            let item_attrs = item_attrs(attr_opts);
+            let assert_ident = Ident::new("assert_derived", Span::mixed_site());
+            let type_ident = Ident::new("T", Span::mixed_site());
+            let trait_ident = derivable.params_trait_ident();
+
+            // use `ty.span()` so that errors point to the field type
+            let assertions = local_types.iter().map(|ty| {
+                quote_spanned! { ty.span() =>
+                    #assert_ident::<#ty>();
+                }
+            });
+
+            quote! {
+                const _: () = {
+                    #item_attrs
+                    #[allow(dead_code)]
+                    // taking the type as an argument makes its implied bounds
+                    // available
+                    fn assert_fields_derived<#(#orig_impl_params),*>(
+                        _: #ident<#(#orig_args),*>
+                    ) #where_clause {
+                        fn #assert_ident<#type_ident>()
+                        where
+                            #type_ident: ?::core::marker::Sized + #crate_path::#trait_ident,
+                        {
+                        }
+
+                        #(#assertions)*
+                    }
+                };
+            }
+        })
+    } else {
+        None
+    };
+
+    let params_impls = derivables.iter().map(|derivable| {
+        let trait_ident = derivable.params_trait_ident();
+        let marker_idxs = input
+            .mapped_type_params
+            .iter()
+            .map(|mapped_type_param| mapped_type_param.marker_idx);
+
+        quote! {
+            #attrs
+            impl<#(#orig_impl_params),*>
+                #crate_path::#trait_ident
+                for #ident<#(#orig_args),*>
+                #where_clause
+            {
+                const PARAMS: &'static [usize] = &[#(#marker_idxs),*];
+            }
+        }
+    });
+    let params_impls: Vec<_> = params_impls.collect();
+
+    // positions of the bounds given via `#[funcmap(drop_bounds(...))]` that
+    // occur in at least one of the generated implementations
+    let mut dropped_bounds = HashSet::new();
+
+    let impls: Vec<(TokenStream, TokenStream)> = input
+        .mapped_type_params
+        .into_iter()
+        .map(|mapped_type_param| {
+            let mut result_builder = result::Builder::new();
+
+            // if a bound on another type binds an associated type to a type
+            // depending on the type parameter, e.g. `M: Mode<Out = T>`, the
+            // output type is only well-formed if the destination type is the
+            // same as the source type, so the type parameter is mapped to
+            // itself, which also avoids conflicting bounds such as
+            // `M: Mode<Out = A> + Mode<Out = B>`
+            let dst_type_ident =
+                if binds_assoc_type_to(&input.generics, &mapped_type_param.type_param.ident) {
+                    src_type_ident.clone()
+                } else {
+                    dst_type_ident.clone()
+                };
+
+            let mut impl_params = Vec::with_capacity(all_params.len() + 1);
+
+            for (param_idx, param) in all_params.iter().enumerate() {
+                if param_idx == mapped_type_param.param_idx {
+                    impl_params.push(GenericParam::Type(TypeParam {
+                        bounds: subs_type_in_bounds(
+                            &mapped_type_param.type_param.bounds,
+                            &mapped_type_param.type_param.ident,
+                            &[&src_type_ident],
+                        )?
+                        .without_maybe_bounds(),
+                        ..src_type_ident.clone().into()
+                    }));
+
+                    if dst_type_ident != src_type_ident {
+                        impl_params.push(GenericParam::Type(TypeParam {
+                            bounds: subs_type_in_bounds(
+                                &mapped_type_param.type_param.bounds,
+                                &mapped_type_param.type_param.ident,
+                                &[&dst_type_ident],
+                            )?
+                            .without_maybe_bounds(),
+                            ..dst_type_ident.clone().into()
+                        }));
+                    }
+                } else {
+                    impl_params.push(match param {
+                        GenericParam::Type(type_param) => GenericParam::Type(TypeParam {
+                            bounds: subs_type_in_bounds(
+                                &type_param.bounds,
+                                &mapped_type_param.type_param.ident,
+                                &[&src_type_ident, &dst_type_ident],
+                            )?,
+                            ..type_param.ident.clone().into()
+                        }),
+                        GenericParam::Const(const_param) => GenericParam::Const(
+                            const_param.clone().without_attrs().without_default(),
+                        ),
+                        GenericParam::Lifetime(lifetime_param) => {
+                            GenericParam::Lifetime(lifetime_param.clone().without_attrs())
+                        }
+                    });
+                }
+            }
+
+            let src_args: Vec<_> = all_params
+                .iter()
+                .enumerate()
+                .map(|(param_idx, param)| {
+                    if param_idx == mapped_type_param.param_idx {
+                        GenericArgument::Type(src_type_ident.clone().into_type())
+                    } else {
+                        param.clone().into_generic_argument()
+                    }
+                })
+                .collect();
+
+            let dst_args: Vec<_> = all_params
+                .iter()
+                .enumerate()
+                .map(|(param_idx, param)| {
+                    if param_idx == mapped_type_param.param_idx {
+                        GenericArgument::Type(dst_type_ident.clone().into_type())
+                    } else {
+                        param.clone().into_generic_argument()
+                    }
+                })
+                .collect();
+
+            let mut unique_predicates = UniquePredicates::new();
+
+            for predicate in where_clause
+                .iter()
+                .flat_map(|clause| clause.predicates.iter())
+            {
+                let predicate = match predicate.clone().without_attrs() {
+                    WherePredicate::Type(predicate_type)
+                        if predicate_type.bounded_ty
+                            == mapped_type_param.type_param.ident.clone().into_type() =>
+                    {
+                        WherePredicate::Type(predicate_type.without_maybe_bounds())
+                    }
+                    predicate => predicate,
+                };
+
+                unique_predicates
+                    .add(
+                        predicate
+                            .clone()
+                            .subs_type(&mapped_type_param.type_param.ident, &src_type_ident),
+                    )
+                    .add_err_to(&mut result_builder);
+
+                unique_predicates
+                    .add(predicate.subs_type(&mapped_type_param.type_param.ident, &dst_type_ident))
+                    .add_err_to(&mut result_builder);
+            }
+
+            // within extra bounds, `A` and `B` refer to the source and
+            // destination types, which may have been reserved under different
+            // names, so substitute via a temporary identifier to avoid clashes
+            for predicate in &input.meta.extra_bounds {
+                let predicate = predicate
+                    .clone()
+                    .subs_type(&Ident::new("A", Span::call_site()), &tmp_type_ident)
+                    .subs_type(&Ident::new("B", Span::call_site()), &dst_type_ident)
+                    .subs_type(&tmp_type_ident, &src_type_ident);
+
+                unique_predicates
+                    .add(predicate)
+                    .add_err_to(&mut result_builder);
+            }
+
+            let mapping = Mapping {
+                type_param: &mapped_type_param.type_param,
+                generics: &input.generics,
+                src_type_ident: &src_type_ident,
+                dst_type_ident: &dst_type_ident,
+                fn_ident: &fn_var_ident,
+                crate_path: &input.meta.crate_path,
+                dyn_as: input.meta.dyn_as.as_ref(),
+                derivable,
+            };
+
+            // with `#[funcmap(dyn_body)]`, the body is generated as an inner
+            // function taking the closure as a trait object, so it is
+            // monomorphized once per combination of generic arguments rather
+            // than once per closure type
+            let fn_param_type = match (input.meta.dyn_body, derivable) {
+                (true, Derivable::Standard) => {
+                    quote!(&mut dyn ::core::ops::FnMut(#src_type_ident) -> #dst_type_ident)
+                }
+                (true, Derivable::Fallible) => quote! {
+                    &mut dyn ::core::ops::FnMut(
+                        #src_type_ident
+                    ) -> ::core::result::Result<#dst_type_ident, #err_type_ident>
+                },
+                (false, _) => quote!(#fn_type_ident),
+            };
+
+            let mut helpers = Vec::new();
+            let mut helper_idents = HashMap::new();
+            let mut type_param_checks = Vec::new();
+
+            for (helper_idx, ty) in
+                shared_field_types(&input.variants, &mapped_type_param.type_param.ident)
+                    .into_iter()
+                    .enumerate()
+            {
+                let value_ident = Ident::new("value", Span::mixed_site());
+
+                // if mapping fails, the error is reported for every single field
+                // of this type below
+                if let Ok(mapped) = mapping.map(&value_ident, ty) {
+                    for predicate in mapped.predicates.into_iter() {
+                        unique_predicates
+                            .add(predicate)
+                            .add_err_to(&mut result_builder);
+                    }
+
+                    type_param_checks.extend(mapped.type_param_checks);
+
+                    let src_type = ty
+                        .clone()
+                        .subs_type(&mapped_type_param.type_param.ident, &src_type_ident);
+
+                    let dst_type = ty
+                        .clone()
+                        .subs_type(&mapped_type_param.type_param.ident, &dst_type_ident);
+
+                    let return_type = match derivable {
+                        Derivable::Standard => quote!(#dst_type),
+                        Derivable::Fallible => {
+                            quote!(::core::result::Result<#dst_type, #err_type_ident>)
+                        }
+                    };
+
+                    let helper_ident =
+                        format_ident!("map_{}", helper_idx, span = Span::mixed_site());
+                    let body = derivable.unit_expr(mapped.tokens);
+
+                    helpers.push(quote! {
+                        let #helper_ident = |
+                            #value_ident: #src_type,
+                            #fn_var_ident: &mut #fn_param_type
+                        | -> #return_type { #body };
+                    });
+
+                    helper_idents.insert(ty, helper_ident);
+                }
+            }
+
+            // if the type parameter only occurs within `PhantomData`, all other
+            // fields are moved, which only requires them to be `Sized`, so a
+            // bound is needed at most for the last field of a struct whose type
+            // depends on any type parameter, as any other field is `Sized`
+            // anyway
+            let phantom_only = input
+                .variants
+                .iter()
+                .flat_map(|variant| &variant.fields)
+                .all(|field| {
+                    field.opaque_via.is_none()
+                        && !matches!(field.ty, Type::Macro(..))
+                        && (field
+                            .ty
+                            .dependency_on_type(&mapped_type_param.type_param.ident)
+                            .is_none()
+                            || is_phantom_data_type(&field.ty))
+                });
+
+            let mut arm_parts = Vec::new();
+
+            for Structish {
+                variant_ident,
+                fields,
+            } in &input.variants
+            {
+                let mut mappings = Vec::new();
+                let mut patterns = Vec::new();
+                let mut parts = Vec::new();
+
+                for (field_idx, field) in fields.iter().enumerate() {
+                    let (member, ident) = field.binding(field_idx);
+
+                    let tokens = if phantom_only
+                        && field
+                            .ty
+                            .dependency_on_type(&mapped_type_param.type_param.ident)
+                            .is_none()
+                    {
+                        let ty = &field.ty;
+
+                        if variant_ident.is_none()
+                            && field_idx + 1 == fields.len()
+                            && all_params.iter().any(|param| match param {
+                                GenericParam::Type(type_param) => {
+                                    ty.dependency_on_type(&type_param.ident).is_some()
+                                }
+                                _ => false,
+                            })
+                        {
+                            unique_predicates
+                                .add(parse_quote!(#ty: ::core::marker::Sized))
+                                .add_err_to(&mut result_builder);
+                        }
+
+                        ident.to_token_stream()
+                    } else if let Some(opaque_via) = &field.opaque_via {
+                        derivable.bind_expr(quote!(#opaque_via(#ident, &mut #fn_var_ident)))
+                    } else if let Some(rebuild) = field.deref_rebuild.as_ref().filter(|_| {
+                        field
+                            .ty
+                            .dependency_on_type(&mapped_type_param.type_param.ident)
+                            .is_some()
+                    }) {
+                        // the target is taken out of the field through
+                        // `DerefMut`, leaving its default value behind, and the
+                        // field is rebuilt from the mapped target
+                        let Some((target_ty, mapped)) = deref_target(&field.ty)
+                            .and_then(|target_ty| Ok((target_ty, mapping.map(&ident, target_ty)?)))
+                            .with_context(field.context(
+                                field_idx,
+                                &input.ident,
+                                variant_ident.as_ref(),
+                            ))
+                            .add_err_to(&mut result_builder)
+                        else {
+                            continue;
+                        };
+
+                        let src_ty = field
+                            .ty
+                            .clone()
+                            .subs_type(&mapped_type_param.type_param.ident, &src_type_ident);
+
+                        let src_target_ty = target_ty
+                            .clone()
+                            .subs_type(&mapped_type_param.type_param.ident, &src_type_ident);
+
+                        for predicate in mapped.predicates.into_iter().chain([
+                            parse_quote!(#src_ty: ::core::ops::DerefMut<Target = #src_target_ty>),
+                            parse_quote!(#src_target_ty: ::core::default::Default),
+                        ]) {
+                            unique_predicates
+                                .add(predicate)
+                                .add_err_to(&mut result_builder);
+                        }
+
+                        type_param_checks.extend(mapped.type_param_checks);
+
+                        let mapped_tokens = mapped.tokens;
+
+                        quote!({
+                            let mut #ident = #ident;
+                            let #ident = ::core::mem::take(
+                                ::core::ops::DerefMut::deref_mut(&mut #ident)
+                            );
+                            #rebuild(#mapped_tokens)
+                        })
+                    } else if let Some(helper_ident) = helper_idents.get(&field.ty) {
+                        derivable.bind_expr(quote!(#helper_ident(#ident, &mut #fn_var_ident)))
+                    } else if let Some(mapped) = mapping
+                        .map(&ident, &field.ty)
+                        .with_context(field.context(
+                            field_idx,
+                            &input.ident,
+                            variant_ident.as_ref(),
+                        ))
+                        .add_err_to(&mut result_builder)
+                    {
+                        for predicate in mapped.predicates.into_iter() {
+                            unique_predicates
+                                .add(predicate)
+                                .add_err_to(&mut result_builder);
+                        }
+
+                        type_param_checks.extend(mapped.type_param_checks);
+
+                        mapped.tokens
+                    } else {
+                        continue;
+                    };
+
+                    // with `#[funcmap(traced)]`, every field depending on the
+                    // type parameter is mapped within a span identified by the
+                    // static path of the field, so errors are reported along
+                    // with the fields leading to the failing value
+                    let tokens = if input.meta.traced
+                        && derivable == Derivable::Fallible
+                        && (field.opaque_via.is_some()
+                            || (field
+                                .ty
+                                .dependency_on_type(&mapped_type_param.type_param.ident)
+                                .is_some()
+                                && !is_phantom_data_type(&field.ty)))
+                    {
+                        let field_name = match &member {
+                            Member::Named(field_ident) => field_ident.unraw().to_string(),
+                            Member::Unnamed(index) => index.index.to_string(),
+                        };
+
+                        let path = match variant_ident {
+                            Some(variant_ident) => format!(
+                                "{}::{}.{}",
+                                input.ident.unraw(),
+                                variant_ident.unraw(),
+                                field_name
+                            ),
+                            None => format!("{}.{}", input.ident.unraw(), field_name),
+                        };
+
+                        let span_ident = Ident::new("_span", Span::mixed_site());
+
+                        quote!({
+                            let #span_ident = #crate_path::enter_field_span(#path);
+                            #tokens
+                        })
+                    } else {
+                        tokens
+                    };
+
+                    patterns.push(quote!(#member: #ident));
+                    mappings.push(quote!(#member: #tokens));
+                    parts.push((ident, tokens));
+                }
+
+                arm_parts.push((variant_ident, patterns, mappings, parts));
+            }
+
+            // variants without fields depending on the type parameter are
+            // rebuilt by moving their fields one by one: `Self` and
+            // `Self::Output` are different types, so moving such a variant as a
+            // whole would require an (unsound) transmute, whereas moving the
+            // fields is optimized into the same code anyway
+            let arms = arm_parts
+                .iter()
+                .map(|(variant_ident, patterns, mappings, _)| {
+                    let (pat_path, output_path) = match variant_ident {
+                        Some(ident) => (
+                            quote!(Self::#ident),
+                            quote!(Self::#OUTPUT_TYPE_IDENT::#ident),
+                        ),
+                        None => (quote!(Self), quote!(Self::#OUTPUT_TYPE_IDENT)),
+                    };
+
+                    quote! {
+                        #pat_path { #(#patterns,)* } => #output_path { #(#mappings,)* }
+                    }
+                });
+
+            // within dropped bounds, `A` and `B` refer to the source and
+            // destination types just like within extra bounds
+            for (predicate_idx, predicate) in input.meta.drop_bounds.iter().enumerate() {
+                let predicate = predicate
+                    .clone()
+                    .subs_type(&Ident::new("A", Span::call_site()), &tmp_type_ident)
+                    .subs_type(&Ident::new("B", Span::call_site()), &dst_type_ident)
+                    .subs_type(&tmp_type_ident, &src_type_ident);
+
+                for (bound_idx, bound) in predicate.bounds.iter().enumerate() {
+                    let removed_from_predicates = unique_predicates.remove(&predicate, bound);
+                    let removed_from_params =
+                        remove_param_bound(&mut impl_params, &predicate, bound);
+
+                    if removed_from_predicates || removed_from_params {
+                        dropped_bounds.insert((predicate_idx, bound_idx));
+                    }
+                }
+            }
+
+            let impl_where_clause = unique_predicates.into_where_clause();
+            let marker_idx = mapped_type_param.marker_idx;
+
+            let type_param_impls = derivables.iter().map(|derivable| {
+                let type_param_ident = &mapped_type_param.type_param.ident;
+                let trait_ident = derivable.type_param_trait_ident();
+
+                quote! {
+                    #attrs
+                    impl<#(#orig_impl_params),*>
+                        #crate_path::#trait_ident<#crate_path::#MARKER_TYPE_IDENT<#marker_idx>>
+                        for #ident<#(#orig_args),*>
+                        #where_clause
+                    {
+                        type Param = #type_param_ident;
+                    }
+                }
+            });
+
+            let named_param_impls = derivables.iter().map(|derivable| {
+                let type_param_name = mapped_type_param.type_param.ident.unraw().to_string();
+                let trait_ident = derivable.named_param_trait_ident();
+
+                quote! {
+                    #attrs
+                    impl<#(#orig_impl_params),*>
+                        #crate_path::#trait_ident<{
+                            #crate_path::#PARAM_NAME_HASH_FN_IDENT(#type_param_name)
+                        }>
+                        for #ident<#(#orig_args),*>
+                        #where_clause
+                    {
+                        type Marker = #crate_path::#MARKER_TYPE_IDENT<#marker_idx>;
+                    }
+                }
+            });
+
+            let type_param_checks = type_param_checks_fn(
+                type_param_checks,
+                derivable,
+                crate_path,
+                &impl_params,
+                &parse_quote!(#ident<#(#src_args),*>),
+                &impl_where_clause,
+                attr_opts,
+            );
+
+            // with decomposition and rebuild functions, the fields are not
+            // accessed directly, but the value is decomposed into a tuple of
+            // its fields and the mapped value is rebuilt from the tuple of the
+            // mapped fields, where `Self` in the paths to these functions refers
+            // to the type with its parameters inferred
+            let parts_expr = |value: TokenStream| {
+                input.meta.parts_fns.as_ref().map(|(with, rebuild)| {
+                    let with = subs_self_in_path(with, ident);
+                    let rebuild = subs_self_in_path(rebuild, ident);
+                    let (bindings, values): (Vec<_>, Vec<_>) = arm_parts
+                        .iter()
+                        .flat_map(|(_, _, _, parts)| parts.iter().cloned())
+                        .unzip();
+
+                    quote! {{
+                        let (#(#bindings,)*) = #with(#value);
+                        #rebuild((#(#values,)*))
+                    }}
+                })
+            };
+
+            // with a validation function, the mapped value is passed through it,
+            // where for `FuncMap` its error type is fixed to `Infallible`
+            let validate_expr = |expr: TokenStream| match (derivable, &input.meta.validate) {
+                (Derivable::Standard, Some(validate)) => {
+                    let result_ident = Ident::new("result", Span::mixed_site());
+                    let output_ident = Ident::new("output", Span::mixed_site());
+                    let never_ident = Ident::new("never", Span::mixed_site());
+
+                    quote! {{
+                        let #result_ident: ::core::result::Result<
+                            _,
+                            ::core::convert::Infallible
+                        > = #validate(#expr);
+
+                        match #result_ident {
+                            ::core::result::Result::Ok(#output_ident) => #output_ident,
+                            ::core::result::Result::Err(#never_ident) => match #never_ident {},
+                        }
+                    }}
+                }
+                (Derivable::Fallible, Some(validate)) => quote!(#validate(#expr)),
+                (_, None) => derivable.unit_expr(expr),
+            };
+
+            // the free function and the inner function generated with
+            // `#[funcmap(dyn_body)]` cannot refer to `Self`, so they get their
+            // own match arms using the name of the type instead
+            let named_body = |value_ident: &Ident| {
+                let arms = arm_parts
+                    .iter()
+                    .map(|(variant_ident, patterns, mappings, _)| {
+                        let path = if let Some(variant_ident) = variant_ident {
+                            quote!(#ident::#variant_ident)
+                        } else {
+                            quote!(#ident)
+                        };
+
+                        quote! {
+                            #path { #(#patterns,)* } => #path { #(#mappings,)* }
+                        }
+                    });
+
+                validate_expr(
+                    parts_expr(value_ident.to_token_stream()).unwrap_or_else(|| {
+                        quote! {
+                            match #value_ident {
+                                #(#arms,)*
+                            }
+                        }
+                    }),
+                )
+            };
+
+            let free_fn = match (derivable, &input.meta.free_fn) {
+                (Derivable::Standard, Some(free_fn_ident)) => {
+                    let value_ident = Ident::new("value", Span::mixed_site());
+                    let item_attrs = item_attrs(attr_opts);
+                    let predicates = impl_where_clause.predicates.iter();
+
+                    // with `#[funcmap(dyn_body)]`, the free function delegates
+                    // to the implementation instead of duplicating its body
+                    let body = if input.meta.dyn_body {
+                        quote! {
+                            #crate_path::#TRAIT_IDENT::<
+                                #src_type_ident,
+                                #dst_type_ident,
+                                #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+                            >::#FN_IDENT(#value_ident, #fn_var_ident)
+                        }
+                    } else {
+                        let body = named_body(&value_ident);
+
+                        quote! {
+                            #(#helpers)*
+
+                            #body
+                        }
+                    };
+
+                    Some(quote! {
+                        #item_attrs
+                        #inline
+                        #[must_use]
+                        pub(crate) fn #free_fn_ident<#(#impl_params,)* #fn_type_ident>(
+                            #value_ident: #ident<#(#src_args),*>,
+                            mut #fn_var_ident: #fn_type_ident
+                        ) -> #ident<#(#dst_args),*>
+                        where
+                            #(#predicates,)*
+                            #fn_type_ident: ::core::ops::FnMut(#src_type_ident) -> #dst_type_ident
+                        {
+                            #body
+                        }
+                    })
+                }
+                _ => None,
+            };
+
+            let body = if input.meta.dyn_body {
+                let value_ident = Ident::new("value", Span::mixed_site());
+                let dyn_body_ident = Ident::new("dyn_body", Span::mixed_site());
+                let predicates = impl_where_clause.predicates.iter();
+                let named_body = named_body(&value_ident);
+
+                let (err_param, return_type) = match derivable {
+                    Derivable::Standard => (None, quote!(#ident<#(#dst_args),*>)),
+                    Derivable::Fallible => (
+                        Some(&err_type_ident),
+                        quote!(::core::result::Result<#ident<#(#dst_args),*>, #err_type_ident>),
+                    ),
+                };
+
+                quote! {
+                    fn #dyn_body_ident<#(#impl_params,)* #err_param>(
+                        #value_ident: #ident<#(#src_args),*>,
+                        mut #fn_var_ident: #fn_param_type
+                    ) -> #return_type
+                    where
+                        #(#predicates,)*
+                    {
+                        #(#helpers)*
+
+                        #named_body
+                    }
+
+                    #dyn_body_ident(self, &mut #fn_var_ident)
+                }
+            } else {
+                let body = validate_expr(parts_expr(quote!(self)).unwrap_or_else(|| {
+                    quote! {
+                        match self {
+                            #(#arms,)*
+                        }
+                    }
+                }));
+
+                quote! {
+                    #(#helpers)*
+
+                    #body
+                }
+            };
+
+            let trait_impl = match derivable {
+                Derivable::Standard => quote! {
+                    #attrs
+                    impl<#(#impl_params),*>
+                        #crate_path::#TRAIT_IDENT<
+                            #src_type_ident,
+                            #dst_type_ident,
+                            #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+                        >
+                        for #ident<#(#src_args),*>
+                        #impl_where_clause
+                    {
+                        type #OUTPUT_TYPE_IDENT = #ident<#(#dst_args),*>;
+
+                        #inline
+                        fn #FN_IDENT<#fn_type_ident>(
+                            self,
+                            mut #fn_var_ident: #fn_type_ident
+                        ) -> Self::#OUTPUT_TYPE_IDENT
+                        where
+                            #fn_type_ident: ::core::ops::FnMut(#src_type_ident) -> #dst_type_ident
+                        {
+                            #body
+                        }
+                    }
+                },
+                Derivable::Fallible => quote! {
+                    #attrs
+                    impl<#(#impl_params),*>
+                        #crate_path::#FALLIBLE_TRAIT_IDENT<
+                            #src_type_ident,
+                            #dst_type_ident,
+                            #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+                        >
+                        for #ident<#(#src_args),*>
+                        #impl_where_clause
+                    {
+                        type #OUTPUT_TYPE_IDENT = #ident<#(#dst_args),*>;
+
+                        #inline
+                        fn #FALLIBLE_FN_IDENT<#err_type_ident, #fn_type_ident>(
+                            self,
+                            mut #fn_var_ident: #fn_type_ident
+                        ) -> ::core::result::Result<Self::#OUTPUT_TYPE_IDENT, #err_type_ident>
+                        where
+                            #fn_type_ident:
+                                ::core::ops::FnMut(
+                                    #src_type_ident
+                                ) -> ::core::result::Result<#dst_type_ident, #err_type_ident>
+                        {
+                            #body
+                        }
+                    }
+                },
+            };
+
+            // the output type is asserted to implement each auto trait whenever
+            // the input type, the destination type and all field types not
+            // depending on the type parameter implement it, where the latter are
+            // the same for the input and output types
+            //
+            // field types not depending on any type parameter are left out, as
+            // they would make for trivial bounds
+            let auto_trait_checks = match derivable {
+                Derivable::Standard => {
+                    let mut field_types = IndexSet::new();
+
+                    for field in input.variants.iter().flat_map(|variant| &variant.fields) {
+                        if field
+                            .ty
+                            .dependency_on_type(&mapped_type_param.type_param.ident)
+                            .is_none()
+                            && all_params.iter().any(|param| match param {
+                                GenericParam::Type(type_param) => {
+                                    field.ty.dependency_on_type(&type_param.ident).is_some()
+                                }
+                                _ => false,
+                            })
+                        {
+                            field_types.insert(&field.ty);
+                        }
+                    }
+
+                    input
+                        .meta
+                        .assert_auto_traits
+                        .iter()
+                        .map(|auto_trait| {
+                            let item_attrs = item_attrs(attr_opts);
+                            let assert_ident = Ident::new("assert_auto_trait", Span::mixed_site());
+                            let type_ident = Ident::new("T", Span::mixed_site());
+                            let predicates = impl_where_clause.predicates.iter();
+                            let field_types = field_types.iter();
+
+                            // use `auto_trait.span()` so that errors point to the
+                            // auto trait within the attribute
+                            let assertion = quote_spanned! { auto_trait.span() =>
+                                #assert_ident::<#ident<#(#dst_args),*>>();
+                            };
+
+                            quote! {
+                                const _: () = {
+                                    #item_attrs
+                                    #[allow(dead_code)]
+                                    // taking the type as an argument makes its
+                                    // implied bounds available
+                                    fn assert_auto_traits<#(#impl_params),*>(
+                                        _: #ident<#(#src_args),*>
+                                    )
+                                    where
+                                        #(#predicates,)*
+                                        #ident<#(#src_args),*>: #auto_trait,
+                                        #dst_type_ident: #auto_trait,
+                                        #(#field_types: #auto_trait,)*
+                                    {
+                                        fn #assert_ident<#type_ident>()
+                                        where
+                                            #type_ident: ?::core::marker::Sized + #auto_trait,
+                                        {
+                                        }
+
+                                        #assertion
+                                    }
+                                };
+                            }
+                        })
+                        .collect()
+                }
+                Derivable::Fallible => Vec::new(),
+            };
+
+            let infallible_impl = input.meta.infallible_via_try.then(|| {
+                let value_ident = Ident::new("value", Span::mixed_site());
+                let output_ident = Ident::new("output", Span::mixed_site());
+                let err_ident = Ident::new("err", Span::mixed_site());
+
+                quote! {
+                    #attrs
+                    impl<#(#impl_params),*>
+                        #crate_path::#TRAIT_IDENT<
+                            #src_type_ident,
+                            #dst_type_ident,
+                            #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+                        >
+                        for #ident<#(#src_args),*>
+                        #impl_where_clause
+                    {
+                        type #OUTPUT_TYPE_IDENT = #ident<#(#dst_args),*>;
+
+                        #inline
+                        fn #FN_IDENT<#fn_type_ident>(
+                            self,
+                            mut #fn_var_ident: #fn_type_ident
+                        ) -> Self::#OUTPUT_TYPE_IDENT
+                        where
+                            #fn_type_ident: ::core::ops::FnMut(#src_type_ident) -> #dst_type_ident
+                        {
+                            match #crate_path::#FALLIBLE_TRAIT_IDENT::<
+                                #src_type_ident,
+                                #dst_type_ident,
+                                #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+                            >::#FALLIBLE_FN_IDENT(self, |#value_ident| {
+                                ::core::result::Result::<_, ::core::convert::Infallible>::Ok(
+                                    #fn_var_ident(#value_ident)
+                                )
+                            }) {
+                                ::core::result::Result::Ok(#output_ident) => #output_ident,
+                                ::core::result::Result::Err(#err_ident) => match #err_ident {},
+                            }
+                        }
+                    }
+                }
+            });
+
+            let impl_tokens = quote! {
+                #trait_impl
+                #infallible_impl
+                #free_fn
+            };
+
+            let extra_tokens = quote! {
+                #(#type_param_impls)*
+                #(#named_param_impls)*
+                #type_param_checks
+                #(#auto_trait_checks)*
+            };
+
+            result_builder.err_or((impl_tokens, extra_tokens))
+        })
+        .collect_with_errors()?;
+
+    let (impls, extras): (Vec<_>, Vec<_>) = impls.into_iter().unzip();
+
+    let mut result_builder = result::Builder::new();
+
+    for (predicate_idx, predicate) in input.meta.drop_bounds.iter().enumerate() {
+        for (bound_idx, bound) in predicate.bounds.iter().enumerate() {
+            if !dropped_bounds.contains(&(predicate_idx, bound_idx)) {
+                result_builder.add_err(syn::Error::new_spanned(
+                    bound,
+                    "bound to drop doesn't occur in any generated implementation",
+                ));
+            }
+        }
+    }
+
+    result_builder.err_or(())?;
+
+    let variant_impls = input
+        .variants
+        .iter()
+        .filter_map(|variant| variant.variant_ident.as_ref())
+        .map(|variant_ident| {
+            let variant_name = variant_ident.unraw().to_string();
+            let trait_ident = derivable.variant_trait_ident();
+
+            quote! {
+                #attrs
+                impl<#(#orig_impl_params),*>
+                    #crate_path::#trait_ident<{
+                        #crate_path::#PARAM_NAME_HASH_FN_IDENT(#variant_name)
+                    }>
+                    for #ident<#(#orig_args),*>
+                    #where_clause
+                {
+                    fn is_variant(&self) -> bool {
+                        ::core::matches!(self, Self::#variant_ident { .. })
+                    }
+                }
+            }
+        });
+
+    // everything except for the implementations of the derived trait and the
+    // free function is put into an anonymous scope, so that helper items
+    // cannot collide with items of the same name next to the type, e.g. when
+    // deriving for types of the same name local to different functions
+    let output = quote! {
+        #(#impls)*
+
+        const _: () = {
+            #assert_not_drop
+            #(#extras)*
+            #(#params_impls)*
+            #(#variant_impls)*
+            #strict_checks
+        };
+    };
+
+    // with `#[funcmap(canonical)]`, predicates and bounds are sorted so that
+    // their order doesn't depend on how the contributing fields are ordered
+    if input.meta.canonical {
+        let file: syn::File = syn::parse2(output)?;
+        Ok(Canonicalizer::new().fold_file(file).into_token_stream())
+    } else {
+        Ok(output)
+    }
+}
+
+/// Removes the given bound on the bounded type of the given predicate from
+/// the bounds of the matching type parameter among `params`, returning whether
+/// it was present
+fn remove_param_bound(
+    params: &mut [GenericParam],
+    predicate: &PredicateType,
+    bound: &TypeParamBound,
+) -> bool {
+    if predicate.lifetimes.is_some() {
+        return false;
+    }
+
+    params.iter_mut().any(|param| match param {
+        GenericParam::Type(type_param)
+            if predicate.bounded_ty == type_param.ident.clone().into_type()
+                && type_param.bounds.iter().any(|b| b == bound) =>
+        {
+            type_param.bounds = mem::take(&mut type_param.bounds)
+                .into_iter()
+                .filter(|b| b != bound)
+                .collect();
+
+            true
+        }
+        _ => false,
+    })
+}
+
+/// Generates checks that the marker types used by a mapping refer to the
+/// intended type parameters
+///
+/// The checks are put into a function generic over `impl_params` taking a
+/// value of `src_type` as an argument, or omitted if there are no checks.
+pub(crate) fn type_param_checks_fn(
+    type_param_checks: Vec<TypeParamCheck>,
+    derivable: Derivable,
+    crate_path: &Path,
+    impl_params: &[GenericParam],
+    src_type: &Type,
+    impl_where_clause: &WhereClause,
+    attr_opts: AttrOpts<'_>,
+) -> Option<TokenStream> {
+    (!type_param_checks.is_empty()).then(|| {
+        let check_trait_ident = derivable.type_param_check_trait_ident();
+
+        let checks = type_param_checks.into_iter().map(
+            |TypeParamCheck {
+                 ty,
+                 marker_idx,
+                 param,
+             }| {
+                // resolve `::core` at the call site so that it refers to the
+                // `core` crate even if `ty` comes from an edition 2015 crate
+                let core = quote_spanned!(ty.span().resolved_at(Span::call_site()) => ::core);
+
+                // use `ty.span()` so that errors point to the field type
+                quote_spanned! { ty.span() =>
+                    let _: #core::marker::PhantomData<#param> = (&#crate_path::#TYPE_PARAM_CHECK_TYPE_IDENT::<
+                        #ty,
+                        #crate_path::#MARKER_TYPE_IDENT<#marker_idx>,
+                        #param
+                    >::new()).check();
+                }
+            },
+        );
+
+        let item_attrs = item_attrs(attr_opts);
+        let use_crate_path = use_path(crate_path);
+
+        quote! {
+            const _: () = {
+                #item_attrs
+                #[allow(dead_code)]
+                // taking the type as an argument makes its implied bounds
+                // available
+                fn check_type_params<#(#impl_params),*>(
+                    _: #src_type
+                ) #impl_where_clause {
+                    use #use_crate_path::{
+                        #check_trait_ident as _,
+                        #TYPE_PARAM_CHECK_FALLBACK_TRAIT_IDENT as _,
+                    };
+
+                    #(#checks)*
+                }
+            };
+        }
+    })
+}
+
+/// Options for the attributes to put on generated items
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AttrOpts<'ast> {
+    /// Paths to the lints to allow in addition to the ones allowed by default
+    pub(crate) allow: &'ast [Path],
+
+    /// Predicates under which to generate the items
+    pub(crate) cfg: &'ast [Meta],
+}
+
+/// Returns the attributes to put on generated implementations
+pub(crate) fn impl_attrs(attr_opts: AttrOpts<'_>) -> TokenStream {
+    let item_attrs = item_attrs(attr_opts);
+
+    quote! {
+        #item_attrs
+        #[automatically_derived]
+    }
+}
+
+/// Returns `path` in a form that resolves within a `use` declaration in the
+/// same way as it does in other positions
+///
+/// In edition 2015, relative paths within `use` declarations start at the crate
+/// root instead of the current module. Giving the first segment of a relative
+/// path a call-site span makes it resolve according to the edition of this
+/// crate, i.e. relative to the current module. Paths with a leading colon as
+/// well as `crate::`, `self::` and `super::` paths are left unchanged.
+fn use_path(path: &Path) -> Path {
+    let mut path = path.clone();
+
+    if path.leading_colon.is_none() {
+        if let Some(first) = path.segments.first_mut() {
+            if !matches!(first.ident.to_string().as_str(), "crate" | "self" | "super") {
+                first.ident.set_span(Span::call_site());
+            }
+        }
+    }
+
+    path
+}
+
+/// Returns the attributes to put on every generated item
+///
+/// These are `#[cfg(...)]` attributes for the predicates configured via
+/// `#[funcmap(cfg(...))]`, so that the items are only generated if all of them
+/// hold, followed by the attributes silencing lints that could otherwise be
+/// triggered by generated code in the user's crate. Besides a default list of
+/// lints, this silences the lints configured via `#[funcmap(allow(...))]`, so
+/// that users can keep up with lints added in future versions of Rust.
+fn item_attrs(attr_opts: AttrOpts<'_>) -> TokenStream {
+    let AttrOpts { allow, cfg } = attr_opts;
+
+    quote! {
+        #(#[cfg(#cfg)])*
+        #[allow(absolute_paths_not_starting_with_crate)]
+        #[allow(bare_trait_objects)]
+        #[allow(deprecated)]
+        #[allow(drop_bounds)]
+        #[allow(dyn_drop)]
+        #[allow(non_camel_case_types)]
+        #[allow(trivial_bounds)]
+        #[allow(unused_parens)]
+        #[allow(unused_qualifications)]
+        #[allow(clippy::disallowed_method)]
+        #[allow(clippy::disallowed_type)]
+        #[allow(clippy::needless_question_mark)]
+        #(#[allow(#allow)])*
+    }
+}
+
+/// Collects the types of fields that can share a mapping helper
+///
+/// These are the types that depend on the type named `type_ident` and occur
+/// for more than one field within `variants`, except for the type named
+/// `type_ident` itself, whose mapping is trivial anyway, and fields mapped via
+/// a custom function or through `DerefMut`. Sharing a helper for
+/// these types keeps the generated code small, e.g. for enums with many
+/// variants of the same shape.
+fn shared_field_types<'ast>(variants: &'ast [Structish], type_ident: &Ident) -> Vec<&'ast Type> {
+    let mut counts: IndexMap<&Type, usize> = IndexMap::new();
+
+    for field in variants.iter().flat_map(|variant| &variant.fields) {
+        if field.opaque_via.is_none()
+            && field.deref_rebuild.is_none()
+            && field.ty.dependency_on_type(type_ident).is_some()
+            && field.ty != type_ident.clone().into_type()
+        {
+            *counts.entry(&field.ty).or_default() += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .filter_map(|(ty, count)| (count > 1).then_some(ty))
+        .collect()
+}
+
+/// Returns the target of a field mapped through `DerefMut`
+///
+/// As the derive macros cannot resolve `<T as Deref>::Target`, this is taken to
+/// be the only type argument of the type of the field, e.g. `Vec<T>` for a
+/// field of type `Tracked<Vec<T>>`. That this is actually the target is
+/// asserted through a bound on the generated implementation.
+fn deref_target(ty: &Type) -> Result<&Type, Error> {
+    if let Type::Path(type_path) = ty {
+        if let Some(PathArguments::AngleBracketed(angle_bracketed)) = type_path
+            .path
+            .segments
+            .last()
+            .map(|segment| &segment.arguments)
+        {
+            let mut type_args = angle_bracketed.args.iter().filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            });
+
+            if let (Some(target_ty), None) = (type_args.next(), type_args.next()) {
+                return Ok(target_ty);
+            }
+        }
+    }
+
+    Err(syn::Error::new_spanned(
+        ty,
+        "expected a type with exactly one type argument for mapping through `DerefMut`",
+    )
+    .into())
+}
+
+/// Names of types outside of the current crate that `funcmap` provides
+/// implementations for and that are commonly referred to by a single identifier
+const NON_LOCAL_TYPE_NAMES: &[&str] = &[
+    "Arc",
+    "BTreeMap",
+    "BTreeSet",
+    "BinaryHeap",
+    "Bound",
+    "Box",
+    "Cell",
+    "Cow",
+    "HashMap",
+    "HashSet",
+    "LinkedList",
+    "Mutex",
+    "Option",
+    "PhantomData",
+    "Pin",
+    "Poll",
+    "Range",
+    "RangeFrom",
+    "RangeInclusive",
+    "RangeTo",
+    "RangeToInclusive",
+    "Rc",
+    "RefCell",
+    "Result",
+    "Reverse",
+    "RwLock",
+    "UnsafeCell",
+    "Vec",
+    "VecDeque",
+    "Wrapping",
+];
+
+/// Returns the types within `ty` that look like they are defined in the
+/// current crate and depend on any of the types named `type_idents`
+///
+/// As the derive macros cannot resolve paths, a type is considered local if its
+/// path starts with `crate`, `self` or `super`, or if it consists of a single
+/// identifier that is not one of [`NON_LOCAL_TYPE_NAMES`]. In any case, the
+/// type must have generic arguments, as it cannot depend on a type parameter
+/// otherwise.
+fn local_types_in<'ast>(ty: &'ast Type, type_idents: &[&Ident]) -> Vec<&'ast Type> {
+    let mut visitor = LocalTypesVisitor {
+        local_types: Vec::new(),
+        type_idents,
+    };
+    visitor.visit_type(ty);
+    visitor.local_types
+}
+
+/// Type implementing [`Visit`] for [`local_types_in`]
+#[derive(Debug)]
+struct LocalTypesVisitor<'ast, 'a> {
+    local_types: Vec<&'ast Type>,
+    type_idents: &'a [&'a Ident],
+}
+
+impl<'ast> Visit<'ast> for LocalTypesVisitor<'ast, '_> {
+    fn visit_type(&mut self, ty: &'ast Type) {
+        match ty {
+            Type::Path(TypePath { qself: None, path }) if path.leading_colon.is_none() => {
+                let is_local = match path.segments.first() {
+                    Some(segment) if path.segments.len() == 1 => !NON_LOCAL_TYPE_NAMES
+                        .iter()
+                        .any(|name| segment.ident == name),
+                    Some(segment) => {
+                        segment.ident == "crate"
+                            || segment.ident == "self"
+                            || segment.ident == "super"
+                    }
+                    None => false,
+                };
+
+                let has_args = path
+                    .segments
+                    .last()
+                    .map_or(false, |segment| !segment.arguments.is_empty());
+
+                if is_local
+                    && has_args
+                    && self
+                        .type_idents
+                        .iter()
+                        .any(|type_ident| ty.dependency_on_type(type_ident).is_some())
+                {
+                    self.local_types.push(ty);
+                }
+
+                visit::visit_type(self, ty);
+            }
+            // references, trait objects, function pointers etc. are not
+            // mapped through their components
+            Type::Array(..)
+            | Type::Group(..)
+            | Type::Paren(..)
+            | Type::Slice(..)
+            | Type::Tuple(..) => visit::visit_type(self, ty),
+            _ => {}
+        }
+    }
+}
+
+/// Returns whether any bound on a type not depending on the type named
+/// `type_ident` binds an associated type to a type depending on it, while the
+/// trait itself does not depend on it
+///
+/// This is the case e.g. for `M: Mode<Out = T>` within the generics of
+/// `Foo<T, M>`, both as a bound on `M` and within the `where` clause, but not
+/// for `M: Convert<T, Out = T>`, as substituting `T` yields bounds for
+/// different traits in the latter case.
+pub(crate) fn binds_assoc_type_to(generics: &Generics, type_ident: &Ident) -> bool {
+    let other_type_param_bounds = generics
+        .type_params()
+        .filter(|type_param| type_param.ident != *type_ident)
+        .flat_map(|type_param| &type_param.bounds);
+
+    let where_clause_bounds = generics
+        .where_clause
+        .iter()
+        .flat_map(|clause| clause.predicates.iter())
+        .filter_map(|predicate| match predicate {
+            WherePredicate::Type(predicate_type)
+                if predicate_type
+                    .bounded_ty
+                    .dependency_on_type(type_ident)
+                    .is_none() =>
+            {
+                Some(&predicate_type.bounds)
+            }
+            _ => None,
+        })
+        .flatten();
+
+    other_type_param_bounds
+        .chain(where_clause_bounds)
+        .any(|bound| match bound {
+            TypeParamBound::Trait(trait_bound) => {
+                let mut trait_path = trait_bound.path.clone();
+                let mut binds = false;
+
+                for segment in &mut trait_path.segments {
+                    if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                        args.args = mem::take(&mut args.args)
+                            .into_iter()
+                            .filter(|arg| match arg {
+                                GenericArgument::AssocType(assoc_type) => {
+                                    binds |= assoc_type.ty.dependency_on_type(type_ident).is_some();
+                                    false
+                                }
+                                _ => true,
+                            })
+                            .collect();
+                    }
+                }
+
+                binds
+                    && Type::Path(TypePath {
+                        qself: None,
+                        path: trait_path,
+                    })
+                    .dependency_on_type(type_ident)
+                    .is_none()
+            }
+            _ => false,
+        })
+}
+
+/// Substitutes a leading `Self` within a path with the identifier of the type
+///
+/// This lets paths such as `Self::from_parts` refer to the type with its
+/// parameters inferred rather than to `Self` within a generated
+/// implementation, which is the source type of the mapping.
+fn subs_self_in_path(path: &Path, type_ident: &Ident) -> Path {
+    let mut path = path.clone();
+
+    if path.leading_colon.is_none() {
+        if let Some(first) = path.segments.first_mut() {
+            if first.ident == "Self" {
+                first.ident = type_ident.clone();
+            }
+        }
+    }
+
+    path
+}
+
+/// Substitutes a type with multiple other types within a collection of bounds
+///
+/// Substitutes the type named `type_ident` with each of `subs_idents` within
+/// each of `bounds`, returning a deduplicated `+`-punctuated sequence
+pub(crate) fn subs_type_in_bounds<'ast>(
+    bounds: impl IntoIterator<Item = &'ast TypeParamBound>,
+    type_ident: &Ident,
+    subs_idents: &[&Ident],
+) -> Result<Punctuated<TypeParamBound, Token![+]>, Error> {
+    let mut unique_type_bounds = UniqueTypeBounds::new();
+
+    let mut result_builder = result::Builder::new();
+
+    for bound in bounds {
+        match bound {
+            TypeParamBound::Trait(trait_bound) => {
+                for subs_ident in subs_idents {
+                    unique_type_bounds.add(TypeParamBound::Trait(
+                        trait_bound
+                            .clone()
+                            .without_attrs()
+                            .subs_type(type_ident, subs_ident),
+                    ));
+                }
+            }
+
+            bound @ TypeParamBound::Lifetime(..) => unique_type_bounds.add(bound.clone()),
+
+            bound => {
+                result_builder.add_err(syn::Error::new_spanned(
+                    bound,
+                    "unsupported type parameter bound",
+                ));
+            }
+        }
+    }
+
+    result_builder.err_or(unique_type_bounds.into_bounds())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapping_of_repeated_field_type_is_generated_once() {
+        let variants = (0_usize..50).map(|idx| format_ident!("Variant{}", idx));
+
+        let item = quote! {
+            enum Test<T> {
+                #(#variants((T, u32)),)*
+            }
+        };
+
+        let output = try_derive(item, Derivable::Standard).unwrap().to_string();
+
+        assert_eq!(output.matches(". 0").count(), 1);
+        assert_eq!(output.matches("map_0").count(), 51);
+    }
+
+    #[test]
+    fn predicates_of_repeated_field_types_are_added_once() {
+        for derivable in [Derivable::Standard, Derivable::Fallible] {
+            let variants = (0_usize..20).map(|idx| format_ident!("Variant{}", idx));
+
+            let item = quote! {
+                enum Test<T> {
+                    #(#variants(Foo<T>, Bar<T>, u32),)*
+                }
+            };
+
+            let output = try_derive(item, derivable).unwrap();
+            let file: syn::File = syn::parse2(output).unwrap();
+
+            let trait_ident = derivable.trait_ident();
+            let trait_impl = file
+                .items
+                .into_iter()
+                .find_map(|item| match item {
+                    syn::Item::Impl(item_impl)
+                        if item_impl.trait_.as_ref().map_or(false, |(_, path, _)| {
+                            path.segments
+                                .last()
+                                .map_or(false, |segment| segment.ident == trait_ident)
+                        }) =>
+                    {
+                        Some(item_impl)
+                    }
+                    _ => None,
+                })
+                .unwrap();
+
+            assert_eq!(
+                trait_impl.generics.where_clause.unwrap().predicates.len(),
+                2
+            );
+        }
+    }
+
+    #[test]
+    fn sized_bounds_are_only_required_for_field_types_depending_on_other_type_params() {
+        for derivable in [Derivable::Standard, Derivable::Fallible] {
+            let item = quote! {
+                #[funcmap(params(T))]
+                struct Test<'a, S, T, const N: usize>(
+                    T,
+                    u64,
+                    &'a str,
+                    [u8; N],
+                    (T, String),
+                    S,
+                    Vec<S>,
+                    (T, Option<S>),
+                );
+            };
+
+            let output = try_derive(item, derivable).unwrap().to_string();
+
+            assert!(!output.contains("u64 : :: core :: marker :: Sized"));
+            assert!(!output.contains("& 'a str : :: core :: marker :: Sized"));
+            assert!(!output.contains("[u8 ; N] : :: core :: marker :: Sized"));
+            assert!(!output.contains("String : :: core :: marker :: Sized"));
+            assert!(output.contains("S : :: core :: marker :: Sized"));
+            assert!(output.contains("Vec < S > : :: core :: marker :: Sized"));
+            assert!(output.contains("Option < S > : :: core :: marker :: Sized"));
+        }
+    }
+
+    #[test]
+    fn option_of_box_and_box_of_option_are_mapped_without_predicates() {
+        for derivable in [Derivable::Standard, Derivable::Fallible] {
+            let fast_path_item = quote! {
+                struct Test<T>(
+                    ::std::option::Option<::std::boxed::Box<T>>,
+                    ::std::boxed::Box<::std::option::Option<T>>,
+                );
+            };
+
+            let generic_item = quote! {
+                struct Test<T>(
+                    ::std::option::Option<::std::vec::Vec<T>>,
+                    ::std::vec::Vec<::std::option::Option<T>>,
+                );
+            };
+
+            let fast_path_output = try_derive(fast_path_item, derivable).unwrap().to_string();
+            let generic_output = try_derive(generic_item, derivable).unwrap().to_string();
+
+            // the only mention of the trait is in the header of the implementation,
+            // as `Option`, `Box` and `Vec` don't require predicates in either case
+            let trait_path = format!(":: funcmap :: {} <", derivable.trait_ident());
+            assert_eq!(fast_path_output.matches(&trait_path).count(), 1);
+            assert_eq!(generic_output.matches(&trait_path).count(), 1);
+
+            // the fast path avoids going through the implementations and closures
+            assert!(fast_path_output.len() < generic_output.len());
+        }
+    }
+
+    #[test]
+    fn option_and_box_are_not_mapped_directly_unless_referred_to_through_qualified_paths() {
+        for derivable in [Derivable::Standard, Derivable::Fallible] {
+            for item in [
+                quote!(
+                    struct Test<T>(Option<Box<T>>);
+                ),
+                quote!(
+                    struct Test<T>(std::option::Option<Box<T>>);
+                ),
+                quote!(
+                    struct Test<T>(Box<std::option::Option<T>>);
+                ),
+            ] {
+                let output = try_derive(item, derivable).unwrap().to_string();
+
+                let trait_path = format!(":: funcmap :: {} <", derivable.trait_ident());
+                assert!(output.matches(&trait_path).count() > 1);
+            }
+        }
+    }
+
+    #[test]
+    fn std_containers_require_predicates_unless_referred_to_through_qualified_paths() {
+        for derivable in [Derivable::Standard, Derivable::Fallible] {
+            let qualified_item = quote! {
+                struct Test<T>(std::vec::Vec<core::option::Option<::alloc::vec::Vec<[T; 2]>>>);
+            };
+
+            let unqualified_item = quote! {
+                struct Test<T>(Vec<Option<Vec<[T; 2]>>>);
+            };
+
+            let qualified_output = try_derive(qualified_item, derivable).unwrap().to_string();
+            let unqualified_output = try_derive(unqualified_item, derivable).unwrap().to_string();
+
+            // types merely named `Vec` or `Option` may be any types, so their
+            // predicates are kept
+            let trait_path = format!(":: funcmap :: {} <", derivable.trait_ident());
+            assert_eq!(qualified_output.matches(&trait_path).count(), 1);
+            assert!(unqualified_output.matches(&trait_path).count() > 1);
+        }
+    }
+
+    #[test]
+    fn mapping_of_single_field_type_is_generated_inline() {
+        let item = quote! {
+            enum Test<T> {
+                First((T, u32)),
+                Second(T),
+                Third(T),
+            }
+        };
+
+        let output = try_derive(item, Derivable::Standard).unwrap().to_string();
+
+        assert!(!output.contains("map_0"));
+    }
+
+    #[test]
+    fn references_to_higher_ranked_types_require_outlives_components() {
+        let item = quote! {
+            struct Test<'a, T>(PhantomData<(&'a for<'b> fn(&'b T), for<'b> fn(&'a &'b T))>);
+        };
+
+        let output = try_derive(item, Derivable::Standard).unwrap().to_string();
+
+        assert!(output.contains("where B : 'a"));
+        assert!(!output.contains("fn (& 'b B) : 'a"));
+        assert!(!output.contains("& 'b B : 'a"));
+    }
+
+    #[test]
+    fn type_param_only_occurring_within_phantom_data_requires_no_sized_bounds() {
+        let item = quote! {
+            #[funcmap(params(T))]
+            struct Test<S: ?Sized, T>(u64, PhantomData<T>, Vec<S>);
+        };
+
+        let output = try_derive(item, Derivable::Standard).unwrap().to_string();
+
+        assert!(!output.contains("u64 : :: core :: marker :: Sized"));
+        assert!(output.contains("Vec < S > : :: core :: marker :: Sized"));
+
+        let item = quote! {
+            #[funcmap(params(T))]
+            enum Test<S, T> {
+                Value(u64, PhantomData<T>, S),
+            }
+        };
+
+        let output = try_derive(item, Derivable::Standard).unwrap().to_string();
+
+        assert!(!output.contains(":: core :: marker :: Sized"));
+    }
+
+    #[test]
+    fn inline_hint_is_put_on_mapping_functions() {
+        let item = quote! {
+            #[funcmap(inline = "always", free_fn = "map_test")]
+            struct Test<T>(T);
+        };
+
+        let output = try_derive(item, Derivable::Standard).unwrap().to_string();
+
+        assert_eq!(output.matches("# [inline (always)]").count(), 2);
+    }
+
+    #[test]
+    fn variants_without_mapped_fields_are_rebuilt_by_moving_fields() {
+        let item = quote! {
+            enum Test<T> {
+                Mapped(T),
+                Unmapped(u32, String),
+            }
+        };
+
+        let output = try_derive(item, Derivable::Fallible).unwrap().to_string();
+
+        assert!(output.contains(
+            "Self :: Unmapped { 0 : field_0 , 1 : field_1 , } => \
+             Self :: Output :: Unmapped { 0 : field_0 , 1 : field_1 , }"
+        ));
+    }
+
+    #[test]
+    fn raw_field_identifiers_are_bound_without_prefix() {
+        let item = quote! {
+            struct Test<T> {
+                r#type: T,
+                r#match: T,
+            }
+        };
+
+        let output = try_derive(item, Derivable::Standard).unwrap().to_string();
+
+        assert!(output.contains("r#type : field_type"));
+        assert!(output.contains("r#match : field_match"));
+        assert!(!output.contains("field_r#"));
+    }
+
+    #[test]
+    fn fields_beyond_index_31_are_bound_by_index() {
+        let fields = (0..64).map(|_| quote!(T));
+
+        let item = quote! {
+            struct Test<T>(#(#fields),*);
+        };
+
+        let output = try_derive(item, Derivable::Standard).unwrap().to_string();
+
+        assert!(output.contains("63 : field_63"));
+    }
+
+    #[cfg(not(feature = "nightly"))]
+    #[test]
+    fn opaque_via_arg_is_rejected_without_nightly_feature() {
+        let item = quote! {
+            struct Test<T>(#[funcmap(opaque_via = "map")] T);
+        };
+
+        let err = try_derive(item, Derivable::Standard).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "expected one of these arguments: `deref`, `rebuild`"
+        );
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn opaque_via_arg_is_accepted_with_nightly_feature() {
+        let item = quote! {
+            struct Test<T>(#[funcmap(opaque_via = "map")] T);
+        };
+
+        let output = try_derive(item, Derivable::Standard).unwrap().to_string();
+
+        assert!(output.contains("map ("));
+    }
+}
@@ -0,0 +1,500 @@
+//! The derive logic for `FamilyMap`
+
+use crate::derivable::Derivable;
+use crate::derive::{impl_attrs, subs_type_in_bounds, type_param_checks_fn, AttrOpts};
+use crate::ident::{
+    FAMILY_ASSOC_TYPE_IDENT, FAMILY_MAP_FN_IDENT, FAMILY_MAP_TRAIT_IDENT, FAMILY_TRAIT_IDENT,
+    NAT_TRANS_FN_IDENT, NAT_TRANS_TRAIT_IDENT, OUTPUT_TYPE_IDENT,
+};
+use crate::input::{parse_derive_input, FuncMapInput, Structish};
+use crate::map::{Mapping, TypeParamCheck};
+use crate::predicates::UniquePredicates;
+use crate::result::{self, Error, ResultExt};
+use crate::syn_ext::{
+    DependencyOnType, IntoGenericArgument, IntoType, SubsType, WithoutAttrs, WithoutDefault,
+    WithoutMaybeBounds,
+};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::fold::{self, Fold};
+use syn::{
+    parse_quote, GenericArgument, GenericParam, Path, PathArguments, QSelf, Type, TypeParam,
+    TypePath, WherePredicate,
+};
+
+/// Generates an implementation of `FamilyMap` for a given item
+pub(crate) fn derive(item: TokenStream) -> TokenStream {
+    match try_derive(item) {
+        Ok(output) => output,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+/// Tries to generate an implementation of `FamilyMap` for a given item
+///
+/// The item must have exactly one mapped type parameter `F`, the family. Within
+/// the type of every field, projections `F::Of<X>` (or `<F as Family>::Of<X>`)
+/// are replaced with a placeholder type parameter, which is then mapped using
+/// the same logic as for deriving `FuncMap`, transforming each projection via
+/// the natural transformation. Fields depending on `F` in any other way are
+/// mapped via their own implementations of `FamilyMap`.
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed
+/// - `item` is not a valid input for deriving `FuncMap`
+/// - `item` does not have exactly one mapped type parameter
+/// - any of the fields of `item` has an unsupported type
+pub(crate) fn try_derive(item: TokenStream) -> Result<TokenStream, Error> {
+    let input = parse_derive_input(item)?;
+    let input: FuncMapInput = input.try_into()?;
+    let mut ident_collector = input.meta.ident_collector;
+    let mut result_builder = result::Builder::new();
+
+    let [family] = input.mapped_type_params.as_slice() else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "expected exactly one type parameter to be mapped for deriving `FamilyMap`",
+        )
+        .into());
+    };
+
+    let src_family_ident = ident_collector.reserve_uppercase_letter('A', Span::mixed_site());
+    let dst_family_ident = ident_collector.reserve_uppercase_letter('B', Span::mixed_site());
+    let nat_trans_type_ident = ident_collector.reserve_uppercase_letter('N', Span::mixed_site());
+    let placeholder_ident = ident_collector.reserve_uppercase_letter('T', Span::mixed_site());
+    let src_type_ident = ident_collector.reserve_uppercase_letter('P', Span::mixed_site());
+    let dst_type_ident = ident_collector.reserve_uppercase_letter('Q', Span::mixed_site());
+    let tmp_type_ident = ident_collector.reserve_uppercase_letter('X', Span::mixed_site());
+    let fn_var_ident = Ident::new("f", Span::mixed_site());
+    let nat_trans_var_ident = Ident::new("n", Span::mixed_site());
+
+    let crate_path = &input.meta.crate_path;
+    let inline = &input.meta.inline;
+    let ident = &input.ident;
+    let all_params = &input.generics.params;
+    let where_clause = &input.generics.where_clause;
+    let family_ident = &family.type_param.ident;
+
+    let attr_opts = AttrOpts {
+        allow: &input.meta.allow,
+        cfg: &input.meta.cfg,
+    };
+    let attrs = impl_attrs(attr_opts);
+
+    let mut impl_params = Vec::with_capacity(all_params.len() + 1);
+
+    for (param_idx, param) in all_params.iter().enumerate() {
+        if param_idx == family.param_idx {
+            for type_ident in [&src_family_ident, &dst_family_ident] {
+                impl_params.push(GenericParam::Type(TypeParam {
+                    bounds: subs_type_in_bounds(
+                        &family.type_param.bounds,
+                        family_ident,
+                        &[type_ident],
+                    )?
+                    .without_maybe_bounds(),
+                    ..type_ident.clone().into()
+                }));
+            }
+        } else {
+            impl_params.push(match param {
+                GenericParam::Type(type_param) => GenericParam::Type(TypeParam {
+                    bounds: subs_type_in_bounds(
+                        &type_param.bounds,
+                        family_ident,
+                        &[&src_family_ident, &dst_family_ident],
+                    )?,
+                    ..type_param.ident.clone().into()
+                }),
+                param => param.clone().without_attrs().without_default(),
+            });
+        }
+    }
+
+    let args = |type_ident: &Ident| -> Vec<GenericArgument> {
+        all_params
+            .iter()
+            .enumerate()
+            .map(|(param_idx, param)| {
+                if param_idx == family.param_idx {
+                    GenericArgument::Type(type_ident.clone().into_type())
+                } else {
+                    param.clone().into_generic_argument()
+                }
+            })
+            .collect()
+    };
+
+    let src_args = args(&src_family_ident);
+    let dst_args = args(&dst_family_ident);
+
+    let mut unique_predicates = UniquePredicates::new();
+
+    for type_ident in [&src_family_ident, &dst_family_ident] {
+        unique_predicates.add(parse_quote!(#type_ident: #crate_path::#FAMILY_TRAIT_IDENT))?;
+    }
+
+    for predicate in where_clause
+        .iter()
+        .flat_map(|clause| clause.predicates.iter())
+    {
+        let predicate = match predicate.clone().without_attrs() {
+            WherePredicate::Type(predicate_type)
+                if predicate_type.bounded_ty == family_ident.clone().into_type() =>
+            {
+                WherePredicate::Type(predicate_type.without_maybe_bounds())
+            }
+            predicate => predicate,
+        };
+
+        for type_ident in [&src_family_ident, &dst_family_ident] {
+            unique_predicates
+                .add(predicate.clone().subs_type(family_ident, type_ident))
+                .add_err_to(&mut result_builder);
+        }
+    }
+
+    // within extra bounds, `A` and `B` refer to the source and destination
+    // families, see the derive logic for `FuncMap`
+    for predicate in &input.meta.extra_bounds {
+        let predicate = predicate
+            .clone()
+            .subs_type(&Ident::new("A", Span::call_site()), &tmp_type_ident)
+            .subs_type(&Ident::new("B", Span::call_site()), &dst_family_ident)
+            .subs_type(&tmp_type_ident, &src_family_ident);
+
+        unique_predicates
+            .add(predicate)
+            .add_err_to(&mut result_builder);
+    }
+
+    let placeholder_param: TypeParam = placeholder_ident.clone().into();
+
+    let mapping = Mapping {
+        type_param: &placeholder_param,
+        generics: &input.generics,
+        src_type_ident: &src_type_ident,
+        dst_type_ident: &dst_type_ident,
+        fn_ident: &fn_var_ident,
+        crate_path,
+        dyn_as: input.meta.dyn_as.as_ref(),
+        derivable: Derivable::Standard,
+    };
+
+    let projection = |family_ident: &Ident, arg: &Type| -> Type {
+        parse_quote! {
+            <#family_ident as #crate_path::#FAMILY_TRAIT_IDENT>::#FAMILY_ASSOC_TYPE_IDENT<#arg>
+        }
+    };
+
+    let mut uses_nat_trans = false;
+    let mut type_param_checks = Vec::new();
+    let mut arms = Vec::new();
+
+    for Structish {
+        variant_ident,
+        fields,
+    } in &input.variants
+    {
+        let mut patterns = Vec::new();
+        let mut mappings = Vec::new();
+
+        for (field_idx, field) in fields.iter().enumerate() {
+            let (member, field_ident) = field.binding(field_idx);
+
+            let mut folder = ProjectionFolder::new(family_ident, &placeholder_ident);
+            let ty = folder.fold_type(field.ty.clone());
+
+            let result = match folder.args.as_slice() {
+                [] if ty.dependency_on_type(family_ident).is_some() => {
+                    let src_type = ty.clone().subs_type(family_ident, &src_family_ident);
+                    let dst_type = ty.subs_type(family_ident, &dst_family_ident);
+
+                    uses_nat_trans = true;
+
+                    unique_predicates
+                        .add(parse_quote! {
+                            #src_type: #crate_path::#FAMILY_MAP_TRAIT_IDENT<
+                                #src_family_ident,
+                                #dst_family_ident,
+                                #OUTPUT_TYPE_IDENT = #dst_type
+                            >
+                        })
+                        .map(|()| {
+                            quote! {
+                                #crate_path::#FAMILY_MAP_TRAIT_IDENT::<
+                                    #src_family_ident,
+                                    #dst_family_ident
+                                >::#FAMILY_MAP_FN_IDENT(#field_ident, &mut #nat_trans_var_ident)
+                            }
+                        })
+                }
+
+                [] => Ok(quote!(#field_ident)),
+
+                [_] if ty.dependency_on_type(family_ident).is_some() => Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "family type parameter must not occur both within and outside of projections",
+                )
+                .into()),
+
+                [arg] if arg.dependency_on_type(family_ident).is_some() => Err(syn::Error::new_spanned(
+                    arg,
+                    "nested projections of family type parameter are not supported",
+                )
+                .into()),
+
+                [arg] => {
+                    let src_proj = projection(&src_family_ident, arg);
+                    let dst_proj = projection(&dst_family_ident, arg);
+
+                    let transform = quote! {
+                        <#nat_trans_type_ident as #crate_path::#NAT_TRANS_TRAIT_IDENT<
+                            #src_family_ident,
+                            #dst_family_ident
+                        >>::#NAT_TRANS_FN_IDENT::<#arg>
+                    };
+
+                    uses_nat_trans = true;
+
+                    if ty == placeholder_ident.clone().into_type() {
+                        Ok(quote!(#transform(&mut #nat_trans_var_ident, #field_ident)))
+                    } else {
+                        mapping.map(&field_ident, &ty).map(|mapped| {
+                            let subs = |ty: Type| {
+                                SubsTypeWithTypeFolder::new(&dst_type_ident, &dst_proj).fold_type(
+                                    SubsTypeWithTypeFolder::new(&src_type_ident, &src_proj)
+                                        .fold_type(ty),
+                                )
+                            };
+
+                            for predicate in mapped.predicates.into_iter() {
+                                let predicate =
+                                    SubsTypeWithTypeFolder::new(&dst_type_ident, &dst_proj)
+                                        .fold_where_predicate(
+                                            SubsTypeWithTypeFolder::new(
+                                                &src_type_ident,
+                                                &src_proj,
+                                            )
+                                            .fold_where_predicate(predicate),
+                                        );
+
+                                unique_predicates
+                                    .add(predicate)
+                                    .add_err_to(&mut result_builder);
+                            }
+
+                            type_param_checks.extend(mapped.type_param_checks.into_iter().map(
+                                |TypeParamCheck {
+                                     ty,
+                                     marker_idx,
+                                     param,
+                                 }| TypeParamCheck {
+                                    ty: subs(ty),
+                                    marker_idx,
+                                    param: subs(param),
+                                },
+                            ));
+
+                            let tokens = mapped.tokens;
+
+                            quote!({
+                                let mut #fn_var_ident = |value| #transform(&mut #nat_trans_var_ident, value);
+                                #tokens
+                            })
+                        })
+                    }
+                }
+
+                [..] => Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "mapping over multiple distinct projections of family type parameter within a single field is not supported",
+                )
+                .into()),
+            };
+
+            let Some(tokens) = result
+                .with_context(field.context(field_idx, &input.ident, variant_ident.as_ref()))
+                .add_err_to(&mut result_builder)
+            else {
+                continue;
+            };
+
+            patterns.push(quote!(#member: #field_ident));
+            mappings.push(quote!(#member: #tokens));
+        }
+
+        let (pat_path, output_path) = match variant_ident {
+            Some(ident) => (
+                quote!(Self::#ident),
+                quote!(Self::#OUTPUT_TYPE_IDENT::#ident),
+            ),
+            None => (quote!(Self), quote!(Self::#OUTPUT_TYPE_IDENT)),
+        };
+
+        arms.push(quote! {
+            #pat_path { #(#patterns,)* } => #output_path { #(#mappings,)* }
+        });
+    }
+
+    let impl_where_clause = unique_predicates.into_where_clause();
+
+    let type_param_checks = type_param_checks_fn(
+        type_param_checks,
+        Derivable::Standard,
+        crate_path,
+        &impl_params,
+        &parse_quote!(#ident<#(#src_args),*>),
+        &impl_where_clause,
+        attr_opts,
+    );
+
+    let nat_trans_pat = if uses_nat_trans {
+        quote!(mut #nat_trans_var_ident)
+    } else {
+        quote!(_)
+    };
+
+    result_builder.err_or(quote! {
+        #attrs
+        impl<#(#impl_params),*>
+            #crate_path::#FAMILY_MAP_TRAIT_IDENT<#src_family_ident, #dst_family_ident>
+            for #ident<#(#src_args),*>
+            #impl_where_clause
+        {
+            type #OUTPUT_TYPE_IDENT = #ident<#(#dst_args),*>;
+
+            #inline
+            fn #FAMILY_MAP_FN_IDENT<#nat_trans_type_ident>(
+                self,
+                #nat_trans_pat: #nat_trans_type_ident
+            ) -> Self::#OUTPUT_TYPE_IDENT
+            where
+                #nat_trans_type_ident: #crate_path::#NAT_TRANS_TRAIT_IDENT<
+                    #src_family_ident,
+                    #dst_family_ident
+                >
+            {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+
+        #type_param_checks
+    })
+}
+
+/// Returns the type argument `X` if `ty` is a projection `F::Of<X>` or
+/// `<F as Family>::Of<X>` of the family type parameter `F`
+fn projection_arg<'ast>(ty: &'ast Type, family_ident: &Ident) -> Option<&'ast Type> {
+    let Type::Path(TypePath { qself, path }) = ty else {
+        return None;
+    };
+
+    let segment = match (qself, path.segments.len()) {
+        // `F::Of<X>`
+        (None, 2)
+            if path.leading_colon.is_none()
+                && path.segments[0].ident == *family_ident
+                && path.segments[0].arguments.is_none() =>
+        {
+            &path.segments[1]
+        }
+
+        // `<F as Family>::Of<X>`
+        (Some(QSelf { ty, position, .. }), len)
+            if **ty == family_ident.clone().into_type() && *position + 1 == len =>
+        {
+            &path.segments[len - 1]
+        }
+
+        _ => return None,
+    };
+
+    if segment.ident != FAMILY_ASSOC_TYPE_IDENT {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(angle_bracketed) if angle_bracketed.args.len() == 1 => {
+            match &angle_bracketed.args[0] {
+                GenericArgument::Type(arg) => Some(arg),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Type implementing [`Fold`] for replacing projections of a family type
+/// parameter with a placeholder type, collecting their type arguments
+struct ProjectionFolder<'a> {
+    family_ident: &'a Ident,
+    placeholder_ident: &'a Ident,
+    args: Vec<Type>,
+}
+
+impl<'a> ProjectionFolder<'a> {
+    fn new(family_ident: &'a Ident, placeholder_ident: &'a Ident) -> Self {
+        Self {
+            family_ident,
+            placeholder_ident,
+            args: Vec::new(),
+        }
+    }
+}
+
+impl Fold for ProjectionFolder<'_> {
+    fn fold_type(&mut self, ty: Type) -> Type {
+        match projection_arg(&ty, self.family_ident) {
+            Some(arg) => {
+                if !self.args.contains(arg) {
+                    self.args.push(arg.clone());
+                }
+
+                self.placeholder_ident.clone().into_type()
+            }
+            None => fold::fold_type(self, ty),
+        }
+    }
+}
+
+/// Type implementing [`Fold`] for substituting a type parameter with an
+/// arbitrary type
+struct SubsTypeWithTypeFolder<'a> {
+    type_ident: &'a Ident,
+    subs_type: &'a Type,
+}
+
+impl<'a> SubsTypeWithTypeFolder<'a> {
+    fn new(type_ident: &'a Ident, subs_type: &'a Type) -> Self {
+        Self {
+            type_ident,
+            subs_type,
+        }
+    }
+}
+
+impl Fold for SubsTypeWithTypeFolder<'_> {
+    fn fold_type(&mut self, ty: Type) -> Type {
+        match &ty {
+            Type::Path(TypePath {
+                qself: None,
+                path:
+                    Path {
+                        leading_colon: None,
+                        segments,
+                    },
+            }) if segments.len() == 1
+                && segments[0].ident == *self.type_ident
+                && segments[0].arguments.is_none() =>
+            {
+                self.subs_type.clone()
+            }
+            _ => fold::fold_type(self, ty),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! The derive logic for `FuncMapFields`
+
+use crate::derive::{binds_assoc_type_to, impl_attrs, AttrOpts};
+use crate::input::{parse_derive_input, FuncMapInput};
+use crate::predicates::UniquePredicates;
+use crate::result::{self, Error, ResultExt};
+use crate::syn_ext::{
+    DependencyOnType, IntoGenericArgument, IntoType, SubsType, WithoutAttrs, WithoutMaybeBounds,
+};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DataEnum, GenericArgument, GenericParam, WherePredicate};
+
+/// Generates an inherent `func_map_fields` method for a given item
+pub(crate) fn derive(item: TokenStream) -> TokenStream {
+    match try_derive(item) {
+        Ok(output) => output,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+/// Tries to generate an inherent `func_map_fields` method for a given item
+///
+/// The method takes a closure receiving all fields of the struct as a tuple
+/// and returning a tuple of the mapped fields, where every mapped type
+/// parameter `T` is replaced with a destination type parameter named `T2`
+/// unless this name is already in use.
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed
+/// - `item` is not a struct
+/// - `item` is not a valid input for deriving `FuncMap`
+pub(crate) fn try_derive(item: TokenStream) -> Result<TokenStream, Error> {
+    let input = parse_derive_input(item)?;
+    let vis = input.vis.clone();
+
+    let mut result_builder = result::Builder::new();
+
+    if let Data::Enum(DataEnum { enum_token, .. }) = &input.data {
+        result_builder.add_err(syn::Error::new_spanned(
+            enum_token,
+            "expected a struct, found an enum",
+        ));
+    }
+
+    let input: FuncMapInput = input.try_into().with_error_from(result_builder)?;
+    let mut ident_collector = input.meta.ident_collector;
+    let mut result_builder = result::Builder::new();
+
+    let inline = &input.meta.inline;
+    let ident = &input.ident;
+    let all_params = &input.generics.params;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let attrs = impl_attrs(AttrOpts {
+        allow: &input.meta.allow,
+        cfg: &input.meta.cfg,
+    });
+
+    // see the derive logic for `FuncMap` regarding bounds binding associated
+    // types to the type parameter
+    let subs: Vec<(&Ident, Ident)> = input
+        .mapped_type_params
+        .iter()
+        .map(|mapped_type_param| &mapped_type_param.type_param.ident)
+        .filter(|type_param_ident| !binds_assoc_type_to(&input.generics, type_param_ident))
+        .map(|type_param_ident| {
+            let dst_type_ident = ident_collector
+                .reserve(&format_ident!("{}2", type_param_ident), Span::mixed_site())
+                .unwrap_or_else(|| {
+                    ident_collector.reserve_uppercase_letter('B', Span::mixed_site())
+                });
+
+            (type_param_ident, dst_type_ident)
+        })
+        .collect();
+
+    let dst_args: Vec<GenericArgument> = all_params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(type_param) => subs
+                .iter()
+                .find(|(type_param_ident, _)| **type_param_ident == type_param.ident)
+                .map_or_else(
+                    || param.clone().into_generic_argument(),
+                    |(_, dst_type_ident)| GenericArgument::Type(dst_type_ident.clone().into_type()),
+                ),
+            _ => param.clone().into_generic_argument(),
+        })
+        .collect();
+
+    // the destination types need to satisfy the bounds of the original type
+    // for the output type to be well-formed
+    let mut unique_predicates = UniquePredicates::new();
+
+    let bound_predicates = all_params.iter().filter_map(|param| match param {
+        GenericParam::Type(type_param) if !type_param.bounds.is_empty() => {
+            let type_param_ident = &type_param.ident;
+            let bounds = &type_param.bounds;
+            Some(parse_quote!(#type_param_ident: #bounds))
+        }
+        _ => None,
+    });
+
+    let where_predicates = where_clause
+        .iter()
+        .flat_map(|clause| clause.predicates.iter())
+        .cloned();
+
+    for predicate in bound_predicates.chain(where_predicates) {
+        let predicate = match predicate.without_attrs() {
+            WherePredicate::Type(predicate_type)
+                if subs.iter().any(|(type_param_ident, _)| {
+                    predicate_type.bounded_ty == (*type_param_ident).clone().into_type()
+                }) =>
+            {
+                WherePredicate::Type(predicate_type.without_maybe_bounds())
+            }
+            predicate => predicate,
+        };
+
+        let depends_on_mapped_type_param = subs
+            .iter()
+            .any(|(type_param_ident, _)| predicate.dependency_on_type(type_param_ident).is_some());
+
+        if depends_on_mapped_type_param {
+            unique_predicates
+                .add(subs_types(predicate, &subs))
+                .add_err_to(&mut result_builder);
+        }
+    }
+
+    let method_where_clause = unique_predicates.into_where_clause();
+    let dst_params = subs.iter().map(|(_, dst_type_ident)| dst_type_ident);
+
+    let fields = input
+        .variants
+        .iter()
+        .flat_map(|variant| &variant.fields)
+        .enumerate();
+
+    let mut members = Vec::new();
+    let mut bindings = Vec::new();
+    let mut src_types = Vec::new();
+    let mut dst_types = Vec::new();
+
+    for (field_idx, field) in fields {
+        let (member, binding) = field.binding(field_idx);
+        members.push(member);
+        bindings.push(binding);
+        src_types.push(&field.ty);
+        dst_types.push(subs_types(field.ty.clone(), &subs));
+    }
+
+    result_builder.err_or(quote! {
+        #attrs
+        impl #impl_generics #ident #type_generics #where_clause {
+            /// Maps all fields of `self` at once using the given closure, which
+            /// receives the fields as a tuple and returns a tuple of the mapped
+            /// fields
+            #inline
+            #[must_use]
+            #vis fn func_map_fields<#(#dst_params),*>(
+                self,
+                f: impl ::core::ops::FnOnce((#(#src_types,)*)) -> (#(#dst_types,)*)
+            ) -> #ident<#(#dst_args),*>
+            #method_where_clause
+            {
+                let Self { #(#members: #bindings),* } = self;
+                let (#(#bindings,)*) = f((#(#bindings,)*));
+                #ident { #(#members: #bindings),* }
+            }
+        }
+    })
+}
+
+/// Substitutes every type parameter within `value` with its destination type
+/// parameter, given as pairs of identifiers in `subs`
+fn subs_types<T>(value: T, subs: &[(&Ident, Ident)]) -> T
+where
+    T: SubsType,
+{
+    subs.iter()
+        .fold(value, |value, (type_param_ident, dst_type_ident)| {
+            value.subs_type(type_param_ident, dst_type_ident)
+        })
+}
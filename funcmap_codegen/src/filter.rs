@@ -0,0 +1,263 @@
+//! The derive logic for `FuncFilterMap`
+
+use crate::derive::{impl_attrs, subs_type_in_bounds, AttrOpts};
+use crate::ident::{FILTER_MAP_FN_IDENT, FILTER_MAP_TRAIT_IDENT, OUTPUT_TYPE_IDENT};
+use crate::input::{parse_derive_input, FuncMapInput, Structish};
+use crate::predicates::UniquePredicates;
+use crate::result::{self, Error, ResultExt};
+use crate::syn_ext::{
+    DependencyOnType, IntoGenericArgument, IntoType, SubsType, WithoutAttrs, WithoutDefault,
+    WithoutMaybeBounds,
+};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, GenericArgument, GenericParam, TypeParam, WherePredicate};
+
+/// Generates an implementation of `FuncFilterMap` for a given item
+pub(crate) fn derive(item: TokenStream) -> TokenStream {
+    match try_derive(item) {
+        Ok(output) => output,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+/// Tries to generate an implementation of `FuncFilterMap` for a given item
+///
+/// The item must have exactly one mapped type parameter `T`. Every field whose
+/// type depends on `T` is mapped via its own implementation of
+/// `FuncFilterMap`, so it cannot be of type `T` itself, as a single value
+/// cannot be dropped. All other fields are moved.
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed
+/// - `item` is not a valid input for deriving `FuncMap`
+/// - `item` does not have exactly one mapped type parameter
+/// - any of the fields of `item` has the mapped type parameter as its type
+pub(crate) fn try_derive(item: TokenStream) -> Result<TokenStream, Error> {
+    let input = parse_derive_input(item)?;
+    let input: FuncMapInput = input.try_into()?;
+    let mut ident_collector = input.meta.ident_collector;
+    let mut result_builder = result::Builder::new();
+
+    let [mapped_type_param] = input.mapped_type_params.as_slice() else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "expected exactly one type parameter to be mapped for deriving `FuncFilterMap`",
+        )
+        .into());
+    };
+
+    let src_type_ident = ident_collector.reserve_uppercase_letter('A', Span::mixed_site());
+    let dst_type_ident = ident_collector.reserve_uppercase_letter('B', Span::mixed_site());
+    let fn_type_ident = ident_collector.reserve_uppercase_letter('F', Span::mixed_site());
+    let tmp_type_ident = ident_collector.reserve_uppercase_letter('X', Span::mixed_site());
+    let fn_var_ident = Ident::new("f", Span::mixed_site());
+
+    let crate_path = &input.meta.crate_path;
+    let inline = &input.meta.inline;
+    let ident = &input.ident;
+    let all_params = &input.generics.params;
+    let where_clause = &input.generics.where_clause;
+    let type_param_ident = &mapped_type_param.type_param.ident;
+
+    let attr_opts = AttrOpts {
+        allow: &input.meta.allow,
+        cfg: &input.meta.cfg,
+    };
+    let attrs = impl_attrs(attr_opts);
+
+    let mut impl_params = Vec::with_capacity(all_params.len() + 1);
+
+    for (param_idx, param) in all_params.iter().enumerate() {
+        if param_idx == mapped_type_param.param_idx {
+            for type_ident in [&src_type_ident, &dst_type_ident] {
+                impl_params.push(GenericParam::Type(TypeParam {
+                    bounds: subs_type_in_bounds(
+                        &mapped_type_param.type_param.bounds,
+                        type_param_ident,
+                        &[type_ident],
+                    )?
+                    .without_maybe_bounds(),
+                    ..type_ident.clone().into()
+                }));
+            }
+        } else {
+            impl_params.push(match param {
+                GenericParam::Type(type_param) => GenericParam::Type(TypeParam {
+                    bounds: subs_type_in_bounds(
+                        &type_param.bounds,
+                        type_param_ident,
+                        &[&src_type_ident, &dst_type_ident],
+                    )?,
+                    ..type_param.ident.clone().into()
+                }),
+                param => param.clone().without_attrs().without_default(),
+            });
+        }
+    }
+
+    let args = |type_ident: &Ident| -> Vec<GenericArgument> {
+        all_params
+            .iter()
+            .enumerate()
+            .map(|(param_idx, param)| {
+                if param_idx == mapped_type_param.param_idx {
+                    GenericArgument::Type(type_ident.clone().into_type())
+                } else {
+                    param.clone().into_generic_argument()
+                }
+            })
+            .collect()
+    };
+
+    let src_args = args(&src_type_ident);
+    let dst_args = args(&dst_type_ident);
+
+    let mut unique_predicates = UniquePredicates::new();
+
+    for predicate in where_clause
+        .iter()
+        .flat_map(|clause| clause.predicates.iter())
+    {
+        let predicate = match predicate.clone().without_attrs() {
+            WherePredicate::Type(predicate_type)
+                if predicate_type.bounded_ty == type_param_ident.clone().into_type() =>
+            {
+                WherePredicate::Type(predicate_type.without_maybe_bounds())
+            }
+            predicate => predicate,
+        };
+
+        for type_ident in [&src_type_ident, &dst_type_ident] {
+            unique_predicates
+                .add(predicate.clone().subs_type(type_param_ident, type_ident))
+                .add_err_to(&mut result_builder);
+        }
+    }
+
+    // within extra bounds, `A` and `B` refer to the source and destination
+    // types, see the derive logic for `FuncMap`
+    for predicate in &input.meta.extra_bounds {
+        let predicate = predicate
+            .clone()
+            .subs_type(&Ident::new("A", Span::call_site()), &tmp_type_ident)
+            .subs_type(&Ident::new("B", Span::call_site()), &dst_type_ident)
+            .subs_type(&tmp_type_ident, &src_type_ident);
+
+        unique_predicates
+            .add(predicate)
+            .add_err_to(&mut result_builder);
+    }
+
+    let mut uses_fn = false;
+    let mut arms = Vec::new();
+
+    for Structish {
+        variant_ident,
+        fields,
+    } in &input.variants
+    {
+        let mut patterns = Vec::new();
+        let mut mappings = Vec::new();
+
+        for (field_idx, field) in fields.iter().enumerate() {
+            let (member, field_ident) = field.binding(field_idx);
+
+            let result = if field.ty.dependency_on_type(type_param_ident).is_none() {
+                Ok(quote!(#field_ident))
+            } else if field.ty == type_param_ident.clone().into_type() {
+                Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "expected a collection or `Option` rather than the type parameter itself, as a single value cannot be dropped",
+                )
+                .into())
+            } else {
+                let src_type = field
+                    .ty
+                    .clone()
+                    .subs_type(type_param_ident, &src_type_ident);
+                let dst_type = field
+                    .ty
+                    .clone()
+                    .subs_type(type_param_ident, &dst_type_ident);
+
+                uses_fn = true;
+
+                unique_predicates
+                    .add(parse_quote! {
+                        #src_type: #crate_path::#FILTER_MAP_TRAIT_IDENT<
+                            #src_type_ident,
+                            #dst_type_ident,
+                            #OUTPUT_TYPE_IDENT = #dst_type
+                        >
+                    })
+                    .map(|()| {
+                        quote! {
+                            #crate_path::#FILTER_MAP_TRAIT_IDENT::<
+                                #src_type_ident,
+                                #dst_type_ident
+                            >::#FILTER_MAP_FN_IDENT(#field_ident, &mut #fn_var_ident)
+                        }
+                    })
+            };
+
+            let Some(tokens) = result
+                .with_context(field.context(field_idx, &input.ident, variant_ident.as_ref()))
+                .add_err_to(&mut result_builder)
+            else {
+                continue;
+            };
+
+            patterns.push(quote!(#member: #field_ident));
+            mappings.push(quote!(#member: #tokens));
+        }
+
+        let (pat_path, output_path) = match variant_ident {
+            Some(ident) => (
+                quote!(Self::#ident),
+                quote!(Self::#OUTPUT_TYPE_IDENT::#ident),
+            ),
+            None => (quote!(Self), quote!(Self::#OUTPUT_TYPE_IDENT)),
+        };
+
+        arms.push(quote! {
+            #pat_path { #(#patterns,)* } => #output_path { #(#mappings,)* }
+        });
+    }
+
+    let impl_where_clause = unique_predicates.into_where_clause();
+
+    let fn_pat = if uses_fn {
+        quote!(mut #fn_var_ident)
+    } else {
+        quote!(_)
+    };
+
+    result_builder.err_or(quote! {
+        #attrs
+        impl<#(#impl_params),*>
+            #crate_path::#FILTER_MAP_TRAIT_IDENT<#src_type_ident, #dst_type_ident>
+            for #ident<#(#src_args),*>
+            #impl_where_clause
+        {
+            type #OUTPUT_TYPE_IDENT = #ident<#(#dst_args),*>;
+
+            #inline
+            fn #FILTER_MAP_FN_IDENT<#fn_type_ident>(
+                self,
+                #fn_pat: #fn_type_ident
+            ) -> Self::#OUTPUT_TYPE_IDENT
+            where
+                #fn_type_ident: ::core::ops::FnMut(
+                    #src_type_ident
+                ) -> ::core::option::Option<#dst_type_ident>
+            {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,94 @@
+//! Static identifiers referring to items from the `funcmap` crate
+
+use std::fmt::{self, Display, Formatter};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::ToTokens;
+use syn::Path;
+
+pub(crate) const CRATE_IDENT: StaticIdent = StaticIdent("funcmap");
+pub(crate) const TRAIT_IDENT: StaticIdent = StaticIdent("FuncMap");
+pub(crate) const FALLIBLE_TRAIT_IDENT: StaticIdent = StaticIdent("TryFuncMap");
+pub(crate) const NO_DROP_MARKER_TRAIT_IDENT: StaticIdent =
+    StaticIdent("FuncMap_cannot_be_derived_for_types_implementing_Drop");
+pub(crate) const NO_DROP_MARKER_FALLIBLE_TRAIT_IDENT: StaticIdent =
+    StaticIdent("TryFuncMap_cannot_be_derived_for_types_implementing_Drop");
+pub(crate) const TYPE_PARAM_TRAIT_IDENT: StaticIdent = StaticIdent("FuncMapTypeParam");
+pub(crate) const TYPE_PARAM_FALLIBLE_TRAIT_IDENT: StaticIdent = StaticIdent("TryFuncMapTypeParam");
+pub(crate) const NAMED_PARAM_TRAIT_IDENT: StaticIdent = StaticIdent("FuncMapNamedParam");
+pub(crate) const NAMED_PARAM_FALLIBLE_TRAIT_IDENT: StaticIdent =
+    StaticIdent("TryFuncMapNamedParam");
+pub(crate) const PARAMS_TRAIT_IDENT: StaticIdent = StaticIdent("FuncMapParams");
+pub(crate) const PARAMS_FALLIBLE_TRAIT_IDENT: StaticIdent = StaticIdent("TryFuncMapParams");
+pub(crate) const VARIANT_TRAIT_IDENT: StaticIdent = StaticIdent("FuncMapVariant");
+pub(crate) const VARIANT_FALLIBLE_TRAIT_IDENT: StaticIdent = StaticIdent("TryFuncMapVariant");
+pub(crate) const PARAM_NAME_HASH_FN_IDENT: StaticIdent = StaticIdent("param_name_hash");
+pub(crate) const TYPE_PARAM_CHECK_TRAIT_IDENT: StaticIdent = StaticIdent("FuncMapTypeParamCheck");
+pub(crate) const TYPE_PARAM_CHECK_FALLIBLE_TRAIT_IDENT: StaticIdent =
+    StaticIdent("TryFuncMapTypeParamCheck");
+pub(crate) const TYPE_PARAM_CHECK_FALLBACK_TRAIT_IDENT: StaticIdent =
+    StaticIdent("TypeParamCheckFallback");
+pub(crate) const TYPE_PARAM_CHECK_TYPE_IDENT: StaticIdent = StaticIdent("TypeParamCheck");
+pub(crate) const ENTRIES_TRAIT_IDENT: StaticIdent = StaticIdent("FuncMapEntries");
+pub(crate) const ENTRIES_FALLIBLE_TRAIT_IDENT: StaticIdent = StaticIdent("TryFuncMapEntries");
+pub(crate) const ENTRIES_FN_IDENT: StaticIdent = StaticIdent("func_map_entries");
+pub(crate) const ENTRIES_FALLIBLE_FN_IDENT: StaticIdent = StaticIdent("try_func_map_entries");
+pub(crate) const JOIN_TRAIT_IDENT: StaticIdent = StaticIdent("JoinParams");
+pub(crate) const JOIN_FN_IDENT: StaticIdent = StaticIdent("join_params");
+pub(crate) const FAMILY_TRAIT_IDENT: StaticIdent = StaticIdent("Family");
+pub(crate) const FAMILY_ASSOC_TYPE_IDENT: StaticIdent = StaticIdent("Of");
+pub(crate) const FAMILY_MAP_TRAIT_IDENT: StaticIdent = StaticIdent("FamilyMap");
+pub(crate) const FAMILY_MAP_FN_IDENT: StaticIdent = StaticIdent("family_map");
+pub(crate) const COUNT_TRAIT_IDENT: StaticIdent = StaticIdent("FuncCount");
+pub(crate) const COUNT_FN_IDENT: StaticIdent = StaticIdent("func_count");
+pub(crate) const COUNT_LEAVES_FN_IDENT: StaticIdent = StaticIdent("count_leaves");
+pub(crate) const REF_TRAIT_IDENT: StaticIdent = StaticIdent("RefFuncMap");
+pub(crate) const REF_FN_IDENT: StaticIdent = StaticIdent("ref_func_map");
+pub(crate) const FILTER_MAP_TRAIT_IDENT: StaticIdent = StaticIdent("FuncFilterMap");
+pub(crate) const FILTER_MAP_FN_IDENT: StaticIdent = StaticIdent("func_filter_map");
+pub(crate) const NAT_TRANS_TRAIT_IDENT: StaticIdent = StaticIdent("NaturalTransformation");
+pub(crate) const NAT_TRANS_FN_IDENT: StaticIdent = StaticIdent("transform");
+pub(crate) const FN_IDENT: StaticIdent = StaticIdent("func_map");
+pub(crate) const FALLIBLE_FN_IDENT: StaticIdent = StaticIdent("try_func_map");
+pub(crate) const OUTPUT_TYPE_IDENT: StaticIdent = StaticIdent("Output");
+pub(crate) const MARKER_TYPE_IDENT: StaticIdent = StaticIdent("TypeParam");
+pub(crate) const ATTR_IDENT: StaticIdent = StaticIdent("funcmap");
+
+/// A static string slice to be used as an identifier
+///
+/// This can be turned into a [`TokenStream`] through its [`ToTokens`]
+/// implementation. However, unlike [`TokenStream`] itself, it can be
+/// constructed in a const context.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct StaticIdent(&'static str);
+
+impl StaticIdent {
+    /// Turns this [`StaticIdent`] into an actual [`Ident`]
+    fn to_ident(self) -> Ident {
+        Ident::new(self.0, Span::call_site())
+    }
+}
+
+impl AsRef<str> for StaticIdent {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl Display for StaticIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<StaticIdent> for Path {
+    fn from(static_ident: StaticIdent) -> Self {
+        static_ident.to_ident().into()
+    }
+}
+
+impl ToTokens for StaticIdent {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.to_ident().to_tokens(tokens);
+    }
+}
@@ -6,6 +6,11 @@ use proc_macro2::{Ident, Span};
 use syn::visit::Visit;
 
 /// Collects unique identifiers for use in generated code
+///
+/// The identifiers reserved by an [`IdentCollector`] only depend on the set of
+/// identifiers previously reserved, not on the order in which they were
+/// reserved or on anything outside of the visited syntax tree. Hence the same
+/// input always leads to the same identifiers in the generated code.
 #[derive(Debug, Default)]
 pub(crate) struct IdentCollector {
     idents: HashSet<String>,
@@ -42,8 +47,13 @@ impl IdentCollector {
     /// identifier previously reserved by this method or by visiting an AST.
     ///
     /// `desired_letter` must be an uppercase letter. It will be used as the
-    /// returned identifier if it is still available. Otherwise, a different
-    /// letter or combination of letters will be used.
+    /// returned identifier if it is still available. Otherwise, the first
+    /// available identifier is used among
+    /// 1. the letters from `desired_letter` to `Z` followed by the letters from
+    ///    `A` to `desired_letter`,
+    /// 2. the same letters prefixed with `__FUNCMAP_`,
+    /// 3. the same letters prefixed with `__FUNCMAP_` and suffixed with `2`,
+    ///    `3`, etc.
     pub(crate) fn reserve_uppercase_letter(&mut self, desired_letter: char, span: Span) -> Ident {
         let letter = self.find_uppercase_letter(desired_letter);
         let ident = Ident::new(&letter, span);
@@ -51,6 +61,25 @@ impl IdentCollector {
         ident
     }
 
+    /// Reserves the given [`Ident`] with the given [`Span`]
+    ///
+    /// Returns [`None`] if the identifier is already reserved, either by this
+    /// method, by [`reserve_uppercase_letter`](Self::reserve_uppercase_letter)
+    /// or by visiting an AST.
+    pub(crate) fn reserve(&mut self, ident: &Ident, span: Span) -> Option<Ident> {
+        let name = ident.to_string();
+
+        if self.idents.contains(&name) {
+            None
+        } else {
+            self.idents.insert(name);
+
+            let mut ident = ident.clone();
+            ident.set_span(span);
+            Some(ident)
+        }
+    }
+
     fn find_uppercase_letter(&self, desired_letter: char) -> String {
         debug_assert!(desired_letter.is_alphabetic() && desired_letter.is_uppercase());
 
@@ -172,6 +201,58 @@ mod tests {
         assert_eq!(ident, "__FUNCMAP_U2");
     }
 
+    #[test]
+    fn when_ident_is_available_it_gets_reserved() {
+        let mut collector = IdentCollector::default();
+
+        let ident = collector.reserve(&parse_quote!(Src), Span::call_site());
+
+        assert_eq!(ident.unwrap(), "Src");
+    }
+
+    #[test]
+    fn when_ident_is_already_reserved_it_is_rejected() {
+        let mut collector = IdentCollector::default();
+        collector.reserve_uppercase_letter('A', Span::call_site());
+
+        let ident = collector.reserve(&parse_quote!(A), Span::call_site());
+
+        assert!(ident.is_none());
+    }
+
+    #[test]
+    fn reserved_ident_is_skipped_by_uppercase_letters() {
+        let mut collector = IdentCollector::default();
+        collector.reserve(&parse_quote!(A), Span::call_site());
+
+        let ident = collector.reserve_uppercase_letter('A', Span::call_site());
+
+        assert_eq!(ident, "B");
+    }
+
+    #[test]
+    fn reserved_idents_do_not_depend_on_order_of_visited_idents() {
+        let reserve = |input: syn::DeriveInput| {
+            let mut collector = IdentCollector::new_visiting();
+            collector.visit_derive_input(&input);
+            let mut collector = collector.into_reserved();
+
+            ['A', 'B', 'F', 'E']
+                .map(|letter| collector.reserve_uppercase_letter(letter, Span::call_site()))
+        };
+
+        let idents = reserve(parse_quote! {
+            struct Test<A, B, C, E, F, G>(A, B, C, E, F, G);
+        });
+
+        let idents_reordered = reserve(parse_quote! {
+            struct Test<G, F, E, C, B, A>(G, F, E, C, B, A);
+        });
+
+        assert_eq!(idents, idents_reordered);
+        assert_eq!(idents, ["D", "H", "I", "J"]);
+    }
+
     #[test]
     fn visiting_reserves_visited_idents() {
         let mut collector = IdentCollector::new_visiting();
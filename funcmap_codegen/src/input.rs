@@ -0,0 +1,670 @@
+//! Functionality for preparing the input to `funcmap` derive macros
+
+use crate::{
+    ident::{CRATE_IDENT, TRAIT_IDENT},
+    ident_collector::IdentCollector,
+    opts::{self, FuncMapOpts, Inline, Param},
+    result::{self, Error, IteratorExt, ResultExt},
+    syn_ext::{DependencyOnType, IsTypish, ToNonEmptyTokens},
+};
+
+use std::{collections::HashSet, iter};
+
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{format_ident, ToTokens};
+use syn::{
+    braced,
+    ext::IdentExt,
+    parenthesized,
+    parse::{ParseStream, Parser},
+    token,
+    visit::Visit,
+    Attribute, Data, DataEnum, DataStruct, DataUnion, DeriveInput, Field, GenericParam, Generics,
+    Member, Meta, Path, PredicateType, Token, Type, TypeParam, Variant, Visibility, WhereClause,
+    WherePredicate,
+};
+
+/// Input to a `funcmap` derive macro
+#[derive(Debug)]
+pub(crate) struct FuncMapInput {
+    /// Meta information for deriving mappings
+    pub(crate) meta: FuncMapMeta,
+
+    /// Identifier of type for which to derive mappings
+    pub(crate) ident: Ident,
+
+    /// Generics of type for which to derive mappings
+    pub(crate) generics: Generics,
+
+    /// Type parameters for which to derive mappings
+    pub(crate) mapped_type_params: Vec<MappedTypeParam>,
+
+    /// Variants of type for which to derive mappings
+    ///
+    /// For structs, this is a one-element vector
+    pub(crate) variants: Vec<Structish>,
+}
+
+/// Meta information for deriving mappings
+#[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub(crate) struct FuncMapMeta {
+    /// Paths to the lints to allow within generated code in addition to the
+    /// ones allowed by default
+    pub(crate) allow: Vec<Path>,
+
+    /// Paths to the auto traits to assert are preserved by the mapping
+    pub(crate) assert_auto_traits: Vec<Path>,
+
+    /// Whether to skip asserting that the type doesn't implement `Drop`
+    pub(crate) assume_no_drop: bool,
+
+    /// Whether to bring predicates and bounds into a canonical order
+    pub(crate) canonical: bool,
+
+    /// Predicates under which to generate the implementations
+    pub(crate) cfg: Vec<Meta>,
+
+    /// Path to the `funcmap` crate
+    pub(crate) crate_path: Path,
+
+    /// Bounds to omit from the generated implementations
+    ///
+    /// Within these, `A` and `B` refer to the source and destination type of
+    /// the mapping, respectively.
+    pub(crate) drop_bounds: Vec<PredicateType>,
+
+    /// Path to the wrapper type to map trait objects through
+    pub(crate) dyn_as: Option<Path>,
+
+    /// Whether to generate the body of the mapping functions as a non-generic
+    /// inner function taking the closure as a trait object
+    pub(crate) dyn_body: bool,
+
+    /// Additional predicates to add to every generated implementation
+    ///
+    /// Within these, `A` and `B` refer to the source and destination type of
+    /// the mapping, respectively.
+    pub(crate) extra_bounds: Vec<WherePredicate>,
+
+    /// Name of a free function to generate in addition to the implementation
+    pub(crate) free_fn: Option<Ident>,
+
+    /// Whether to derive `FuncMap` via `TryFuncMap` in addition to `TryFuncMap`
+    pub(crate) infallible_via_try: bool,
+
+    /// Inline hint to put on the generated mapping functions
+    pub(crate) inline: Option<Inline>,
+
+    /// Paths to the types to generate `From` implementations for
+    pub(crate) from_paths: Vec<Path>,
+
+    /// Identifier to use for the source type of the mapping instead of an
+    /// automatically reserved one
+    pub(crate) src_type_ident: Option<Ident>,
+
+    /// Identifier to use for the destination type of the mapping instead of an
+    /// automatically reserved one
+    pub(crate) dst_type_ident: Option<Ident>,
+
+    /// Whether to check at derive time that local field types derive the same
+    /// trait
+    pub(crate) strict: bool,
+
+    /// Whether to enter a `tracing` span for every mapped field
+    pub(crate) traced: bool,
+
+    /// Path to a function to pass the mapped value through before returning it
+    pub(crate) validate: Option<Path>,
+
+    /// Paths to the functions to decompose the value into a tuple of its
+    /// fields and to rebuild the mapped value from the tuple of its mapped
+    /// fields instead of accessing the fields directly
+    pub(crate) parts_fns: Option<(Path, Path)>,
+
+    /// [`IdentCollector`] where all identifiers that occur within the
+    /// definition of the type are already reserved
+    pub(crate) ident_collector: IdentCollector,
+}
+
+/// Type parameter for which to derive a mapping
+#[derive(Debug)]
+pub(crate) struct MappedTypeParam {
+    /// 0-based index of the type parameter within *all* parameters of the type,
+    /// including lifetimes
+    pub(crate) param_idx: usize,
+
+    /// 0-based index of the type parameter within all *typish* parameters of
+    /// the type (i.e. types and const generics)
+    ///
+    /// This is used as `N` in the marker type `TypeParam<N>`.
+    pub(crate) marker_idx: usize,
+
+    /// The type parameter itself
+    pub(crate) type_param: TypeParam,
+}
+
+/// Either a struct or a variant of an enum
+#[derive(Debug)]
+pub(crate) struct Structish {
+    /// Name of the struct/variant
+    ///
+    /// For structs, this is [`None`]
+    pub(crate) variant_ident: Option<Ident>,
+
+    /// Fields of the struct/variant
+    pub(crate) fields: Vec<Fieldish>,
+}
+
+/// Field of a struct/variant
+#[derive(Debug)]
+pub(crate) struct Fieldish {
+    /// Identifier of the field
+    ///
+    /// For tuple structs/variants, this is [`None`]
+    pub(crate) ident: Option<Ident>,
+
+    /// Type of the field
+    pub(crate) ty: Type,
+
+    /// Path to the function to rebuild the field from its mapped target when
+    /// mapping through `DerefMut` instead of mapping the field itself
+    pub(crate) deref_rebuild: Option<Path>,
+
+    /// Path to the function to map the field with instead of deriving a
+    /// mapping from its type
+    pub(crate) opaque_via: Option<Path>,
+}
+
+impl Fieldish {
+    /// Returns the member to access the field and the identifier to bind it to
+    ///
+    /// Named fields are accessed via their identifier, which may be a raw
+    /// identifier such as `r#type`, while unnamed fields are accessed via their
+    /// index `field_idx`. The binding is `field_<name>` respectively
+    /// `field_<index>`, where `format_ident!` strips the `r#` prefix of raw
+    /// identifiers, so bindings are never raw identifiers themselves.
+    pub(crate) fn binding(&self, field_idx: usize) -> (Member, Ident) {
+        match &self.ident {
+            Some(field_ident) => (
+                field_ident.clone().into(),
+                format_ident!("field_{}", field_ident, span = Span::mixed_site()),
+            ),
+            None => (
+                field_idx.into(),
+                format_ident!("field_{}", field_idx, span = Span::mixed_site()),
+            ),
+        }
+    }
+
+    /// Returns tokens pointing to the field together with a description of
+    /// where it is located, as context for errors concerning the field
+    ///
+    /// The description reads "in field `<name>` of struct `<Type>`"
+    /// respectively "in field `<name>` of variant `<Type>::<Variant>`", where
+    /// `<name>` is the index of the field for tuple structs/variants.
+    pub(crate) fn context(
+        &self,
+        field_idx: usize,
+        type_ident: &Ident,
+        variant_ident: Option<&Ident>,
+    ) -> (TokenStream, String) {
+        let (tokens, name) = match &self.ident {
+            Some(field_ident) => (
+                field_ident.to_token_stream(),
+                field_ident.unraw().to_string(),
+            ),
+            None => (self.ty.to_token_stream(), field_idx.to_string()),
+        };
+
+        let location = match variant_ident {
+            Some(variant_ident) => format!("variant `{type_ident}::{variant_ident}`"),
+            None => format!("struct `{type_ident}`"),
+        };
+
+        (tokens, format!("in field `{name}` of {location}"))
+    }
+}
+
+/// Parses the item a `funcmap` derive macro is applied to
+///
+/// If the item cannot be parsed, e.g. because it contains syntax that is not
+/// supported by the version of `syn` in use, the parse error is followed by a
+/// message pointing at the part of the item containing the unsupported syntax,
+/// such as a particular field or variant, as far as it can be determined.
+pub(crate) fn parse_derive_input(item: TokenStream) -> Result<DeriveInput, Error> {
+    let err = match syn::parse2(item.clone()) {
+        Ok(derive_input) => return Ok(derive_input),
+        Err(err) => err,
+    };
+
+    // parsing the item piece by piece is bound to fail as well, so its result
+    // is only used to locate the error
+    let mut context = None;
+    let _ = (|input: ParseStream<'_>| {
+        context = locate_parse_error(input).ok().flatten();
+        Ok(())
+    })
+    .parse2(item);
+
+    match context {
+        Some(context) => Err(err).with_context(context),
+        None => Err(err.into()),
+    }
+}
+
+/// Determines the part of an item that cannot be parsed as [`DeriveInput`]
+///
+/// Returns tokens pointing to this part together with a description of it, or
+/// [`None`] if the part cannot be determined.
+fn locate_parse_error(input: ParseStream<'_>) -> syn::Result<Option<(TokenStream, String)>> {
+    input.call(Attribute::parse_outer)?;
+    input.parse::<Visibility>()?;
+
+    let lookahead = input.lookahead1();
+    let kind = if lookahead.peek(Token![struct]) {
+        input.parse::<Token![struct]>()?;
+        "struct"
+    } else if lookahead.peek(Token![enum]) {
+        input.parse::<Token![enum]>()?;
+        "enum"
+    } else if lookahead.peek(Token![union]) {
+        input.parse::<Token![union]>()?;
+        "union"
+    } else {
+        return Err(lookahead.error());
+    };
+
+    let ident: Ident = input.parse()?;
+    let location = format!("{kind} `{ident}`");
+
+    if input.parse::<Generics>().is_err() {
+        return Ok(Some((
+            ident.to_token_stream(),
+            format!("in generic parameters of {location}"),
+        )));
+    }
+
+    let where_clause_context = || {
+        Some((
+            ident.to_token_stream(),
+            format!("in `where` clause of {location}"),
+        ))
+    };
+
+    if input.peek(Token![where]) && input.parse::<WhereClause>().is_err() {
+        return Ok(where_clause_context());
+    }
+
+    if input.peek(token::Brace) {
+        let content;
+        braced!(content in input);
+
+        return Ok(if kind == "enum" {
+            locate_parse_error_in_variants(&content, &ident)
+        } else {
+            locate_parse_error_in_fields(&content, &location, Field::parse_named)
+        });
+    }
+
+    if input.peek(token::Paren) {
+        let content;
+        parenthesized!(content in input);
+
+        if let Some(context) =
+            locate_parse_error_in_fields(&content, &location, Field::parse_unnamed)
+        {
+            return Ok(Some(context));
+        }
+
+        if input.peek(Token![where]) && input.parse::<WhereClause>().is_err() {
+            return Ok(where_clause_context());
+        }
+    }
+
+    Ok(None)
+}
+
+/// Determines the field that cannot be parsed among the fields of a struct or
+/// union, given its `location` such as "struct `Test`"
+fn locate_parse_error_in_fields(
+    content: ParseStream<'_>,
+    location: &str,
+    parse_field: fn(ParseStream<'_>) -> syn::Result<Field>,
+) -> Option<(TokenStream, String)> {
+    let mut field_idx = 0;
+
+    while !content.is_empty() {
+        let fork = content.fork();
+        fork.call(Attribute::parse_outer).ok()?;
+        fork.parse::<Visibility>().ok()?;
+        let token: TokenTree = fork.parse().ok()?;
+
+        if content.call(parse_field).is_err() {
+            let name = match &token {
+                TokenTree::Ident(field_ident) if fork.peek(Token![:]) => {
+                    field_ident.unraw().to_string()
+                }
+                _ => field_idx.to_string(),
+            };
+
+            return Some((
+                token.into_token_stream(),
+                format!("in field `{name}` of {location}"),
+            ));
+        }
+
+        if content.is_empty() {
+            break;
+        }
+
+        content.parse::<Token![,]>().ok()?;
+        field_idx += 1;
+    }
+
+    None
+}
+
+/// Determines the variant that cannot be parsed among the variants of an enum
+fn locate_parse_error_in_variants(
+    content: ParseStream<'_>,
+    type_ident: &Ident,
+) -> Option<(TokenStream, String)> {
+    while !content.is_empty() {
+        let fork = content.fork();
+        fork.call(Attribute::parse_outer).ok()?;
+        fork.parse::<Visibility>().ok()?;
+        let variant_ident: Ident = fork.parse().ok()?;
+
+        if content.parse::<Variant>().is_err() {
+            return Some((
+                variant_ident.to_token_stream(),
+                format!("in variant `{type_ident}::{variant_ident}`"),
+            ));
+        }
+
+        if content.is_empty() {
+            break;
+        }
+
+        content.parse::<Token![,]>().ok()?;
+    }
+
+    None
+}
+
+impl TryFrom<DeriveInput> for FuncMapInput {
+    type Error = Error;
+
+    fn try_from(derive_input: DeriveInput) -> Result<Self, Self::Error> {
+        let ident_collector = {
+            let mut ident_collector = IdentCollector::new_visiting();
+            ident_collector.visit_derive_input(&derive_input);
+            ident_collector.into_reserved()
+        };
+
+        let opts: FuncMapOpts = derive_input.attrs.try_into()?;
+
+        let mut result_builder = result::Builder::new();
+
+        // within extra bounds, `A` and `B` refer to the source and destination
+        // types of the mapping, so type parameters of the same names cannot be
+        // referred to
+        for (placeholder, role) in [("A", "source"), ("B", "destination")] {
+            if let Some(type_param) = derive_input
+                .generics
+                .type_params()
+                .find(|type_param| type_param.ident == placeholder)
+            {
+                for predicate in &opts.extra_bounds {
+                    if let Some(ident) = predicate.dependency_on_type(&type_param.ident) {
+                        result_builder.add_err(syn::Error::new(
+                            ident.span(),
+                            format!(
+                                "`{placeholder}` within extra bounds refers to the {role} type of \
+                                 the mapping, so it cannot refer to the type parameter \
+                                 `{placeholder}`; consider renaming the type parameter"
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let parts_fns = match (opts.with, opts.rebuild) {
+            (Some(with), Some(rebuild)) => {
+                if !matches!(derive_input.data, Data::Struct(..)) {
+                    result_builder.add_err(syn::Error::new_spanned(
+                        &with,
+                        "decomposition function is only supported for structs",
+                    ));
+                }
+
+                Some((with, rebuild))
+            }
+            (Some(with), None) => {
+                result_builder.add_err(syn::Error::new_spanned(
+                    with,
+                    "expected rebuild function to be configured along with decomposition function",
+                ));
+
+                None
+            }
+            (None, Some(rebuild)) => {
+                result_builder.add_err(syn::Error::new_spanned(
+                    rebuild,
+                    "expected decomposition function to be configured along with rebuild function",
+                ));
+
+                None
+            }
+            (None, None) => None,
+        };
+
+        let meta = FuncMapMeta {
+            allow: opts.allow,
+            assert_auto_traits: opts.assert_auto_traits,
+            assume_no_drop: opts.assume_no_drop,
+            canonical: opts.canonical,
+            cfg: opts.cfg,
+
+            crate_path: opts.crate_path.unwrap_or_else(|| {
+                let path = CRATE_IDENT.into();
+
+                Path {
+                    leading_colon: Some(<Token![::]>::default()),
+                    ..path
+                }
+            }),
+
+            drop_bounds: opts.drop_bounds,
+            dyn_as: opts.dyn_as,
+            dyn_body: opts.dyn_body,
+            extra_bounds: opts.extra_bounds,
+            free_fn: opts.free_fn,
+            infallible_via_try: opts.infallible_via_try,
+            inline: opts.inline,
+
+            from_paths: opts.from_paths,
+            src_type_ident: opts.src_type_ident,
+            dst_type_ident: opts.dst_type_ident,
+            strict: opts.strict,
+            traced: opts.traced,
+            validate: opts.validate,
+            parts_fns,
+
+            ident_collector,
+        };
+
+        let mut mapped_type_param_idents = HashSet::new();
+
+        for param in opts.params {
+            match (
+                derive_input.generics.params.iter().find(|p| &&param == p),
+                param,
+            ) {
+                (Some(GenericParam::Type(..)), Param::TypeOrConst(ident)) => {
+                    mapped_type_param_idents.insert(ident);
+                }
+                (Some(GenericParam::Lifetime(..)), param) => {
+                    result_builder.add_err(syn::Error::new_spanned(
+                        param,
+                        format!("cannot implement {TRAIT_IDENT} over lifetime parameter"),
+                    ));
+                }
+                (Some(GenericParam::Const(..)), param) => {
+                    result_builder.add_err(syn::Error::new_spanned(
+                        param,
+                        format!("cannot implement {TRAIT_IDENT} over const generic"),
+                    ));
+                }
+                (_, param) => {
+                    result_builder
+                        .add_err(syn::Error::new_spanned(param, "unknown generic parameter"));
+                }
+            }
+        }
+
+        let mut skipped_type_param_idents = HashSet::new();
+
+        for param in opts.skip_params {
+            match (
+                derive_input.generics.params.iter().find(|p| &&param == p),
+                param,
+            ) {
+                (Some(GenericParam::Type(..)), Param::TypeOrConst(ident)) => {
+                    skipped_type_param_idents.insert(ident);
+                }
+                (Some(GenericParam::Lifetime(..)), param) => {
+                    result_builder.add_err(syn::Error::new_spanned(
+                        param,
+                        "cannot skip lifetime parameter, as it is never mapped",
+                    ));
+                }
+                (Some(GenericParam::Const(..)), param) => {
+                    result_builder.add_err(syn::Error::new_spanned(
+                        param,
+                        "cannot skip const generic, as it is never mapped",
+                    ));
+                }
+                (_, param) => {
+                    result_builder
+                        .add_err(syn::Error::new_spanned(param, "unknown generic parameter"));
+                }
+            }
+        }
+
+        let mapped_type_params: Vec<_> = derive_input
+            .generics
+            .params
+            .iter()
+            .enumerate()
+            .filter(|(_, param)| param.is_typish())
+            .enumerate()
+            .filter_map(|(marker_idx, (param_idx, param))| match param {
+                GenericParam::Type(type_param)
+                    if (mapped_type_param_idents.is_empty()
+                        || mapped_type_param_idents.contains(&type_param.ident))
+                        && !skipped_type_param_idents.contains(&type_param.ident) =>
+                {
+                    Some(MappedTypeParam {
+                        param_idx,
+                        marker_idx,
+                        type_param: type_param.clone(),
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+
+        if mapped_type_params.is_empty() {
+            let message = if skipped_type_param_idents.is_empty() {
+                "expected at least one type parameter, found none"
+            } else {
+                "expected at least one type parameter not listed in `skip_params`, found none"
+            };
+
+            result_builder.add_err(syn::Error::new_spanned(
+                derive_input
+                    .generics
+                    .to_non_empty_token_stream()
+                    .unwrap_or_else(|| derive_input.ident.to_token_stream()),
+                message,
+            ));
+        }
+
+        let variants = match derive_input.data {
+            Data::Struct(data_struct) => iter::once(data_struct.try_into()).collect_with_errors(),
+
+            Data::Enum(DataEnum { variants, .. }) => variants
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect_with_errors(),
+
+            Data::Union(DataUnion { union_token, .. }) => iter::once(Err(syn::Error::new_spanned(
+                union_token,
+                "expected a struct or an enum, found a union",
+            )))
+            .collect_with_errors(),
+        }
+        .with_error_from(result_builder)?;
+
+        Ok(Self {
+            meta,
+            ident: derive_input.ident,
+            generics: derive_input.generics,
+            mapped_type_params,
+            variants,
+        })
+    }
+}
+
+impl TryFrom<DataStruct> for Structish {
+    type Error = Error;
+
+    fn try_from(data_struct: DataStruct) -> Result<Self, Self::Error> {
+        Ok(Self {
+            variant_ident: None,
+            fields: data_struct
+                .fields
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect_with_errors()?,
+        })
+    }
+}
+
+impl TryFrom<Variant> for Structish {
+    type Error = Error;
+
+    fn try_from(variant: Variant) -> Result<Self, Self::Error> {
+        let mut result_builder = result::Builder::new();
+
+        opts::assert_absent(&variant.attrs, "variants").add_err_to(&mut result_builder);
+
+        Ok(Self {
+            variant_ident: Some(variant.ident),
+            fields: variant
+                .fields
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect_with_errors()
+                .with_error_from(result_builder)?,
+        })
+    }
+}
+
+impl TryFrom<Field> for Fieldish {
+    type Error = Error;
+
+    fn try_from(field: Field) -> Result<Self, Self::Error> {
+        let opts = opts::FieldOpts::try_from(field.attrs)?;
+
+        Ok(Self {
+            ident: field.ident,
+            ty: field.ty,
+            deref_rebuild: opts.deref_rebuild,
+            opaque_via: opts.opaque_via,
+        })
+    }
+}
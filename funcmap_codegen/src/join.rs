@@ -0,0 +1,280 @@
+//! The derive logic for `JoinParams`
+
+use crate::derivable::Derivable;
+use crate::derive::{impl_attrs, subs_type_in_bounds, type_param_checks_fn, AttrOpts};
+use crate::ident::{JOIN_FN_IDENT, JOIN_TRAIT_IDENT, MARKER_TYPE_IDENT, OUTPUT_TYPE_IDENT};
+use crate::input::{parse_derive_input, FuncMapInput, Structish};
+use crate::map::Mapping;
+use crate::predicates::UniquePredicates;
+use crate::result::{self, Error, ResultExt};
+use crate::syn_ext::{
+    IntoGenericArgument, IntoType, SubsType, WithoutAttrs, WithoutDefault, WithoutMaybeBounds,
+};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, GenericArgument, GenericParam, TypeParam, TypeParamBound, WherePredicate};
+
+/// Generates an implementation of `JoinParams` for a given item
+pub(crate) fn derive(item: TokenStream) -> TokenStream {
+    match try_derive(item) {
+        Ok(output) => output,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+/// Tries to generate an implementation of `JoinParams` for a given item
+///
+/// The item must have exactly two mapped type parameters `P` and `Q`. This
+/// generates an implementation of `JoinParams` for the item with both `P` and
+/// `Q` instantiated with the same type, mapping it in a single traversal. This
+/// works by substituting `Q` with `P` within the types of all fields and then
+/// deriving a mapping over `P`.
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed
+/// - `item` is not a valid input for deriving `FuncMap`
+/// - `item` does not have exactly two mapped type parameters
+/// - any of the fields of `item` has an unsupported type
+pub(crate) fn try_derive(item: TokenStream) -> Result<TokenStream, Error> {
+    let input = parse_derive_input(item)?;
+    let input: FuncMapInput = input.try_into()?;
+    let mut ident_collector = input.meta.ident_collector;
+    let mut result_builder = result::Builder::new();
+
+    let [first, second] = input.mapped_type_params.as_slice() else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "expected exactly two type parameters to be mapped for deriving `JoinParams`",
+        )
+        .into());
+    };
+
+    let src_type_ident = ident_collector.reserve_uppercase_letter('A', Span::mixed_site());
+    let dst_type_ident = ident_collector.reserve_uppercase_letter('B', Span::mixed_site());
+    let fn_type_ident = ident_collector.reserve_uppercase_letter('F', Span::mixed_site());
+    let tmp_type_ident = ident_collector.reserve_uppercase_letter('X', Span::mixed_site());
+    let fn_var_ident = Ident::new("f", Span::mixed_site());
+
+    let crate_path = &input.meta.crate_path;
+    let inline = &input.meta.inline;
+    let ident = &input.ident;
+    let all_params = &input.generics.params;
+    let where_clause = &input.generics.where_clause;
+    let first_ident = &first.type_param.ident;
+    let second_ident = &second.type_param.ident;
+
+    let attr_opts = AttrOpts {
+        allow: &input.meta.allow,
+        cfg: &input.meta.cfg,
+    };
+    let attrs = impl_attrs(attr_opts);
+
+    // within bounds, the second type parameter is replaced with the first one
+    let joined_bounds = |type_params: &[&TypeParam]| -> Vec<TypeParamBound> {
+        type_params
+            .iter()
+            .flat_map(|type_param| &type_param.bounds)
+            .map(|bound| match bound {
+                TypeParamBound::Trait(trait_bound) => {
+                    TypeParamBound::Trait(trait_bound.clone().subs_type(second_ident, first_ident))
+                }
+                bound => bound.clone(),
+            })
+            .collect()
+    };
+
+    let mut impl_params = Vec::with_capacity(all_params.len());
+
+    for (param_idx, param) in all_params.iter().enumerate() {
+        if param_idx == first.param_idx {
+            let bounds = joined_bounds(&[&first.type_param, &second.type_param]);
+
+            for type_ident in [&src_type_ident, &dst_type_ident] {
+                impl_params.push(GenericParam::Type(TypeParam {
+                    bounds: subs_type_in_bounds(&bounds, first_ident, &[type_ident])?
+                        .without_maybe_bounds(),
+                    ..type_ident.clone().into()
+                }));
+            }
+        } else if param_idx != second.param_idx {
+            impl_params.push(match param {
+                GenericParam::Type(type_param) => GenericParam::Type(TypeParam {
+                    bounds: subs_type_in_bounds(
+                        &joined_bounds(&[type_param]),
+                        first_ident,
+                        &[&src_type_ident, &dst_type_ident],
+                    )?,
+                    ..type_param.ident.clone().into()
+                }),
+                param => param.clone().without_attrs().without_default(),
+            });
+        }
+    }
+
+    let args = |type_ident: &Ident| -> Vec<GenericArgument> {
+        all_params
+            .iter()
+            .enumerate()
+            .map(|(param_idx, param)| {
+                if param_idx == first.param_idx || param_idx == second.param_idx {
+                    GenericArgument::Type(type_ident.clone().into_type())
+                } else {
+                    param.clone().into_generic_argument()
+                }
+            })
+            .collect()
+    };
+
+    let src_args = args(&src_type_ident);
+    let dst_args = args(&dst_type_ident);
+
+    let mut unique_predicates = UniquePredicates::new();
+
+    for predicate in where_clause
+        .iter()
+        .flat_map(|clause| clause.predicates.iter())
+    {
+        let predicate = match predicate
+            .clone()
+            .without_attrs()
+            .subs_type(second_ident, first_ident)
+        {
+            WherePredicate::Type(predicate_type)
+                if predicate_type.bounded_ty == first_ident.clone().into_type() =>
+            {
+                WherePredicate::Type(predicate_type.without_maybe_bounds())
+            }
+            predicate => predicate,
+        };
+
+        for type_ident in [&src_type_ident, &dst_type_ident] {
+            unique_predicates
+                .add(predicate.clone().subs_type(first_ident, type_ident))
+                .add_err_to(&mut result_builder);
+        }
+    }
+
+    // within extra bounds, `A` and `B` refer to the source and destination
+    // types, see the derive logic for `FuncMap`
+    for predicate in &input.meta.extra_bounds {
+        let predicate = predicate
+            .clone()
+            .subs_type(&Ident::new("A", Span::call_site()), &tmp_type_ident)
+            .subs_type(&Ident::new("B", Span::call_site()), &dst_type_ident)
+            .subs_type(&tmp_type_ident, &src_type_ident);
+
+        unique_predicates
+            .add(predicate)
+            .add_err_to(&mut result_builder);
+    }
+
+    let mapping = Mapping {
+        type_param: &first.type_param,
+        generics: &input.generics,
+        src_type_ident: &src_type_ident,
+        dst_type_ident: &dst_type_ident,
+        fn_ident: &fn_var_ident,
+        crate_path,
+        dyn_as: input.meta.dyn_as.as_ref(),
+        derivable: Derivable::Standard,
+    };
+
+    let mut type_param_checks = Vec::new();
+    let mut arms = Vec::new();
+
+    for Structish {
+        variant_ident,
+        fields,
+    } in &input.variants
+    {
+        let mut patterns = Vec::new();
+        let mut mappings = Vec::new();
+
+        for (field_idx, field) in fields.iter().enumerate() {
+            let (member, ident) = field.binding(field_idx);
+            let ty = field.ty.clone().subs_type(second_ident, first_ident);
+
+            let tokens = if let Some(opaque_via) = &field.opaque_via {
+                quote!(#opaque_via(#ident, &mut #fn_var_ident))
+            } else if let Some(mapped) = mapping
+                .map(&ident, &ty)
+                .with_context(field.context(field_idx, &input.ident, variant_ident.as_ref()))
+                .add_err_to(&mut result_builder)
+            {
+                for predicate in mapped.predicates.into_iter() {
+                    unique_predicates
+                        .add(predicate)
+                        .add_err_to(&mut result_builder);
+                }
+
+                type_param_checks.extend(mapped.type_param_checks);
+
+                mapped.tokens
+            } else {
+                continue;
+            };
+
+            patterns.push(quote!(#member: #ident));
+            mappings.push(quote!(#member: #tokens));
+        }
+
+        let (pat_path, output_path) = match variant_ident {
+            Some(ident) => (
+                quote!(Self::#ident),
+                quote!(Self::#OUTPUT_TYPE_IDENT::#ident),
+            ),
+            None => (quote!(Self), quote!(Self::#OUTPUT_TYPE_IDENT)),
+        };
+
+        arms.push(quote! {
+            #pat_path { #(#patterns,)* } => #output_path { #(#mappings,)* }
+        });
+    }
+
+    let impl_where_clause = unique_predicates.into_where_clause();
+    let first_marker_idx = first.marker_idx;
+    let second_marker_idx = second.marker_idx;
+
+    let type_param_checks = type_param_checks_fn(
+        type_param_checks,
+        Derivable::Standard,
+        crate_path,
+        &impl_params,
+        &parse_quote!(#ident<#(#src_args),*>),
+        &impl_where_clause,
+        attr_opts,
+    );
+
+    result_builder.err_or(quote! {
+        #attrs
+        impl<#(#impl_params),*>
+            #crate_path::#JOIN_TRAIT_IDENT<
+                #src_type_ident,
+                #dst_type_ident,
+                #crate_path::#MARKER_TYPE_IDENT<#first_marker_idx>,
+                #crate_path::#MARKER_TYPE_IDENT<#second_marker_idx>
+            >
+            for #ident<#(#src_args),*>
+            #impl_where_clause
+        {
+            type #OUTPUT_TYPE_IDENT = #ident<#(#dst_args),*>;
+
+            #inline
+            fn #JOIN_FN_IDENT<#fn_type_ident>(
+                self,
+                mut #fn_var_ident: #fn_type_ident
+            ) -> Self::#OUTPUT_TYPE_IDENT
+            where
+                #fn_type_ident: ::core::ops::FnMut(#src_type_ident) -> #dst_type_ident
+            {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+
+        #type_param_checks
+    })
+}
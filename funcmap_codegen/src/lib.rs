@@ -0,0 +1,277 @@
+//! Code generation for the `funcmap` crate
+//!
+//! This crate contains the logic behind the derive macros of the `funcmap`
+//! crate and exposes it as ordinary functions operating on
+//! [`proc_macro2::TokenStream`]s. This allows generating implementations of
+//! `FuncMap`, `TryFuncMap`, `JoinParams` and `From` outside of a derive macro, e.g. in build
+//! scripts, code generators or other procedural macros that synthesize types.
+//!
+//! Users of the derive macros should not depend on this crate directly. See
+//! the documentation of [funcmap](https://docs.rs/funcmap) instead.
+//!
+//! # Example
+//!
+//! ```
+//! use funcmap_codegen::{try_derive, Derivable};
+//! use quote::quote;
+//!
+//! let item = quote! {
+//!     struct Foo<T> {
+//!         value: T,
+//!     }
+//! };
+//!
+//! let output = try_derive(item, Derivable::Standard).unwrap();
+//! assert!(output.to_string().contains("FuncMap"));
+//!
+//! let err = try_derive(quote!(struct Foo;), Derivable::Standard).unwrap_err();
+//! assert_eq!(err.to_string(), "expected at least one type parameter, found none");
+//! ```
+//!
+//! The generated code refers to the `funcmap` crate via `::funcmap` unless
+//! configured otherwise via `#[funcmap(crate = "...")]`, so it is only valid
+//! within crates depending on `funcmap`.
+
+#![deny(missing_copy_implementations)]
+#![deny(missing_debug_implementations)]
+#![deny(missing_docs)]
+#![deny(unreachable_pub)]
+#![deny(unused_crate_dependencies)]
+#![deny(unused_extern_crates)]
+#![deny(unused_lifetimes)]
+#![deny(unused_qualifications)]
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::cargo_common_metadata)]
+#![deny(clippy::multiple_crate_versions)]
+#![deny(clippy::rest_pat_in_fully_bound_structs)]
+#![deny(clippy::use_debug)]
+#![allow(clippy::module_name_repetitions)]
+#![allow(clippy::too_many_lines)]
+#![deny(rustdoc::broken_intra_doc_links)]
+#![deny(rustdoc::private_intra_doc_links)]
+#![deny(rustdoc::invalid_codeblock_attributes)]
+#![deny(rustdoc::invalid_rust_codeblocks)]
+#![deny(rustdoc::bare_urls)]
+
+pub use derivable::Derivable;
+pub use result::Error;
+
+use proc_macro2::TokenStream;
+
+mod builders;
+mod count;
+mod derivable;
+mod derive;
+mod family;
+mod fields;
+mod filter;
+mod ident;
+mod ident_collector;
+mod input;
+mod join;
+mod map;
+mod opts;
+mod predicates;
+mod ref_map;
+mod result;
+mod structural;
+mod syn_ext;
+
+/// Generates an implementation of `FuncMap` or `TryFuncMap` for a given item
+///
+/// This is what the derive macros `FuncMap` and `TryFuncMap` expand to. If
+/// generation fails, the output is a `compile_error!(...)` invocation
+/// describing the error, see [`try_derive`].
+#[must_use]
+pub fn derive(item: TokenStream, derivable: Derivable) -> TokenStream {
+    derive::derive(item, derivable)
+}
+
+/// Tries to generate an implementation of `FuncMap` or `TryFuncMap` for a given
+/// item
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed as a struct, enum or union
+/// - `item` is not a valid input for deriving `FuncMap` or `TryFuncMap`
+/// - any of the fields of `item` has an unsupported type
+pub fn try_derive(item: TokenStream, derivable: Derivable) -> Result<TokenStream, Error> {
+    derive::try_derive(item, derivable)
+}
+
+/// Generates implementations of `From` between types of the same shape for a
+/// given item
+///
+/// This is what the derive macro `StructuralFrom` expands to. If generation
+/// fails, the output is a `compile_error!(...)` invocation describing the
+/// error, see [`try_derive_structural_from`].
+#[must_use]
+pub fn derive_structural_from(item: TokenStream) -> TokenStream {
+    structural::derive(item)
+}
+
+/// Tries to generate implementations of `From` between types of the same shape
+/// for a given item
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed as a struct, enum or union
+/// - `item` is not a valid input for deriving `StructuralFrom`
+pub fn try_derive_structural_from(item: TokenStream) -> Result<TokenStream, Error> {
+    structural::try_derive(item)
+}
+
+/// Generates an implementation of `JoinParams` for a given item
+///
+/// This is what the derive macro `JoinParams` expands to. If generation fails,
+/// the output is a `compile_error!(...)` invocation describing the error, see
+/// [`try_derive_join_params`].
+#[must_use]
+pub fn derive_join_params(item: TokenStream) -> TokenStream {
+    join::derive(item)
+}
+
+/// Tries to generate an implementation of `JoinParams` for a given item
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed as a struct, enum or union
+/// - `item` is not a valid input for deriving `JoinParams`, in particular if it
+///   does not have exactly two type parameters to be mapped
+/// - any of the fields of `item` has an unsupported type
+pub fn try_derive_join_params(item: TokenStream) -> Result<TokenStream, Error> {
+    join::try_derive(item)
+}
+
+/// Generates an implementation of `FamilyMap` for a given item
+///
+/// This is what the derive macro `FamilyMap` expands to. If generation fails,
+/// the output is a `compile_error!(...)` invocation describing the error, see
+/// [`try_derive_family_map`].
+#[must_use]
+pub fn derive_family_map(item: TokenStream) -> TokenStream {
+    family::derive(item)
+}
+
+/// Tries to generate an implementation of `FamilyMap` for a given item
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed as a struct, enum or union
+/// - `item` is not a valid input for deriving `FamilyMap`, in particular if it
+///   does not have exactly one type parameter to be mapped
+/// - any of the fields of `item` has an unsupported type
+pub fn try_derive_family_map(item: TokenStream) -> Result<TokenStream, Error> {
+    family::try_derive(item)
+}
+
+/// Generates an implementation of `FuncFilterMap` for a given item
+///
+/// This is what the derive macro `FuncFilterMap` expands to. If generation
+/// fails, the output is a `compile_error!(...)` invocation describing the
+/// error, see [`try_derive_func_filter_map`].
+#[must_use]
+pub fn derive_func_filter_map(item: TokenStream) -> TokenStream {
+    filter::derive(item)
+}
+
+/// Tries to generate an implementation of `FuncFilterMap` for a given item
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed as a struct, enum or union
+/// - `item` is not a valid input for deriving `FuncFilterMap`, in particular if
+///   it does not have exactly one type parameter to be mapped
+/// - any of the fields of `item` has the type parameter itself as its type
+pub fn try_derive_func_filter_map(item: TokenStream) -> Result<TokenStream, Error> {
+    filter::try_derive(item)
+}
+
+/// Generates implementations of `FuncCount` for a given item
+///
+/// This is what the derive macro `FuncCount` expands to. If generation fails,
+/// the output is a `compile_error!(...)` invocation describing the error, see
+/// [`try_derive_func_count`].
+#[must_use]
+pub fn derive_func_count(item: TokenStream) -> TokenStream {
+    count::derive(item)
+}
+
+/// Tries to generate implementations of `FuncCount` for a given item
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed as a struct, enum or union
+/// - `item` is not a valid input for deriving `FuncCount`
+/// - any of the fields of `item` has an unsupported type
+pub fn try_derive_func_count(item: TokenStream) -> Result<TokenStream, Error> {
+    count::try_derive(item)
+}
+
+/// Generates implementations of `RefFuncMap` for a given item
+///
+/// This is what the derive macro `RefFuncMap` expands to. If generation fails,
+/// the output is a `compile_error!(...)` invocation describing the error, see
+/// [`try_derive_ref_func_map`].
+#[must_use]
+pub fn derive_ref_func_map(item: TokenStream) -> TokenStream {
+    ref_map::derive(item)
+}
+
+/// Tries to generate implementations of `RefFuncMap` for a given item
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed as a struct, enum or union
+/// - `item` is not a valid input for deriving `RefFuncMap`
+/// - any of the fields of `item` has an unsupported type, in particular a type
+///   with more than one type argument depending on the mapped type parameter
+pub fn try_derive_ref_func_map(item: TokenStream) -> Result<TokenStream, Error> {
+    ref_map::try_derive(item)
+}
+
+/// Generates inherent `map_*` methods for the mapped type parameters of a
+/// given item
+///
+/// This is what the derive macro `MapBuilders` expands to. If generation fails,
+/// the output is a `compile_error!(...)` invocation describing the error, see
+/// [`try_derive_map_builders`].
+#[must_use]
+pub fn derive_map_builders(item: TokenStream) -> TokenStream {
+    builders::derive(item)
+}
+
+/// Tries to generate inherent `map_*` methods for the mapped type parameters of
+/// a given item
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed as a struct, enum or union
+/// - `item` is not a valid input for deriving `MapBuilders`
+pub fn try_derive_map_builders(item: TokenStream) -> Result<TokenStream, Error> {
+    builders::try_derive(item)
+}
+
+/// Generates an inherent `func_map_fields` method for a given struct, mapping
+/// all of its fields at once
+///
+/// This is what the derive macro `FuncMapFields` expands to. If generation
+/// fails, the output is a `compile_error!(...)` invocation describing the
+/// error, see [`try_derive_func_map_fields`].
+#[must_use]
+pub fn derive_func_map_fields(item: TokenStream) -> TokenStream {
+    fields::derive(item)
+}
+
+/// Tries to generate an inherent `func_map_fields` method for a given struct,
+/// mapping all of its fields at once
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed as a struct, enum or union
+/// - `item` is not a struct
+/// - `item` is not a valid input for deriving `FuncMapFields`
+pub fn try_derive_func_map_fields(item: TokenStream) -> Result<TokenStream, Error> {
+    fields::try_derive(item)
+}
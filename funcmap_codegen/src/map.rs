@@ -0,0 +1,1002 @@
+//! Logic for deriving a mapping for a given type
+
+use crate::derivable::Derivable;
+use crate::ident::{MARKER_TYPE_IDENT, OUTPUT_TYPE_IDENT};
+use crate::predicates::UniquePredicates;
+use crate::result::Error;
+use crate::syn_ext::{DependencyOnType, IntoType, IsTypish, SubsType};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::visit::{self, Visit};
+use syn::TypeParen;
+use syn::{
+    parse_quote,
+    punctuated::{Pair, Punctuated},
+    AngleBracketedGenericArguments, BoundLifetimes, GenericArgument, GenericParam, Generics, Index,
+    Lifetime, Path, PathArguments, PathSegment, QSelf, Token, Type, TypeArray, TypeParam, TypePath,
+    TypeReference, TypeSlice,
+};
+
+/// Paths to the maps from the standard library whose keys and values are
+/// mapped in a single pass
+const MAP_PATHS: &[&str] = &[
+    "alloc::collections::BTreeMap",
+    "alloc::collections::btree_map::BTreeMap",
+    "std::collections::BTreeMap",
+    "std::collections::HashMap",
+    "std::collections::btree_map::BTreeMap",
+    "std::collections::hash_map::HashMap",
+];
+
+/// Paths to `Vec` in the standard library
+const VEC_PATHS: &[&str] = &["alloc::vec::Vec", "std::vec::Vec"];
+
+/// Paths to `Box` in the standard library
+const BOX_PATHS: &[&str] = &["alloc::boxed::Box", "std::boxed::Box"];
+
+/// Paths to `Option` in the standard library
+const OPTION_PATHS: &[&str] = &["core::option::Option", "std::option::Option"];
+
+/// Configuration of a mapping for a given type
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Mapping<'ast> {
+    /// Type parameter to map over
+    pub(crate) type_param: &'ast TypeParam,
+
+    /// Generics of the type the mapping is derived for
+    pub(crate) generics: &'ast Generics,
+
+    /// Identifier of the source type of the mapping
+    pub(crate) src_type_ident: &'ast Ident,
+
+    /// Identifier of the destination type of the mapping
+    pub(crate) dst_type_ident: &'ast Ident,
+
+    /// Identifier of the mapping function
+    pub(crate) fn_ident: &'ast Ident,
+
+    /// Path to the `funcmap` crate
+    pub(crate) crate_path: &'ast Path,
+
+    /// Path to the wrapper type to map trait objects through
+    pub(crate) dyn_as: Option<&'ast Path>,
+
+    /// Trait being derived
+    pub(crate) derivable: Derivable,
+}
+
+/// Result of a mapping
+pub(crate) struct Mapped {
+    /// Tokens of the mapping implementation
+    pub(crate) tokens: TokenStream,
+
+    /// Predicates required by the mapping
+    pub(crate) predicates: UniquePredicates,
+
+    /// Checks of the marker types used by the mapping
+    pub(crate) type_param_checks: Vec<TypeParamCheck>,
+}
+
+/// Check that a marker type `TypeParam<N>` used by a mapping refers to the
+/// intended type parameter
+///
+/// This is violated e.g. if a type alias reorders type parameters.
+#[derive(Debug)]
+pub(crate) struct TypeParamCheck {
+    /// Type that is mapped using the marker type
+    pub(crate) ty: Type,
+
+    /// Index `N` of the marker type
+    pub(crate) marker_idx: usize,
+
+    /// Type expected to be the type parameter of `ty` at index `N`
+    pub(crate) param: Type,
+}
+
+impl Mapping<'_> {
+    /// Applies this mapping to a given expression for a given type
+    ///
+    /// Generates an implementation of the `self` mapping for the `mappable`
+    /// expression of type `ty`, also returning required predicates.
+    ///
+    /// # Errors
+    /// Fails if mapping `ty` is not supported
+    pub(crate) fn map(self, mappable: impl ToTokens, ty: &Type) -> Result<Mapped, Error> {
+        let mut mapper = Mapper::new(self);
+        let mapped_tokens = mapper.map(mappable.into_token_stream(), ty)?;
+
+        Ok(Mapped {
+            tokens: mapped_tokens,
+            predicates: mapper.unique_predicates,
+            type_param_checks: mapper.type_param_checks,
+        })
+    }
+}
+
+/// Returns whether `ty` is `PhantomData` with a single type argument
+///
+/// See [`Mapper::is_phantom_data`].
+pub(crate) fn is_phantom_data_type(ty: &Type) -> bool {
+    match ty {
+        Type::Paren(TypeParen { elem, .. }) => is_phantom_data_type(elem),
+        Type::Path(TypePath { path, .. }) => match path.segments.last() {
+            Some(PathSegment {
+                ident,
+                arguments: PathArguments::AngleBracketed(angle_bracketed),
+            }) => Mapper::is_phantom_data(ident, &angle_bracketed.args),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns the path of `type_path` without its generic arguments if it is
+/// fully qualified through one of the crates `std`, `alloc` or `core`, e.g.
+/// `std::vec::Vec` for `::std::vec::Vec<T>`
+///
+/// Types from the standard library are only treated specially if they are
+/// referred to through such a path, as a type that is merely named e.g. `Vec`
+/// may as well be a type defined elsewhere with a different implementation of
+/// `FuncMap`.
+fn qualified_std_path(type_path: &TypePath) -> Option<String> {
+    let segments = &type_path.path.segments;
+
+    if type_path.qself.is_some()
+        || segments.len() < 2
+        || !["std", "alloc", "core"]
+            .iter()
+            .any(|krate| segments[0].ident == krate)
+        || segments
+            .iter()
+            .take(segments.len() - 1)
+            .any(|segment| !segment.arguments.is_none())
+    {
+        return None;
+    }
+
+    Some(
+        segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::"),
+    )
+}
+
+/// Helper used for collecting predicates while mapping
+#[derive(Debug)]
+struct Mapper<'ast> {
+    /// The associated [`Mapping`] configuration
+    mapping: Mapping<'ast>,
+
+    /// Collected predicates
+    unique_predicates: UniquePredicates,
+
+    /// Collected checks of marker types
+    type_param_checks: Vec<TypeParamCheck>,
+}
+
+impl<'ast> Mapper<'ast> {
+    fn new(mapping: Mapping<'ast>) -> Self {
+        Self {
+            mapping,
+            unique_predicates: UniquePredicates::new(),
+            type_param_checks: Vec::new(),
+        }
+    }
+
+    fn map(&mut self, mappable: TokenStream, ty: &Type) -> Result<TokenStream, Error> {
+        if let Type::Macro(..) = ty {
+            return Err(syn::Error::new_spanned(
+                ty,
+                // this is literally the same error message
+                // that would be emitted by builtin derive macros
+                "`derive` cannot be used on items with type macros",
+            )
+            .into());
+        }
+
+        if ty
+            .dependency_on_type(&self.mapping.type_param.ident)
+            .is_none()
+        {
+            // the value is moved, which requires its type to be `Sized`, but a
+            // type not depending on any type parameter is `Sized` or not
+            // regardless of the generic arguments, so the bound would be trivial
+            if self
+                .mapping
+                .generics
+                .type_params()
+                .any(|type_param| ty.dependency_on_type(&type_param.ident).is_some())
+            {
+                self.unique_predicates.add(parse_quote! {
+                    #ty: ::core::marker::Sized
+                })?;
+            }
+
+            return Ok(mappable);
+        }
+
+        let crate_path = self.mapping.crate_path;
+        let trait_ident = self.mapping.derivable.trait_ident();
+        let fn_ident = self.mapping.derivable.fn_ident();
+
+        match ty {
+            Type::Array(TypeArray { elem: inner_ty, .. }) => {
+                let closure = self.map_closure(inner_ty)?;
+                Ok(self
+                    .mapping
+                    .derivable
+                    .bind_expr(quote!(#crate_path::#trait_ident::#fn_ident(#mappable, #closure))))
+            }
+
+            Type::Paren(TypeParen { elem: inner_ty, .. }) => self.map(mappable, inner_ty),
+
+            Type::Path(type_path) => {
+                let TypePath {
+                    qself,
+                    path:
+                        Path {
+                            leading_colon,
+                            segments,
+                        },
+                } = type_path;
+
+                if let Some(QSelf { ty: inner_ty, .. }) = &qself {
+                    if inner_ty
+                        .dependency_on_type(&self.mapping.type_param.ident)
+                        .is_some()
+                    {
+                        return Err(syn::Error::new_spanned(
+                            ty,
+                            "mapping over type with associated item is not supported",
+                        )
+                        .into());
+                    }
+                }
+
+                let (prefix, ident, arguments) = {
+                    let mut prefix = segments.clone();
+
+                    match prefix.pop() {
+                        Some(Pair::End(PathSegment { ident, arguments })) => {
+                            (prefix, ident, arguments)
+                        }
+                        Some(..) => {
+                            return Err(syn::Error::new_spanned(
+                                ty,
+                                "mapping over type with trailing :: is not supported",
+                            )
+                            .into());
+                        }
+                        None => {
+                            return Err(syn::Error::new_spanned(
+                                ty,
+                                "mapping over empty type is not supported",
+                            )
+                            .into());
+                        }
+                    }
+                };
+
+                let prefix_type = Type::Path(TypePath {
+                    qself: qself.clone(),
+                    path: Path {
+                        leading_colon: *leading_colon,
+                        segments: prefix.clone(),
+                    },
+                });
+
+                if prefix_type
+                    .dependency_on_type(&self.mapping.type_param.ident)
+                    .is_some()
+                {
+                    return Err(syn::Error::new_spanned(
+                        ty,
+                        "mapping over type with associated item is not supported",
+                    )
+                    .into());
+                }
+
+                let angle_bracketed = match arguments {
+                    PathArguments::None => {
+                        let mapping_fn_ident = self.mapping.fn_ident;
+                        return Ok(self
+                            .mapping
+                            .derivable
+                            .bind_expr(quote!(#mapping_fn_ident(#mappable))));
+                    }
+
+                    PathArguments::AngleBracketed(angle_bracketed) => angle_bracketed,
+
+                    PathArguments::Parenthesized(..) => {
+                        return Err(syn::Error::new_spanned(
+                            ty,
+                            "mapping over function type is not supported",
+                        )
+                        .into());
+                    }
+                };
+
+                let args = angle_bracketed.args;
+
+                if Self::is_phantom_data(&ident, &args) {
+                    // the type argument of `PhantomData` is never instantiated,
+                    // so it may be anything mentioning the type parameter, even
+                    // types that are otherwise not supported
+                    //
+                    // references within the type argument are well-formed for
+                    // the source type by its implied bounds, but for the
+                    // destination type their lifetimes must be required
+                    // explicitly
+                    let mut reference_collector = ReferenceCollector::default();
+                    reference_collector.visit_type(ty);
+
+                    for (lifetime, elem) in reference_collector.into_references() {
+                        let elem = self.subs_dst_type(elem.clone());
+                        self.unique_predicates.add(parse_quote!(#elem: #lifetime))?;
+                    }
+
+                    return Ok(quote!({
+                        let _ = #mappable;
+                        ::core::marker::PhantomData
+                    }));
+                }
+
+                self.add_outlives_predicates(&args)?;
+
+                if let Some(wrapper) = self.mapping.dyn_as {
+                    if args
+                        .iter()
+                        .any(|arg| matches!(arg, GenericArgument::Type(Type::TraitObject(..))))
+                    {
+                        return self.map_via_wrapper(&mappable, wrapper);
+                    }
+                }
+
+                let std_path = qualified_std_path(type_path);
+
+                if let Some((key_type, value_type)) =
+                    self.map_entry_types(std_path.as_deref(), &args)
+                {
+                    return self.map_entries(&mappable, ty, key_type, value_type);
+                }
+
+                if let Some((nesting, inner_ty)) =
+                    Self::option_box_types(std_path.as_deref(), &args)
+                {
+                    return self.map_option_box(&mappable, ty, nesting, inner_ty);
+                }
+
+                if let Some(elem_ty) = Self::cow_slice_elem_type(&ident, &args) {
+                    return self.map_cow_slice(&mappable, ty, elem_ty);
+                }
+
+                let arg_types = args
+                    .iter()
+                    .filter(|arg| arg.is_typish())
+                    .enumerate()
+                    .filter_map(|(marker_idx, arg)| match arg {
+                        GenericArgument::Type(ty) => Some((marker_idx, ty)),
+                        _ => None,
+                    });
+
+                let needs_predicate =
+                    !Self::is_unconditionally_mappable(std_path.as_deref(), &args);
+                let mut mappable = mappable;
+
+                // every type argument depending on the type parameter is mapped
+                // in a separate pass with a separate bound, even if several of
+                // them depend on it, e.g. for `Result<Vec<T>, Box<T>>`; fusing
+                // these passes would require a trait for mapping over several
+                // type parameters at once, which existing implementations of
+                // `FuncMap` (such as the one for `Result`) don't provide
+                for (marker_idx, arg_type) in arg_types {
+                    if arg_type
+                        .dependency_on_type(&self.mapping.type_param.ident)
+                        .is_none()
+                    {
+                        continue;
+                    }
+
+                    let (inner_src_type, inner_dst_type) = self.subs_types(arg_type.clone());
+
+                    let make_type = |mapped_until_idx: usize| {
+                        let mapped_args = args.iter().cloned().scan(0, |marker_arg_idx, arg| {
+                            let mapped = match arg {
+                                GenericArgument::Type(ty) => {
+                                    GenericArgument::Type(if *marker_arg_idx >= mapped_until_idx {
+                                        self.subs_src_type(ty)
+                                    } else {
+                                        self.subs_dst_type(ty)
+                                    })
+                                }
+                                _ => arg,
+                            };
+
+                            if mapped.is_typish() {
+                                *marker_arg_idx += 1;
+                            }
+
+                            Some(mapped)
+                        });
+
+                        Type::Path(TypePath {
+                            qself: qself.clone(),
+                            path: Path {
+                                leading_colon: *leading_colon,
+                                segments: prefix
+                                    .iter()
+                                    .cloned()
+                                    .chain([PathSegment {
+                                        ident: ident.clone(),
+                                        arguments: PathArguments::AngleBracketed(
+                                            AngleBracketedGenericArguments {
+                                                args: mapped_args.collect(),
+                                                ..angle_bracketed
+                                            },
+                                        ),
+                                    }])
+                                    .collect(),
+                            },
+                        })
+                    };
+
+                    let src_type = make_type(marker_idx);
+                    let dst_type = make_type(marker_idx + 1);
+
+                    if needs_predicate {
+                        self.unique_predicates.add(parse_quote! {
+                            #src_type: #crate_path::#trait_ident<
+                                #inner_src_type,
+                                #inner_dst_type,
+                                #crate_path::#MARKER_TYPE_IDENT<#marker_idx>,
+                                #OUTPUT_TYPE_IDENT = #dst_type
+                            >
+                        })?;
+                    }
+
+                    self.type_param_checks.push(TypeParamCheck {
+                        ty: self.subs_src_type(ty.clone()),
+                        marker_idx,
+                        param: inner_src_type,
+                    });
+
+                    let closure = self.map_closure(arg_type)?;
+
+                    mappable = self.mapping.derivable.bind_expr(quote! {
+                        #crate_path::#trait_ident::<
+                            _,
+                            _,
+                            #crate_path::#MARKER_TYPE_IDENT::<#marker_idx>
+                        >::#fn_ident(#mappable, #closure)
+                    });
+                }
+
+                Ok(mappable)
+            }
+
+            Type::Tuple(type_tuple) => {
+                let mapped = type_tuple
+                    .elems
+                    .iter()
+                    .enumerate()
+                    .map(|(i, ty)| {
+                        let idx = Index::from(i);
+                        let mappable = quote!(#mappable.#idx);
+                        self.map(mappable, ty)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(quote!((#(#mapped),*)))
+            }
+
+            Type::BareFn(..) => Err(syn::Error::new_spanned(
+                ty,
+                "mapping over function type is not supported",
+            )
+            .into()),
+
+            Type::Ptr(..) => Err(syn::Error::new_spanned(
+                ty,
+                "mapping over pointer type is not supported",
+            )
+            .into()),
+
+            Type::Reference(..) => Err(syn::Error::new_spanned(
+                ty,
+                "mapping over reference type is not supported",
+            )
+            .into()),
+
+            Type::Slice(..) => {
+                Err(syn::Error::new_spanned(ty, "mapping over slice type is not supported").into())
+            }
+
+            Type::TraitObject(..) => Err(syn::Error::new_spanned(
+                ty,
+                "mapping over trait object type is not supported",
+            )
+            .into()),
+
+            _ => Err(syn::Error::new_spanned(ty, "mapping over this type is not supported").into()),
+        }
+    }
+
+    /// Adds predicates requiring the destination types of all type arguments
+    /// depending on the type parameter to outlive all lifetime arguments
+    ///
+    /// For the source type, these are usually implied by the type of the field
+    /// (e.g. `Bar<'a, T>` where `Bar` requires `T: 'a`), so they must hold for
+    /// the destination type as well for the mapped type to be well-formed.
+    fn add_outlives_predicates(
+        &mut self,
+        args: &Punctuated<GenericArgument, Token![,]>,
+    ) -> Result<(), Error> {
+        let lifetimes: Vec<_> = args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Lifetime(lifetime) if lifetime.ident != "static" => Some(lifetime),
+                _ => None,
+            })
+            .collect();
+
+        if lifetimes.is_empty() {
+            return Ok(());
+        }
+
+        for arg in args {
+            if let GenericArgument::Type(ty) = arg {
+                if ty
+                    .dependency_on_type(&self.mapping.type_param.ident)
+                    .is_some()
+                {
+                    let dst_type = self.subs_dst_type(ty.clone());
+                    self.unique_predicates
+                        .add(parse_quote!(#dst_type: #(#lifetimes)+*))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether `ident<args>` is `PhantomData` with a single type argument
+    ///
+    /// This is recognized by name, so this assumes that a type named
+    /// `PhantomData` is the one from the standard library.
+    fn is_phantom_data(ident: &Ident, args: &Punctuated<GenericArgument, Token![,]>) -> bool {
+        ident == "PhantomData"
+            && args.len() == 1
+            && matches!(args.first(), Some(GenericArgument::Type(..)))
+    }
+
+    /// Returns whether the type at `std_path` with `args` is `Vec`, `Box` or
+    /// `Option` with a single type argument
+    ///
+    /// These implement `FuncMap` and `TryFuncMap` without any further bounds,
+    /// so predicates for them would always hold and only lengthen the `where`
+    /// clause, especially for deeply nested types such as `Vec<Option<U>>`.
+    /// Only the predicates for the inner type `U` are required. Unlike
+    /// `PhantomData`, these are only recognized through fully qualified paths
+    /// such as `std::vec::Vec`, see [`qualified_std_path`], as the predicates
+    /// for other types named `Vec`, `Box` or `Option` may not hold.
+    fn is_unconditionally_mappable(
+        std_path: Option<&str>,
+        args: &Punctuated<GenericArgument, Token![,]>,
+    ) -> bool {
+        std_path.map_or(false, |std_path| {
+            [VEC_PATHS, BOX_PATHS, OPTION_PATHS]
+                .iter()
+                .any(|paths| paths.contains(&std_path))
+        }) && args.len() == 1
+            && matches!(args.first(), Some(GenericArgument::Type(..)))
+    }
+
+    /// Returns the key and value types if the type at `std_path` with `args` is
+    /// a map whose keys and values both depend on the type parameter
+    ///
+    /// Such maps are only recognized through fully qualified paths such as
+    /// `std::collections::BTreeMap`, see [`qualified_std_path`]. Other maps are
+    /// mapped over their keys and their values one after another through
+    /// their implementations of `FuncMap`.
+    fn map_entry_types<'a>(
+        &self,
+        std_path: Option<&str>,
+        args: &'a Punctuated<GenericArgument, Token![,]>,
+    ) -> Option<(&'a Type, &'a Type)> {
+        if !std_path.map_or(false, |std_path| MAP_PATHS.contains(&std_path)) {
+            return None;
+        }
+
+        let type_ident = &self.mapping.type_param.ident;
+        let mut typish_args = args.iter().filter(|arg| arg.is_typish());
+
+        match (typish_args.next(), typish_args.next()) {
+            (Some(GenericArgument::Type(key_type)), Some(GenericArgument::Type(value_type)))
+                if key_type.dependency_on_type(type_ident).is_some()
+                    && value_type.dependency_on_type(type_ident).is_some()
+                    && typish_args.all(|arg| match arg {
+                        GenericArgument::Type(ty) => ty.dependency_on_type(type_ident).is_none(),
+                        _ => true,
+                    }) =>
+            {
+                Some((key_type, value_type))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns how `Option` and `Box` are nested and the innermost type if the
+    /// type at `std_path` with `args` is `Option<Box<U>>` or `Box<Option<U>>`
+    ///
+    /// Both `Option` and `Box` are only recognized through fully qualified
+    /// paths such as `std::option::Option`, see [`qualified_std_path`], as the
+    /// generated code relies on their variants and constructors. Trait objects
+    /// are excluded as they cannot be moved out of the box.
+    fn option_box_types<'a>(
+        std_path: Option<&str>,
+        args: &'a Punctuated<GenericArgument, Token![,]>,
+    ) -> Option<(OptionBox<'a>, &'a Type)> {
+        fn single_type_arg(args: &Punctuated<GenericArgument, Token![,]>) -> Option<&Type> {
+            match args.first() {
+                Some(GenericArgument::Type(ty)) if args.len() == 1 => Some(ty),
+                _ => None,
+            }
+        }
+
+        fn type_with_single_type_arg<'a>(ty: &'a Type, paths: &[&str]) -> Option<&'a Type> {
+            match ty {
+                Type::Path(type_path)
+                    if qualified_std_path(type_path)
+                        .map_or(false, |std_path| paths.contains(&std_path.as_str())) =>
+                {
+                    match type_path.path.segments.last() {
+                        Some(PathSegment {
+                            arguments: PathArguments::AngleBracketed(angle_bracketed),
+                            ..
+                        }) => single_type_arg(&angle_bracketed.args),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+
+        let std_path = std_path?;
+        let arg_type = single_type_arg(args)?;
+
+        let (nesting, inner_ty) = if OPTION_PATHS.contains(&std_path) {
+            let inner_ty = type_with_single_type_arg(arg_type, BOX_PATHS)?;
+            (OptionBox::OptionOfBox(arg_type), inner_ty)
+        } else if BOX_PATHS.contains(&std_path) {
+            let inner_ty = type_with_single_type_arg(arg_type, OPTION_PATHS)?;
+            (OptionBox::BoxOfOption, inner_ty)
+        } else {
+            return None;
+        };
+
+        match inner_ty {
+            Type::TraitObject(..) => None,
+            _ => Some((nesting, inner_ty)),
+        }
+    }
+
+    /// Maps over `ty`, which is an `Option<Box<U>>` or a `Box<Option<U>>`, by
+    /// matching on the option directly
+    ///
+    /// This avoids going through the implementations for both `Option` and
+    /// `Box`, each of which would require a predicate and a closure.
+    fn map_option_box(
+        &mut self,
+        mappable: &TokenStream,
+        ty: &Type,
+        nesting: OptionBox<'_>,
+        inner_ty: &Type,
+    ) -> Result<TokenStream, Error> {
+        let value_ident = Ident::new("value", Span::mixed_site());
+
+        Ok(match nesting {
+            OptionBox::OptionOfBox(box_type) => {
+                let dst_box_type = self.subs_dst_type(box_type.clone());
+                let mapped = self.map(quote!((*#value_ident)), inner_ty)?;
+
+                quote! {
+                    match #mappable {
+                        ::core::option::Option::Some(#value_ident) => {
+                            ::core::option::Option::Some(<#dst_box_type>::new(#mapped))
+                        }
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                }
+            }
+
+            OptionBox::BoxOfOption => {
+                let dst_box_type = self.subs_dst_type(ty.clone());
+                let mapped = self.map(value_ident.to_token_stream(), inner_ty)?;
+
+                quote! {
+                    <#dst_box_type>::new(match *#mappable {
+                        ::core::option::Option::Some(#value_ident) => {
+                            ::core::option::Option::Some(#mapped)
+                        }
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    })
+                }
+            }
+        })
+    }
+
+    /// Returns the element type `U` if `ident` and `args` make up a
+    /// `Cow<'a, [U]>`
+    fn cow_slice_elem_type<'a>(
+        ident: &Ident,
+        args: &'a Punctuated<GenericArgument, Token![,]>,
+    ) -> Option<&'a Type> {
+        if ident != "Cow" {
+            return None;
+        }
+
+        let mut type_args = args.iter().filter(|arg| arg.is_typish());
+
+        match (type_args.next(), type_args.next()) {
+            (Some(GenericArgument::Type(Type::Slice(TypeSlice { elem, .. }))), None) => Some(elem),
+            _ => None,
+        }
+    }
+
+    /// Maps over `ty`, which is a `Cow<'a, [U]>`, through the implementation
+    /// for `Cow<'a, [A]>`, mapping every element of type `U`
+    ///
+    /// The slice type itself is not supported by the generic mapping of type
+    /// arguments, as it cannot be mapped on its own.
+    fn map_cow_slice(
+        &mut self,
+        mappable: &TokenStream,
+        ty: &Type,
+        elem_ty: &Type,
+    ) -> Result<TokenStream, Error> {
+        let crate_path = self.mapping.crate_path;
+        let trait_ident = self.mapping.derivable.trait_ident();
+        let fn_ident = self.mapping.derivable.fn_ident();
+
+        let (src_type, dst_type) = self.subs_types(ty.clone());
+        let (elem_src_type, elem_dst_type) = self.subs_types(elem_ty.clone());
+
+        self.unique_predicates.add(parse_quote! {
+            #src_type: #crate_path::#trait_ident<
+                #elem_src_type,
+                #elem_dst_type,
+                #crate_path::#MARKER_TYPE_IDENT<0>,
+                #OUTPUT_TYPE_IDENT = #dst_type
+            >
+        })?;
+
+        let closure = self.map_closure(elem_ty)?;
+
+        Ok(self.mapping.derivable.bind_expr(quote! {
+            #crate_path::#trait_ident::<
+                _,
+                _,
+                #crate_path::#MARKER_TYPE_IDENT::<0>
+            >::#fn_ident(#mappable, #closure)
+        }))
+    }
+
+    /// Maps over the keys and values of a map in a single pass
+    fn map_entries(
+        &mut self,
+        mappable: &TokenStream,
+        ty: &Type,
+        key_type: &Type,
+        value_type: &Type,
+    ) -> Result<TokenStream, Error> {
+        let crate_path = self.mapping.crate_path;
+        let trait_ident = self.mapping.derivable.entries_trait_ident();
+        let fn_ident = self.mapping.derivable.entries_fn_ident();
+
+        let (src_type, dst_type) = self.subs_types(ty.clone());
+        let (key_src_type, key_dst_type) = self.subs_types(key_type.clone());
+        let (value_src_type, value_dst_type) = self.subs_types(value_type.clone());
+
+        self.unique_predicates.add(parse_quote! {
+            #src_type: #crate_path::#trait_ident<
+                #key_src_type,
+                #key_dst_type,
+                #value_src_type,
+                #value_dst_type,
+                #OUTPUT_TYPE_IDENT = #dst_type
+            >
+        })?;
+
+        let key_arg = Ident::new("key", Span::mixed_site());
+        let value_arg = Ident::new("value", Span::mixed_site());
+        let mapped_key = self.map(key_arg.clone().into_token_stream(), key_type)?;
+        let mapped_value = self.map(value_arg.clone().into_token_stream(), value_type)?;
+        let expr = self
+            .mapping
+            .derivable
+            .unit_expr(quote!((#mapped_key, #mapped_value)));
+
+        Ok(self.mapping.derivable.bind_expr(quote! {
+            #crate_path::#trait_ident::#fn_ident(#mappable, |(#key_arg, #value_arg)| #expr)
+        }))
+    }
+
+    /// Maps a type containing a trait object through a wrapper type
+    ///
+    /// The wrapper is expected to be a tuple struct `Wrapper<T>` with a single
+    /// field of the mapped type that implements `FuncMap` over `T`.
+    fn map_via_wrapper(
+        &mut self,
+        mappable: &TokenStream,
+        wrapper: &Path,
+    ) -> Result<TokenStream, Error> {
+        let crate_path = self.mapping.crate_path;
+        let trait_ident = self.mapping.derivable.trait_ident();
+        let fn_ident = self.mapping.derivable.fn_ident();
+        let src_type_ident = self.mapping.src_type_ident;
+        let dst_type_ident = self.mapping.dst_type_ident;
+
+        self.unique_predicates.add(parse_quote! {
+            #wrapper<#src_type_ident>: #crate_path::#trait_ident<
+                #src_type_ident,
+                #dst_type_ident,
+                #OUTPUT_TYPE_IDENT = #wrapper<#dst_type_ident>
+            >
+        })?;
+
+        let closure = self.map_closure(&self.mapping.type_param.ident.clone().into_type())?;
+        let value_ident = Ident::new("value", Span::mixed_site());
+        let mapped = self.mapping.derivable.bind_expr(quote! {
+            #crate_path::#trait_ident::#fn_ident(#wrapper(#mappable), #closure)
+        });
+
+        Ok(quote!({
+            let #wrapper(#value_ident) = #mapped;
+            #value_ident
+        }))
+    }
+
+    fn map_closure(&mut self, ty: &Type) -> Result<TokenStream, Error> {
+        let closure_arg = Ident::new("value", Span::mixed_site());
+        let mapped = self.map(closure_arg.clone().into_token_stream(), ty)?;
+        let expr = self.mapping.derivable.unit_expr(mapped);
+        Ok(quote!(|#closure_arg| #expr))
+    }
+
+    fn subs_src_type(&self, ty: Type) -> Type {
+        ty.subs_type(&self.mapping.type_param.ident, self.mapping.src_type_ident)
+    }
+
+    fn subs_dst_type(&self, ty: Type) -> Type {
+        ty.subs_type(&self.mapping.type_param.ident, self.mapping.dst_type_ident)
+    }
+
+    fn subs_types(&self, ty: Type) -> (Type, Type) {
+        (self.subs_src_type(ty.clone()), self.subs_dst_type(ty))
+    }
+}
+
+/// Nesting of `Option` and `Box` handled by [`Mapper::map_option_box`]
+#[derive(Copy, Clone, Debug)]
+enum OptionBox<'ast> {
+    /// `Option<Box<U>>`, holding the type `Box<U>`
+    OptionOfBox(&'ast Type),
+
+    /// `Box<Option<U>>`
+    BoxOfOption,
+}
+
+/// Visitor collecting the lifetimes and referenced types of all references
+/// with explicit lifetimes within a type
+///
+/// References with higher-ranked lifetimes (introduced by `for<...>`) are
+/// skipped as they cannot be required to outlive anything. Referenced types
+/// mentioning higher-ranked lifetimes are replaced by their
+/// [outlives components](ReferenceCollector::outlives_components).
+#[derive(Debug, Default)]
+struct ReferenceCollector<'ast> {
+    references: Vec<(&'ast Lifetime, &'ast Type)>,
+    bound_lifetimes: Vec<&'ast Lifetime>,
+}
+
+impl<'ast> ReferenceCollector<'ast> {
+    /// Returns the collected references, skipping those with higher-ranked
+    /// lifetimes
+    fn into_references(self) -> Vec<(&'ast Lifetime, &'ast Type)> {
+        self.references
+            .iter()
+            .filter(|(lifetime, _)| !self.bound_lifetimes.contains(lifetime))
+            .flat_map(|&(lifetime, elem)| {
+                self.outlives_components(elem)
+                    .into_iter()
+                    .map(move |component| (lifetime, component))
+            })
+            .collect()
+    }
+
+    /// Returns the largest types within `ty` that don't mention any of the
+    /// collected higher-ranked lifetimes
+    ///
+    /// `ty` outlives a lifetime if and only if all of these components do. A
+    /// predicate like `for<'a> fn(&'a T): 'b` cannot be used by the compiler
+    /// and would even refer to an undeclared lifetime if `ty` is only part of
+    /// the type introducing `'a`, so `T: 'b` has to be required instead.
+    fn outlives_components(&self, ty: &'ast Type) -> Vec<&'ast Type> {
+        let mut component_collector = OutlivesComponentCollector {
+            bound_lifetimes: &self.bound_lifetimes,
+            components: Vec::new(),
+        };
+
+        component_collector.visit_type(ty);
+        component_collector.components
+    }
+}
+
+impl<'ast> Visit<'ast> for ReferenceCollector<'ast> {
+    fn visit_bound_lifetimes(&mut self, bound_lifetimes: &'ast BoundLifetimes) {
+        self.bound_lifetimes
+            .extend(
+                bound_lifetimes
+                    .lifetimes
+                    .iter()
+                    .filter_map(|param| match param {
+                        GenericParam::Lifetime(lifetime_param) => Some(&lifetime_param.lifetime),
+                        _ => None,
+                    }),
+            );
+
+        visit::visit_bound_lifetimes(self, bound_lifetimes);
+    }
+
+    fn visit_type_reference(&mut self, type_reference: &'ast TypeReference) {
+        if let Some(lifetime) = &type_reference.lifetime {
+            self.references.push((lifetime, &type_reference.elem));
+        }
+
+        visit::visit_type_reference(self, type_reference);
+    }
+}
+
+/// Visitor collecting the
+/// [outlives components](ReferenceCollector::outlives_components) of a type
+#[derive(Debug)]
+struct OutlivesComponentCollector<'ast, 'a> {
+    bound_lifetimes: &'a [&'ast Lifetime],
+    components: Vec<&'ast Type>,
+}
+
+impl<'ast> Visit<'ast> for OutlivesComponentCollector<'ast, '_> {
+    fn visit_type(&mut self, ty: &'ast Type) {
+        let mut lifetime_finder = LifetimeFinder {
+            lifetimes: self.bound_lifetimes,
+            found: false,
+        };
+
+        lifetime_finder.visit_type(ty);
+
+        if lifetime_finder.found {
+            visit::visit_type(self, ty);
+        } else {
+            self.components.push(ty);
+        }
+    }
+}
+
+/// Visitor determining whether any of the given lifetimes occurs within an AST
+/// node
+#[derive(Debug)]
+struct LifetimeFinder<'ast, 'a> {
+    lifetimes: &'a [&'ast Lifetime],
+    found: bool,
+}
+
+impl Visit<'_> for LifetimeFinder<'_, '_> {
+    fn visit_lifetime(&mut self, lifetime: &Lifetime) {
+        if self.lifetimes.contains(&lifetime) {
+            self.found = true;
+        }
+    }
+}
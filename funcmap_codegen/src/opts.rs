@@ -0,0 +1,1311 @@
+//! Functionality for parsing options configured via `#[funcmap]` helper
+//! attributes
+
+use crate::ident::ATTR_IDENT;
+use crate::result::{self, Error};
+
+use std::vec;
+
+use indexmap::IndexSet;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream, Parser},
+    punctuated::Punctuated,
+    Attribute, ConstParam, GenericParam, Lifetime, LifetimeParam, LitStr, Meta, Path,
+    PredicateType, Token, TypeParam, WherePredicate,
+};
+
+/// Custom keywords
+mod kw {
+    syn::custom_keyword!(allow);
+    syn::custom_keyword!(assert_auto_traits);
+    syn::custom_keyword!(assume_no_drop);
+    syn::custom_keyword!(canonical);
+    syn::custom_keyword!(cfg);
+    syn::custom_keyword!(deref);
+    syn::custom_keyword!(drop_bounds);
+    syn::custom_keyword!(dst);
+    syn::custom_keyword!(dyn_as);
+    syn::custom_keyword!(dyn_body);
+    syn::custom_keyword!(extra_bounds);
+    syn::custom_keyword!(free_fn);
+    syn::custom_keyword!(from);
+    syn::custom_keyword!(idents);
+    syn::custom_keyword!(infallible_via_try);
+    syn::custom_keyword!(inline);
+    syn::custom_keyword!(opaque_via);
+    syn::custom_keyword!(params);
+    syn::custom_keyword!(rebuild);
+    syn::custom_keyword!(skip_params);
+    syn::custom_keyword!(src);
+    syn::custom_keyword!(strict);
+    syn::custom_keyword!(traced);
+    syn::custom_keyword!(validate);
+    syn::custom_keyword!(with);
+}
+
+/// Options for `funcmap` derive macros
+#[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub(crate) struct FuncMapOpts {
+    /// Paths to the lints to allow within generated code in addition to the
+    /// ones allowed by default
+    ///
+    /// Configured via `#[funcmap(allow(...))]`
+    pub(crate) allow: Vec<Path>,
+
+    /// Paths to the auto traits to assert are preserved by the mapping
+    ///
+    /// Configured via `#[funcmap(assert_auto_traits(...))]`
+    /// This is only used by the `FuncMap` derive macro.
+    pub(crate) assert_auto_traits: Vec<Path>,
+
+    /// Whether to skip asserting that the type doesn't implement `Drop`
+    ///
+    /// Configured via `#[funcmap(assume_no_drop)]`
+    pub(crate) assume_no_drop: bool,
+
+    /// Whether to bring the predicates and bounds within the generated code
+    /// into a canonical order
+    ///
+    /// Configured via `#[funcmap(canonical)]`
+    /// This is only used by the `FuncMap` and `TryFuncMap` derive macros.
+    pub(crate) canonical: bool,
+
+    /// Predicates under which to generate the implementations
+    ///
+    /// Configured via `#[funcmap(cfg(...))]`
+    pub(crate) cfg: Vec<Meta>,
+
+    /// Path to the `funcmap` crate
+    ///
+    /// Configured via `#[funcmap(crate = "...")]`
+    pub(crate) crate_path: Option<Path>,
+
+    /// Bounds to omit from the generated implementations
+    ///
+    /// Configured via `#[funcmap(drop_bounds(...))]`
+    /// This is only used by the `FuncMap` and `TryFuncMap` derive macros.
+    pub(crate) drop_bounds: Vec<PredicateType>,
+
+    /// Path to the wrapper type to map trait objects through
+    ///
+    /// Configured via `#[funcmap(dyn_as = "...")]`
+    pub(crate) dyn_as: Option<Path>,
+
+    /// Whether to generate the body of the mapping functions as a non-generic
+    /// inner function taking the closure as a trait object
+    ///
+    /// Configured via `#[funcmap(dyn_body)]`
+    /// This is only used by the `FuncMap` and `TryFuncMap` derive macros.
+    pub(crate) dyn_body: bool,
+
+    /// Additional predicates to add to every generated implementation
+    ///
+    /// Configured via `#[funcmap(extra_bounds = "...")]`
+    pub(crate) extra_bounds: Vec<WherePredicate>,
+
+    /// Name of a free function to generate in addition to the implementation
+    ///
+    /// Configured via `#[funcmap(free_fn = "...")]`
+    /// This is only used by the `FuncMap` derive macro.
+    pub(crate) free_fn: Option<Ident>,
+
+    /// Paths to the types to generate `From` implementations for
+    ///
+    /// Configured via `#[funcmap(from = "...")]`
+    /// This is only used by the `StructuralFrom` derive macro.
+    pub(crate) from_paths: Vec<Path>,
+
+    /// Identifier to use for the source type of the mapping
+    ///
+    /// Configured via `#[funcmap(idents(src = "..."))]`
+    pub(crate) src_type_ident: Option<Ident>,
+
+    /// Identifier to use for the destination type of the mapping
+    ///
+    /// Configured via `#[funcmap(idents(dst = "..."))]`
+    pub(crate) dst_type_ident: Option<Ident>,
+
+    /// Whether to derive `FuncMap` via `TryFuncMap` in addition to `TryFuncMap`
+    ///
+    /// Configured via `#[funcmap(infallible_via_try)]`
+    pub(crate) infallible_via_try: bool,
+
+    /// Inline hint to put on the generated mapping functions
+    ///
+    /// Configured via `#[funcmap(inline = "...")]`
+    pub(crate) inline: Option<Inline>,
+
+    /// Set of parameters for which to generate an implementation
+    ///
+    /// Configured via `#[funcmap(params(...))]`
+    /// This uses [`IndexSet`] rather than e.g.
+    /// [`HashSet`](std::collections::HashSet) to maintain a consistent order
+    /// and make error messages of the derive macros deterministic.
+    pub(crate) params: IndexSet<Param>,
+
+    /// Path to a function to build the mapped value from its mapped fields
+    ///
+    /// Configured via `#[funcmap(rebuild = "...")]`
+    pub(crate) rebuild: Option<Path>,
+
+    /// Set of parameters for which not to generate an implementation
+    ///
+    /// Configured via `#[funcmap(skip_params(...))]`
+    /// This cannot be combined with `params`.
+    pub(crate) skip_params: IndexSet<Param>,
+
+    /// Whether to check at derive time that local field types derive the same
+    /// trait
+    ///
+    /// Configured via `#[funcmap(strict)]`
+    /// This is only used by the `FuncMap` and `TryFuncMap` derive macros.
+    pub(crate) strict: bool,
+
+    /// Whether to enter a `tracing` span for every mapped field
+    ///
+    /// Configured via `#[funcmap(traced)]`
+    /// This is only used by the `TryFuncMap` derive macro.
+    pub(crate) traced: bool,
+
+    /// Path to a function to pass the mapped value through before returning it
+    ///
+    /// Configured via `#[funcmap(validate = "...")]`
+    pub(crate) validate: Option<Path>,
+
+    /// Path to a function to decompose the value into its fields
+    ///
+    /// Configured via `#[funcmap(with = "...")]`
+    pub(crate) with: Option<Path>,
+}
+
+impl TryFrom<Vec<Attribute>> for FuncMapOpts {
+    type Error = Error;
+
+    fn try_from(attrs: Vec<Attribute>) -> Result<Self, Self::Error> {
+        let mut allow: Vec<Path> = Vec::new();
+        let mut assert_auto_traits: Vec<Path> = Vec::new();
+        let mut assume_no_drop = false;
+        let mut canonical = false;
+        let mut cfg = Vec::new();
+        let mut crate_path = None;
+        let mut drop_bounds = Vec::new();
+        let mut dyn_as = None;
+        let mut dyn_body = false;
+        let mut extra_bounds = Vec::new();
+        let mut free_fn = None;
+        let mut from_paths: Vec<Path> = Vec::new();
+        let mut src_type_ident = None;
+        let mut dst_type_ident = None;
+        let mut infallible_via_try = false;
+        let mut inline = None;
+        let mut params = IndexSet::new();
+        let mut rebuild = None;
+        let mut skip_params = IndexSet::new();
+        let mut strict = false;
+        let mut traced = false;
+        let mut validate = None;
+        let mut with = None;
+        let mut result_builder = result::Builder::new();
+
+        for args_result in attrs
+            .into_iter()
+            .filter(|attr| attr.path().is_ident(&ATTR_IDENT))
+            .map(TryInto::<Args>::try_into)
+        {
+            match args_result {
+                Ok(args) => {
+                    for arg in args {
+                        match arg {
+                            Arg::Allow(ArgAllow(values)) => {
+                                for value in values {
+                                    if allow.contains(&value) {
+                                        result_builder.add_err(syn::Error::new_spanned(
+                                            value,
+                                            "duplicate lint",
+                                        ));
+                                    } else {
+                                        allow.push(value);
+                                    }
+                                }
+                            }
+
+                            Arg::AssertAutoTraits(ArgAssertAutoTraits(values)) => {
+                                for value in values {
+                                    if assert_auto_traits.contains(&value) {
+                                        result_builder.add_err(syn::Error::new_spanned(
+                                            value,
+                                            "duplicate auto trait",
+                                        ));
+                                    } else {
+                                        assert_auto_traits.push(value);
+                                    }
+                                }
+                            }
+
+                            Arg::AssumeNoDrop(..) if !assume_no_drop => {
+                                assume_no_drop = true;
+                            }
+
+                            Arg::AssumeNoDrop(ArgAssumeNoDrop(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate assumption of no `Drop` implementation",
+                                ));
+                            }
+
+                            Arg::Canonical(..) if !canonical => {
+                                canonical = true;
+                            }
+
+                            Arg::Canonical(ArgCanonical(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate canonical order",
+                                ));
+                            }
+
+                            Arg::Cfg(ArgCfg(value)) => {
+                                cfg.push(value);
+                            }
+
+                            Arg::Crate(ArgCrate(value)) if crate_path.is_none() => {
+                                crate_path = Some(value);
+                            }
+
+                            Arg::Crate(ArgCrate(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate crate path",
+                                ));
+                            }
+
+                            Arg::DynAs(ArgDynAs(value)) if dyn_as.is_none() => {
+                                dyn_as = Some(value);
+                            }
+
+                            Arg::DynAs(ArgDynAs(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate trait object wrapper",
+                                ));
+                            }
+
+                            Arg::DropBounds(ArgDropBounds(values)) => {
+                                drop_bounds.extend(values);
+                            }
+
+                            Arg::DynBody(..) if !dyn_body => {
+                                dyn_body = true;
+                            }
+
+                            Arg::DynBody(ArgDynBody(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate dynamic dispatch body",
+                                ));
+                            }
+
+                            Arg::ExtraBounds(ArgExtraBounds(values)) => {
+                                extra_bounds.extend(values);
+                            }
+
+                            Arg::FreeFn(ArgFreeFn(value)) if free_fn.is_none() => {
+                                free_fn = Some(value);
+                            }
+
+                            Arg::FreeFn(ArgFreeFn(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate free function name",
+                                ));
+                            }
+
+                            Arg::From(ArgFrom(value)) if from_paths.contains(&value) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate source type",
+                                ));
+                            }
+
+                            Arg::From(ArgFrom(value)) => {
+                                from_paths.push(value);
+                            }
+
+                            Arg::Idents(ArgIdents(values)) => {
+                                for value in values {
+                                    match value {
+                                        TypeIdent::Src(ident) if src_type_ident.is_none() => {
+                                            src_type_ident = Some(ident);
+                                        }
+
+                                        TypeIdent::Src(ident) => {
+                                            result_builder.add_err(syn::Error::new_spanned(
+                                                ident,
+                                                "duplicate source type identifier",
+                                            ));
+                                        }
+
+                                        TypeIdent::Dst(ident) if dst_type_ident.is_none() => {
+                                            dst_type_ident = Some(ident);
+                                        }
+
+                                        TypeIdent::Dst(ident) => {
+                                            result_builder.add_err(syn::Error::new_spanned(
+                                                ident,
+                                                "duplicate destination type identifier",
+                                            ));
+                                        }
+                                    }
+                                }
+                            }
+
+                            Arg::InfallibleViaTry(..) if !infallible_via_try => {
+                                infallible_via_try = true;
+                            }
+
+                            Arg::InfallibleViaTry(ArgInfallibleViaTry(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate derivation of `FuncMap` via `TryFuncMap`",
+                                ));
+                            }
+
+                            Arg::Inline(ArgInline(_, value)) if inline.is_none() => {
+                                inline = Some(value);
+                            }
+
+                            Arg::Inline(ArgInline(lit, _)) => {
+                                result_builder
+                                    .add_err(syn::Error::new_spanned(lit, "duplicate inline hint"));
+                            }
+
+                            Arg::Params(ArgParams(values)) => {
+                                for value in values {
+                                    if params.contains(&value) {
+                                        result_builder.add_err(syn::Error::new_spanned(
+                                            value,
+                                            "duplicate parameter",
+                                        ));
+                                    } else {
+                                        params.insert(value);
+                                    }
+                                }
+                            }
+
+                            Arg::Rebuild(ArgRebuild(value)) if rebuild.is_none() => {
+                                rebuild = Some(value);
+                            }
+
+                            Arg::Rebuild(ArgRebuild(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate rebuild function",
+                                ));
+                            }
+
+                            Arg::SkipParams(ArgSkipParams(values)) => {
+                                for value in values {
+                                    if skip_params.contains(&value) {
+                                        result_builder.add_err(syn::Error::new_spanned(
+                                            value,
+                                            "duplicate parameter",
+                                        ));
+                                    } else {
+                                        skip_params.insert(value);
+                                    }
+                                }
+                            }
+
+                            Arg::Strict(..) if !strict => {
+                                strict = true;
+                            }
+
+                            Arg::Strict(ArgStrict(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate strict mode",
+                                ));
+                            }
+
+                            Arg::Traced(..) if !traced => {
+                                traced = true;
+                            }
+
+                            Arg::Traced(ArgTraced(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate tracing of fields",
+                                ));
+                            }
+
+                            Arg::Validate(ArgValidate(value)) if validate.is_none() => {
+                                validate = Some(value);
+                            }
+
+                            Arg::Validate(ArgValidate(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate validation function",
+                                ));
+                            }
+
+                            Arg::With(ArgWith(value)) if with.is_none() => {
+                                with = Some(value);
+                            }
+
+                            Arg::With(ArgWith(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate decomposition function",
+                                ));
+                            }
+                        }
+                    }
+                }
+
+                Err(err) => {
+                    result_builder.add_err(err);
+                }
+            }
+        }
+
+        // a parameter listed in both `params` and `skip_params` gets a
+        // dedicated error, otherwise the first skipped parameter stands for
+        // the combination of both
+        if !params.is_empty() {
+            let conflicting: Vec<_> = skip_params
+                .iter()
+                .filter(|param| params.contains(*param))
+                .collect();
+
+            if conflicting.is_empty() {
+                if let Some(param) = skip_params.first() {
+                    result_builder.add_err(syn::Error::new_spanned(
+                        param,
+                        "`skip_params` cannot be combined with `params`",
+                    ));
+                }
+            } else {
+                for param in conflicting {
+                    result_builder.add_err(syn::Error::new_spanned(
+                        param,
+                        "parameter cannot be listed in both `params` and `skip_params`",
+                    ));
+                }
+            }
+        }
+
+        result_builder.err_or(Self {
+            allow,
+            assert_auto_traits,
+            assume_no_drop,
+            canonical,
+            cfg,
+            crate_path,
+            drop_bounds,
+            dyn_as,
+            dyn_body,
+            extra_bounds,
+            free_fn,
+            from_paths,
+            src_type_ident,
+            dst_type_ident,
+            infallible_via_try,
+            inline,
+            params,
+            rebuild,
+            skip_params,
+            strict,
+            traced,
+            validate,
+            with,
+        })
+    }
+}
+
+/// Options for `funcmap` derive macros configured on a field
+#[derive(Debug)]
+pub(crate) struct FieldOpts {
+    /// Path to the function to rebuild the field from its mapped target when
+    /// mapping through `DerefMut`
+    ///
+    /// Configured via `#[funcmap(deref, rebuild = "...")]`
+    pub(crate) deref_rebuild: Option<Path>,
+
+    /// Path to the function to map the field with
+    ///
+    /// Configured via `#[funcmap(opaque_via = "...")]`
+    pub(crate) opaque_via: Option<Path>,
+}
+
+impl TryFrom<Vec<Attribute>> for FieldOpts {
+    type Error = Error;
+
+    fn try_from(attrs: Vec<Attribute>) -> Result<Self, Self::Error> {
+        let mut deref = None;
+
+        #[cfg(feature = "nightly")]
+        let mut opaque_via = None;
+
+        #[cfg(not(feature = "nightly"))]
+        let opaque_via = None;
+
+        let mut rebuild = None;
+        let mut result_builder = result::Builder::new();
+
+        for attr in attrs
+            .into_iter()
+            .filter(|attr| attr.path().is_ident(&ATTR_IDENT))
+        {
+            match attr.parse_args_with(Punctuated::<FieldArg, Token![,]>::parse_terminated) {
+                Ok(args) if args.is_empty() => {
+                    result_builder.add_err(syn::Error::new_spanned(
+                        attr,
+                        "expected at least one argument",
+                    ));
+                }
+
+                Ok(args) => {
+                    for arg in args {
+                        match arg {
+                            FieldArg::Deref(ArgDeref(value)) if deref.is_none() => {
+                                deref = Some(value);
+                            }
+
+                            FieldArg::Deref(ArgDeref(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate mapping through `DerefMut`",
+                                ));
+                            }
+
+                            #[cfg(feature = "nightly")]
+                            FieldArg::OpaqueVia(ArgOpaqueVia(value)) if opaque_via.is_none() => {
+                                opaque_via = Some(value);
+                            }
+
+                            #[cfg(feature = "nightly")]
+                            FieldArg::OpaqueVia(ArgOpaqueVia(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate mapping function",
+                                ));
+                            }
+
+                            FieldArg::Rebuild(ArgRebuild(value)) if rebuild.is_none() => {
+                                rebuild = Some(value);
+                            }
+
+                            FieldArg::Rebuild(ArgRebuild(value)) => {
+                                result_builder.add_err(syn::Error::new_spanned(
+                                    value,
+                                    "duplicate rebuild function",
+                                ));
+                            }
+                        }
+                    }
+                }
+
+                Err(err) => {
+                    result_builder.add_err(err);
+                }
+            }
+        }
+
+        let deref_rebuild = match (deref, rebuild) {
+            (Some(_), Some(rebuild)) => {
+                if let Some(opaque_via) = &opaque_via {
+                    result_builder.add_err(syn::Error::new_spanned(
+                        opaque_via,
+                        "mapping function cannot be combined with mapping through `DerefMut`",
+                    ));
+                }
+
+                Some(rebuild)
+            }
+            (Some(deref), None) => {
+                result_builder.add_err(syn::Error::new_spanned(
+                    deref,
+                    "expected rebuild function to be configured along with mapping through `DerefMut`",
+                ));
+
+                None
+            }
+            (None, Some(rebuild)) => {
+                result_builder.add_err(syn::Error::new_spanned(
+                    rebuild,
+                    "expected mapping through `DerefMut` to be configured along with rebuild function",
+                ));
+
+                None
+            }
+            (None, None) => None,
+        };
+
+        result_builder.err_or(Self {
+            deref_rebuild,
+            opaque_via,
+        })
+    }
+}
+
+/// A single argument of a `#[funcmap]` helper attribute on a field
+#[derive(Debug)]
+enum FieldArg {
+    Deref(ArgDeref),
+    #[cfg(feature = "nightly")]
+    OpaqueVia(ArgOpaqueVia),
+    Rebuild(ArgRebuild),
+}
+
+impl Parse for FieldArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::deref) {
+            return Ok(Self::Deref(input.call(ArgDeref::parse)?));
+        }
+
+        #[cfg(feature = "nightly")]
+        if input.peek(kw::opaque_via) {
+            return Ok(Self::OpaqueVia(input.call(ArgOpaqueVia::parse)?));
+        }
+
+        if input.peek(kw::rebuild) {
+            return Ok(Self::Rebuild(input.call(ArgRebuild::parse)?));
+        }
+
+        #[cfg(feature = "nightly")]
+        let msg = "expected one of these arguments: `deref`, `opaque_via`, `rebuild`";
+
+        #[cfg(not(feature = "nightly"))]
+        let msg = "expected one of these arguments: `deref`, `rebuild`";
+
+        Err(input.error(msg))
+    }
+}
+
+/// The arguments of a `#[funcmap]` helper attribute
+#[derive(Debug)]
+struct Args(Vec<Arg>);
+
+impl TryFrom<Attribute> for Args {
+    type Error = Error;
+
+    fn try_from(attr: Attribute) -> Result<Self, Self::Error> {
+        Ok(attr.parse_args()?)
+    }
+}
+
+impl IntoIterator for Args {
+    type Item = Arg;
+    type IntoIter = vec::IntoIter<Arg>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let args: Vec<_> = input
+            .call(Punctuated::<_, Token![,]>::parse_terminated)?
+            .into_iter()
+            .collect();
+
+        if args.is_empty() {
+            Err(input.error("expected at least one argument"))
+        } else {
+            Ok(Self(args))
+        }
+    }
+}
+
+/// An argument of a `#[funcmap]` helper attribute
+#[derive(Debug)]
+enum Arg {
+    Allow(ArgAllow),
+    AssertAutoTraits(ArgAssertAutoTraits),
+    AssumeNoDrop(ArgAssumeNoDrop),
+    Canonical(ArgCanonical),
+    Cfg(ArgCfg),
+    Crate(ArgCrate),
+    DropBounds(ArgDropBounds),
+    DynAs(ArgDynAs),
+    DynBody(ArgDynBody),
+    ExtraBounds(ArgExtraBounds),
+    FreeFn(ArgFreeFn),
+    From(ArgFrom),
+    Idents(ArgIdents),
+    InfallibleViaTry(ArgInfallibleViaTry),
+    Inline(ArgInline),
+    Params(ArgParams),
+    Rebuild(ArgRebuild),
+    SkipParams(ArgSkipParams),
+    Strict(ArgStrict),
+    Traced(ArgTraced),
+    Validate(ArgValidate),
+    With(ArgWith),
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::allow) {
+            Ok(Self::Allow(input.call(ArgAllow::parse)?))
+        } else if input.peek(kw::assert_auto_traits) {
+            Ok(Self::AssertAutoTraits(
+                input.call(ArgAssertAutoTraits::parse)?,
+            ))
+        } else if input.peek(kw::assume_no_drop) {
+            Ok(Self::AssumeNoDrop(input.call(ArgAssumeNoDrop::parse)?))
+        } else if input.peek(kw::canonical) {
+            Ok(Self::Canonical(input.call(ArgCanonical::parse)?))
+        } else if input.peek(kw::cfg) {
+            Ok(Self::Cfg(input.call(ArgCfg::parse)?))
+        } else if input.peek(Token![crate]) {
+            Ok(Self::Crate(input.call(ArgCrate::parse)?))
+        } else if input.peek(kw::drop_bounds) {
+            Ok(Self::DropBounds(input.call(ArgDropBounds::parse)?))
+        } else if input.peek(kw::dyn_as) {
+            Ok(Self::DynAs(input.call(ArgDynAs::parse)?))
+        } else if input.peek(kw::dyn_body) {
+            Ok(Self::DynBody(input.call(ArgDynBody::parse)?))
+        } else if input.peek(kw::extra_bounds) {
+            Ok(Self::ExtraBounds(input.call(ArgExtraBounds::parse)?))
+        } else if input.peek(kw::free_fn) {
+            Ok(Self::FreeFn(input.call(ArgFreeFn::parse)?))
+        } else if input.peek(kw::from) {
+            Ok(Self::From(input.call(ArgFrom::parse)?))
+        } else if input.peek(kw::idents) {
+            Ok(Self::Idents(input.call(ArgIdents::parse)?))
+        } else if input.peek(kw::infallible_via_try) {
+            Ok(Self::InfallibleViaTry(
+                input.call(ArgInfallibleViaTry::parse)?,
+            ))
+        } else if input.peek(kw::inline) {
+            Ok(Self::Inline(input.call(ArgInline::parse)?))
+        } else if input.peek(kw::params) {
+            Ok(Self::Params(input.call(ArgParams::parse)?))
+        } else if input.peek(kw::rebuild) {
+            Ok(Self::Rebuild(input.call(ArgRebuild::parse)?))
+        } else if input.peek(kw::skip_params) {
+            Ok(Self::SkipParams(input.call(ArgSkipParams::parse)?))
+        } else if input.peek(kw::strict) {
+            Ok(Self::Strict(input.call(ArgStrict::parse)?))
+        } else if input.peek(kw::traced) {
+            Ok(Self::Traced(input.call(ArgTraced::parse)?))
+        } else if input.peek(kw::validate) {
+            Ok(Self::Validate(input.call(ArgValidate::parse)?))
+        } else if input.peek(kw::with) {
+            Ok(Self::With(input.call(ArgWith::parse)?))
+        } else {
+            Err(input.error(
+                "expected one of these arguments: `allow`, `assert_auto_traits`, `assume_no_drop`, `canonical`, `cfg`, `crate`, `drop_bounds`, `dyn_as`, `dyn_body`, `extra_bounds`, `free_fn`, `from`, `idents`, `infallible_via_try`, `inline`, `params`, `rebuild`, `skip_params`, `strict`, `traced`, `validate`, `with`",
+            ))
+        }
+    }
+}
+
+/// An `allow(...)` argument
+#[derive(Debug)]
+struct ArgAllow(Vec<Path>);
+
+impl Parse for ArgAllow {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::allow>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let paths = content.call(Punctuated::<Path, Token![,]>::parse_terminated)?;
+
+        if paths.is_empty() {
+            Err(content.error("expected path to lint"))
+        } else {
+            Ok(Self(paths.into_iter().collect()))
+        }
+    }
+}
+
+/// An `assert_auto_traits(...)` argument
+#[derive(Debug)]
+struct ArgAssertAutoTraits(Vec<Path>);
+
+impl Parse for ArgAssertAutoTraits {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::assert_auto_traits>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let paths = content.call(Punctuated::<Path, Token![,]>::parse_terminated)?;
+
+        if paths.is_empty() {
+            Err(content.error("expected path to auto trait"))
+        } else {
+            Ok(Self(paths.into_iter().collect()))
+        }
+    }
+}
+
+/// An `assume_no_drop` argument
+#[derive(Debug)]
+struct ArgAssumeNoDrop(kw::assume_no_drop);
+
+impl Parse for ArgAssumeNoDrop {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self(input.parse::<kw::assume_no_drop>()?))
+    }
+}
+
+/// A `canonical` argument
+#[derive(Debug)]
+struct ArgCanonical(kw::canonical);
+
+impl Parse for ArgCanonical {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self(input.parse::<kw::canonical>()?))
+    }
+}
+
+/// A `cfg(...)` argument
+#[derive(Debug)]
+struct ArgCfg(Meta);
+
+impl Parse for ArgCfg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::cfg>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let predicate = content.parse()?;
+
+        if content.is_empty() {
+            Ok(Self(predicate))
+        } else {
+            Err(content.error("expected a single configuration predicate"))
+        }
+    }
+}
+
+/// A `crate = "..."` argument
+#[derive(Debug)]
+struct ArgCrate(Path);
+
+impl Parse for ArgCrate {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![crate]>()?;
+        input.parse::<Token![=]>()?;
+        Ok(Self(input.parse::<LitStr>()?.parse_with(PathParser)?))
+    }
+}
+
+/// A `deref` argument
+#[derive(Debug)]
+struct ArgDeref(kw::deref);
+
+impl Parse for ArgDeref {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self(input.parse::<kw::deref>()?))
+    }
+}
+
+/// A `drop_bounds(...)` argument
+#[derive(Debug)]
+struct ArgDropBounds(Vec<PredicateType>);
+
+impl Parse for ArgDropBounds {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::drop_bounds>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let predicates = content.call(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+
+        if predicates.is_empty() {
+            return Err(content.error("expected bound"));
+        }
+
+        predicates
+            .into_iter()
+            .map(|predicate| match predicate {
+                WherePredicate::Type(predicate_type) => Ok(predicate_type),
+                predicate => Err(syn::Error::new_spanned(
+                    predicate,
+                    "expected bound on a type",
+                )),
+            })
+            .collect::<syn::Result<_>>()
+            .map(Self)
+    }
+}
+
+/// A `dyn_as = "..."` argument
+#[derive(Debug)]
+struct ArgDynAs(Path);
+
+impl Parse for ArgDynAs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::dyn_as>()?;
+        input.parse::<Token![=]>()?;
+        Ok(Self(input.parse::<LitStr>()?.parse_with(PathParser)?))
+    }
+}
+
+/// A `dyn_body` argument
+#[derive(Debug)]
+struct ArgDynBody(kw::dyn_body);
+
+impl Parse for ArgDynBody {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self(input.parse::<kw::dyn_body>()?))
+    }
+}
+
+/// An `extra_bounds = "..."` argument
+#[derive(Debug)]
+struct ArgExtraBounds(Vec<WherePredicate>);
+
+impl Parse for ArgExtraBounds {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::extra_bounds>()?;
+        input.parse::<Token![=]>()?;
+
+        let predicates = input
+            .parse::<LitStr>()?
+            .parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+
+        Ok(Self(predicates.into_iter().collect()))
+    }
+}
+
+/// A `free_fn = "..."` argument
+#[derive(Debug)]
+struct ArgFreeFn(Ident);
+
+impl Parse for ArgFreeFn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::free_fn>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+
+        lit.parse()
+            .map(Self)
+            .map_err(|_| syn::Error::new_spanned(lit, "expected function name"))
+    }
+}
+
+/// A `from = "..."` argument
+#[derive(Debug)]
+struct ArgFrom(Path);
+
+impl Parse for ArgFrom {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::from>()?;
+        input.parse::<Token![=]>()?;
+        Ok(Self(input.parse::<LitStr>()?.parse_with(PathParser)?))
+    }
+}
+
+/// An `idents(...)` argument
+#[derive(Debug)]
+struct ArgIdents(Vec<TypeIdent>);
+
+impl Parse for ArgIdents {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::idents>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let idents = content.call(Punctuated::<TypeIdent, Token![,]>::parse_terminated)?;
+
+        if idents.is_empty() {
+            Err(content.error("expected one of these arguments: `src`, `dst`"))
+        } else {
+            Ok(Self(idents.into_iter().collect()))
+        }
+    }
+}
+
+/// An identifier for a type in generated code to be used within `idents(...)`
+#[derive(Debug)]
+enum TypeIdent {
+    /// A `src = "..."` argument
+    Src(Ident),
+
+    /// A `dst = "..."` argument
+    Dst(Ident),
+}
+
+impl Parse for TypeIdent {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let variant: fn(Ident) -> Self = if input.peek(kw::src) {
+            input.parse::<kw::src>()?;
+            Self::Src
+        } else if input.peek(kw::dst) {
+            input.parse::<kw::dst>()?;
+            Self::Dst
+        } else {
+            return Err(input.error("expected one of these arguments: `src`, `dst`"));
+        };
+
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+
+        lit.parse()
+            .map(variant)
+            .map_err(|_| syn::Error::new_spanned(lit, "expected identifier"))
+    }
+}
+
+/// An `infallible_via_try` argument
+#[derive(Debug)]
+struct ArgInfallibleViaTry(kw::infallible_via_try);
+
+impl Parse for ArgInfallibleViaTry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self(input.parse::<kw::infallible_via_try>()?))
+    }
+}
+
+/// An `opaque_via = "..."` argument
+#[cfg(feature = "nightly")]
+#[derive(Debug)]
+struct ArgOpaqueVia(Path);
+
+#[cfg(feature = "nightly")]
+impl Parse for ArgOpaqueVia {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::opaque_via>()?;
+        input.parse::<Token![=]>()?;
+        Ok(Self(input.parse::<LitStr>()?.parse_with(PathParser)?))
+    }
+}
+
+/// Parser for [`Path`] producing errors spanned to the parsed tokens
+#[derive(Debug)]
+struct PathParser;
+
+impl Parser for PathParser {
+    type Output = Path;
+
+    fn parse2(self, tokens: TokenStream) -> syn::Result<Self::Output> {
+        syn::parse2(tokens.clone()).map_err(|_| syn::Error::new_spanned(tokens, "expected path"))
+    }
+}
+
+/// A `params(...)` argument
+#[derive(Debug)]
+struct ArgParams(Vec<Param>);
+
+impl Parse for ArgParams {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::params>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let params = content.call(Punctuated::<Param, Token![,]>::parse_terminated)?;
+
+        if params.is_empty() {
+            Err(content.error("expected name of generic parameter"))
+        } else {
+            Ok(Self(params.into_iter().collect()))
+        }
+    }
+}
+
+/// A `rebuild = "..."` argument
+#[derive(Debug)]
+struct ArgRebuild(Path);
+
+impl Parse for ArgRebuild {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::rebuild>()?;
+        input.parse::<Token![=]>()?;
+        Ok(Self(input.parse::<LitStr>()?.parse_with(PathParser)?))
+    }
+}
+
+/// An `inline = "..."` argument
+#[derive(Debug)]
+struct ArgInline(LitStr, Inline);
+
+impl Parse for ArgInline {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::inline>()?;
+        input.parse::<Token![=]>()?;
+
+        let lit = input.parse::<LitStr>()?;
+
+        let inline = match lit.value().as_str() {
+            "always" => Inline::Always,
+            "hint" => Inline::Hint,
+            "never" => Inline::Never,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "expected one of these inline hints: `always`, `hint`, `never`",
+                ))
+            }
+        };
+
+        Ok(Self(lit, inline))
+    }
+}
+
+/// A `skip_params(...)` argument
+#[derive(Debug)]
+struct ArgSkipParams(Vec<Param>);
+
+impl Parse for ArgSkipParams {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::skip_params>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let params = content.call(Punctuated::<Param, Token![,]>::parse_terminated)?;
+
+        if params.is_empty() {
+            Err(content.error("expected name of generic parameter"))
+        } else {
+            Ok(Self(params.into_iter().collect()))
+        }
+    }
+}
+
+/// A `strict` argument
+#[derive(Debug)]
+struct ArgStrict(kw::strict);
+
+impl Parse for ArgStrict {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self(input.parse::<kw::strict>()?))
+    }
+}
+
+/// A `traced` argument
+#[derive(Debug)]
+struct ArgTraced(kw::traced);
+
+impl Parse for ArgTraced {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self(input.parse::<kw::traced>()?))
+    }
+}
+
+/// A `validate = "..."` argument
+#[derive(Debug)]
+struct ArgValidate(Path);
+
+impl Parse for ArgValidate {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::validate>()?;
+        input.parse::<Token![=]>()?;
+        Ok(Self(input.parse::<LitStr>()?.parse_with(PathParser)?))
+    }
+}
+
+/// A `with = "..."` argument
+#[derive(Debug)]
+struct ArgWith(Path);
+
+impl Parse for ArgWith {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<kw::with>()?;
+        input.parse::<Token![=]>()?;
+        Ok(Self(input.parse::<LitStr>()?.parse_with(PathParser)?))
+    }
+}
+
+/// An inline hint to be used within `inline = "..."`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Inline {
+    /// `#[inline(always)]`
+    Always,
+
+    /// `#[inline]`
+    Hint,
+
+    /// `#[inline(never)]`
+    Never,
+}
+
+impl ToTokens for Inline {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Always => quote!(#[inline(always)]),
+            Self::Hint => quote!(#[inline]),
+            Self::Never => quote!(#[inline(never)]),
+        });
+    }
+}
+
+/// A generic parameter to be used within `params(..)` or `skip_params(..)`
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub(crate) enum Param {
+    /// A lifetime parameter
+    Lifetime(Lifetime),
+
+    /// A type or const parameter
+    TypeOrConst(Ident),
+}
+
+impl Parse for Param {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input
+            .parse::<Lifetime>()
+            .map(Self::Lifetime)
+            .or_else(|_| input.parse::<Ident>().map(Self::TypeOrConst))
+            .map_err(|_| input.error("expected name of generic parameter"))
+    }
+}
+
+impl ToTokens for Param {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Lifetime(lifetime) => lifetime.to_tokens(tokens),
+            Self::TypeOrConst(ident) => ident.to_tokens(tokens),
+        }
+    }
+}
+
+impl PartialEq<GenericParam> for Param {
+    fn eq(&self, other: &GenericParam) -> bool {
+        match (self, other) {
+            (Self::Lifetime(l), GenericParam::Lifetime(LifetimeParam { lifetime: r, .. })) => {
+                l == r
+            }
+            (
+                Self::TypeOrConst(l),
+                GenericParam::Type(TypeParam { ident: r, .. })
+                | GenericParam::Const(ConstParam { ident: r, .. }),
+            ) => l == r,
+            _ => false,
+        }
+    }
+}
+
+/// Asserts that a given slice of attributes doesn't contain a `#[funcmap]`
+/// helper attribute
+///
+/// This is meant to be invoked with the slice of all attributes of an item for
+/// which `#[funcmap]` helper attributes aren't supported. `name` is the kind of
+/// item, i.e. `"variants"` or `"fields"`.
+///
+/// # Errors
+/// Fails if `attrs` contains a `#[funcmap]` helper attribute
+pub(crate) fn assert_absent(attrs: &[Attribute], name: &str) -> Result<(), Error> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(&ATTR_IDENT))
+        .map(|attr| {
+            syn::Error::new_spanned(
+                attr,
+                format!("#[{ATTR_IDENT}] helper attribute is not supported for {name}"),
+            )
+        })
+        .collect::<result::Builder>()
+        .err_or(())
+}
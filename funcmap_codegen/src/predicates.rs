@@ -3,9 +3,11 @@
 use crate::result::Error;
 
 use indexmap::{IndexMap, IndexSet};
+use quote::ToTokens;
+use syn::fold::{self, Fold};
 use syn::{
     punctuated::Punctuated, BoundLifetimes, Lifetime, PredicateLifetime, PredicateType, Token,
-    Type, TypeParamBound, WhereClause, WherePredicate,
+    Type, TypeParam, TypeParamBound, WhereClause, WherePredicate,
 };
 
 /// A set of unique type bounds
@@ -27,6 +29,12 @@ impl UniqueTypeBounds {
         self.0.insert(bound);
     }
 
+    /// Removes the given type bound from this set, returning whether it was
+    /// present
+    pub(crate) fn remove(&mut self, bound: &TypeParamBound) -> bool {
+        self.0.shift_remove(bound)
+    }
+
     /// Turn this set into a `+`-punctuated sequence
     pub(crate) fn into_bounds(self) -> Punctuated<TypeParamBound, Token![+]> {
         self.0.into_iter().collect()
@@ -120,6 +128,17 @@ impl UniquePredicates {
         Ok(())
     }
 
+    /// Removes the given bound on the bounded type of the given predicate from
+    /// this set, returning whether it was present
+    pub(crate) fn remove(&mut self, predicate: &PredicateType, bound: &TypeParamBound) -> bool {
+        self.for_types
+            .get_mut(&TypePredicateLhs {
+                lifetimes: predicate.lifetimes.clone(),
+                bounded_ty: predicate.bounded_ty.clone(),
+            })
+            .map_or(false, |bounds| bounds.remove(bound))
+    }
+
     /// Turns this set into an iterator of predicates
     pub(crate) fn into_iter(self) -> impl Iterator<Item = WherePredicate> {
         let for_lifetimes = self.for_lifetimes.into_iter().filter_map(|(lhs, rhs)| {
@@ -159,6 +178,78 @@ impl UniquePredicates {
     }
 }
 
+/// A [`Fold`] bringing all predicates and bounds into a canonical order
+///
+/// Lifetime predicates come before type predicates, as produced by
+/// [`UniquePredicates`], and within each kind, predicates and their bounds are
+/// sorted by their token representation. This makes the output of the derive
+/// macros independent of e.g. the order of the fields of the input type.
+#[derive(Debug, Default)]
+pub(crate) struct Canonicalizer;
+
+impl Canonicalizer {
+    /// Creates a new canonicalizer
+    pub(crate) fn new() -> Self {
+        Self
+    }
+}
+
+impl Fold for Canonicalizer {
+    fn fold_where_clause(&mut self, where_clause: WhereClause) -> WhereClause {
+        let mut where_clause = fold::fold_where_clause(self, where_clause);
+
+        where_clause.predicates = sorted(where_clause.predicates, |predicate| {
+            (
+                !matches!(predicate, WherePredicate::Lifetime(..)),
+                predicate.to_token_stream().to_string(),
+            )
+        });
+
+        where_clause
+    }
+
+    fn fold_predicate_type(&mut self, predicate_type: PredicateType) -> PredicateType {
+        let mut predicate_type = fold::fold_predicate_type(self, predicate_type);
+        predicate_type.bounds = sorted(predicate_type.bounds, token_string);
+        predicate_type
+    }
+
+    fn fold_predicate_lifetime(
+        &mut self,
+        predicate_lifetime: PredicateLifetime,
+    ) -> PredicateLifetime {
+        let mut predicate_lifetime = fold::fold_predicate_lifetime(self, predicate_lifetime);
+        predicate_lifetime.bounds = sorted(predicate_lifetime.bounds, token_string);
+        predicate_lifetime
+    }
+
+    fn fold_type_param(&mut self, type_param: TypeParam) -> TypeParam {
+        let mut type_param = fold::fold_type_param(self, type_param);
+        type_param.bounds = sorted(type_param.bounds, token_string);
+        type_param
+    }
+}
+
+/// Sorts the items of a punctuated sequence by the given key
+fn sorted<T, P, K, F>(items: Punctuated<T, P>, key: F) -> Punctuated<T, P>
+where
+    P: Default,
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut items: Vec<_> = items.into_iter().collect();
+    items.sort_by_cached_key(key);
+    items.into_iter().collect()
+}
+
+/// Returns the token representation of the given item as a string
+fn token_string<T>(item: &T) -> String
+where
+    T: ToTokens,
+{
+    item.to_token_stream().to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,4 +325,61 @@ mod tests {
             parse_quote!(where 'b: 'c, for<'a> TestType: TestTrait<'a>)
         );
     }
+
+    #[test]
+    fn unique_predicates_removes_bounds_and_empty_predicates() {
+        let mut unique_predicates = UniquePredicates::new();
+        for predicate in [
+            parse_quote!(TestType1: TestTrait1 + TestTrait2),
+            parse_quote!(TestType2: TestTrait1),
+        ] {
+            unique_predicates.add(predicate).unwrap();
+        }
+
+        let predicate1 = predicate_type(parse_quote!(TestType1: TestTrait1));
+        let predicate2 = predicate_type(parse_quote!(TestType2: TestTrait1));
+
+        assert!(unique_predicates.remove(&predicate1, &predicate1.bounds[0]));
+        assert!(unique_predicates.remove(&predicate2, &predicate2.bounds[0]));
+        assert!(!unique_predicates.remove(&predicate2, &predicate2.bounds[0]));
+
+        assert_eq!(
+            unique_predicates.into_where_clause(),
+            parse_quote!(where TestType1: TestTrait2)
+        );
+    }
+
+    #[test]
+    fn canonicalizer_sorts_predicates_with_lifetimes_first() {
+        let where_clause = Canonicalizer::new().fold_where_clause(parse_quote! {
+            where TestType2: TestTrait2, 'b: 'c, TestType1: TestTrait1, 'a: 'c
+        });
+
+        assert_eq!(
+            where_clause,
+            parse_quote!(where 'a: 'c, 'b: 'c, TestType1: TestTrait1, TestType2: TestTrait2)
+        );
+    }
+
+    #[test]
+    fn canonicalizer_sorts_bounds() {
+        let where_clause = Canonicalizer::new().fold_where_clause(parse_quote! {
+            where TestType: TestTrait2 + TestTrait1, 'a: 'c + 'b
+        });
+        let type_param =
+            Canonicalizer::new().fold_type_param(parse_quote!(T: TestTrait2 + TestTrait1));
+
+        assert_eq!(
+            where_clause,
+            parse_quote!(where 'a: 'b + 'c, TestType: TestTrait1 + TestTrait2)
+        );
+        assert_eq!(type_param, parse_quote!(T: TestTrait1 + TestTrait2));
+    }
+
+    fn predicate_type(predicate: WherePredicate) -> PredicateType {
+        match predicate {
+            WherePredicate::Type(predicate_type) => predicate_type,
+            _ => panic!("expected type predicate"),
+        }
+    }
 }
@@ -0,0 +1,447 @@
+//! The derive logic for `RefFuncMap`
+
+use crate::derive::{impl_attrs, subs_type_in_bounds, AttrOpts};
+use crate::ident::{MARKER_TYPE_IDENT, OUTPUT_TYPE_IDENT, REF_FN_IDENT, REF_TRAIT_IDENT};
+use crate::input::{parse_derive_input, FuncMapInput, Structish};
+use crate::predicates::UniquePredicates;
+use crate::result::{self, Error, ResultExt};
+use crate::syn_ext::{
+    DependencyOnType, IntoGenericArgument, IntoType, IsTypish, SubsType, WithoutAttrs,
+    WithoutDefault, WithoutMaybeBounds,
+};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, GenericArgument, GenericParam, Index, Lifetime, Path, PathArguments, QSelf, Type,
+    TypeArray, TypeParam, TypeParen, TypePath, TypeTuple, WherePredicate,
+};
+
+/// Generates implementations of `RefFuncMap` for a given item
+pub(crate) fn derive(item: TokenStream) -> TokenStream {
+    match try_derive(item) {
+        Ok(output) => output,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+/// Tries to generate implementations of `RefFuncMap` for a given item
+///
+/// This generates one implementation for every mapped type parameter `T`.
+/// Every field whose type depends on `T` is mapped through the implementations
+/// of `RefFuncMap` for the types within its type, while all other fields are
+/// cloned. As a borrowed value can be mapped into only one output, a type
+/// within the type of a field may have at most one type argument depending on
+/// `T`.
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed
+/// - `item` is not a valid input for deriving `FuncMap`
+/// - any of the fields of `item` has an unsupported type
+pub(crate) fn try_derive(item: TokenStream) -> Result<TokenStream, Error> {
+    let input = parse_derive_input(item)?;
+    let input: FuncMapInput = input.try_into()?;
+    let mut ident_collector = input.meta.ident_collector;
+    let mut result_builder = result::Builder::new();
+
+    let src_type_ident = ident_collector.reserve_uppercase_letter('A', Span::mixed_site());
+    let dst_type_ident = ident_collector.reserve_uppercase_letter('B', Span::mixed_site());
+    let fn_type_ident = ident_collector.reserve_uppercase_letter('F', Span::mixed_site());
+    let fn_var_ident = Ident::new("f", Span::mixed_site());
+
+    let lifetime_ident = (1_usize..)
+        .find_map(|idx| {
+            let ident = match idx {
+                1 => format_ident!("a"),
+                idx => format_ident!("__funcmap_a{}", idx),
+            };
+
+            ident_collector.reserve(&ident, Span::mixed_site())
+        })
+        .unwrap();
+    let lifetime = Lifetime {
+        apostrophe: Span::mixed_site(),
+        ident: lifetime_ident,
+    };
+
+    let crate_path = &input.meta.crate_path;
+    let inline = &input.meta.inline;
+    let ident = &input.ident;
+    let all_params = &input.generics.params;
+    let where_clause = &input.generics.where_clause;
+
+    let attrs = impl_attrs(AttrOpts {
+        allow: &input.meta.allow,
+        cfg: &input.meta.cfg,
+    });
+
+    let mut impls = Vec::with_capacity(input.mapped_type_params.len());
+
+    for mapped_type_param in &input.mapped_type_params {
+        let type_param_ident = &mapped_type_param.type_param.ident;
+        let marker_idx = mapped_type_param.marker_idx;
+
+        let mut impl_params = Vec::with_capacity(all_params.len() + 2);
+        impl_params.push(GenericParam::Lifetime(parse_quote!(#lifetime)));
+
+        for (param_idx, param) in all_params.iter().enumerate() {
+            if param_idx == mapped_type_param.param_idx {
+                for type_ident in [&src_type_ident, &dst_type_ident] {
+                    impl_params.push(GenericParam::Type(TypeParam {
+                        bounds: subs_type_in_bounds(
+                            &mapped_type_param.type_param.bounds,
+                            type_param_ident,
+                            &[type_ident],
+                        )?
+                        .without_maybe_bounds(),
+                        ..type_ident.clone().into()
+                    }));
+                }
+            } else {
+                impl_params.push(match param {
+                    GenericParam::Type(type_param) => GenericParam::Type(TypeParam {
+                        bounds: subs_type_in_bounds(
+                            &type_param.bounds,
+                            type_param_ident,
+                            &[&src_type_ident, &dst_type_ident],
+                        )?,
+                        ..type_param.ident.clone().into()
+                    }),
+                    param => param.clone().without_attrs().without_default(),
+                });
+            }
+        }
+
+        let args = |type_ident: &Ident| -> Vec<GenericArgument> {
+            all_params
+                .iter()
+                .enumerate()
+                .map(|(param_idx, param)| {
+                    if param_idx == mapped_type_param.param_idx {
+                        GenericArgument::Type(type_ident.clone().into_type())
+                    } else {
+                        param.clone().into_generic_argument()
+                    }
+                })
+                .collect()
+        };
+
+        let src_args = args(&src_type_ident);
+        let dst_args = args(&dst_type_ident);
+
+        let mut mapper = RefMapper {
+            type_ident: type_param_ident,
+            src_type_ident: &src_type_ident,
+            dst_type_ident: &dst_type_ident,
+            lifetime: &lifetime,
+            fn_ident: &fn_var_ident,
+            crate_path,
+            unique_predicates: UniquePredicates::new(),
+            uses_fn: false,
+        };
+
+        for predicate in where_clause
+            .iter()
+            .flat_map(|clause| clause.predicates.iter())
+        {
+            let predicate = match predicate.clone().without_attrs() {
+                WherePredicate::Type(predicate_type)
+                    if predicate_type.bounded_ty == type_param_ident.clone().into_type() =>
+                {
+                    WherePredicate::Type(predicate_type.without_maybe_bounds())
+                }
+                predicate => predicate,
+            };
+
+            for type_ident in [&src_type_ident, &dst_type_ident] {
+                mapper
+                    .unique_predicates
+                    .add(predicate.clone().subs_type(type_param_ident, type_ident))
+                    .add_err_to(&mut result_builder);
+            }
+        }
+
+        let mut arms = Vec::new();
+
+        for Structish {
+            variant_ident,
+            fields,
+        } in &input.variants
+        {
+            let mut patterns = Vec::new();
+            let mut mappings = Vec::new();
+
+            for (field_idx, field) in fields.iter().enumerate() {
+                let (member, field_ident) = field.binding(field_idx);
+
+                let Some(mapping) = mapper
+                    .map(quote!(#field_ident), &field.ty)
+                    .with_context(field.context(field_idx, &input.ident, variant_ident.as_ref()))
+                    .add_err_to(&mut result_builder)
+                else {
+                    continue;
+                };
+
+                patterns.push(quote!(#member: #field_ident));
+                mappings.push(quote!(#member: #mapping));
+            }
+
+            let (pat_path, output_path) = match variant_ident {
+                Some(ident) => (
+                    quote!(Self::#ident),
+                    quote!(Self::#OUTPUT_TYPE_IDENT::#ident),
+                ),
+                None => (quote!(Self), quote!(Self::#OUTPUT_TYPE_IDENT)),
+            };
+
+            arms.push(quote! {
+                #pat_path { #(#patterns,)* } => #output_path { #(#mappings,)* }
+            });
+        }
+
+        let fn_pat = if mapper.uses_fn {
+            quote!(mut #fn_var_ident)
+        } else {
+            quote!(_)
+        };
+
+        let impl_where_clause = mapper.unique_predicates.into_where_clause();
+
+        impls.push(quote! {
+            #attrs
+            impl<#(#impl_params),*>
+                #crate_path::#REF_TRAIT_IDENT<
+                    #lifetime,
+                    #src_type_ident,
+                    #dst_type_ident,
+                    #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+                >
+                for #ident<#(#src_args),*>
+                #impl_where_clause
+            {
+                type #OUTPUT_TYPE_IDENT = #ident<#(#dst_args),*>;
+
+                #inline
+                fn #REF_FN_IDENT<#fn_type_ident>(
+                    &#lifetime self,
+                    #fn_pat: #fn_type_ident
+                ) -> Self::#OUTPUT_TYPE_IDENT
+                where
+                    #src_type_ident: #lifetime,
+                    #fn_type_ident: ::core::ops::FnMut(
+                        &#lifetime #src_type_ident
+                    ) -> #dst_type_ident
+                {
+                    match self {
+                        #(#arms,)*
+                    }
+                }
+            }
+        });
+    }
+
+    result_builder.err_or(quote!(#(#impls)*))
+}
+
+/// Helper used for generating mapping expressions and collecting predicates
+#[derive(Debug)]
+struct RefMapper<'ast> {
+    /// Identifier of the type parameter that is mapped
+    type_ident: &'ast Ident,
+
+    /// Identifier of the source type parameter of the implementation
+    src_type_ident: &'ast Ident,
+
+    /// Identifier of the destination type parameter of the implementation
+    dst_type_ident: &'ast Ident,
+
+    /// Lifetime of the borrow of the mapped value
+    lifetime: &'ast Lifetime,
+
+    /// Identifier of the closure passed to `ref_func_map`
+    fn_ident: &'ast Ident,
+
+    /// Path to the `funcmap` crate
+    crate_path: &'ast Path,
+
+    /// Collected predicates
+    unique_predicates: UniquePredicates,
+
+    /// Whether any of the generated expressions uses the closure
+    uses_fn: bool,
+}
+
+impl RefMapper<'_> {
+    /// Returns an expression mapping `mappable`, which is a reference to a
+    /// value of type `ty`, into an owned value of the corresponding destination
+    /// type
+    fn map(&mut self, mappable: TokenStream, ty: &Type) -> Result<TokenStream, Error> {
+        if let Type::Macro(..) = ty {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "`derive` cannot be used on items with type macros",
+            )
+            .into());
+        }
+
+        if ty.dependency_on_type(self.type_ident).is_none() {
+            self.unique_predicates
+                .add(parse_quote!(#ty: ::core::clone::Clone))?;
+
+            return Ok(quote!(::core::clone::Clone::clone(#mappable)));
+        }
+
+        if *ty == self.type_ident.clone().into_type() {
+            let fn_ident = self.fn_ident;
+            self.uses_fn = true;
+            return Ok(quote!(#fn_ident(#mappable)));
+        }
+
+        match ty {
+            Type::Array(TypeArray { elem, .. }) => self.map_arg(&mappable, ty, 0, elem),
+
+            Type::Paren(TypeParen { elem, .. }) => self.map(mappable, elem),
+
+            Type::Tuple(TypeTuple { elems, .. }) => {
+                let mut mappings = Vec::with_capacity(elems.len());
+
+                for (idx, elem) in elems.iter().enumerate() {
+                    let idx = Index::from(idx);
+                    mappings.push(self.map(quote!(&(#mappable).#idx), elem)?);
+                }
+
+                Ok(quote!((#(#mappings,)*)))
+            }
+
+            Type::Path(TypePath { qself, path }) => {
+                let unsupported = || -> Error {
+                    syn::Error::new_spanned(
+                        ty,
+                        "mapping by reference over type with associated item is not supported",
+                    )
+                    .into()
+                };
+
+                if let Some(QSelf { ty: inner_ty, .. }) = qself {
+                    if inner_ty.dependency_on_type(self.type_ident).is_some() {
+                        return Err(unsupported());
+                    }
+                }
+
+                let Some(last) = path.segments.last() else {
+                    return Err(unsupported());
+                };
+
+                let mut prefix = path.clone();
+                prefix.segments.pop();
+
+                if Type::Path(TypePath {
+                    qself: None,
+                    path: prefix,
+                })
+                .dependency_on_type(self.type_ident)
+                .is_some()
+                {
+                    return Err(unsupported());
+                }
+
+                let PathArguments::AngleBracketed(angle_bracketed) = &last.arguments else {
+                    return Err(syn::Error::new_spanned(
+                        ty,
+                        "mapping by reference over this type is not supported",
+                    )
+                    .into());
+                };
+
+                // the type argument of `PhantomData` is never instantiated
+                if last.ident == "PhantomData" {
+                    return Ok(quote!(::core::marker::PhantomData));
+                }
+
+                let mut dependent_args = angle_bracketed
+                    .args
+                    .iter()
+                    .filter(|arg| arg.is_typish())
+                    .enumerate()
+                    .filter_map(|(marker_idx, arg)| match arg {
+                        GenericArgument::Type(arg_type)
+                            if arg_type.dependency_on_type(self.type_ident).is_some() =>
+                        {
+                            Some((marker_idx, arg_type))
+                        }
+                        _ => None,
+                    });
+
+                match (dependent_args.next(), dependent_args.next()) {
+                    (Some((marker_idx, arg_type)), None) => {
+                        self.map_arg(&mappable, ty, marker_idx, arg_type)
+                    }
+                    _ => Err(syn::Error::new_spanned(
+                        ty,
+                        "mapping by reference over type with multiple type arguments depending on the type parameter is not supported",
+                    )
+                    .into()),
+                }
+            }
+
+            _ => Err(syn::Error::new_spanned(
+                ty,
+                "mapping by reference over this type is not supported",
+            )
+            .into()),
+        }
+    }
+
+    /// Returns an expression mapping `mappable`, which is a reference to a
+    /// value of type `ty`, over its type argument `arg_type` with the given
+    /// marker index, via the implementation of `RefFuncMap` for `ty`
+    fn map_arg(
+        &mut self,
+        mappable: &TokenStream,
+        ty: &Type,
+        marker_idx: usize,
+        arg_type: &Type,
+    ) -> Result<TokenStream, Error> {
+        let crate_path = self.crate_path;
+        let lifetime = self.lifetime;
+
+        let src_type = ty.clone().subs_type(self.type_ident, self.src_type_ident);
+        let dst_type = ty.clone().subs_type(self.type_ident, self.dst_type_ident);
+        let src_arg_type = arg_type
+            .clone()
+            .subs_type(self.type_ident, self.src_type_ident);
+        let dst_arg_type = arg_type
+            .clone()
+            .subs_type(self.type_ident, self.dst_type_ident);
+
+        self.unique_predicates.add(parse_quote! {
+            #src_type: #crate_path::#REF_TRAIT_IDENT<
+                #lifetime,
+                #src_arg_type,
+                #dst_arg_type,
+                #crate_path::#MARKER_TYPE_IDENT<#marker_idx>,
+                #OUTPUT_TYPE_IDENT = #dst_type
+            >
+        })?;
+
+        let fn_ident = self.fn_ident;
+        let closure = if *arg_type == self.type_ident.clone().into_type() {
+            self.uses_fn = true;
+            quote!(&mut #fn_ident)
+        } else {
+            let value_ident = Ident::new("value", Span::mixed_site());
+            let inner = self.map(quote!(#value_ident), arg_type)?;
+            quote!(|#value_ident| #inner)
+        };
+
+        Ok(quote! {
+            #crate_path::#REF_TRAIT_IDENT::<
+                #lifetime,
+                #src_arg_type,
+                #dst_arg_type,
+                #crate_path::#MARKER_TYPE_IDENT<#marker_idx>
+            >::#REF_FN_IDENT(#mappable, #closure)
+        })
+    }
+}
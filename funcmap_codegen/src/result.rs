@@ -4,16 +4,21 @@ use std::error;
 use std::fmt::{self, Display, Formatter};
 
 use proc_macro2::TokenStream;
+use quote::ToTokens;
 
 /// An error that occurred while deriving
+///
+/// Its [`Display`] implementation yields the message of the first error, while
+/// [`Error::into_compile_error`] yields all messages with their spans.
 #[derive(Debug)]
-pub(crate) struct Error(syn::Error);
+pub struct Error(syn::Error);
 
 impl Error {
     /// Turns this error into a [`TokenStream`] containing a
     /// `compile_error!(...)` macro invocation with the appropriate
-    /// [`Span`](proc_macro2::Span)
-    pub(crate) fn into_compile_error(self) -> TokenStream {
+    /// [`Span`](proc_macro2::Span) for every message
+    #[must_use]
+    pub fn into_compile_error(self) -> TokenStream {
         self.0.into_compile_error()
     }
 
@@ -42,6 +47,12 @@ impl From<syn::Error> for Error {
     }
 }
 
+impl From<Error> for syn::Error {
+    fn from(err: Error) -> Self {
+        err.0
+    }
+}
+
 /// A builder for values of type [`Result<T, Error>`]
 #[derive(Debug, Default)]
 pub(crate) struct Builder(Option<Error>);
@@ -159,6 +170,17 @@ pub(crate) trait ResultExt<T> {
     /// no error. Otherwise returns [`Err`] containing an error combined from
     /// the given builder's error and this result's error.
     fn with_error_from(self, builder: Builder) -> Result<T, Error>;
+
+    /// If this result is [`Err`], adds a message describing the context in
+    /// which the error occurred to it
+    ///
+    /// The message is given as the second component of `context` and spanned
+    /// to its first component, so that the context is pointed at in addition
+    /// to the original location of the error.
+    fn with_context<S, D>(self, context: (S, D)) -> Result<T, Error>
+    where
+        S: ToTokens,
+        D: Display;
 }
 
 impl<T, E> ResultExt<T> for Result<T, E>
@@ -181,6 +203,18 @@ where
             Err(err) => Err(builder.err_combined_with(err)),
         }
     }
+
+    fn with_context<S, D>(self, (tokens, message): (S, D)) -> Result<T, Error>
+    where
+        S: ToTokens,
+        D: Display,
+    {
+        self.map_err(|err| {
+            let mut err = err.into();
+            err.combine(syn::Error::new_spanned(tokens, message));
+            err
+        })
+    }
 }
 
 #[cfg(test)]
@@ -344,6 +378,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ok_result_with_context_yields_value() {
+        let result: Result<_, syn::Error> = Ok("Value");
+
+        let with_context = result.with_context((quote::quote!(x), "Context"));
+
+        assert_eq!(with_context.ok(), Some("Value"));
+    }
+
+    #[test]
+    fn err_result_with_context_yields_error_followed_by_context() {
+        let result: Result<(), _> = Err(syn_error("Error"));
+
+        let with_context = result.with_context((quote::quote!(x), "Context"));
+
+        assert_eq!(
+            with_context.err().map(err_messages),
+            Some(vec!["Error".into(), "Context".into()])
+        );
+    }
+
     fn syn_error(message: impl Display) -> syn::Error {
         syn::Error::new(Span::call_site(), message)
     }
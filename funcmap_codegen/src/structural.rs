@@ -0,0 +1,132 @@
+//! The derive logic for `StructuralFrom`
+
+use crate::derive::{impl_attrs, AttrOpts};
+use crate::input::{parse_derive_input, FuncMapInput, Structish};
+use crate::result::{self, Error};
+use crate::syn_ext::{IntoGenericArgument, WithoutAttrs, WithoutDefault};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::PathArguments;
+
+/// Generates implementations of `From` for a given item
+pub(crate) fn derive(item: TokenStream) -> TokenStream {
+    match try_derive(item) {
+        Ok(output) => output,
+        Err(err) => err.into_compile_error(),
+    }
+}
+
+/// Tries to generate implementations of `From` for a given item
+///
+/// For every path `Source` configured via `#[funcmap(from = "Source")]`, this
+/// generates an implementation of `From<Source<...>>` for the item, converting
+/// every field of `Source` into the field of the item with the same name
+/// (respectively the same index for tuple structs/variants) via `Into`.
+///
+/// The types of the fields of `Source` cannot be named here, so the
+/// implementation carries no predicates for these conversions. They have to
+/// hold for all generic arguments satisfying the bounds of the item, which is
+/// the case e.g. for identical types or for types deriving `StructuralFrom`
+/// themselves.
+///
+/// # Errors
+/// Fails if
+/// - `item` cannot be parsed
+/// - `item` is not a valid input for deriving `FuncMap`
+/// - no `from` option is configured or any `from` path has generic arguments
+pub(crate) fn try_derive(item: TokenStream) -> Result<TokenStream, Error> {
+    let input = parse_derive_input(item)?;
+    let input: FuncMapInput = input.try_into()?;
+
+    let mut result_builder = result::Builder::new();
+
+    if input.meta.from_paths.is_empty() {
+        result_builder.add_err(syn::Error::new_spanned(
+            &input.ident,
+            "expected at least one source type configured via #[funcmap(from = \"...\")]",
+        ));
+    }
+
+    for from_path in &input.meta.from_paths {
+        if from_path
+            .segments
+            .iter()
+            .any(|segment| !matches!(segment.arguments, PathArguments::None))
+        {
+            result_builder.add_err(syn::Error::new_spanned(
+                from_path,
+                "expected path to source type without generic arguments",
+            ));
+        }
+    }
+
+    let attrs = impl_attrs(AttrOpts {
+        allow: &input.meta.allow,
+        cfg: &input.meta.cfg,
+    });
+    let ident = &input.ident;
+    let all_params = &input.generics.params;
+    let where_clause = &input.generics.where_clause;
+    let src_var_ident = Ident::new("source", Span::mixed_site());
+
+    let impl_params: Vec<_> = all_params
+        .iter()
+        .cloned()
+        .map(|param| param.without_attrs().without_default())
+        .collect();
+
+    let args: Vec<_> = all_params
+        .iter()
+        .cloned()
+        .map(IntoGenericArgument::into_generic_argument)
+        .collect();
+
+    let impls = input.meta.from_paths.iter().map(|from_path| {
+        let arms = input.variants.iter().map(
+            |Structish {
+                 variant_ident,
+                 fields,
+             }| {
+                let (members, idents): (Vec<_>, Vec<_>) = fields
+                    .iter()
+                    .enumerate()
+                    .map(|(field_idx, field)| field.binding(field_idx))
+                    .unzip();
+
+                let (src_path, dst_path) = match variant_ident {
+                    Some(variant_ident) => (
+                        quote!(#from_path::#variant_ident),
+                        quote!(Self::#variant_ident),
+                    ),
+                    None => (quote!(#from_path), quote!(Self)),
+                };
+
+                quote! {
+                    #src_path { #(#members: #idents,)* } => #dst_path {
+                        #(#members: ::core::convert::Into::into(#idents),)*
+                    }
+                }
+            },
+        );
+
+        quote! {
+            #attrs
+            impl<#(#impl_params),*>
+                ::core::convert::From<#from_path<#(#args),*>>
+                for #ident<#(#args),*>
+                #where_clause
+            {
+                fn from(#src_var_ident: #from_path<#(#args),*>) -> Self {
+                    match #src_var_ident {
+                        #(#arms,)*
+                    }
+                }
+            }
+        }
+    });
+
+    let output = quote!(#(#impls)*);
+
+    result_builder.err_or(output)
+}
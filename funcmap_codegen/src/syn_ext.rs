@@ -30,6 +30,22 @@ impl DependencyOnType for Type {
     }
 }
 
+impl DependencyOnType for TypeParamBound {
+    fn dependency_on_type(&self, type_ident: &Ident) -> Option<&Ident> {
+        let mut visitor = DependencyOnTypeVisitor::new(type_ident);
+        visitor.visit_type_param_bound(self);
+        visitor.into_dependency()
+    }
+}
+
+impl DependencyOnType for WherePredicate {
+    fn dependency_on_type(&self, type_ident: &Ident) -> Option<&Ident> {
+        let mut visitor = DependencyOnTypeVisitor::new(type_ident);
+        visitor.visit_where_predicate(self);
+        visitor.into_dependency()
+    }
+}
+
 /// Type implementing [`Visit`] for
 /// [`dependency_on_type`](DependencyOnType::dependency_on_type)
 #[derive(Debug)]
@@ -91,6 +107,13 @@ impl SubsType for TraitBound {
     }
 }
 
+impl SubsType for PredicateType {
+    fn subs_type(self, type_ident: &Ident, subs_ident: &Ident) -> Self {
+        let mut folder = SubsTypeFolder::new(type_ident, subs_ident);
+        folder.fold_predicate_type(self)
+    }
+}
+
 impl SubsType for WherePredicate {
     fn subs_type(self, type_ident: &Ident, subs_ident: &Ident) -> Self {
         let mut folder = SubsTypeFolder::new(type_ident, subs_ident);
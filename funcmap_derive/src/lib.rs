@@ -1,7 +1,8 @@
 //! Derive macros for the `funcmap` crate
 //!
 //! This crate should not be depended on directly. See the documentation of
-//! [funcmap](/funcmap) instead.
+//! [funcmap](/funcmap) instead. The logic behind the derive macros is available
+//! as ordinary functions in the `funcmap_codegen` crate.
 
 #![deny(missing_copy_implementations)]
 #![deny(missing_debug_implementations)]
@@ -18,36 +19,75 @@
 #![deny(clippy::rest_pat_in_fully_bound_structs)]
 #![deny(clippy::use_debug)]
 #![allow(clippy::module_name_repetitions)]
-#![allow(clippy::too_many_lines)]
 #![deny(rustdoc::broken_intra_doc_links)]
 #![deny(rustdoc::private_intra_doc_links)]
 #![deny(rustdoc::invalid_codeblock_attributes)]
 #![deny(rustdoc::invalid_rust_codeblocks)]
 #![deny(rustdoc::bare_urls)]
 
-use derivable::Derivable;
+use funcmap_codegen::Derivable;
 
 use proc_macro::TokenStream;
 
-mod derivable;
-mod derive;
-mod ident;
-mod ident_collector;
-mod input;
-mod map;
-mod opts;
-mod predicates;
-mod result;
-mod syn_ext;
-
 /// Derive macro generating implementations of the `FuncMap` trait
 #[proc_macro_derive(FuncMap, attributes(funcmap))]
 pub fn derive_func_map(item: TokenStream) -> TokenStream {
-    derive::derive(item.into(), Derivable::Standard).into()
+    funcmap_codegen::derive(item.into(), Derivable::Standard).into()
 }
 
 /// Derive macro generating implementations of the `TryFuncMap` trait
 #[proc_macro_derive(TryFuncMap, attributes(funcmap))]
 pub fn derive_try_func_map(item: TokenStream) -> TokenStream {
-    derive::derive(item.into(), Derivable::Fallible).into()
+    funcmap_codegen::derive(item.into(), Derivable::Fallible).into()
+}
+
+/// Derive macro generating implementations of `From` between types of the same
+/// shape
+#[proc_macro_derive(StructuralFrom, attributes(funcmap))]
+pub fn derive_structural_from(item: TokenStream) -> TokenStream {
+    funcmap_codegen::derive_structural_from(item.into()).into()
+}
+
+/// Derive macro generating an implementation of the `JoinParams` trait
+#[proc_macro_derive(JoinParams, attributes(funcmap))]
+pub fn derive_join_params(item: TokenStream) -> TokenStream {
+    funcmap_codegen::derive_join_params(item.into()).into()
+}
+
+/// Derive macro generating an implementation of the `FamilyMap` trait
+#[proc_macro_derive(FamilyMap, attributes(funcmap))]
+pub fn derive_family_map(item: TokenStream) -> TokenStream {
+    funcmap_codegen::derive_family_map(item.into()).into()
+}
+
+/// Derive macro generating an implementation of the `FuncFilterMap` trait
+#[proc_macro_derive(FuncFilterMap, attributes(funcmap))]
+pub fn derive_func_filter_map(item: TokenStream) -> TokenStream {
+    funcmap_codegen::derive_func_filter_map(item.into()).into()
+}
+
+/// Derive macro generating implementations of the `FuncCount` trait
+#[proc_macro_derive(FuncCount, attributes(funcmap))]
+pub fn derive_func_count(item: TokenStream) -> TokenStream {
+    funcmap_codegen::derive_func_count(item.into()).into()
+}
+
+/// Derive macro generating implementations of the `RefFuncMap` trait
+#[proc_macro_derive(RefFuncMap, attributes(funcmap))]
+pub fn derive_ref_func_map(item: TokenStream) -> TokenStream {
+    funcmap_codegen::derive_ref_func_map(item.into()).into()
+}
+
+/// Derive macro generating inherent `map_*` methods named after the mapped
+/// type parameters, delegating to the `FuncMap` trait
+#[proc_macro_derive(MapBuilders, attributes(funcmap))]
+pub fn derive_map_builders(item: TokenStream) -> TokenStream {
+    funcmap_codegen::derive_map_builders(item.into()).into()
+}
+
+/// Derive macro generating an inherent `func_map_fields` method mapping all
+/// fields of a struct at once
+#[proc_macro_derive(FuncMapFields, attributes(funcmap))]
+pub fn derive_func_map_fields(item: TokenStream) -> TokenStream {
+    funcmap_codegen::derive_func_map_fields(item.into()).into()
 }
@@ -0,0 +1,336 @@
+//! This crate provides slow but obviously correct reference implementations
+//! of the mappings provided by the [`funcmap`] crate, which implementations of
+//! [`FuncMap`] and [`TryFuncMap`] can be tested against.
+//!
+//! # How It Works
+//!
+//! The reference implementations don't make use of [`FuncMap`] at all.
+//! Instead, they
+//! 1. serialize a value into a tree (a [`serde_json::Value`]),
+//! 2. apply the closure to all leaves of the tree that represent values of the
+//!    mapped type parameter and
+//! 3. deserialize the resulting tree into the output type.
+//!
+//! In order to tell the values of the mapped type parameter apart from all
+//! other values, they must be wrapped in [`Leaf`]. Hence, given a type
+//! `Foo<T>` implementing [`Serialize`] and [`Deserialize`],
+//! the reference implementation maps `Foo<Leaf<X>>` to `Foo<Leaf<Y>>` given a
+//! closure `X -> Y`.
+//!
+//! # Example
+//!
+//! ```
+//! use funcmap::FuncMap;
+//! use funcmap_testing::{assert_func_map_matches_model, Leaf};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(FuncMap, Serialize, Deserialize, Debug, PartialEq)]
+//! struct Foo<T> {
+//!     value: T,
+//!     more_values: Vec<Option<T>>,
+//! }
+//!
+//! let foo = Foo {
+//!     value: Leaf(1),
+//!     more_values: vec![Some(Leaf(2)), None],
+//! };
+//!
+//! assert_func_map_matches_model(foo, |v: i32| v.to_string());
+//! ```
+//!
+//! # Limitations
+//!
+//! The order in which the reference implementations visit the leaves of the
+//! tree is unspecified and in general differs from the order in which
+//! [`FuncMap`] implementations visit the values of the mapped type parameter.
+//! Consequently, the reference implementations are only meaningful for closures
+//! without side effects. For the same reason, the reference implementation of
+//! [`TryFuncMap`] may fail with a different error than the implementation
+//! under test, so [`assert_try_func_map_matches_model`] only checks whether
+//! both succeed or fail.
+//!
+//! Values of the mapped type parameter must not occur at positions where they
+//! are not mapped by the implementation under test, such as within another
+//! type parameter of the same type.
+
+#![deny(missing_copy_implementations)]
+#![deny(missing_debug_implementations)]
+#![deny(missing_docs)]
+#![deny(unreachable_pub)]
+#![deny(unused_crate_dependencies)]
+#![deny(unused_extern_crates)]
+#![deny(unused_lifetimes)]
+#![deny(unused_qualifications)]
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::cargo_common_metadata)]
+#![deny(clippy::rest_pat_in_fully_bound_structs)]
+#![deny(clippy::use_debug)]
+#![allow(clippy::module_name_repetitions)]
+#![deny(rustdoc::broken_intra_doc_links)]
+#![deny(rustdoc::private_intra_doc_links)]
+#![deny(rustdoc::invalid_codeblock_attributes)]
+#![deny(rustdoc::invalid_rust_codeblocks)]
+#![deny(rustdoc::bare_urls)]
+
+use std::error::Error;
+use std::fmt::{self, Debug, Display, Formatter};
+
+use funcmap::{FuncMap, FuncMarker, TryFuncMap};
+use serde::de::{self, DeserializeOwned, Deserializer};
+use serde::ser::{self, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Prefix of the strings that values wrapped in [`Leaf`] are serialized into
+const LEAF_PREFIX: &str = "$funcmap_testing::Leaf:";
+
+/// Wrapper marking values of the mapped type parameter
+///
+/// A value `Leaf(x)` is serialized into a string containing the serialization
+/// of `x`, so it can be recognized within the serialized tree, even when it is
+/// used as the key of a map.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Leaf<T>(pub T);
+
+impl<T> Serialize for Leaf<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = serde_json::to_string(&self.0).map_err(ser::Error::custom)?;
+        serializer.serialize_str(&format!("{LEAF_PREFIX}{value}"))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Leaf<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        let value = string
+            .strip_prefix(LEAF_PREFIX)
+            .ok_or_else(|| de::Error::custom(format!("expected leaf, found {string:?}")))?;
+
+        serde_json::from_str(value)
+            .map(Leaf)
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Error that occurred while serializing or deserializing in a reference
+/// implementation
+#[derive(Debug)]
+pub struct ModelError(serde_json::Error);
+
+impl Display for ModelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "reference implementation failed: {}", self.0)
+    }
+}
+
+impl Error for ModelError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl From<serde_json::Error> for ModelError {
+    fn from(err: serde_json::Error) -> Self {
+        Self(err)
+    }
+}
+
+/// Reference implementation of [`FuncMap::func_map`]
+///
+/// Maps `src` of a type `S<Leaf<X>>` to the type `D = S<Leaf<Y>>` by applying
+/// `f` to every value wrapped in [`Leaf`].
+///
+/// # Errors
+/// Fails if `src` cannot be serialized or the result cannot be deserialized
+/// into `D`
+pub fn model_func_map<S, D, X, Y, F>(src: &S, mut f: F) -> Result<D, ModelError>
+where
+    S: Serialize,
+    D: DeserializeOwned,
+    X: DeserializeOwned,
+    Y: Serialize,
+    F: FnMut(X) -> Y,
+{
+    match model_try_func_map(src, |value| Ok::<_, ModelError>(f(value))) {
+        Ok(Ok(dst)) => Ok(dst),
+        Ok(Err(err)) | Err(err) => Err(err),
+    }
+}
+
+/// Reference implementation of [`TryFuncMap::try_func_map`]
+///
+/// Maps `src` of a type `S<Leaf<X>>` to the type `D = S<Leaf<Y>>` by applying
+/// `f` to every value wrapped in [`Leaf`], stopping at the first error
+/// according to the (unspecified) order of the serialized tree.
+///
+/// # Errors
+/// Fails if `src` cannot be serialized or the result cannot be deserialized
+/// into `D`. If this doesn't happen, but `f` fails, the result is `Ok(Err(_))`.
+pub fn model_try_func_map<S, D, X, Y, E, F>(src: &S, mut f: F) -> Result<Result<D, E>, ModelError>
+where
+    S: Serialize,
+    D: DeserializeOwned,
+    X: DeserializeOwned,
+    Y: Serialize,
+    F: FnMut(X) -> Result<Y, E>,
+{
+    let tree = serde_json::to_value(src)?;
+
+    let tree = match map_tree(tree, &mut |leaf: Leaf<X>| f(leaf.0).map(Leaf))? {
+        Ok(tree) => tree,
+        Err(err) => return Ok(Err(err)),
+    };
+
+    Ok(Ok(serde_json::from_value(tree)?))
+}
+
+/// Asserts that [`FuncMap::func_map`] produces the same output as the
+/// reference implementation [`model_func_map`]
+///
+/// # Panics
+/// Panics if the outputs differ or the reference implementation fails
+pub fn assert_func_map_matches_model<S, X, Y, F, P>(src: S, f: F)
+where
+    S: Serialize + FuncMap<Leaf<X>, Leaf<Y>, P>,
+    S::Output: DeserializeOwned + PartialEq + Debug,
+    X: DeserializeOwned,
+    Y: Serialize,
+    F: Fn(X) -> Y,
+    P: FuncMarker<P>,
+{
+    let expected: S::Output = model_func_map(&src, &f).unwrap_or_else(|err| panic!("{err}"));
+    let actual = src.func_map(|Leaf(value)| Leaf(f(value)));
+
+    assert_eq!(actual, expected);
+}
+
+/// Asserts that [`TryFuncMap::try_func_map`] produces the same output as the
+/// reference implementation [`model_try_func_map`]
+///
+/// If the reference implementation fails, this only asserts that
+/// [`TryFuncMap::try_func_map`] fails as well, but not that it fails with the
+/// same error.
+///
+/// # Panics
+/// Panics if the outputs differ or the reference implementation fails to
+/// serialize or deserialize
+pub fn assert_try_func_map_matches_model<S, X, Y, E, F, P>(src: S, f: F)
+where
+    S: Serialize + TryFuncMap<Leaf<X>, Leaf<Y>, P>,
+    S::Output: DeserializeOwned + PartialEq + Debug,
+    X: DeserializeOwned,
+    Y: Serialize,
+    E: Debug,
+    F: Fn(X) -> Result<Y, E>,
+    P: FuncMarker<P>,
+{
+    let expected: Result<S::Output, E> =
+        model_try_func_map(&src, &f).unwrap_or_else(|err| panic!("{err}"));
+    let actual = src.try_func_map(|Leaf(value)| f(value).map(Leaf));
+
+    match (actual, expected) {
+        (Ok(actual), Ok(expected)) => assert_eq!(actual, expected),
+        (Err(..), Err(..)) => {}
+        (actual, expected) => panic!(
+            "expected {}, found {}",
+            describe(&expected),
+            describe(&actual)
+        ),
+    }
+}
+
+/// Returns whether a result is `Ok(_)` or `Err(_)` for use in a panic message
+fn describe<T, E>(result: &Result<T, E>) -> &'static str {
+    match result {
+        Ok(..) => "`Ok(_)`",
+        Err(..) => "`Err(_)`",
+    }
+}
+
+/// Applies `f` to all leaves within `tree`, including keys of maps
+///
+/// Returns `Ok(Err(_))` as soon as `f` fails.
+fn map_tree<X, Y, E, F>(tree: Value, f: &mut F) -> Result<Result<Value, E>, ModelError>
+where
+    X: DeserializeOwned,
+    Y: Serialize,
+    F: FnMut(Leaf<X>) -> Result<Leaf<Y>, E>,
+{
+    Ok(Ok(match tree {
+        Value::String(string) => match map_leaf(string, f)? {
+            Ok(string) => Value::String(string),
+            Err(err) => return Ok(Err(err)),
+        },
+
+        Value::Array(values) => {
+            let mut mapped = Vec::with_capacity(values.len());
+
+            for value in values {
+                match map_tree(value, f)? {
+                    Ok(value) => mapped.push(value),
+                    Err(err) => return Ok(Err(err)),
+                }
+            }
+
+            Value::Array(mapped)
+        }
+
+        Value::Object(entries) => {
+            let mut mapped = serde_json::Map::new();
+
+            for (key, value) in entries {
+                let key = match map_leaf(key, f)? {
+                    Ok(key) => key,
+                    Err(err) => return Ok(Err(err)),
+                };
+
+                let value = match map_tree(value, f)? {
+                    Ok(value) => value,
+                    Err(err) => return Ok(Err(err)),
+                };
+
+                mapped.insert(key, value);
+            }
+
+            Value::Object(mapped)
+        }
+
+        tree @ (Value::Null | Value::Bool(..) | Value::Number(..)) => tree,
+    }))
+}
+
+/// Applies `f` to `string` if it represents a leaf, otherwise returns it
+/// unchanged
+fn map_leaf<X, Y, E, F>(string: String, f: &mut F) -> Result<Result<String, E>, ModelError>
+where
+    X: DeserializeOwned,
+    Y: Serialize,
+    F: FnMut(Leaf<X>) -> Result<Leaf<Y>, E>,
+{
+    if !string.starts_with(LEAF_PREFIX) {
+        return Ok(Ok(string));
+    }
+
+    let leaf = serde_json::from_value(Value::String(string))?;
+
+    Ok(match f(leaf) {
+        Ok(leaf) => match serde_json::to_value(leaf)? {
+            Value::String(string) => Ok(string),
+            _ => unreachable!("leaves are serialized into strings"),
+        },
+        Err(err) => Err(err),
+    })
+}
@@ -0,0 +1,44 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use funcmap::FuncMap;
+
+use std::collections::BinaryHeap;
+
+fn bench_binary_heap(c: &mut Criterion) {
+    let mut group = c.benchmark_group("binary_heap");
+
+    for len in [1_000, 100_000] {
+        let heap: BinaryHeap<u64> = (0..len).collect();
+
+        group.bench_with_input(BenchmarkId::new("func_map", len), &heap, |b, heap| {
+            b.iter_batched(
+                || heap.clone(),
+                |heap| heap.func_map(|v| v * 2),
+                BatchSize::LargeInput,
+            );
+        });
+
+        // rebuilding the heap by pushing the mapped values one by one takes
+        // `O(n log n)` time, as opposed to heapifying them in `O(n)` time as
+        // `func_map` does through `FromIterator`
+        group.bench_with_input(BenchmarkId::new("push", len), &heap, |b, heap| {
+            b.iter_batched(
+                || heap.clone(),
+                |heap| {
+                    let mut mapped = BinaryHeap::with_capacity(heap.len());
+
+                    for value in heap {
+                        mapped.push(value * 2);
+                    }
+
+                    mapped
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_binary_heap);
+criterion_main!(benches);
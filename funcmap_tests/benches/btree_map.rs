@@ -0,0 +1,52 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use funcmap::{FuncMap, TypeParam};
+
+use std::collections::BTreeMap;
+
+fn bench_btree_map(c: &mut Criterion) {
+    let mut group = c.benchmark_group("btree_map_values");
+
+    for len in [1_000, 100_000] {
+        let map: BTreeMap<String, u64> = (0..len).map(|i| (format!("key{i:08}"), i)).collect();
+
+        group.bench_with_input(BenchmarkId::new("func_map", len), &map, |b, map| {
+            b.iter_batched(
+                || map.clone(),
+                |map| FuncMap::<_, _, TypeParam<1>>::func_map(map, |v: u64| v * 2),
+                BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("insert", len), &map, |b, map| {
+            b.iter_batched(
+                || map.clone(),
+                |map| {
+                    let mut mapped = BTreeMap::new();
+                    for (k, v) in map {
+                        mapped.insert(k, v * 2);
+                    }
+                    mapped
+                },
+                BatchSize::LargeInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("values_mut", len), &map, |b, map| {
+            b.iter_batched(
+                || map.clone(),
+                |mut map| {
+                    for v in map.values_mut() {
+                        *v *= 2;
+                    }
+                    map
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_btree_map);
+criterion_main!(benches);
@@ -0,0 +1,67 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use funcmap::FuncMap;
+
+macro_rules! newtype_graph {
+    ($($inline:literal => $outer:ident, $middle:ident, $inner:ident;)*) => {
+        $(
+            #[derive(FuncMap, Clone)]
+            #[funcmap(inline = $inline)]
+            struct $inner<T>(T);
+
+            #[derive(FuncMap, Clone)]
+            #[funcmap(inline = $inline)]
+            struct $middle<T>($inner<T>, $inner<T>);
+
+            #[derive(FuncMap, Clone)]
+            #[funcmap(inline = $inline)]
+            struct $outer<T>(Vec<$middle<T>>);
+        )*
+    };
+}
+
+newtype_graph! {
+    "always" => OuterAlways, MiddleAlways, InnerAlways;
+    "hint" => OuterHint, MiddleHint, InnerHint;
+    "never" => OuterNever, MiddleNever, InnerNever;
+}
+
+#[derive(FuncMap, Clone)]
+struct InnerDefault<T>(T);
+
+#[derive(FuncMap, Clone)]
+struct MiddleDefault<T>(InnerDefault<T>, InnerDefault<T>);
+
+#[derive(FuncMap, Clone)]
+struct OuterDefault<T>(Vec<MiddleDefault<T>>);
+
+macro_rules! bench_graph {
+    ($group:ident, $name:literal, $outer:ident, $middle:ident, $inner:ident) => {
+        let graph = $outer(
+            (0..10_000_u64)
+                .map(|v| $middle($inner(v), $inner(v)))
+                .collect(),
+        );
+
+        $group.bench_function($name, |b| {
+            b.iter_batched(
+                || graph.clone(),
+                |graph| graph.func_map(|v| v * 2),
+                BatchSize::LargeInput,
+            );
+        });
+    };
+}
+
+fn bench_newtype(c: &mut Criterion) {
+    let mut group = c.benchmark_group("newtype");
+
+    bench_graph!(group, "default", OuterDefault, MiddleDefault, InnerDefault);
+    bench_graph!(group, "always", OuterAlways, MiddleAlways, InnerAlways);
+    bench_graph!(group, "hint", OuterHint, MiddleHint, InnerHint);
+    bench_graph!(group, "never", OuterNever, MiddleNever, InnerNever);
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_newtype);
+criterion_main!(benches);
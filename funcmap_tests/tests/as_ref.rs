@@ -0,0 +1,90 @@
+use funcmap::{FuncMap, FuncMapAsRef, TypeParam};
+
+use std::path::{Path, PathBuf};
+
+#[test]
+fn field_of_generic_param_type_is_mapped_by_reference() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, i32);
+
+    let src = Test(String::from("abc"), 42);
+    let dst = src.func_map_as_ref(str::len);
+
+    assert_eq!(dst, Test(3, 42));
+}
+
+#[test]
+fn target_of_as_ref_is_inferred_from_closure() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<Option<T>>,
+    }
+
+    let src = Test {
+        value: PathBuf::from("a/b.txt"),
+        values: vec![Some(PathBuf::from("c")), None],
+    };
+    let dst = src.func_map_as_ref(|path: &Path| path.extension().is_some());
+
+    assert_eq!(
+        dst,
+        Test {
+            value: true,
+            values: vec![Some(false), None],
+        }
+    );
+}
+
+#[test]
+fn string_fields_are_mapped_via_str() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+    }
+
+    let src = Test {
+        value: String::from("a"),
+        values: vec![String::from("b"), String::from("c")],
+    };
+    let dst = src.func_map_str(|s| format!("{}!", s));
+
+    assert_eq!(
+        dst,
+        Test {
+            value: String::from("a!"),
+            values: vec![String::from("b!"), String::from("c!")],
+        }
+    );
+}
+
+#[test]
+fn str_fields_are_mapped_into_strings() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, T);
+
+    let src = Test("a", "b");
+    let dst = src.func_map_str(str::to_uppercase);
+
+    assert_eq!(dst, Test(String::from("A"), String::from("B")));
+}
+
+#[test]
+fn type_param_to_map_by_reference_can_be_specified() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(String::from("a"), String::from("bc"));
+    let dst = FuncMapAsRef::<_, TypeParam<1>>::func_map_as_ref(src, str::len);
+
+    assert_eq!(dst, Test(String::from("a"), 2));
+}
+
+#[test]
+fn foreign_type_is_mapped_by_reference() {
+    let src = vec![vec![1, 2], vec![3]];
+    let dst = src.func_map_as_ref(<[i32]>::len);
+
+    assert_eq!(dst, vec![2, 1]);
+}
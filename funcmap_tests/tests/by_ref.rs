@@ -0,0 +1,71 @@
+use funcmap::{FuncMap, TryFuncMap, TypeParam};
+
+#[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+struct Test<T>(T, Vec<T>);
+
+#[test]
+fn closure_is_reused_across_calls() {
+    let mut input = Vec::new();
+    let mut f = |v: i32| {
+        input.push(v);
+        v * 10
+    };
+
+    let first = Test(1, vec![2]).func_map_by_ref(&mut f);
+    let second = Test(3, vec![]).func_map_by_ref(&mut f);
+
+    assert_eq!(first, Test(10, vec![20]));
+    assert_eq!(second, Test(30, vec![]));
+    assert_eq!(input, [1, 2, 3]);
+}
+
+#[test]
+fn trait_object_is_accepted() {
+    let mut f = |v: i32| v + 1;
+    let f: &mut dyn FnMut(i32) -> i32 = &mut f;
+
+    let dst = Test(1, vec![2]).func_map_by_ref(f);
+
+    assert_eq!(dst, Test(2, vec![3]));
+}
+
+#[test]
+fn closure_is_reused_across_calls_over_given_type_param() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let mut count = 0;
+    let mut f = |v: i32| {
+        count += 1;
+        v + count
+    };
+
+    let first = FuncMap::<_, _, TypeParam<1>>::func_map_by_ref(Test(1, 1), &mut f);
+    let second = FuncMap::<_, _, TypeParam<1>>::func_map_by_ref(Test(1, 1), &mut f);
+
+    assert_eq!(first, Test(1, 2));
+    assert_eq!(second, Test(1, 3));
+}
+
+#[test]
+fn closure_is_reused_across_fallible_calls() {
+    let mut count = 0;
+    let mut f = |v: i32| {
+        count += 1;
+        if v < 0 {
+            Err(TestError)
+        } else {
+            Ok(v * 10)
+        }
+    };
+
+    let first = Test(1, vec![2]).try_func_map_by_ref(&mut f);
+    let second = Test(3, vec![-1, 4]).try_func_map_by_ref(&mut f);
+
+    assert_eq!(first, Ok(Test(10, vec![20])));
+    assert_eq!(second, Err(TestError));
+    assert_eq!(count, 4);
+}
+
+#[derive(Debug, PartialEq)]
+struct TestError;
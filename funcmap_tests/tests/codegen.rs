@@ -0,0 +1,175 @@
+use funcmap_codegen::{Derivable, Error};
+use proc_macro2::TokenStream;
+
+#[test]
+fn func_map_impl_is_generated() {
+    let output = try_derive("struct Test<T>(T);", Derivable::Standard).unwrap();
+
+    assert!(output.contains(":: funcmap :: FuncMap <"));
+    assert!(!output.contains(":: funcmap :: TryFuncMap <"));
+}
+
+#[test]
+fn try_func_map_impl_is_generated() {
+    let output = try_derive("struct Test<T>(T);", Derivable::Fallible).unwrap();
+
+    assert!(output.contains(":: funcmap :: TryFuncMap <"));
+}
+
+#[test]
+fn generated_impl_respects_crate_option() {
+    let output = try_derive(
+        r#"#[funcmap(crate = "fm")] struct Test<T>(T);"#,
+        Derivable::Standard,
+    )
+    .unwrap();
+
+    assert!(output.contains("fm :: FuncMap <"));
+    assert!(!output.contains(":: funcmap ::"));
+}
+
+#[test]
+fn generated_impl_respects_allow_option() {
+    let output = try_derive(
+        "#[funcmap(allow(unused_results, clippy::pedantic))] struct Test<T>(T);",
+        Derivable::Standard,
+    )
+    .unwrap();
+
+    assert!(output.contains("# [allow (unused_results)]"));
+    assert!(output.contains("# [allow (clippy :: pedantic)]"));
+}
+
+#[test]
+fn generated_impl_respects_canonical_option() {
+    let output1 = try_derive(
+        "#[funcmap(canonical)] struct Test<T: Copy + Clone> { a: Foo<T>, b: Bar<T> }",
+        Derivable::Standard,
+    )
+    .unwrap();
+    let output2 = try_derive(
+        "#[funcmap(canonical)] struct Test<T: Clone + Copy> { b: Bar<T>, a: Foo<T> }",
+        Derivable::Standard,
+    )
+    .unwrap();
+
+    let impl_header = |output: &str| {
+        let start = output.find("impl <").unwrap();
+        let end = output.find("{ type Output").unwrap();
+        output[start..end].to_owned()
+    };
+
+    assert_eq!(impl_header(&output1), impl_header(&output2));
+    assert!(impl_header(&output1).contains("impl < A : Clone + Copy , B : Clone + Copy >"));
+    assert!(impl_header(&output1).find("Bar < A >") < impl_header(&output1).find("Foo < A >"));
+}
+
+#[test]
+fn generated_impl_respects_drop_bounds_option() {
+    let output = try_derive(
+        "#[funcmap(drop_bounds(B: Marker))] struct Test<T: Scalar + Marker>(T);",
+        Derivable::Standard,
+    )
+    .unwrap();
+
+    assert!(output.contains("impl < A : Scalar + Marker , B : Scalar >"));
+}
+
+#[test]
+fn invalid_item_yields_error() {
+    let err = try_derive("struct Test;", Derivable::Standard).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "expected at least one type parameter, found none"
+    );
+}
+
+#[test]
+fn unparsable_item_yields_error() {
+    let err = try_derive("fn test() {}", Derivable::Standard).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "expected one of: `struct`, `enum`, `union`"
+    );
+}
+
+#[test]
+fn unparsable_field_yields_error_pointing_at_field() {
+    let item: TokenStream = "struct Test<T> { value: T, other: [T; +] }"
+        .parse()
+        .unwrap();
+    let output = funcmap_codegen::derive(item, Derivable::Standard).to_string();
+
+    assert!(output.contains("in field `other` of struct `Test`"));
+}
+
+#[test]
+fn unparsable_tuple_field_yields_error_pointing_at_field() {
+    let item: TokenStream = "struct Test<T>(T, [T; +]);".parse().unwrap();
+    let output = funcmap_codegen::derive(item, Derivable::Standard).to_string();
+
+    assert!(output.contains("in field `1` of struct `Test`"));
+}
+
+#[test]
+fn unparsable_variant_yields_error_pointing_at_variant() {
+    let item: TokenStream = "enum Test<T> { First(T), Second(T) = + }".parse().unwrap();
+    let output = funcmap_codegen::derive(item, Derivable::Standard).to_string();
+
+    assert!(output.contains("in variant `Test::Second`"));
+}
+
+#[test]
+fn unparsable_generics_yield_error_pointing_at_generics() {
+    let item: TokenStream = "struct Test<T: +>(T);".parse().unwrap();
+    let output = funcmap_codegen::derive(item, Derivable::Standard).to_string();
+
+    assert!(output.contains("in generic parameters of struct `Test`"));
+}
+
+#[test]
+fn invalid_item_yields_compile_error() {
+    let item: TokenStream = "struct Test;".parse().unwrap();
+    let output = funcmap_codegen::derive(item, Derivable::Standard).to_string();
+
+    assert!(output.starts_with(":: core :: compile_error !"));
+    assert!(output.contains("expected at least one type parameter, found none"));
+}
+
+#[test]
+fn structural_from_impl_is_generated() {
+    let item: TokenStream = r#"#[funcmap(from = "Source")] struct Test<T>(T);"#.parse().unwrap();
+    let output = funcmap_codegen::try_derive_structural_from(item)
+        .unwrap()
+        .to_string();
+
+    assert!(output.contains("From < Source <"));
+}
+
+#[test]
+fn join_params_impl_is_generated() {
+    let item: TokenStream = "struct Test<S, T>(S, T);".parse().unwrap();
+    let output = funcmap_codegen::try_derive_join_params(item)
+        .unwrap()
+        .to_string();
+
+    assert!(output.contains("JoinParams <"));
+}
+
+#[test]
+fn family_map_impl_is_generated() {
+    let item: TokenStream = "struct Test<F: Family>(F::Of<i32>);".parse().unwrap();
+    let output = funcmap_codegen::try_derive_family_map(item)
+        .unwrap()
+        .to_string();
+
+    assert!(output.contains("FamilyMap <"));
+    assert!(output.contains("NaturalTransformation <"));
+}
+
+fn try_derive(item: &str, derivable: Derivable) -> Result<String, Error> {
+    let item: TokenStream = item.parse().unwrap();
+    funcmap_codegen::try_derive(item, derivable).map(|output| output.to_string())
+}
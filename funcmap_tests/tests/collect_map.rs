@@ -0,0 +1,116 @@
+use funcmap::{CollectMap, FuncMap, TryFuncMap};
+
+use std::collections::{btree_set, BTreeSet};
+use std::iter::FromIterator;
+use std::vec;
+
+#[derive(Debug, PartialEq)]
+struct Stack<T>(Vec<T>);
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+funcmap::impl_collect_map!(Stack);
+
+#[derive(Debug, PartialEq)]
+struct Set<T>(BTreeSet<T>);
+
+impl<T> IntoIterator for Set<T> {
+    type Item = T;
+    type IntoIter = btree_set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for Set<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+funcmap::impl_collect_map!(Set where B: Ord);
+
+#[test]
+fn collection_is_mapped() {
+    let src = Stack(vec![1, 2, 3]);
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(dst, Stack(vec![10, 20, 30]));
+}
+
+#[test]
+fn collection_is_try_mapped() {
+    let dst: Result<_, i32> = Stack(vec![1, 2, 3]).try_func_map(Ok);
+    assert_eq!(dst, Ok(Stack(vec![1, 2, 3])));
+
+    let mut calls = 0;
+    let dst: Result<Stack<i32>, _> = Stack(vec![1, 2, 3]).try_func_map(|v| {
+        calls += 1;
+        if v < 2 {
+            Ok(v)
+        } else {
+            Err(v)
+        }
+    });
+
+    assert_eq!(dst, Err(2));
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn collection_with_bounds_is_mapped() {
+    let src = Set(BTreeSet::from([1, 2, 3]));
+    let dst = src.func_map(|v| v % 2);
+
+    assert_eq!(dst, Set(BTreeSet::from([0, 1])));
+}
+
+#[test]
+fn collection_is_mapped_through_collect_map() {
+    let dst = Stack(vec![1, 2]).collect_map(|v| v.to_string());
+
+    assert_eq!(dst, Stack(vec![String::from("1"), String::from("2")]));
+}
+
+#[test]
+fn field_of_collection_type_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        stack: Stack<T>,
+        set: Set<T>,
+    }
+
+    let src = Test {
+        stack: Stack(vec![T1, T1]),
+        set: Set(BTreeSet::from([T1])),
+    };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test {
+            stack: Stack(vec![T2, T2]),
+            set: Set(BTreeSet::from([T2])),
+        }
+    );
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct T1;
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct T2;
@@ -0,0 +1,61 @@
+use funcmap::convert::{parse, truncate_checked, widen};
+use funcmap::{FuncMap, TryFuncMap};
+
+use std::num::{ParseIntError, TryFromIntError};
+
+#[test]
+fn widen_converts_via_from() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test(1_u8, vec![2, 3]);
+    let dst: Test<u64> = src.func_map(widen());
+
+    assert_eq!(dst, Test(1, vec![2, 3]));
+}
+
+#[test]
+fn truncate_checked_converts_values_that_fit() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test(1_u64, vec![2, 255]);
+    let dst = src.try_func_map(truncate_checked::<u8, _>());
+
+    assert_eq!(dst, Ok(Test(1, vec![2, 255])));
+}
+
+#[test]
+fn truncate_checked_fails_for_values_that_dont_fit() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test(1_i64, vec![2, -1]);
+    let dst: Result<Test<u32>, TryFromIntError> = src.try_func_map(truncate_checked());
+
+    assert!(dst.is_err());
+}
+
+#[test]
+fn parse_converts_str_and_string() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test("1", vec!["2", "3"]);
+    let dst: Result<Test<i32>, ParseIntError> = src.try_func_map(parse());
+
+    assert_eq!(dst, Ok(Test(1, vec![2, 3])));
+
+    let src = Test(String::from("1"), vec![String::from("-2")]);
+    let dst: Result<Test<i32>, ParseIntError> = src.try_func_map(parse());
+
+    assert_eq!(dst, Ok(Test(1, vec![-2])));
+}
+
+#[test]
+fn parse_fails_for_invalid_strings() {
+    let src = vec!["1", "two"];
+    let dst = src.try_func_map(parse::<i32, _>());
+
+    assert!(dst.is_err());
+}
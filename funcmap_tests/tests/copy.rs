@@ -0,0 +1,68 @@
+use funcmap::{CopyFuncMap, FuncMap, TypeParam};
+
+#[test]
+fn func_map_copy_maps_copy_of_value() {
+    #[derive(FuncMap, Copy, Clone, Debug, PartialEq)]
+    struct Test<T> {
+        x: T,
+        y: T,
+        other: u8,
+    }
+
+    let src = Test {
+        x: 1,
+        y: 2,
+        other: 3,
+    };
+    let dst = src.func_map_copy(|v| v * 10);
+
+    assert_eq!(
+        src,
+        Test {
+            x: 1,
+            y: 2,
+            other: 3
+        }
+    );
+    assert_eq!(
+        dst,
+        Test {
+            x: 10,
+            y: 20,
+            other: 3
+        }
+    );
+}
+
+#[test]
+fn func_map_copy_can_change_type() {
+    #[derive(FuncMap, Copy, Clone, Debug, PartialEq)]
+    struct Test<T>([T; 2]);
+
+    let src = Test([1_i32, 2]);
+    let dst = src.func_map_copy(f64::from);
+
+    assert_eq!(dst, Test([1.0, 2.0]));
+}
+
+#[test]
+fn func_map_copy_can_be_used_repeatedly_through_reference() {
+    #[derive(FuncMap, Copy, Clone, Debug, PartialEq)]
+    struct Test<T>(T, Option<T>);
+
+    let src = &Test(1, Some(2));
+    let dsts: Vec<_> = (0..3).map(|i| src.func_map_copy(|v| v + i)).collect();
+
+    assert_eq!(dsts, [Test(1, Some(2)), Test(2, Some(3)), Test(3, Some(4))]);
+}
+
+#[test]
+fn type_param_to_map_over_can_be_specified() {
+    #[derive(FuncMap, Copy, Clone, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(1, 2);
+    let dst = CopyFuncMap::<_, _, TypeParam<1>>::func_map_copy(&src, |v| v + 1);
+
+    assert_eq!(dst, Test(1, 3));
+}
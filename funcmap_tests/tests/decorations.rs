@@ -46,6 +46,24 @@ fn defaults_on_type_params_are_supported() {
     assert_eq!(dst, Test(T2, T1));
 }
 
+#[test]
+fn defaults_referring_to_earlier_type_params_are_not_recomputed() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T = Vec<S>>(S, T);
+
+    let src: Test<T1> = Test(T1, vec![T1]);
+    let dst: Test<T2, Vec<T1>> = src.func_map_over::<TypeParam<0>, _>(|_| T2);
+
+    assert_eq!(dst, Test(T2, vec![T1]));
+
+    let src: Test<T1> = Test(T1, vec![T1]);
+    let dst: Test<T2> = src
+        .func_map_over::<TypeParam<0>, _>(|_| T2)
+        .func_map_over::<TypeParam<1>, _>(|v| v.func_map(|_| T2));
+
+    assert_eq!(dst, Test(T2, vec![T2]));
+}
+
 #[test]
 fn defaults_on_const_params_are_supported() {
     #[derive(FuncMap, Debug, PartialEq)]
@@ -374,6 +392,115 @@ fn impl_is_restricted_to_sized_bound_on_unmapped_inner_type() {
     assert_eq!(dst, Test(T2, ()));
 }
 
+#[test]
+fn impl_is_restricted_to_trait_bound_with_assoc_items_on_unmapped_generic_of_original_type() {
+    trait Mode {
+        const N: usize;
+        type Out;
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Fast;
+
+    impl Mode for Fast {
+        const N: usize = 2;
+        type Out = i32;
+    }
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T, M: Mode>(T, PhantomData<M>);
+
+    impl<T, M: Mode> Test<T, M> {
+        fn n(&self) -> usize {
+            M::N
+        }
+    }
+
+    let src = Test::<_, Fast>(T1, PhantomData);
+    let dst = src.func_map_over::<TypeParam<0>, _>(|_| T2);
+
+    assert_eq!(dst.n(), 2);
+    assert_eq!(dst, Test(T2, PhantomData));
+}
+
+#[test]
+fn impl_is_restricted_to_assoc_type_bindings_in_where_clause_on_unmapped_generic() {
+    trait Mode {
+        type Out;
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Fast;
+
+    impl Mode for Fast {
+        type Out = i32;
+    }
+
+    // derived impl is supposed to keep the binding of `M::Out` as well as the
+    // bounds on projections of `M`
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<T, M>(T, M::Out, PhantomData<M>)
+    where
+        M: Mode<Out = i32>,
+        M::Out: Copy,
+        <M as Mode>::Out: Default;
+
+    let src = Test::<_, Fast>(T1, 42, PhantomData);
+    let dst = src.func_map_over::<TypeParam<0>, _>(|_| T2);
+
+    assert_eq!(dst, Test(T2, 42, PhantomData));
+}
+
+#[test]
+fn impl_is_restricted_to_mapped_generic_bounded_by_projection_of_unmapped_generic() {
+    trait Mode {
+        type Out;
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Fast;
+
+    impl Mode for Fast {
+        type Out = i32;
+    }
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<T, M: Mode>(Vec<T>, PhantomData<M>)
+    where
+        T: PartialEq<M::Out>;
+
+    let src = Test::<_, Fast>(vec![1, 2], PhantomData);
+    let dst = src.func_map(|v| v + 1);
+
+    assert_eq!(dst, Test(vec![2, 3], PhantomData));
+}
+
+#[test]
+fn impl_maps_to_same_type_for_assoc_type_of_unmapped_generic_bound_to_mapped_generic() {
+    trait Mode {
+        type Out;
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Fast;
+
+    impl Mode for Fast {
+        type Out = i32;
+    }
+
+    // `M: Mode<Out = A>` and `M: Mode<Out = B>` can only both hold for
+    // `A == B`, so the derived impl is supposed to map `T` to itself
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T, M: Mode<Out = T>>(Vec<T>, PhantomData<M>);
+
+    let src = Test::<_, Fast>(vec![1, 2], PhantomData);
+    let dst = src.func_map_over::<TypeParam<0>, _>(|v| v + 1);
+
+    assert_eq!(dst, Test(vec![2, 3], PhantomData));
+}
+
 #[test]
 fn const_args_with_braces_are_supported() {
     #[derive(Debug, PartialEq)]
@@ -0,0 +1,128 @@
+use funcmap::{FuncMap, FuncMapDiff, TypeParam};
+
+#[test]
+fn diff_contains_changed_values_at_their_positions() {
+    #[derive(FuncMap, Clone, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+        other: i32,
+        last: Option<T>,
+    }
+
+    let old = Test {
+        value: "a",
+        values: vec!["b", "c"],
+        other: 42,
+        last: Some("d"),
+    };
+    let new = Test {
+        value: "x",
+        values: vec!["b", "y"],
+        other: 42,
+        last: Some("d"),
+    };
+
+    let diff = old.func_diff(&new).unwrap();
+
+    assert_eq!(diff.changes(), [(0, "x"), (2, "y")]);
+    assert_eq!(diff.num_values(), 4);
+    assert!(!diff.is_unchanged());
+}
+
+#[test]
+fn diff_of_equal_values_is_unchanged() {
+    #[derive(FuncMap, Clone, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let value = Test(1, vec![2, 3]);
+
+    let diff = value.func_diff(&value).unwrap();
+
+    assert!(diff.is_unchanged());
+    assert_eq!(diff.num_values(), 3);
+}
+
+#[test]
+fn diff_of_values_with_different_shapes_is_none() {
+    #[derive(FuncMap, Clone, Debug, PartialEq)]
+    struct Test<T>(Vec<T>);
+
+    let old = Test(vec![1, 2]);
+    let new = Test(vec![1, 2, 3]);
+
+    assert_eq!(old.func_diff(&new), None);
+}
+
+#[test]
+fn diff_ignores_fields_not_depending_on_type_param() {
+    #[derive(FuncMap, Clone, Debug, PartialEq)]
+    struct Test<T>(T, i32);
+
+    let old = Test(1, 2);
+    let new = Test(1, 3);
+
+    assert!(old.func_diff(&new).unwrap().is_unchanged());
+}
+
+#[test]
+fn applying_diff_turns_old_value_into_new_value() {
+    #[derive(FuncMap, Clone, Debug, PartialEq)]
+    enum Test<T> {
+        Empty,
+        Values(T, Vec<Option<T>>),
+    }
+
+    let old = Test::Values(1, vec![Some(2), None, Some(3)]);
+    let new = Test::Values(1, vec![Some(4), None, Some(5)]);
+
+    let diff = old.func_diff(&new).unwrap();
+
+    assert_eq!(diff.clone().into_changes(), vec![(1, 4), (2, 5)]);
+    assert_eq!(old.apply_diff(&diff), new);
+    assert_eq!(
+        Test::<i32>::Empty
+            .func_diff(&Test::Empty)
+            .unwrap()
+            .num_values(),
+        0
+    );
+}
+
+#[test]
+fn applying_diff_to_value_of_same_shape_replaces_changed_positions() {
+    #[derive(FuncMap, Clone, Debug, PartialEq)]
+    struct Test<T>(T, T, T);
+
+    let diff = Test(1, 2, 3).func_diff(&Test(1, 20, 3)).unwrap();
+
+    assert_eq!(Test(7, 8, 9).apply_diff(&diff), Test(7, 20, 9));
+}
+
+#[test]
+#[should_panic = "diff was computed from values of a different shape"]
+fn applying_diff_to_value_of_different_shape_panics() {
+    #[derive(FuncMap, Clone, Debug, PartialEq)]
+    struct Test<T>(Vec<T>);
+
+    let diff = Test(vec![1, 2]).func_diff(&Test(vec![1, 3])).unwrap();
+
+    let _ = Test(vec![1, 2, 3]).apply_diff(&diff);
+}
+
+#[test]
+fn type_param_to_diff_over_can_be_specified() {
+    #[derive(FuncMap, Clone, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let old = Test(1, 2);
+    let new = Test(3, 4);
+
+    let diff = FuncMapDiff::<_, TypeParam<1>>::func_diff(&old, &new).unwrap();
+
+    assert_eq!(diff.changes(), [(0, 4)]);
+    assert_eq!(
+        FuncMapDiff::<_, TypeParam<1>>::apply_diff(old, &diff),
+        Test(1, 4)
+    );
+}
@@ -0,0 +1,82 @@
+use funcmap::{map_all, FuncMap, FuncMapEach, TypeParam};
+
+#[derive(FuncMap, Debug, PartialEq)]
+struct Test<T>(T, Vec<T>);
+
+#[test]
+fn items_are_mapped_with_shared_closure() {
+    let src = vec![Test(1, vec![2]), Test(3, vec![4, 5])];
+
+    let mut input = Vec::new();
+    let dst: Vec<_> = src
+        .into_iter()
+        .func_map_each(|v| {
+            input.push(v);
+            v * 10
+        })
+        .collect();
+
+    assert_eq!(input, [1, 2, 3, 4, 5]);
+    assert_eq!(dst, [Test(10, vec![20]), Test(30, vec![40, 50])]);
+}
+
+#[test]
+fn items_are_mapped_via_map_all() {
+    let src = [Test(1, vec![]), Test(2, vec![3])];
+
+    let dst: Vec<_> = map_all(src, |v| v + 1).collect();
+
+    assert_eq!(dst, [Test(2, vec![]), Test(3, vec![4])]);
+}
+
+#[test]
+fn items_are_mapped_over_given_type_param() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = vec![Test(1, 2), Test(3, 4)];
+
+    let dst: Vec<_> = src
+        .into_iter()
+        .func_map_each::<_, _, TypeParam<1>, _>(|v| v * 10)
+        .collect();
+
+    assert_eq!(dst, [Test(1, 20), Test(3, 40)]);
+}
+
+#[test]
+fn size_hint_is_preserved() {
+    let src = vec![Test(1, vec![2]), Test(3, vec![])];
+
+    let mut dst = src.into_iter().func_map_each(|v| v + 1);
+
+    assert_eq!(dst.size_hint(), (2, Some(2)));
+    assert_eq!(dst.len(), 2);
+
+    let _ = dst.next();
+
+    assert_eq!(dst.size_hint(), (1, Some(1)));
+
+    let src = (0..).map(|v| Test(v, vec![]));
+    let dst = src.func_map_each(|v| v + 1);
+
+    assert_eq!(dst.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn items_are_mapped_from_back() {
+    let src = vec![Test(1, vec![]), Test(2, vec![])];
+
+    let mut input = Vec::new();
+    let dst: Vec<_> = src
+        .into_iter()
+        .func_map_each(|v| {
+            input.push(v);
+            v
+        })
+        .rev()
+        .collect();
+
+    assert_eq!(input, [2, 1]);
+    assert_eq!(dst, [Test(2, vec![]), Test(1, vec![])]);
+}
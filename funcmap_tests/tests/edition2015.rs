@@ -53,6 +53,67 @@ fn leading_colon_referring_to_crate_root_is_supported() {
     assert_eq!(dst, Test(T2));
 }
 
+#[test]
+fn crate_path_is_resolved_relative_to_current_module() {
+    mod inner {
+        use funcmap::FuncMap;
+
+        #[derive(FuncMap, Debug, PartialEq)]
+        #[funcmap(crate = "reexports::funcmap")]
+        pub struct Test<T>(pub T, pub Vec<T>);
+
+        mod reexports {
+            pub mod funcmap {
+                pub use funcmap::*;
+            }
+        }
+    }
+
+    let src = inner::Test(T1, vec![T1]);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, inner::Test(T2, vec![T2]));
+}
+
+#[test]
+fn crate_path_starting_with_crate_or_self_is_supported() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(crate = "crate::reexports::funcmap")]
+    struct Test<T>(T, Vec<T>);
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(crate = "self::reexports::funcmap")]
+    struct OtherTest<T>(T, Vec<T>);
+
+    let src = Test(T1, vec![T1]);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, vec![T2]));
+
+    let src = OtherTest(T1, vec![T1]);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, OtherTest(T2, vec![T2]));
+}
+
+#[test]
+fn crate_path_with_leading_colon_refers_to_crate_root() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(crate = "::reexports::funcmap")]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test(T1, vec![T1]);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, vec![T2]));
+}
+
+mod reexports {
+    pub mod funcmap {
+        pub use funcmap::*;
+    }
+}
+
 #[derive(Debug, PartialEq)]
 struct T1;
 
@@ -0,0 +1,75 @@
+use funcmap::{FuncMap, FuncMapEnumerated, TypeParam};
+
+use std::collections::BTreeMap;
+
+#[test]
+fn positions_are_assigned_depth_first_in_field_order() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<Option<T>>,
+        other: i32,
+        nested: Inner<T>,
+    }
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    enum Inner<T> {
+        Pair(T, [T; 2]),
+    }
+
+    let src = Test {
+        value: 'a',
+        values: vec![Some('b'), None, Some('c')],
+        other: 42,
+        nested: Inner::Pair('d', ['e', 'f']),
+    };
+    let dst = src.func_map_enumerated(|idx, value| (idx, value));
+
+    assert_eq!(
+        dst,
+        Test {
+            value: (0, 'a'),
+            values: vec![Some((1, 'b')), None, Some((2, 'c'))],
+            other: 42,
+            nested: Inner::Pair((3, 'd'), [(4, 'e'), (5, 'f')]),
+        }
+    );
+}
+
+#[test]
+fn positions_are_stable_for_values_of_same_shape() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let src1 = Test("a", vec!["b", "c"]);
+    let src2 = Test(1, vec![2, 3]);
+
+    let dst1 = src1.func_map_enumerated(|idx, _| idx);
+    let dst2 = src2.func_map_enumerated(|idx, _| idx);
+
+    assert_eq!(dst1, dst2);
+}
+
+#[test]
+fn type_param_to_enumerate_can_be_specified() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T, T);
+
+    let src = Test('a', 'b', 'c');
+    let dst = FuncMapEnumerated::<_, _, TypeParam<1>>::func_map_enumerated(src, |idx, _| idx);
+
+    assert_eq!(dst, Test('a', 0, 1));
+}
+
+#[test]
+fn foreign_type_is_mapped_enumerated() {
+    let src: BTreeMap<_, _> = vec![(1, 'a'), (2, 'b')].into_iter().collect();
+    let dst = src.func_map_enumerated(|idx, value: char| format!("{}{}", value, idx));
+
+    assert_eq!(
+        dst,
+        vec![(1, String::from("a0")), (2, String::from("b1"))]
+            .into_iter()
+            .collect::<BTreeMap<_, _>>()
+    );
+}
@@ -11,26 +11,13 @@ enum Test<T> {
 #[allow(dyn_drop)]
 #[allow(non_camel_case_types)]
 #[allow(trivial_bounds)]
+#[allow(unused_parens)]
 #[allow(unused_qualifications)]
 #[allow(clippy::disallowed_method)]
 #[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
 #[automatically_derived]
-impl<T> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop for Test<T> {}
-#[allow(absolute_paths_not_starting_with_crate)]
-#[allow(bare_trait_objects)]
-#[allow(deprecated)]
-#[allow(drop_bounds)]
-#[allow(dyn_drop)]
-#[allow(non_camel_case_types)]
-#[allow(trivial_bounds)]
-#[allow(unused_qualifications)]
-#[allow(clippy::disallowed_method)]
-#[allow(clippy::disallowed_type)]
-#[automatically_derived]
-impl<A, B> ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<0usize>> for Test<A>
-where
-    i32: ::core::marker::Sized,
-{
+impl<A, B> ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<0usize>> for Test<A> {
     type Output = Test<B>;
     fn func_map<F>(self, mut f: F) -> Self::Output
     where
@@ -56,6 +43,129 @@ where
         }
     }
 }
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop
+    for Test<T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<0usize>> for Test<T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for Test<T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapParams for Test<T> {
+        const PARAMS: &'static [usize] = &[0usize];
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapVariant<{ ::funcmap::param_name_hash("UnitVariant") }>
+    for Test<T> {
+        fn is_variant(&self) -> bool {
+            ::core::matches!(self, Self::UnitVariant { .. })
+        }
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapVariant<{ ::funcmap::param_name_hash("TupleVariant") }>
+    for Test<T> {
+        fn is_variant(&self) -> bool {
+            ::core::matches!(self, Self::TupleVariant { .. })
+        }
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapVariant<{ ::funcmap::param_name_hash("StructVariant") }>
+    for Test<T> {
+        fn is_variant(&self) -> bool {
+            ::core::matches!(self, Self::StructVariant { .. })
+        }
+    }
+};
 #[allow(absolute_paths_not_starting_with_crate)]
 #[allow(bare_trait_objects)]
 #[allow(deprecated)]
@@ -63,26 +173,13 @@ where
 #[allow(dyn_drop)]
 #[allow(non_camel_case_types)]
 #[allow(trivial_bounds)]
+#[allow(unused_parens)]
 #[allow(unused_qualifications)]
 #[allow(clippy::disallowed_method)]
 #[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
 #[automatically_derived]
-impl<T> ::funcmap::TryFuncMap_cannot_be_derived_for_types_implementing_Drop for Test<T> {}
-#[allow(absolute_paths_not_starting_with_crate)]
-#[allow(bare_trait_objects)]
-#[allow(deprecated)]
-#[allow(drop_bounds)]
-#[allow(dyn_drop)]
-#[allow(non_camel_case_types)]
-#[allow(trivial_bounds)]
-#[allow(unused_qualifications)]
-#[allow(clippy::disallowed_method)]
-#[allow(clippy::disallowed_type)]
-#[automatically_derived]
-impl<A, B> ::funcmap::TryFuncMap<A, B, ::funcmap::TypeParam<0usize>> for Test<A>
-where
-    i32: ::core::marker::Sized,
-{
+impl<A, B> ::funcmap::TryFuncMap<A, B, ::funcmap::TypeParam<0usize>> for Test<A> {
     type Output = Test<B>;
     fn try_func_map<E, F>(self, mut f: F) -> ::core::result::Result<Self::Output, E>
     where
@@ -110,3 +207,126 @@ where
         )
     }
 }
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMap_cannot_be_derived_for_types_implementing_Drop
+    for Test<T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapTypeParam<::funcmap::TypeParam<0usize>> for Test<T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for Test<T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapParams for Test<T> {
+        const PARAMS: &'static [usize] = &[0usize];
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapVariant<{ ::funcmap::param_name_hash("UnitVariant") }>
+    for Test<T> {
+        fn is_variant(&self) -> bool {
+            ::core::matches!(self, Self::UnitVariant { .. })
+        }
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapVariant<{ ::funcmap::param_name_hash("TupleVariant") }>
+    for Test<T> {
+        fn is_variant(&self) -> bool {
+            ::core::matches!(self, Self::TupleVariant { .. })
+        }
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapVariant<{ ::funcmap::param_name_hash("StructVariant") }>
+    for Test<T> {
+        fn is_variant(&self) -> bool {
+            ::core::matches!(self, Self::StructVariant { .. })
+        }
+    }
+};
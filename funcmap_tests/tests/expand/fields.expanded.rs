@@ -0,0 +1,914 @@
+use funcmap::{FuncMap, TryFuncMap};
+struct RawIdents<T> {
+    r#type: T,
+    r#match: T,
+}
+#[allow(absolute_paths_not_starting_with_crate)]
+#[allow(bare_trait_objects)]
+#[allow(deprecated)]
+#[allow(drop_bounds)]
+#[allow(dyn_drop)]
+#[allow(non_camel_case_types)]
+#[allow(trivial_bounds)]
+#[allow(unused_parens)]
+#[allow(unused_qualifications)]
+#[allow(clippy::disallowed_method)]
+#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
+#[automatically_derived]
+impl<A, B> ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<0usize>> for RawIdents<A> {
+    type Output = RawIdents<B>;
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: ::core::ops::FnMut(A) -> B,
+    {
+        match self {
+            Self { r#type: field_type, r#match: field_match } => {
+                Self::Output {
+                    r#type: f(field_type),
+                    r#match: f(field_match),
+                }
+            }
+        }
+    }
+}
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop
+    for RawIdents<T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<0usize>> for RawIdents<T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for RawIdents<T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapParams for RawIdents<T> {
+        const PARAMS: &'static [usize] = &[0usize];
+    }
+};
+#[allow(absolute_paths_not_starting_with_crate)]
+#[allow(bare_trait_objects)]
+#[allow(deprecated)]
+#[allow(drop_bounds)]
+#[allow(dyn_drop)]
+#[allow(non_camel_case_types)]
+#[allow(trivial_bounds)]
+#[allow(unused_parens)]
+#[allow(unused_qualifications)]
+#[allow(clippy::disallowed_method)]
+#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
+#[automatically_derived]
+impl<A, B> ::funcmap::TryFuncMap<A, B, ::funcmap::TypeParam<0usize>> for RawIdents<A> {
+    type Output = RawIdents<B>;
+    fn try_func_map<E, F>(self, mut f: F) -> ::core::result::Result<Self::Output, E>
+    where
+        F: ::core::ops::FnMut(A) -> ::core::result::Result<B, E>,
+    {
+        ::core::result::Result::Ok(
+            match self {
+                Self { r#type: field_type, r#match: field_match } => {
+                    Self::Output {
+                        r#type: f(field_type)?,
+                        r#match: f(field_match)?,
+                    }
+                }
+            },
+        )
+    }
+}
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMap_cannot_be_derived_for_types_implementing_Drop
+    for RawIdents<T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapTypeParam<::funcmap::TypeParam<0usize>>
+    for RawIdents<T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for RawIdents<T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapParams for RawIdents<T> {
+        const PARAMS: &'static [usize] = &[0usize];
+    }
+};
+struct UnicodeIdents<T> {
+    länge: T,
+    高さ: T,
+}
+#[allow(absolute_paths_not_starting_with_crate)]
+#[allow(bare_trait_objects)]
+#[allow(deprecated)]
+#[allow(drop_bounds)]
+#[allow(dyn_drop)]
+#[allow(non_camel_case_types)]
+#[allow(trivial_bounds)]
+#[allow(unused_parens)]
+#[allow(unused_qualifications)]
+#[allow(clippy::disallowed_method)]
+#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
+#[automatically_derived]
+impl<A, B> ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<0usize>> for UnicodeIdents<A> {
+    type Output = UnicodeIdents<B>;
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: ::core::ops::FnMut(A) -> B,
+    {
+        match self {
+            Self { länge: field_länge, 高さ: field_高さ } => {
+                Self::Output {
+                    länge: f(field_länge),
+                    高さ: f(field_高さ),
+                }
+            }
+        }
+    }
+}
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop
+    for UnicodeIdents<T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<0usize>>
+    for UnicodeIdents<T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for UnicodeIdents<T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapParams for UnicodeIdents<T> {
+        const PARAMS: &'static [usize] = &[0usize];
+    }
+};
+#[allow(absolute_paths_not_starting_with_crate)]
+#[allow(bare_trait_objects)]
+#[allow(deprecated)]
+#[allow(drop_bounds)]
+#[allow(dyn_drop)]
+#[allow(non_camel_case_types)]
+#[allow(trivial_bounds)]
+#[allow(unused_parens)]
+#[allow(unused_qualifications)]
+#[allow(clippy::disallowed_method)]
+#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
+#[automatically_derived]
+impl<A, B> ::funcmap::TryFuncMap<A, B, ::funcmap::TypeParam<0usize>>
+for UnicodeIdents<A> {
+    type Output = UnicodeIdents<B>;
+    fn try_func_map<E, F>(self, mut f: F) -> ::core::result::Result<Self::Output, E>
+    where
+        F: ::core::ops::FnMut(A) -> ::core::result::Result<B, E>,
+    {
+        ::core::result::Result::Ok(
+            match self {
+                Self { länge: field_länge, 高さ: field_高さ } => {
+                    Self::Output {
+                        länge: f(field_länge)?,
+                        高さ: f(field_高さ)?,
+                    }
+                }
+            },
+        )
+    }
+}
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMap_cannot_be_derived_for_types_implementing_Drop
+    for UnicodeIdents<T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapTypeParam<::funcmap::TypeParam<0usize>>
+    for UnicodeIdents<T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for UnicodeIdents<T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapParams for UnicodeIdents<T> {
+        const PARAMS: &'static [usize] = &[0usize];
+    }
+};
+#[rustfmt::skip]
+struct LongTupleStruct<T>(
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+    T,
+);
+#[allow(absolute_paths_not_starting_with_crate)]
+#[allow(bare_trait_objects)]
+#[allow(deprecated)]
+#[allow(drop_bounds)]
+#[allow(dyn_drop)]
+#[allow(non_camel_case_types)]
+#[allow(trivial_bounds)]
+#[allow(unused_parens)]
+#[allow(unused_qualifications)]
+#[allow(clippy::disallowed_method)]
+#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
+#[automatically_derived]
+impl<A, B> ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<0usize>>
+for LongTupleStruct<A> {
+    type Output = LongTupleStruct<B>;
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: ::core::ops::FnMut(A) -> B,
+    {
+        match self {
+            Self {
+                0: field_0,
+                1: field_1,
+                2: field_2,
+                3: field_3,
+                4: field_4,
+                5: field_5,
+                6: field_6,
+                7: field_7,
+                8: field_8,
+                9: field_9,
+                10: field_10,
+                11: field_11,
+                12: field_12,
+                13: field_13,
+                14: field_14,
+                15: field_15,
+                16: field_16,
+                17: field_17,
+                18: field_18,
+                19: field_19,
+                20: field_20,
+                21: field_21,
+                22: field_22,
+                23: field_23,
+                24: field_24,
+                25: field_25,
+                26: field_26,
+                27: field_27,
+                28: field_28,
+                29: field_29,
+                30: field_30,
+                31: field_31,
+                32: field_32,
+                33: field_33,
+                34: field_34,
+                35: field_35,
+                36: field_36,
+                37: field_37,
+                38: field_38,
+                39: field_39,
+                40: field_40,
+                41: field_41,
+                42: field_42,
+                43: field_43,
+                44: field_44,
+                45: field_45,
+                46: field_46,
+                47: field_47,
+                48: field_48,
+                49: field_49,
+                50: field_50,
+                51: field_51,
+                52: field_52,
+                53: field_53,
+                54: field_54,
+                55: field_55,
+                56: field_56,
+                57: field_57,
+                58: field_58,
+                59: field_59,
+                60: field_60,
+                61: field_61,
+                62: field_62,
+                63: field_63,
+            } => {
+                Self::Output {
+                    0: f(field_0),
+                    1: f(field_1),
+                    2: f(field_2),
+                    3: f(field_3),
+                    4: f(field_4),
+                    5: f(field_5),
+                    6: f(field_6),
+                    7: f(field_7),
+                    8: f(field_8),
+                    9: f(field_9),
+                    10: f(field_10),
+                    11: f(field_11),
+                    12: f(field_12),
+                    13: f(field_13),
+                    14: f(field_14),
+                    15: f(field_15),
+                    16: f(field_16),
+                    17: f(field_17),
+                    18: f(field_18),
+                    19: f(field_19),
+                    20: f(field_20),
+                    21: f(field_21),
+                    22: f(field_22),
+                    23: f(field_23),
+                    24: f(field_24),
+                    25: f(field_25),
+                    26: f(field_26),
+                    27: f(field_27),
+                    28: f(field_28),
+                    29: f(field_29),
+                    30: f(field_30),
+                    31: f(field_31),
+                    32: f(field_32),
+                    33: f(field_33),
+                    34: f(field_34),
+                    35: f(field_35),
+                    36: f(field_36),
+                    37: f(field_37),
+                    38: f(field_38),
+                    39: f(field_39),
+                    40: f(field_40),
+                    41: f(field_41),
+                    42: f(field_42),
+                    43: f(field_43),
+                    44: f(field_44),
+                    45: f(field_45),
+                    46: f(field_46),
+                    47: f(field_47),
+                    48: f(field_48),
+                    49: f(field_49),
+                    50: f(field_50),
+                    51: f(field_51),
+                    52: f(field_52),
+                    53: f(field_53),
+                    54: f(field_54),
+                    55: f(field_55),
+                    56: f(field_56),
+                    57: f(field_57),
+                    58: f(field_58),
+                    59: f(field_59),
+                    60: f(field_60),
+                    61: f(field_61),
+                    62: f(field_62),
+                    63: f(field_63),
+                }
+            }
+        }
+    }
+}
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop
+    for LongTupleStruct<T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<0usize>>
+    for LongTupleStruct<T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for LongTupleStruct<T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapParams for LongTupleStruct<T> {
+        const PARAMS: &'static [usize] = &[0usize];
+    }
+};
+#[allow(absolute_paths_not_starting_with_crate)]
+#[allow(bare_trait_objects)]
+#[allow(deprecated)]
+#[allow(drop_bounds)]
+#[allow(dyn_drop)]
+#[allow(non_camel_case_types)]
+#[allow(trivial_bounds)]
+#[allow(unused_parens)]
+#[allow(unused_qualifications)]
+#[allow(clippy::disallowed_method)]
+#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
+#[automatically_derived]
+impl<A, B> ::funcmap::TryFuncMap<A, B, ::funcmap::TypeParam<0usize>>
+for LongTupleStruct<A> {
+    type Output = LongTupleStruct<B>;
+    fn try_func_map<E, F>(self, mut f: F) -> ::core::result::Result<Self::Output, E>
+    where
+        F: ::core::ops::FnMut(A) -> ::core::result::Result<B, E>,
+    {
+        ::core::result::Result::Ok(
+            match self {
+                Self {
+                    0: field_0,
+                    1: field_1,
+                    2: field_2,
+                    3: field_3,
+                    4: field_4,
+                    5: field_5,
+                    6: field_6,
+                    7: field_7,
+                    8: field_8,
+                    9: field_9,
+                    10: field_10,
+                    11: field_11,
+                    12: field_12,
+                    13: field_13,
+                    14: field_14,
+                    15: field_15,
+                    16: field_16,
+                    17: field_17,
+                    18: field_18,
+                    19: field_19,
+                    20: field_20,
+                    21: field_21,
+                    22: field_22,
+                    23: field_23,
+                    24: field_24,
+                    25: field_25,
+                    26: field_26,
+                    27: field_27,
+                    28: field_28,
+                    29: field_29,
+                    30: field_30,
+                    31: field_31,
+                    32: field_32,
+                    33: field_33,
+                    34: field_34,
+                    35: field_35,
+                    36: field_36,
+                    37: field_37,
+                    38: field_38,
+                    39: field_39,
+                    40: field_40,
+                    41: field_41,
+                    42: field_42,
+                    43: field_43,
+                    44: field_44,
+                    45: field_45,
+                    46: field_46,
+                    47: field_47,
+                    48: field_48,
+                    49: field_49,
+                    50: field_50,
+                    51: field_51,
+                    52: field_52,
+                    53: field_53,
+                    54: field_54,
+                    55: field_55,
+                    56: field_56,
+                    57: field_57,
+                    58: field_58,
+                    59: field_59,
+                    60: field_60,
+                    61: field_61,
+                    62: field_62,
+                    63: field_63,
+                } => {
+                    Self::Output {
+                        0: f(field_0)?,
+                        1: f(field_1)?,
+                        2: f(field_2)?,
+                        3: f(field_3)?,
+                        4: f(field_4)?,
+                        5: f(field_5)?,
+                        6: f(field_6)?,
+                        7: f(field_7)?,
+                        8: f(field_8)?,
+                        9: f(field_9)?,
+                        10: f(field_10)?,
+                        11: f(field_11)?,
+                        12: f(field_12)?,
+                        13: f(field_13)?,
+                        14: f(field_14)?,
+                        15: f(field_15)?,
+                        16: f(field_16)?,
+                        17: f(field_17)?,
+                        18: f(field_18)?,
+                        19: f(field_19)?,
+                        20: f(field_20)?,
+                        21: f(field_21)?,
+                        22: f(field_22)?,
+                        23: f(field_23)?,
+                        24: f(field_24)?,
+                        25: f(field_25)?,
+                        26: f(field_26)?,
+                        27: f(field_27)?,
+                        28: f(field_28)?,
+                        29: f(field_29)?,
+                        30: f(field_30)?,
+                        31: f(field_31)?,
+                        32: f(field_32)?,
+                        33: f(field_33)?,
+                        34: f(field_34)?,
+                        35: f(field_35)?,
+                        36: f(field_36)?,
+                        37: f(field_37)?,
+                        38: f(field_38)?,
+                        39: f(field_39)?,
+                        40: f(field_40)?,
+                        41: f(field_41)?,
+                        42: f(field_42)?,
+                        43: f(field_43)?,
+                        44: f(field_44)?,
+                        45: f(field_45)?,
+                        46: f(field_46)?,
+                        47: f(field_47)?,
+                        48: f(field_48)?,
+                        49: f(field_49)?,
+                        50: f(field_50)?,
+                        51: f(field_51)?,
+                        52: f(field_52)?,
+                        53: f(field_53)?,
+                        54: f(field_54)?,
+                        55: f(field_55)?,
+                        56: f(field_56)?,
+                        57: f(field_57)?,
+                        58: f(field_58)?,
+                        59: f(field_59)?,
+                        60: f(field_60)?,
+                        61: f(field_61)?,
+                        62: f(field_62)?,
+                        63: f(field_63)?,
+                    }
+                }
+            },
+        )
+    }
+}
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMap_cannot_be_derived_for_types_implementing_Drop
+    for LongTupleStruct<T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapTypeParam<::funcmap::TypeParam<0usize>>
+    for LongTupleStruct<T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for LongTupleStruct<T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapParams for LongTupleStruct<T> {
+        const PARAMS: &'static [usize] = &[0usize];
+    }
+};
@@ -0,0 +1,22 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+#[derive(FuncMap, TryFuncMap)]
+struct RawIdents<T> {
+    r#type: T,
+    r#match: T,
+}
+
+#[derive(FuncMap, TryFuncMap)]
+struct UnicodeIdents<T> {
+    länge: T,
+    高さ: T,
+}
+
+#[rustfmt::skip]
+#[derive(FuncMap, TryFuncMap)]
+struct LongTupleStruct<T>(
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+);
@@ -0,0 +1,167 @@
+use funcmap::FuncMap;
+struct Test<A, B>(A, B);
+#[allow(absolute_paths_not_starting_with_crate)]
+#[allow(bare_trait_objects)]
+#[allow(deprecated)]
+#[allow(drop_bounds)]
+#[allow(dyn_drop)]
+#[allow(non_camel_case_types)]
+#[allow(trivial_bounds)]
+#[allow(unused_parens)]
+#[allow(unused_qualifications)]
+#[allow(clippy::disallowed_method)]
+#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
+#[automatically_derived]
+impl<Src, Dst, B> ::funcmap::FuncMap<Src, Dst, ::funcmap::TypeParam<0usize>>
+for Test<Src, B>
+where
+    B: ::core::marker::Sized,
+{
+    type Output = Test<Dst, B>;
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: ::core::ops::FnMut(Src) -> Dst,
+    {
+        match self {
+            Self { 0: field_0, 1: field_1 } => {
+                Self::Output {
+                    0: f(field_0),
+                    1: field_1,
+                }
+            }
+        }
+    }
+}
+#[allow(absolute_paths_not_starting_with_crate)]
+#[allow(bare_trait_objects)]
+#[allow(deprecated)]
+#[allow(drop_bounds)]
+#[allow(dyn_drop)]
+#[allow(non_camel_case_types)]
+#[allow(trivial_bounds)]
+#[allow(unused_parens)]
+#[allow(unused_qualifications)]
+#[allow(clippy::disallowed_method)]
+#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
+#[automatically_derived]
+impl<A, Src, Dst> ::funcmap::FuncMap<Src, Dst, ::funcmap::TypeParam<1usize>>
+for Test<A, Src>
+where
+    A: ::core::marker::Sized,
+{
+    type Output = Test<A, Dst>;
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: ::core::ops::FnMut(Src) -> Dst,
+    {
+        match self {
+            Self { 0: field_0, 1: field_1 } => {
+                Self::Output {
+                    0: field_0,
+                    1: f(field_1),
+                }
+            }
+        }
+    }
+}
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<A, B> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop
+    for Test<A, B> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<A, B> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<0usize>> for Test<A, B> {
+        type Param = A;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<A, B> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("A") }>
+    for Test<A, B> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<A, B> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<1usize>> for Test<A, B> {
+        type Param = B;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<A, B> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("B") }>
+    for Test<A, B> {
+        type Marker = ::funcmap::TypeParam<1usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<A, B> ::funcmap::FuncMapParams for Test<A, B> {
+        const PARAMS: &'static [usize] = &[0usize, 1usize];
+    }
+};
@@ -0,0 +1,5 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(idents(src = "Src", dst = "Dst"))]
+struct Test<A, B>(A, B);
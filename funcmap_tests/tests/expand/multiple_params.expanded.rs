@@ -7,27 +7,15 @@ struct Test<S, T>(S, T, i32);
 #[allow(dyn_drop)]
 #[allow(non_camel_case_types)]
 #[allow(trivial_bounds)]
+#[allow(unused_parens)]
 #[allow(unused_qualifications)]
 #[allow(clippy::disallowed_method)]
 #[allow(clippy::disallowed_type)]
-#[automatically_derived]
-impl<S, T> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop
-for Test<S, T> {}
-#[allow(absolute_paths_not_starting_with_crate)]
-#[allow(bare_trait_objects)]
-#[allow(deprecated)]
-#[allow(drop_bounds)]
-#[allow(dyn_drop)]
-#[allow(non_camel_case_types)]
-#[allow(trivial_bounds)]
-#[allow(unused_qualifications)]
-#[allow(clippy::disallowed_method)]
-#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
 #[automatically_derived]
 impl<A, B, T> ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<0usize>> for Test<A, T>
 where
     T: ::core::marker::Sized,
-    i32: ::core::marker::Sized,
 {
     type Output = Test<B, T>;
     fn func_map<F>(self, mut f: F) -> Self::Output
@@ -52,14 +40,15 @@ where
 #[allow(dyn_drop)]
 #[allow(non_camel_case_types)]
 #[allow(trivial_bounds)]
+#[allow(unused_parens)]
 #[allow(unused_qualifications)]
 #[allow(clippy::disallowed_method)]
 #[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
 #[automatically_derived]
 impl<S, A, B> ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<1usize>> for Test<S, A>
 where
     S: ::core::marker::Sized,
-    i32: ::core::marker::Sized,
 {
     type Output = Test<S, B>;
     fn func_map<F>(self, mut f: F) -> Self::Output
@@ -77,6 +66,105 @@ where
         }
     }
 }
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop
+    for Test<S, T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<0usize>> for Test<S, T> {
+        type Param = S;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("S") }>
+    for Test<S, T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<1usize>> for Test<S, T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for Test<S, T> {
+        type Marker = ::funcmap::TypeParam<1usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::FuncMapParams for Test<S, T> {
+        const PARAMS: &'static [usize] = &[0usize, 1usize];
+    }
+};
 #[allow(absolute_paths_not_starting_with_crate)]
 #[allow(bare_trait_objects)]
 #[allow(deprecated)]
@@ -84,27 +172,15 @@ where
 #[allow(dyn_drop)]
 #[allow(non_camel_case_types)]
 #[allow(trivial_bounds)]
+#[allow(unused_parens)]
 #[allow(unused_qualifications)]
 #[allow(clippy::disallowed_method)]
 #[allow(clippy::disallowed_type)]
-#[automatically_derived]
-impl<S, T> ::funcmap::TryFuncMap_cannot_be_derived_for_types_implementing_Drop
-for Test<S, T> {}
-#[allow(absolute_paths_not_starting_with_crate)]
-#[allow(bare_trait_objects)]
-#[allow(deprecated)]
-#[allow(drop_bounds)]
-#[allow(dyn_drop)]
-#[allow(non_camel_case_types)]
-#[allow(trivial_bounds)]
-#[allow(unused_qualifications)]
-#[allow(clippy::disallowed_method)]
-#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
 #[automatically_derived]
 impl<A, B, T> ::funcmap::TryFuncMap<A, B, ::funcmap::TypeParam<0usize>> for Test<A, T>
 where
     T: ::core::marker::Sized,
-    i32: ::core::marker::Sized,
 {
     type Output = Test<B, T>;
     fn try_func_map<E, F>(self, mut f: F) -> ::core::result::Result<Self::Output, E>
@@ -131,14 +207,15 @@ where
 #[allow(dyn_drop)]
 #[allow(non_camel_case_types)]
 #[allow(trivial_bounds)]
+#[allow(unused_parens)]
 #[allow(unused_qualifications)]
 #[allow(clippy::disallowed_method)]
 #[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
 #[automatically_derived]
 impl<S, A, B> ::funcmap::TryFuncMap<A, B, ::funcmap::TypeParam<1usize>> for Test<S, A>
 where
     S: ::core::marker::Sized,
-    i32: ::core::marker::Sized,
 {
     type Output = Test<S, B>;
     fn try_func_map<E, F>(self, mut f: F) -> ::core::result::Result<Self::Output, E>
@@ -158,3 +235,104 @@ where
         )
     }
 }
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::TryFuncMap_cannot_be_derived_for_types_implementing_Drop
+    for Test<S, T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::TryFuncMapTypeParam<::funcmap::TypeParam<0usize>>
+    for Test<S, T> {
+        type Param = S;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::TryFuncMapNamedParam<{ ::funcmap::param_name_hash("S") }>
+    for Test<S, T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::TryFuncMapTypeParam<::funcmap::TypeParam<1usize>>
+    for Test<S, T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::TryFuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for Test<S, T> {
+        type Marker = ::funcmap::TypeParam<1usize>;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<S, T> ::funcmap::TryFuncMapParams for Test<S, T> {
+        const PARAMS: &'static [usize] = &[0usize, 1usize];
+    }
+};
@@ -0,0 +1,357 @@
+use funcmap::FuncMap;
+use std::collections::BTreeMap;
+struct Test<K, T> {
+    nested: std::vec::Vec<
+        BTreeMap<K, std::vec::Vec<std::option::Option<std::boxed::Box<T>>>>,
+    >,
+    boxed: std::boxed::Box<std::vec::Vec<T>>,
+}
+#[allow(absolute_paths_not_starting_with_crate)]
+#[allow(bare_trait_objects)]
+#[allow(deprecated)]
+#[allow(drop_bounds)]
+#[allow(dyn_drop)]
+#[allow(non_camel_case_types)]
+#[allow(trivial_bounds)]
+#[allow(unused_parens)]
+#[allow(unused_qualifications)]
+#[allow(clippy::disallowed_method)]
+#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
+#[automatically_derived]
+impl<A, B, T> ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<0usize>> for Test<A, T>
+where
+    BTreeMap<
+        A,
+        std::vec::Vec<std::option::Option<std::boxed::Box<T>>>,
+    >: ::funcmap::FuncMap<
+        A,
+        B,
+        ::funcmap::TypeParam<0usize>,
+        Output = BTreeMap<B, std::vec::Vec<std::option::Option<std::boxed::Box<T>>>>,
+    >,
+    std::boxed::Box<std::vec::Vec<T>>: ::core::marker::Sized,
+{
+    type Output = Test<B, T>;
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: ::core::ops::FnMut(A) -> B,
+    {
+        match self {
+            Self { nested: field_nested, boxed: field_boxed } => {
+                Self::Output {
+                    nested: ::funcmap::FuncMap::<
+                        _,
+                        _,
+                        ::funcmap::TypeParam<0usize>,
+                    >::func_map(
+                        field_nested,
+                        |value| ::funcmap::FuncMap::<
+                            _,
+                            _,
+                            ::funcmap::TypeParam<0usize>,
+                        >::func_map(value, |value| f(value)),
+                    ),
+                    boxed: field_boxed,
+                }
+            }
+        }
+    }
+}
+#[allow(absolute_paths_not_starting_with_crate)]
+#[allow(bare_trait_objects)]
+#[allow(deprecated)]
+#[allow(drop_bounds)]
+#[allow(dyn_drop)]
+#[allow(non_camel_case_types)]
+#[allow(trivial_bounds)]
+#[allow(unused_parens)]
+#[allow(unused_qualifications)]
+#[allow(clippy::disallowed_method)]
+#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
+#[automatically_derived]
+impl<K, A, B> ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<1usize>> for Test<K, A>
+where
+    BTreeMap<
+        K,
+        std::vec::Vec<std::option::Option<std::boxed::Box<A>>>,
+    >: ::funcmap::FuncMap<
+        std::vec::Vec<std::option::Option<std::boxed::Box<A>>>,
+        std::vec::Vec<std::option::Option<std::boxed::Box<B>>>,
+        ::funcmap::TypeParam<1usize>,
+        Output = BTreeMap<K, std::vec::Vec<std::option::Option<std::boxed::Box<B>>>>,
+    >,
+{
+    type Output = Test<K, B>;
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: ::core::ops::FnMut(A) -> B,
+    {
+        match self {
+            Self { nested: field_nested, boxed: field_boxed } => {
+                Self::Output {
+                    nested: ::funcmap::FuncMap::<
+                        _,
+                        _,
+                        ::funcmap::TypeParam<0usize>,
+                    >::func_map(
+                        field_nested,
+                        |value| ::funcmap::FuncMap::<
+                            _,
+                            _,
+                            ::funcmap::TypeParam<1usize>,
+                        >::func_map(
+                            value,
+                            |value| ::funcmap::FuncMap::<
+                                _,
+                                _,
+                                ::funcmap::TypeParam<0usize>,
+                            >::func_map(
+                                value,
+                                |value| match value {
+                                    ::core::option::Option::Some(value) => {
+                                        ::core::option::Option::Some(
+                                            <std::boxed::Box<B>>::new(f((*value))),
+                                        )
+                                    }
+                                    ::core::option::Option::None => ::core::option::Option::None,
+                                },
+                            ),
+                        ),
+                    ),
+                    boxed: ::funcmap::FuncMap::<
+                        _,
+                        _,
+                        ::funcmap::TypeParam<0usize>,
+                    >::func_map(
+                        field_boxed,
+                        |value| ::funcmap::FuncMap::<
+                            _,
+                            _,
+                            ::funcmap::TypeParam<0usize>,
+                        >::func_map(value, |value| f(value)),
+                    ),
+                }
+            }
+        }
+    }
+}
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<K, T> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop
+    for Test<K, T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<K, T> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<0usize>> for Test<K, T> {
+        type Param = K;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<K, T> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("K") }>
+    for Test<K, T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    const _: () = {
+        #[allow(absolute_paths_not_starting_with_crate)]
+        #[allow(bare_trait_objects)]
+        #[allow(deprecated)]
+        #[allow(drop_bounds)]
+        #[allow(dyn_drop)]
+        #[allow(non_camel_case_types)]
+        #[allow(trivial_bounds)]
+        #[allow(unused_parens)]
+        #[allow(unused_qualifications)]
+        #[allow(clippy::disallowed_method)]
+        #[allow(clippy::disallowed_type)]
+        #[allow(clippy::needless_question_mark)]
+        #[allow(dead_code)]
+        fn check_type_params<A, B, T>(_: Test<A, T>)
+        where
+            BTreeMap<
+                A,
+                std::vec::Vec<std::option::Option<std::boxed::Box<T>>>,
+            >: ::funcmap::FuncMap<
+                A,
+                B,
+                ::funcmap::TypeParam<0usize>,
+                Output = BTreeMap<
+                    B,
+                    std::vec::Vec<std::option::Option<std::boxed::Box<T>>>,
+                >,
+            >,
+            std::boxed::Box<std::vec::Vec<T>>: ::core::marker::Sized,
+        {
+            use ::funcmap::{FuncMapTypeParamCheck as _, TypeParamCheckFallback as _};
+            let _: ::core::marker::PhantomData<
+                BTreeMap<A, std::vec::Vec<std::option::Option<std::boxed::Box<T>>>>,
+            > = (&::funcmap::TypeParamCheck::<
+                std::vec::Vec<
+                    BTreeMap<A, std::vec::Vec<std::option::Option<std::boxed::Box<T>>>>,
+                >,
+                ::funcmap::TypeParam<0usize>,
+                BTreeMap<A, std::vec::Vec<std::option::Option<std::boxed::Box<T>>>>,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<A> = (&::funcmap::TypeParamCheck::<
+                BTreeMap<A, std::vec::Vec<std::option::Option<std::boxed::Box<T>>>>,
+                ::funcmap::TypeParam<0usize>,
+                A,
+            >::new())
+                .check();
+        }
+    };
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<K, T> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<1usize>> for Test<K, T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<K, T> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for Test<K, T> {
+        type Marker = ::funcmap::TypeParam<1usize>;
+    }
+    const _: () = {
+        #[allow(absolute_paths_not_starting_with_crate)]
+        #[allow(bare_trait_objects)]
+        #[allow(deprecated)]
+        #[allow(drop_bounds)]
+        #[allow(dyn_drop)]
+        #[allow(non_camel_case_types)]
+        #[allow(trivial_bounds)]
+        #[allow(unused_parens)]
+        #[allow(unused_qualifications)]
+        #[allow(clippy::disallowed_method)]
+        #[allow(clippy::disallowed_type)]
+        #[allow(clippy::needless_question_mark)]
+        #[allow(dead_code)]
+        fn check_type_params<K, A, B>(_: Test<K, A>)
+        where
+            BTreeMap<
+                K,
+                std::vec::Vec<std::option::Option<std::boxed::Box<A>>>,
+            >: ::funcmap::FuncMap<
+                std::vec::Vec<std::option::Option<std::boxed::Box<A>>>,
+                std::vec::Vec<std::option::Option<std::boxed::Box<B>>>,
+                ::funcmap::TypeParam<1usize>,
+                Output = BTreeMap<
+                    K,
+                    std::vec::Vec<std::option::Option<std::boxed::Box<B>>>,
+                >,
+            >,
+        {
+            use ::funcmap::{FuncMapTypeParamCheck as _, TypeParamCheckFallback as _};
+            let _: ::core::marker::PhantomData<
+                BTreeMap<K, std::vec::Vec<std::option::Option<std::boxed::Box<A>>>>,
+            > = (&::funcmap::TypeParamCheck::<
+                std::vec::Vec<
+                    BTreeMap<K, std::vec::Vec<std::option::Option<std::boxed::Box<A>>>>,
+                >,
+                ::funcmap::TypeParam<0usize>,
+                BTreeMap<K, std::vec::Vec<std::option::Option<std::boxed::Box<A>>>>,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<
+                std::vec::Vec<std::option::Option<std::boxed::Box<A>>>,
+            > = (&::funcmap::TypeParamCheck::<
+                BTreeMap<K, std::vec::Vec<std::option::Option<std::boxed::Box<A>>>>,
+                ::funcmap::TypeParam<1usize>,
+                std::vec::Vec<std::option::Option<std::boxed::Box<A>>>,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<
+                std::option::Option<std::boxed::Box<A>>,
+            > = (&::funcmap::TypeParamCheck::<
+                std::vec::Vec<std::option::Option<std::boxed::Box<A>>>,
+                ::funcmap::TypeParam<0usize>,
+                std::option::Option<std::boxed::Box<A>>,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<std::vec::Vec<A>> = (&::funcmap::TypeParamCheck::<
+                std::boxed::Box<std::vec::Vec<A>>,
+                ::funcmap::TypeParam<0usize>,
+                std::vec::Vec<A>,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<A> = (&::funcmap::TypeParamCheck::<
+                std::vec::Vec<A>,
+                ::funcmap::TypeParam<0usize>,
+                A,
+            >::new())
+                .check();
+        }
+    };
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<K, T> ::funcmap::FuncMapParams for Test<K, T> {
+        const PARAMS: &'static [usize] = &[0usize, 1usize];
+    }
+};
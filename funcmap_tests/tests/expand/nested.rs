@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+use std::collections::BTreeMap;
+
+#[derive(FuncMap)]
+struct Test<K, T> {
+    nested: std::vec::Vec<BTreeMap<K, std::vec::Vec<std::option::Option<std::boxed::Box<T>>>>>,
+    boxed: std::boxed::Box<std::vec::Vec<T>>,
+}
@@ -12,25 +12,14 @@ struct Test<T> {
 #[allow(dyn_drop)]
 #[allow(non_camel_case_types)]
 #[allow(trivial_bounds)]
+#[allow(unused_parens)]
 #[allow(unused_qualifications)]
 #[allow(clippy::disallowed_method)]
 #[allow(clippy::disallowed_type)]
-#[automatically_derived]
-impl<T> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop for Test<T> {}
-#[allow(absolute_paths_not_starting_with_crate)]
-#[allow(bare_trait_objects)]
-#[allow(deprecated)]
-#[allow(drop_bounds)]
-#[allow(dyn_drop)]
-#[allow(non_camel_case_types)]
-#[allow(trivial_bounds)]
-#[allow(unused_qualifications)]
-#[allow(clippy::disallowed_method)]
-#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
 #[automatically_derived]
 impl<A, B> ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<0usize>> for Test<A>
 where
-    i32: ::core::marker::Sized,
     Foo<
         Bar<A>,
     >: ::funcmap::FuncMap<
@@ -93,6 +82,145 @@ where
         }
     }
 }
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMap_cannot_be_derived_for_types_implementing_Drop
+    for Test<T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapTypeParam<::funcmap::TypeParam<0usize>> for Test<T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for Test<T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    const _: () = {
+        #[allow(absolute_paths_not_starting_with_crate)]
+        #[allow(bare_trait_objects)]
+        #[allow(deprecated)]
+        #[allow(drop_bounds)]
+        #[allow(dyn_drop)]
+        #[allow(non_camel_case_types)]
+        #[allow(trivial_bounds)]
+        #[allow(unused_parens)]
+        #[allow(unused_qualifications)]
+        #[allow(clippy::disallowed_method)]
+        #[allow(clippy::disallowed_type)]
+        #[allow(clippy::needless_question_mark)]
+        #[allow(dead_code)]
+        fn check_type_params<A, B>(_: Test<A>)
+        where
+            Foo<
+                Bar<A>,
+            >: ::funcmap::FuncMap<
+                Bar<A>,
+                Bar<B>,
+                ::funcmap::TypeParam<0usize>,
+                Output = Foo<Bar<B>>,
+            >,
+            Bar<
+                A,
+            >: ::funcmap::FuncMap<A, B, ::funcmap::TypeParam<0usize>, Output = Bar<B>>,
+            Foo<
+                A,
+                A,
+            >: ::funcmap::FuncMap<
+                A,
+                B,
+                ::funcmap::TypeParam<0usize>,
+                Output = Foo<B, A>,
+            >,
+            Foo<
+                B,
+                A,
+            >: ::funcmap::FuncMap<
+                A,
+                B,
+                ::funcmap::TypeParam<1usize>,
+                Output = Foo<B, B>,
+            >,
+        {
+            use ::funcmap::{FuncMapTypeParamCheck as _, TypeParamCheckFallback as _};
+            let _: ::core::marker::PhantomData<Bar<A>> = (&::funcmap::TypeParamCheck::<
+                Foo<Bar<A>>,
+                ::funcmap::TypeParam<0usize>,
+                Bar<A>,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<A> = (&::funcmap::TypeParamCheck::<
+                Bar<A>,
+                ::funcmap::TypeParam<0usize>,
+                A,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<A> = (&::funcmap::TypeParamCheck::<
+                Foo<A, A>,
+                ::funcmap::TypeParam<0usize>,
+                A,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<A> = (&::funcmap::TypeParamCheck::<
+                Foo<A, A>,
+                ::funcmap::TypeParam<1usize>,
+                A,
+            >::new())
+                .check();
+        }
+    };
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::FuncMapParams for Test<T> {
+        const PARAMS: &'static [usize] = &[0usize];
+    }
+};
 #[allow(absolute_paths_not_starting_with_crate)]
 #[allow(bare_trait_objects)]
 #[allow(deprecated)]
@@ -100,25 +228,14 @@ where
 #[allow(dyn_drop)]
 #[allow(non_camel_case_types)]
 #[allow(trivial_bounds)]
+#[allow(unused_parens)]
 #[allow(unused_qualifications)]
 #[allow(clippy::disallowed_method)]
 #[allow(clippy::disallowed_type)]
-#[automatically_derived]
-impl<T> ::funcmap::TryFuncMap_cannot_be_derived_for_types_implementing_Drop for Test<T> {}
-#[allow(absolute_paths_not_starting_with_crate)]
-#[allow(bare_trait_objects)]
-#[allow(deprecated)]
-#[allow(drop_bounds)]
-#[allow(dyn_drop)]
-#[allow(non_camel_case_types)]
-#[allow(trivial_bounds)]
-#[allow(unused_qualifications)]
-#[allow(clippy::disallowed_method)]
-#[allow(clippy::disallowed_type)]
+#[allow(clippy::needless_question_mark)]
 #[automatically_derived]
 impl<A, B> ::funcmap::TryFuncMap<A, B, ::funcmap::TypeParam<0usize>> for Test<A>
 where
-    i32: ::core::marker::Sized,
     Foo<
         Bar<A>,
     >: ::funcmap::TryFuncMap<
@@ -194,3 +311,147 @@ where
         )
     }
 }
+const _: () = {
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMap_cannot_be_derived_for_types_implementing_Drop
+    for Test<T> {}
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapTypeParam<::funcmap::TypeParam<0usize>> for Test<T> {
+        type Param = T;
+    }
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapNamedParam<{ ::funcmap::param_name_hash("T") }>
+    for Test<T> {
+        type Marker = ::funcmap::TypeParam<0usize>;
+    }
+    const _: () = {
+        #[allow(absolute_paths_not_starting_with_crate)]
+        #[allow(bare_trait_objects)]
+        #[allow(deprecated)]
+        #[allow(drop_bounds)]
+        #[allow(dyn_drop)]
+        #[allow(non_camel_case_types)]
+        #[allow(trivial_bounds)]
+        #[allow(unused_parens)]
+        #[allow(unused_qualifications)]
+        #[allow(clippy::disallowed_method)]
+        #[allow(clippy::disallowed_type)]
+        #[allow(clippy::needless_question_mark)]
+        #[allow(dead_code)]
+        fn check_type_params<A, B>(_: Test<A>)
+        where
+            Foo<
+                Bar<A>,
+            >: ::funcmap::TryFuncMap<
+                Bar<A>,
+                Bar<B>,
+                ::funcmap::TypeParam<0usize>,
+                Output = Foo<Bar<B>>,
+            >,
+            Bar<
+                A,
+            >: ::funcmap::TryFuncMap<
+                A,
+                B,
+                ::funcmap::TypeParam<0usize>,
+                Output = Bar<B>,
+            >,
+            Foo<
+                A,
+                A,
+            >: ::funcmap::TryFuncMap<
+                A,
+                B,
+                ::funcmap::TypeParam<0usize>,
+                Output = Foo<B, A>,
+            >,
+            Foo<
+                B,
+                A,
+            >: ::funcmap::TryFuncMap<
+                A,
+                B,
+                ::funcmap::TypeParam<1usize>,
+                Output = Foo<B, B>,
+            >,
+        {
+            use ::funcmap::{TryFuncMapTypeParamCheck as _, TypeParamCheckFallback as _};
+            let _: ::core::marker::PhantomData<Bar<A>> = (&::funcmap::TypeParamCheck::<
+                Foo<Bar<A>>,
+                ::funcmap::TypeParam<0usize>,
+                Bar<A>,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<A> = (&::funcmap::TypeParamCheck::<
+                Bar<A>,
+                ::funcmap::TypeParam<0usize>,
+                A,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<A> = (&::funcmap::TypeParamCheck::<
+                Foo<A, A>,
+                ::funcmap::TypeParam<0usize>,
+                A,
+            >::new())
+                .check();
+            let _: ::core::marker::PhantomData<A> = (&::funcmap::TypeParamCheck::<
+                Foo<A, A>,
+                ::funcmap::TypeParam<1usize>,
+                A,
+            >::new())
+                .check();
+        }
+    };
+    #[allow(absolute_paths_not_starting_with_crate)]
+    #[allow(bare_trait_objects)]
+    #[allow(deprecated)]
+    #[allow(drop_bounds)]
+    #[allow(dyn_drop)]
+    #[allow(non_camel_case_types)]
+    #[allow(trivial_bounds)]
+    #[allow(unused_parens)]
+    #[allow(unused_qualifications)]
+    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::needless_question_mark)]
+    #[automatically_derived]
+    impl<T> ::funcmap::TryFuncMapParams for Test<T> {
+        const PARAMS: &'static [usize] = &[0usize];
+    }
+};
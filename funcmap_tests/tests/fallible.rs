@@ -28,6 +28,22 @@ fn mapping_fails_with_first_error_when_function_fails() {
     assert_eq!(dst, Err(MappingError("First Error")));
 }
 
+#[test]
+fn mapping_fails_with_first_error_for_fields_of_same_type() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(Option<T>, Option<T>);
+
+    let src = Test(Some(T1::Mappable), Some(T1::NotMappable("First Error")));
+    let dst: Result<Test<T2>, _> = src.try_func_map(TryInto::try_into);
+
+    assert_eq!(dst, Err(MappingError("First Error")));
+
+    let src = Test(Some(T1::Mappable), None);
+    let dst: Result<Test<T2>, _> = src.try_func_map(TryInto::try_into);
+
+    assert_eq!(dst, Ok(Test(Some(T2), None)));
+}
+
 #[derive(Debug, PartialEq)]
 enum T1 {
     Mappable,
@@ -0,0 +1,196 @@
+use funcmap::{Family, FamilyMap, FuncMap, NaturalTransformation};
+
+use std::collections::HashMap;
+
+#[test]
+fn projections_are_transformed() {
+    #[derive(FamilyMap)]
+    struct Test<F: Family> {
+        name: F::Of<String>,
+        port: F::Of<u16>,
+    }
+
+    let src: Test<OptionFamily> = Test {
+        name: Some(String::from("server")),
+        port: None,
+    };
+    let dst: Test<VecFamily> = src.family_map(OptionToVec);
+
+    assert_eq!(dst.name, [String::from("server")]);
+    assert!(dst.port.is_empty());
+}
+
+#[test]
+fn qualified_projections_are_transformed() {
+    #[derive(FamilyMap)]
+    struct Test<F>(<F as Family>::Of<i32>, <F as funcmap::Family>::Of<bool>)
+    where
+        F: Family;
+
+    let src: Test<OptionFamily> = Test(Some(1), Some(true));
+    let dst: Test<VecFamily> = src.family_map(OptionToVec);
+
+    assert_eq!(dst.0, [1]);
+    assert_eq!(dst.1, [true]);
+}
+
+#[test]
+fn projections_are_transformed_within_nested_types() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Inner<T>(T);
+
+    #[derive(FamilyMap)]
+    struct Test<F: Family> {
+        array: [F::Of<i32>; 2],
+        vec: Vec<F::Of<char>>,
+        inner: Inner<(F::Of<u8>, bool)>,
+        map: HashMap<&'static str, F::Of<i32>>,
+    }
+
+    let src: Test<OptionFamily> = Test {
+        array: [Some(1), None],
+        vec: vec![Some('a')],
+        inner: Inner((None, true)),
+        map: vec![("key", Some(2))].into_iter().collect(),
+    };
+    let dst: Test<VecFamily> = src.family_map(OptionToVec);
+
+    assert_eq!(dst.array, [vec![1], vec![]]);
+    assert_eq!(dst.vec, [vec!['a']]);
+    assert_eq!(dst.inner, Inner((vec![], true)));
+    assert_eq!(dst.map, HashMap::from([("key", vec![2])]));
+}
+
+#[test]
+fn fields_independent_of_family_are_kept() {
+    #[derive(FamilyMap)]
+    struct Test<F: Family> {
+        value: F::Of<i32>,
+        flag: bool,
+        text: String,
+    }
+
+    let src: Test<OptionFamily> = Test {
+        value: Some(1),
+        flag: true,
+        text: String::from("text"),
+    };
+    let dst: Test<VecFamily> = src.family_map(OptionToVec);
+
+    assert_eq!(dst.value, [1]);
+    assert!(dst.flag);
+    assert_eq!(dst.text, "text");
+}
+
+#[test]
+fn fields_depending_on_family_are_mapped_via_family_map() {
+    #[derive(FamilyMap)]
+    struct Inner<F: Family>(F::Of<i32>);
+
+    #[derive(FamilyMap)]
+    struct Test<F: Family> {
+        inner: Inner<F>,
+        value: F::Of<bool>,
+    }
+
+    let src: Test<OptionFamily> = Test {
+        inner: Inner(Some(1)),
+        value: None,
+    };
+    let dst: Test<VecFamily> = src.family_map(OptionToVec);
+
+    assert_eq!(dst.inner.0, [1]);
+    assert_eq!(dst.value, Vec::<bool>::new());
+}
+
+#[test]
+fn enum_variants_are_mapped() {
+    #[derive(FamilyMap)]
+    enum Test<F: Family> {
+        Unit,
+        Tuple(F::Of<i32>),
+        Struct { value: F::Of<bool> },
+    }
+
+    assert!(matches!(
+        Test::<OptionFamily>::Unit.family_map(OptionToVec),
+        Test::<VecFamily>::Unit
+    ));
+    assert!(matches!(
+        Test::<OptionFamily>::Tuple(Some(1)).family_map(OptionToVec),
+        Test::<VecFamily>::Tuple(value) if value == [1]
+    ));
+    assert!(matches!(
+        Test::<OptionFamily>::Struct { value: None }.family_map(OptionToVec),
+        Test::<VecFamily>::Struct { value } if value.is_empty()
+    ));
+}
+
+#[test]
+fn transformation_is_applied_in_order_of_fields() {
+    #[derive(FamilyMap)]
+    struct Test<F: Family> {
+        first: F::Of<i32>,
+        second: [F::Of<i32>; 2],
+    }
+
+    let src: Test<OptionFamily> = Test {
+        first: Some(1),
+        second: [None, Some(2)],
+    };
+    let mut counter = Counter(0);
+    let dst: Test<CountFamily> = src.family_map(&mut counter);
+
+    assert_eq!(dst.first, (1, Some(1)));
+    assert_eq!(dst.second, [(2, None), (3, Some(2))]);
+    assert_eq!(counter.0, 3);
+}
+
+#[test]
+fn other_params_are_kept() {
+    #[derive(FamilyMap)]
+    #[funcmap(params(F))]
+    struct Test<'a, F: Family, T, const N: usize>(&'a str, F::Of<T>, [T; N]);
+
+    let src: Test<OptionFamily, i32, 1> = Test("a", Some(1), [2]);
+    let dst: Test<VecFamily, i32, 1> = src.family_map(OptionToVec);
+
+    assert_eq!(dst.0, "a");
+    assert_eq!(dst.1, [1]);
+    assert_eq!(dst.2, [2]);
+}
+
+enum OptionFamily {}
+
+impl Family for OptionFamily {
+    type Of<T> = Option<T>;
+}
+
+enum VecFamily {}
+
+impl Family for VecFamily {
+    type Of<T> = Vec<T>;
+}
+
+enum CountFamily {}
+
+impl Family for CountFamily {
+    type Of<T> = (usize, Option<T>);
+}
+
+struct OptionToVec;
+
+impl NaturalTransformation<OptionFamily, VecFamily> for OptionToVec {
+    fn transform<T>(&mut self, value: Option<T>) -> Vec<T> {
+        value.into_iter().collect()
+    }
+}
+
+struct Counter(usize);
+
+impl NaturalTransformation<OptionFamily, CountFamily> for Counter {
+    fn transform<T>(&mut self, value: Option<T>) -> (usize, Option<T>) {
+        self.0 += 1;
+        (self.0, value)
+    }
+}
@@ -0,0 +1,73 @@
+use funcmap::{Fmap, FuncMap, TryFuncMap, TypeParam};
+
+#[test]
+fn fmap_maps_like_func_map() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test(1, vec![2, 3]);
+    let dst = src.fmap(|v| v * 10);
+
+    assert_eq!(dst, Test(10, vec![20, 30]));
+}
+
+#[test]
+fn try_fmap_maps_like_try_func_map() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test("1", vec!["2", "3"]);
+    let dst = src.try_fmap(str::parse::<i32>);
+
+    assert_eq!(dst, Ok(Test(1, vec![2, 3])));
+}
+
+#[test]
+fn try_fmap_returns_first_error() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test(1, vec![-2, -3]);
+    let dst = src.try_fmap(|v| if v > 0 { Ok(v) } else { Err(v) });
+
+    assert_eq!(dst, Err(-2));
+}
+
+#[test]
+fn void_maps_to_unit() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Option<T>);
+
+    let src = Test(1, Some(2));
+    let dst = src.void();
+
+    assert_eq!(dst, Test((), Some(())));
+}
+
+#[test]
+fn replace_maps_to_clones_of_value() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test(1, vec![2, 3]);
+    let dst = src.replace(String::from("x"));
+
+    assert_eq!(
+        dst,
+        Test(
+            String::from("x"),
+            vec![String::from("x"), String::from("x")]
+        )
+    );
+}
+
+#[test]
+fn type_param_can_be_specified() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(1, 2);
+    let dst = Fmap::<_, TypeParam<1>>::fmap(src, |v| v + 1);
+
+    assert_eq!(dst, Test(1, 3));
+}
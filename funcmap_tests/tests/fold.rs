@@ -0,0 +1,66 @@
+use funcmap::{FuncMap, FuncMapFold, TypeParam};
+
+#[test]
+fn accumulator_is_threaded_through_fields_in_order() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+        other: i32,
+        last: Option<T>,
+    }
+
+    let src = Test {
+        value: "a",
+        values: vec!["b", "c"],
+        other: 42,
+        last: Some("d"),
+    };
+    let (acc, dst) = src.func_map_fold(String::new(), |mut acc, value| {
+        acc.push_str(value);
+        let len = acc.len();
+        (acc, len)
+    });
+
+    assert_eq!(acc, "abcd");
+    assert_eq!(
+        dst,
+        Test {
+            value: 1,
+            values: vec![2, 3],
+            other: 42,
+            last: Some(4),
+        }
+    );
+}
+
+#[test]
+fn initial_accumulator_is_returned_when_closure_is_not_invoked() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(Option<T>);
+
+    let src: Test<T1> = Test(None);
+    let (acc, dst) = src.func_map_fold(42, |acc, _| (acc + 1, T2));
+
+    assert_eq!(acc, 42);
+    assert_eq!(dst, Test(None));
+}
+
+#[test]
+fn type_param_to_fold_over_can_be_specified() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let (acc, dst) =
+        FuncMapFold::<_, _, TypeParam<1>>::func_map_fold(src, 0, |acc, _| (acc + 1, T2));
+
+    assert_eq!(acc, 1);
+    assert_eq!(dst, Test(T1, T2));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
@@ -0,0 +1,137 @@
+#![allow(dead_code)]
+
+use clap::{Args, Parser};
+use funcmap::{FuncMap, TryFuncMap};
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn serde_attributes_are_ignored() {
+    #[derive(FuncMap, TryFuncMap, Serialize, Deserialize, Debug, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    struct Test<T> {
+        #[serde(rename = "renamed")]
+        mapped_value: T,
+
+        #[serde(skip)]
+        unmapped_value: i32,
+
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        more_values: Vec<T>,
+    }
+
+    let src = Test {
+        mapped_value: T1,
+        unmapped_value: 42,
+        more_values: vec![T1],
+    };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test {
+            mapped_value: T2,
+            unmapped_value: 42,
+            more_values: vec![T2],
+        }
+    );
+}
+
+#[test]
+fn serde_attributes_on_variants_are_ignored() {
+    #[derive(FuncMap, TryFuncMap, Serialize, Deserialize, Debug, PartialEq)]
+    #[serde(tag = "type", content = "content")]
+    enum Test<T> {
+        #[serde(rename = "first")]
+        First(T),
+
+        #[serde(alias = "second")]
+        Second {
+            #[serde(rename = "v")]
+            value: T,
+        },
+
+        #[serde(skip)]
+        Skipped,
+    }
+
+    let src = Test::Second { value: T1 };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test::Second { value: T2 });
+}
+
+#[test]
+fn clap_attributes_are_ignored() {
+    #[derive(Args, Debug, PartialEq)]
+    struct Inner1 {
+        #[arg(long)]
+        value: i32,
+    }
+
+    #[derive(Args, Debug, PartialEq)]
+    struct Inner2 {
+        #[arg(long)]
+        value: String,
+    }
+
+    /// Test command
+    #[derive(FuncMap, Parser, Debug, PartialEq)]
+    #[command(name = "test")]
+    struct Test<T: Args> {
+        /// Whether to be verbose
+        #[arg(short, long)]
+        verbose: bool,
+
+        #[command(flatten)]
+        inner: T,
+    }
+
+    let src = Test::<Inner1>::parse_from(["test", "--verbose", "--value", "42"]);
+    let dst = src.func_map(|inner| Inner2 {
+        value: inner.value.to_string(),
+    });
+
+    assert_eq!(
+        dst,
+        Test {
+            verbose: true,
+            inner: Inner2 {
+                value: "42".to_string()
+            },
+        }
+    );
+}
+
+#[test]
+fn doc_and_cfg_attr_attributes_are_ignored() {
+    /// Test type
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[cfg_attr(all(), derive(Clone))]
+    enum Test<T> {
+        /// First variant
+        #[cfg_attr(all(), doc = "with more docs")]
+        First(
+            /// Value
+            #[cfg_attr(any(), serde(skip))]
+            T,
+        ),
+
+        #[doc = "Second variant"]
+        Second {
+            #[doc = "Value"]
+            #[cfg_attr(all(), allow(unused))]
+            value: T,
+        },
+    }
+
+    let src = Test::First(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test::First(T2));
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+struct T1;
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+struct T2;
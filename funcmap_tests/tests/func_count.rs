@@ -0,0 +1,195 @@
+use funcmap::{FuncCount, FuncMap, TypeParam};
+
+use std::cell::Cell;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::marker::PhantomData;
+use std::ops::Bound;
+
+#[test]
+fn core_types_are_counted() {
+    assert_eq!([1, 2, 3].count_leaves(), 3);
+    assert_eq!([1, 2, 3].func_count(|v| *v), 6);
+    assert_eq!(Bound::Included(1).count_leaves(), 1);
+    assert_eq!(Bound::<i32>::Unbounded.count_leaves(), 0);
+    assert_eq!(Some(1).count_leaves(), 1);
+    assert_eq!(None::<i32>.count_leaves(), 0);
+    assert_eq!(PhantomData::<i32>.count_leaves(), 0);
+
+    let ok = Ok::<i32, i32>(1);
+    assert_eq!(FuncCount::<_, TypeParam<0>>::count_leaves(&ok), 1);
+    assert_eq!(FuncCount::<_, TypeParam<1>>::count_leaves(&ok), 0);
+    assert_eq!(
+        FuncCount::<_, TypeParam<1>>::func_count(&Err::<i32, i32>(2), |v| *v as usize),
+        2
+    );
+}
+
+#[test]
+fn collections_are_counted() {
+    assert_eq!(Box::new(1).count_leaves(), 1);
+    assert_eq!(vec![1, 2, 3].count_leaves(), 3);
+    assert_eq!(VecDeque::from(vec![1, 2, 3]).count_leaves(), 3);
+    assert_eq!(
+        vec![1, 2, 3]
+            .into_iter()
+            .collect::<LinkedList<_>>()
+            .count_leaves(),
+        3
+    );
+    assert_eq!(BinaryHeap::from(vec![1, 2, 3]).count_leaves(), 3);
+    assert_eq!(BTreeSet::from([1, 2, 3]).count_leaves(), 3);
+    assert_eq!(HashSet::from([1, 2, 3]).count_leaves(), 3);
+
+    let btree_map = BTreeMap::from([(1, 10), (2, 20)]);
+    assert_eq!(
+        FuncCount::<_, TypeParam<0>>::func_count(&btree_map, |k| *k),
+        3
+    );
+    assert_eq!(
+        FuncCount::<_, TypeParam<1>>::func_count(&btree_map, |v| *v),
+        30
+    );
+
+    let hash_map = HashMap::from([(1, 10), (2, 20)]);
+    assert_eq!(FuncCount::<_, TypeParam<0>>::count_leaves(&hash_map), 2);
+    assert_eq!(
+        FuncCount::<_, TypeParam<1>>::func_count(&hash_map, |v| *v),
+        30
+    );
+}
+
+#[test]
+fn nested_collections_are_counted() {
+    let values = vec![Some(1), None, Some(3)];
+
+    assert_eq!(values.count_leaves(), 3);
+    assert_eq!(values.func_count(|v| v.count_leaves()), 2);
+}
+
+#[test]
+fn fields_are_counted() {
+    #[derive(FuncMap, FuncCount, Debug)]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+        nested: Vec<Option<T>>,
+        tuple: (T, i32, Option<T>),
+        array: [T; 2],
+        other: i32,
+    }
+
+    let test = Test {
+        value: 1,
+        values: vec![2, 3],
+        nested: vec![Some(4), None],
+        tuple: (5, 0, None),
+        array: [6, 7],
+        other: 0,
+    };
+
+    assert_eq!(test.count_leaves(), 7);
+    assert_eq!(test.func_count(|v| *v), 28);
+}
+
+#[test]
+fn count_matches_number_of_closure_invocations() {
+    #[derive(FuncMap, FuncCount, Debug)]
+    struct Test<T> {
+        value: T,
+        nested: Vec<BTreeMap<u8, Vec<Option<Box<T>>>>>,
+        result: Result<T, Option<T>>,
+    }
+
+    let test = Test {
+        value: 1,
+        nested: vec![
+            BTreeMap::from([(1, vec![Some(Box::new(2)), None]), (2, vec![])]),
+            BTreeMap::new(),
+        ],
+        result: Err(Some(3)),
+    };
+
+    let count = test.count_leaves();
+    let invocations = Cell::new(0);
+    let _ = test.func_map(|v| {
+        invocations.set(invocations.get() + 1);
+        v
+    });
+
+    assert_eq!(count, 3);
+    assert_eq!(invocations.get(), count);
+}
+
+#[test]
+fn values_are_visited_in_mapping_order() {
+    #[derive(FuncMap, FuncCount, Debug)]
+    struct Test<T>(T, Vec<T>, (T, T));
+
+    let test = Test(1, vec![2, 3], (4, 5));
+
+    let mut visited = Vec::new();
+    test.func_count(|v| {
+        visited.push(*v);
+        1
+    });
+
+    let mut mapped = Vec::new();
+    let _ = test.func_map(|v| mapped.push(v));
+
+    assert_eq!(visited, mapped);
+}
+
+#[test]
+fn enum_variants_are_counted() {
+    #[allow(dead_code)]
+    #[derive(FuncCount, Debug)]
+    enum Test<T> {
+        Single(T),
+        Many { values: Vec<T>, name: &'static str },
+        Empty,
+    }
+
+    assert_eq!(Test::Single(1).count_leaves(), 1);
+    assert_eq!(
+        Test::Many {
+            values: vec![1, 2],
+            name: "many"
+        }
+        .count_leaves(),
+        2
+    );
+    assert_eq!(Test::<i32>::Empty.count_leaves(), 0);
+}
+
+#[test]
+fn multiple_type_params_are_counted_separately() {
+    #[derive(FuncCount, Debug)]
+    struct Test<S, T>(S, Vec<T>, Option<(S, T)>);
+
+    let test = Test(1, vec![2, 3], Some((4, 5)));
+
+    assert_eq!(FuncCount::<_, TypeParam<0>>::count_leaves(&test), 2);
+    assert_eq!(FuncCount::<_, TypeParam<1>>::count_leaves(&test), 3);
+}
+
+#[test]
+fn references_are_counted() {
+    #[allow(dead_code)]
+    #[derive(FuncCount, Debug)]
+    struct Test<'a, T>(&'a T, &'a [u8], &'a Vec<T>);
+
+    let value = 1;
+    let values = vec![2, 3];
+    let test = Test(&value, &[], &values);
+
+    assert_eq!(test.count_leaves(), 3);
+    assert_eq!(test.func_count(|v| *v), 6);
+}
+
+#[test]
+fn phantom_data_is_not_counted() {
+    #[derive(FuncCount, Debug)]
+    struct Test<T>(PhantomData<fn() -> T>, Option<T>);
+
+    assert_eq!(Test(PhantomData, Some(1)).count_leaves(), 1);
+}
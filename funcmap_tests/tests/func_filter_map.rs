@@ -0,0 +1,160 @@
+use funcmap::{FuncFilterMap, FuncMap};
+
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+
+#[test]
+fn option_is_filter_mapped() {
+    assert_eq!(Some(1).func_filter_map(|v| Some(v + 1)), Some(2));
+    assert_eq!(Some(1).func_filter_map(|_| None::<i32>), None);
+    assert_eq!(None::<i32>.func_filter_map(|v| Some(v + 1)), None);
+}
+
+#[test]
+fn sequences_are_filter_mapped() {
+    let even = |v: i32| (v % 2 == 0).then_some(v * 10);
+
+    assert_eq!(vec![1, 2, 3, 4].func_filter_map(even), [20, 40]);
+    assert_eq!(
+        VecDeque::from(vec![1, 2, 3, 4]).func_filter_map(even),
+        [20, 40]
+    );
+    assert_eq!(
+        vec![1, 2, 3, 4]
+            .into_iter()
+            .collect::<LinkedList<_>>()
+            .func_filter_map(even),
+        vec![20, 40].into_iter().collect::<LinkedList<_>>()
+    );
+    assert_eq!(
+        BinaryHeap::from(vec![1, 2, 3, 4])
+            .func_filter_map(even)
+            .into_sorted_vec(),
+        [20, 40]
+    );
+}
+
+#[test]
+fn sets_are_filter_mapped() {
+    let half = |v: i32| (v % 2 == 0).then_some(v / 2);
+
+    assert_eq!(
+        BTreeSet::from([1, 2, 4]).func_filter_map(half),
+        BTreeSet::from([1, 2])
+    );
+    assert_eq!(
+        HashSet::from([1, 2, 4]).func_filter_map(half),
+        HashSet::from([1, 2])
+    );
+}
+
+#[test]
+fn map_values_are_filter_mapped_keeping_keys() {
+    let non_empty = |v: &str| (!v.is_empty()).then_some(v.len());
+
+    assert_eq!(
+        BTreeMap::from([('a', "one"), ('b', ""), ('c', "three")]).func_filter_map(non_empty),
+        BTreeMap::from([('a', 3), ('c', 5)])
+    );
+    assert_eq!(
+        HashMap::from([('a', "one"), ('b', ""), ('c', "three")]).func_filter_map(non_empty),
+        HashMap::from([('a', 3), ('c', 5)])
+    );
+}
+
+#[test]
+fn struct_fields_are_filter_mapped() {
+    #[derive(FuncFilterMap, Debug, PartialEq)]
+    struct Test<T> {
+        name: &'static str,
+        value: Option<T>,
+        values: Vec<T>,
+        map: BTreeMap<char, T>,
+    }
+
+    let src = Test {
+        name: "test",
+        value: Some(1),
+        values: vec![2, 3],
+        map: BTreeMap::from([('a', 4), ('b', 5)]),
+    };
+
+    assert_eq!(
+        src.func_filter_map(|v| (v % 2 == 0).then_some(v)),
+        Test {
+            name: "test",
+            value: None,
+            values: vec![2],
+            map: BTreeMap::from([('a', 4)]),
+        }
+    );
+}
+
+#[test]
+fn enum_variants_are_filter_mapped() {
+    #[derive(FuncFilterMap, Debug, PartialEq)]
+    enum Test<T> {
+        Unit,
+        Tuple(Vec<T>),
+        Struct { value: Option<T>, flag: bool },
+    }
+
+    let positive = |v: i32| (v > 0).then_some(v);
+
+    assert_eq!(Test::Unit.func_filter_map(positive), Test::Unit);
+    assert_eq!(
+        Test::Tuple(vec![-1, 1]).func_filter_map(positive),
+        Test::Tuple(vec![1])
+    );
+    assert_eq!(
+        Test::Struct {
+            value: Some(-1),
+            flag: true
+        }
+        .func_filter_map(positive),
+        Test::Struct {
+            value: None,
+            flag: true
+        }
+    );
+}
+
+#[test]
+fn nested_derived_types_are_filter_mapped() {
+    #[derive(FuncFilterMap, Debug, PartialEq)]
+    struct Inner<T>(Vec<T>);
+
+    #[derive(FuncFilterMap, Debug, PartialEq)]
+    struct Test<T>(Inner<T>, Option<T>);
+
+    assert_eq!(
+        Test(Inner(vec![1, 2]), Some(3)).func_filter_map(|v| (v != 2).then_some(v)),
+        Test(Inner(vec![1]), Some(3))
+    );
+}
+
+#[test]
+fn closure_is_called_in_order_of_fields() {
+    #[derive(FuncFilterMap)]
+    struct Test<T>(Vec<T>, Option<T>, Vec<T>);
+
+    let mut calls = Vec::new();
+
+    let _ = Test(vec![1, 2], Some(3), vec![4]).func_filter_map(|v| {
+        calls.push(v);
+        Some(v)
+    });
+
+    assert_eq!(calls, [1, 2, 3, 4]);
+}
+
+#[test]
+fn other_params_are_kept() {
+    #[derive(FuncFilterMap, FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<'a, S, T, const N: usize>(&'a str, [S; N], Vec<T>);
+
+    assert_eq!(
+        Test("a", [true], vec![1, 2]).func_filter_map(|v| (v > 1).then_some(v)),
+        Test("a", [true], vec![2])
+    );
+}
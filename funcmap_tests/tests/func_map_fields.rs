@@ -0,0 +1,149 @@
+#![deny(missing_docs)]
+
+//! Tests for the `FuncMapFields` derive macro
+
+use funcmap::{FuncMap, FuncMapFields};
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+#[test]
+fn fields_are_mapped_with_access_to_each_other() {
+    #[derive(FuncMapFields, Debug, PartialEq)]
+    struct Amount<V, C> {
+        value: V,
+        currency: C,
+    }
+
+    let src = Amount {
+        value: 250,
+        currency: "EUR",
+    };
+    let dst = src.func_map_fields(|(value, currency)| {
+        let rate = if currency == "EUR" { 2 } else { 1 };
+        (value * rate, currency.len())
+    });
+
+    assert_eq!(
+        dst,
+        Amount {
+            value: 500,
+            currency: 3
+        }
+    );
+}
+
+#[test]
+fn fields_depending_on_type_params_are_mapped() {
+    #[derive(FuncMap, FuncMapFields, Debug, PartialEq)]
+    struct Test<S, T> {
+        scale: S,
+        values: Vec<T>,
+        label: &'static str,
+    }
+
+    let src = Test {
+        scale: 10,
+        values: vec![1, 2],
+        label: "test",
+    };
+    let dst = src.func_map_fields(|(scale, values, label)| {
+        (f64::from(scale), values.func_map(|v| v * scale), label)
+    });
+
+    assert_eq!(
+        dst,
+        Test {
+            scale: 10.0,
+            values: vec![10, 20],
+            label: "test",
+        }
+    );
+}
+
+#[test]
+fn tuple_struct_fields_are_mapped() {
+    #[derive(FuncMapFields, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(2, "a");
+    let dst = src.func_map_fields(|(count, text)| (text.len(), text.repeat(count)));
+
+    assert_eq!(dst, Test(1, String::from("aa")));
+}
+
+#[test]
+fn single_field_is_mapped() {
+    #[derive(FuncMapFields, Debug, PartialEq)]
+    struct Test<T>(T);
+
+    let dst = Test(1).func_map_fields(|(v,)| (v + 1,));
+
+    assert_eq!(dst, Test(2));
+}
+
+#[test]
+fn only_mapped_type_params_are_replaced() {
+    #[derive(FuncMapFields, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<S, T>(S, T);
+
+    let src = Test(1, 2);
+    let dst = src.func_map_fields(|(s, t)| (s + 1, t.to_string()));
+
+    assert_eq!(dst, Test(2, String::from("2")));
+}
+
+#[test]
+fn bounds_of_original_type_are_kept() {
+    trait TestTrait<T> {}
+
+    impl<T> TestTrait<T> for () {}
+
+    #[derive(FuncMapFields, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<S: TestTrait<T>, T: Clone>(PhantomData<S>, T)
+    where
+        T: Debug;
+
+    let src = Test::<(), _>(PhantomData, 1);
+    let dst = src.func_map_fields(|(s, t)| (s, t.to_string()));
+
+    assert_eq!(dst, Test(PhantomData, String::from("1")));
+}
+
+#[test]
+fn lifetimes_and_const_generics_are_supported() {
+    #[derive(FuncMapFields, Debug, PartialEq)]
+    struct Test<'a, T, const N: usize>(&'a str, [T; N]);
+
+    let src = Test("a", [1, 2]);
+    let dst =
+        src.func_map_fields(|(prefix, values)| (prefix, values.map(|v| format!("{prefix}{v}"))));
+
+    assert_eq!(dst, Test("a", [String::from("a1"), String::from("a2")]));
+}
+
+#[test]
+fn conflicting_destination_type_ident_is_avoided() {
+    #[derive(FuncMapFields, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<T, T2>(T, T2);
+
+    let src = Test(1, 2);
+    let dst = src.func_map_fields(|(t, t2)| (t + t2, t2));
+
+    assert_eq!(dst, Test(3, 2));
+}
+
+/// Type with a public generated method, which needs to be documented
+#[derive(FuncMapFields, Debug, PartialEq)]
+pub struct Documented<T>(pub T);
+
+#[test]
+fn generated_method_is_documented() {
+    assert_eq!(
+        Documented(1).func_map_fields(|(v,)| (v + 1,)),
+        Documented(2)
+    );
+}
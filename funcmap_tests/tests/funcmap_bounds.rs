@@ -0,0 +1,139 @@
+use funcmap::{FuncMap, TryFuncMap, TypeParam};
+
+use std::convert::Infallible;
+
+#[test]
+fn mapped_type_param_can_be_bounded_by_func_map() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<C: FuncMap<u8, u16>>(C);
+
+    let src = Test(vec![1_u8, 2_u8]);
+    let dst = src.func_map(|values| values.into_iter().rev().collect::<Vec<_>>());
+
+    assert_eq!(dst, Test(vec![2_u8, 1_u8]));
+}
+
+#[test]
+fn mapped_type_param_can_be_bounded_by_try_func_map() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<C: TryFuncMap<u8, u16>>(C);
+
+    let src = Test(vec![1_u8, 2_u8]);
+    let dst: Result<_, Infallible> =
+        src.try_func_map(|values| Ok(values.into_iter().rev().collect::<Vec<_>>()));
+
+    assert_eq!(dst, Ok(Test(vec![2_u8, 1_u8])));
+}
+
+#[test]
+fn unmapped_type_param_can_be_bounded_by_func_map() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<T, C: FuncMap<u8, u16>>(T, C);
+
+    let src = Test(T1, vec![1_u8]);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, vec![1_u8]));
+}
+
+#[test]
+fn func_map_bound_can_depend_on_mapped_type_param() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<T, C: FuncMap<T, u16>>(T, C);
+
+    let src = Test(T1, Constant);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, Constant));
+}
+
+#[test]
+fn func_map_bound_in_where_clause_can_depend_on_mapped_type_param() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<T, C>(T, C)
+    where
+        C: funcmap::FuncMap<T, T, Output = C>;
+
+    let src = Test(T1, Constant);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, Constant));
+}
+
+#[test]
+fn func_map_bound_can_have_output_type() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T: FuncMap<u8, u16, Output = T>>(T);
+
+    let src = Test(Wrapper(1));
+    let dst = src.func_map(|Wrapper(value)| Wrapper(value + 1));
+
+    assert_eq!(dst, Test(Wrapper(2)));
+}
+
+#[test]
+fn func_map_bound_can_have_explicit_marker_type() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<T, C: FuncMap<u8, u16, TypeParam<0>>>(T, C);
+
+    let src = Test(T1, vec![1_u8]);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, vec![1_u8]));
+}
+
+#[test]
+fn func_map_bound_is_kept_verbatim_with_configured_crate_path() {
+    extern crate funcmap as fm;
+
+    #[derive(fm::FuncMap, Debug, PartialEq)]
+    #[funcmap(crate = "fm", params(T))]
+    struct Test<T, C: fm::FuncMap<u8, u16>, D: ::funcmap::FuncMap<u8, u16, Output = Vec<u16>>>(
+        T,
+        C,
+        D,
+    );
+
+    let src = Test(T1, Some(1_u8), vec![1_u8]);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, Some(1_u8), vec![1_u8]));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
+
+#[derive(Debug, PartialEq)]
+struct Wrapper<T>(T);
+
+impl FuncMap<u8, u16> for Wrapper<u8> {
+    type Output = Self;
+
+    fn func_map<F>(self, _: F) -> Self::Output
+    where
+        F: FnMut(u8) -> u16,
+    {
+        self
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Constant;
+
+impl<A, B> FuncMap<A, B> for Constant {
+    type Output = Self;
+
+    fn func_map<F>(self, _: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+    {
+        self
+    }
+}
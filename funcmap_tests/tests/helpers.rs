@@ -0,0 +1,54 @@
+use funcmap::helpers::{try_collect_btreemap, try_collect_vec};
+use funcmap::{TryFuncMap, TypeParam};
+
+use std::collections::BTreeMap;
+
+#[test]
+fn try_collect_vec_collects_in_iteration_order() {
+    let result: Result<_, ()> = try_collect_vec([Ok(3), Ok(1), Ok(2)]);
+
+    assert_eq!(result, Ok(vec![3, 1, 2]));
+}
+
+#[test]
+fn try_collect_vec_returns_first_error() {
+    let result = try_collect_vec([Ok(1), Err(2), Err(3)]);
+
+    assert_eq!(result, Err(2));
+}
+
+#[test]
+fn try_collect_btreemap_keeps_last_value_for_equal_keys() {
+    let result: Result<_, ()> = try_collect_btreemap([Ok((1, 'a')), Ok((2, 'b')), Ok((1, 'c'))]);
+
+    assert_eq!(result, Ok(BTreeMap::from([(1, 'c'), (2, 'b')])));
+}
+
+#[test]
+fn try_collect_btreemap_returns_first_error() {
+    let result = try_collect_btreemap([Ok((1, 'a')), Err(2), Err(3)]);
+
+    assert_eq!(result, Err(2));
+}
+
+#[test]
+fn helpers_can_be_used_in_manual_implementations() {
+    #[derive(Debug, PartialEq)]
+    struct Pairs<K, V>(Vec<(K, V)>);
+
+    impl<A, B, V> TryFuncMap<A, B, TypeParam<0>> for Pairs<A, V> {
+        type Output = Pairs<B, V>;
+
+        fn try_func_map<E, F>(self, mut f: F) -> Result<Self::Output, E>
+        where
+            F: FnMut(A) -> Result<B, E>,
+        {
+            try_collect_vec(self.0.into_iter().map(|(k, v)| Ok((f(k)?, v)))).map(Pairs)
+        }
+    }
+
+    let src = Pairs(vec![(1, 'a'), (2, 'b')]);
+    let dst: Result<_, ()> = src.try_func_map(|k| Ok(k * 10));
+
+    assert_eq!(dst, Ok(Pairs(vec![(10, 'a'), (20, 'b')])));
+}
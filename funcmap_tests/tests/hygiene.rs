@@ -13,6 +13,46 @@ fn conflicting_type_params_are_avoided() {
     }
 }
 
+#[test]
+fn conflicting_type_params_covering_alphabet_are_avoided() {
+    #[derive(FuncMap)]
+    struct Test<A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z>(
+        A,
+        B,
+        C,
+        D,
+        E,
+        F,
+        G,
+        H,
+        I,
+        J,
+        K,
+        L,
+        M,
+        N,
+        O,
+        P,
+        Q,
+        R,
+        S,
+        T,
+        U,
+        V,
+        W,
+        X,
+        Y,
+        Z,
+    );
+}
+
+#[test]
+fn conflicting_type_params_with_prefix_are_avoided() {
+    #[allow(non_camel_case_types)]
+    #[derive(FuncMap)]
+    struct Test<A, B, E, F, X, __FUNCMAP_A, __FUNCMAP_B>(A, B, E, F, X, __FUNCMAP_A, __FUNCMAP_B);
+}
+
 #[test]
 fn fields_conflicting_with_items_are_supported() {
     #[allow(non_snake_case)]
@@ -88,3 +128,86 @@ fn raw_identifiers_are_supported() {
         r#let,
     }
 }
+
+#[test]
+fn raw_field_identifiers_are_mapped() {
+    #![allow(non_camel_case_types)]
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    enum Test<T> {
+        r#struct { r#type: T, r#match: T },
+        r#enum { r#ref: T },
+    }
+
+    let src = Test::r#struct {
+        r#type: 1,
+        r#match: 2,
+    };
+    let dst = src.func_map(|v| v * 2);
+
+    assert_eq!(
+        dst,
+        Test::r#struct {
+            r#type: 2,
+            r#match: 4,
+        }
+    );
+}
+
+#[test]
+fn unicode_identifiers_are_supported() {
+    #![allow(non_camel_case_types)]
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Größe<Ü> {
+        länge: Ü,
+        ширина: Ü,
+        高さ: Ü,
+    }
+
+    let src = Größe {
+        länge: 1,
+        ширина: 2,
+        高さ: 3,
+    };
+    let dst = src.func_map(|v| v * 2);
+
+    assert_eq!(
+        dst,
+        Größe {
+            länge: 2,
+            ширина: 4,
+            高さ: 6,
+        }
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn fields_of_long_tuple_structs_are_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(
+        T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+        T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+        T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+        T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    );
+
+    let src = Test(
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+        16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31,
+        32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+        48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
+    );
+    let dst = src.func_map(|v| v * 2);
+
+    assert_eq!(
+        dst,
+        Test(
+            0, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 22, 24, 26, 28, 30,
+            32, 34, 36, 38, 40, 42, 44, 46, 48, 50, 52, 54, 56, 58, 60, 62,
+            64, 66, 68, 70, 72, 74, 76, 78, 80, 82, 84, 86, 88, 90, 92, 94,
+            96, 98, 100, 102, 104, 106, 108, 110, 112, 114, 116, 118, 120, 122, 124, 126,
+        )
+    );
+}
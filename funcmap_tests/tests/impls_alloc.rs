@@ -1,8 +1,9 @@
 extern crate alloc;
 
-use funcmap::FuncMap;
+use funcmap::{DrainFuncMap, FuncMap, FuncMapChunked, TryFuncMap, TryFuncMapReserve, TypeParam};
 
 use alloc::{
+    borrow::Cow,
     collections::{
         binary_heap, btree_map, btree_set, linked_list, vec_deque, BTreeMap, BTreeSet, BinaryHeap,
         LinkedList, VecDeque,
@@ -21,6 +22,16 @@ fn field_of_binary_heap_type_is_mapped() {
     assert_eq!(dst.0.into_vec(), [T2]);
 }
 
+#[test]
+fn binary_heap_is_drained_and_mapped() {
+    let mut src = BinaryHeap::from([1, 3, 2]);
+
+    let dst = src.drain_func_map(|v| v * 10);
+
+    assert_eq!(dst.into_sorted_vec(), [10, 20, 30]);
+    assert!(src.is_empty());
+}
+
 #[test]
 fn field_of_binary_heap_into_iter_type_is_mapped() {
     #[derive(FuncMap, Debug)]
@@ -32,6 +43,61 @@ fn field_of_binary_heap_into_iter_type_is_mapped() {
     assert_eq!(dst.0.collect::<Vec<_>>(), [T2]);
 }
 
+#[test]
+fn field_of_cow_slice_type_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<'a, T: Clone>(Cow<'a, [T]>);
+
+    let values = [1, 2];
+    let src = Test(Cow::Borrowed(&values[..]));
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(dst, Test(Cow::Owned(vec![10, 20])));
+}
+
+#[test]
+fn field_of_cow_slice_of_nested_type_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    struct Test<'a, T: Clone>(Cow<'a, [Option<T>]>);
+
+    let values = [Some(1), None];
+    let src = Test(Cow::Borrowed(&values[..]));
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(dst, Test(Cow::Owned(vec![Some(10), None])));
+}
+
+#[test]
+fn borrowed_cow_slice_is_mapped_into_owned() {
+    let values = [T1, T1];
+    let src: Cow<[T1]> = Cow::Borrowed(&values);
+    let dst = src.func_map(|_| T2);
+
+    assert!(matches!(dst, Cow::Owned(values) if values == [T2, T2]));
+}
+
+#[test]
+fn owned_cow_slice_is_mapped_into_owned() {
+    let src: Cow<[T1]> = Cow::Owned(vec![T1, T1]);
+    let dst = src.func_map(|_| T2);
+
+    assert!(matches!(dst, Cow::Owned(values) if values == [T2, T2]));
+}
+
+#[test]
+fn cow_slice_is_try_mapped() {
+    let values = [1, -2, 3];
+    let src: Cow<[i32]> = Cow::Borrowed(&values);
+    let dst: Result<Cow<[i32]>, _> = src.try_func_map(|v| if v > 0 { Ok(v) } else { Err(v) });
+
+    assert_eq!(dst, Err(-2));
+
+    let src: Cow<[i32]> = Cow::Owned(vec![1, 3]);
+    let dst: Result<Cow<[i32]>, ()> = src.try_func_map(Ok);
+
+    assert!(matches!(dst, Ok(Cow::Owned(values)) if values == [1, 3]));
+}
+
 #[test]
 fn field_of_box_type_is_mapped() {
     #[derive(FuncMap, Debug, PartialEq)]
@@ -43,6 +109,47 @@ fn field_of_box_type_is_mapped() {
     assert_eq!(dst, Test(Box::new(T2)));
 }
 
+#[test]
+fn field_of_option_of_box_type_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(
+        core::option::Option<alloc::boxed::Box<T>>,
+        Option<Box<(T, i32)>>,
+    );
+
+    let src = Test(Some(Box::new(T1)), Some(Box::new((T1, 42))));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Some(Box::new(T2)), Some(Box::new((T2, 42)))));
+
+    let src = Test::<T1>(None, None);
+    let dst: Result<Test<T2>, ()> = src.try_func_map(|_| Ok(T2));
+
+    assert_eq!(dst, Ok(Test(None, None)));
+}
+
+#[test]
+fn field_of_box_of_option_type_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(
+        alloc::boxed::Box<core::option::Option<T>>,
+        Box<Option<Option<Box<T>>>>,
+    );
+
+    let src = Test(Box::new(Some(T1)), Box::new(Some(Some(Box::new(T1)))));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test(Box::new(Some(T2)), Box::new(Some(Some(Box::new(T2)))))
+    );
+
+    let src = Test(Box::new(Some(T1)), Box::new(None));
+    let dst: Result<Test<T2>, _> = src.try_func_map(|_| Err::<T2, _>(42));
+
+    assert_eq!(dst, Err(42));
+}
+
 #[test]
 fn field_of_btree_map_type_is_mapped_over_key() {
     #[derive(FuncMap, Debug, PartialEq)]
@@ -65,6 +172,101 @@ fn field_of_btree_map_type_is_mapped_over_value() {
     assert_eq!(dst, Test([((), T2)].into()));
 }
 
+#[test]
+fn btree_map_is_drained_and_mapped_over_key() {
+    let mut src = BTreeMap::from([(1, 'a'), (2, 'b')]);
+
+    let dst = DrainFuncMap::<_, _, TypeParam<0>>::drain_func_map(&mut src, |k| -k);
+
+    assert_eq!(dst, BTreeMap::from([(-1, 'a'), (-2, 'b')]));
+    assert!(src.is_empty());
+}
+
+#[test]
+fn btree_map_is_drained_and_mapped_over_value() {
+    let mut src = BTreeMap::from([('a', 1), ('b', 2)]);
+
+    let dst = DrainFuncMap::<_, _, TypeParam<1>>::drain_func_map(&mut src, |v| v * 10);
+
+    assert_eq!(dst, BTreeMap::from([('a', 10), ('b', 20)]));
+    assert!(src.is_empty());
+}
+
+#[test]
+fn btree_map_is_mapped_over_key_and_value_with_reserve() {
+    let src = BTreeMap::from([(1, 'a'), (2, 'b')]);
+    let dst =
+        TryFuncMapReserve::<_, _, TypeParam<0>>::try_func_map_reserve(src, |k| Ok::<_, ()>(-k));
+
+    assert_eq!(dst, Ok(BTreeMap::from([(-1, 'a'), (-2, 'b')])));
+
+    let src = BTreeMap::from([('a', 1), ('b', 2)]);
+    let dst = TryFuncMapReserve::<_, _, TypeParam<1>>::try_func_map_reserve(src, |v| {
+        if v < 2 {
+            Ok(v * 10)
+        } else {
+            Err(v)
+        }
+    });
+
+    assert_eq!(dst, Err(2));
+}
+
+#[test]
+fn field_of_btree_map_type_is_mapped_over_key_and_value_in_single_pass() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(alloc::collections::BTreeMap<T, Vec<T>>);
+
+    let mut calls = Vec::new();
+
+    let src = Test([(1, vec![2, 3]), (4, vec![5])].into());
+    let dst = src.func_map(|v| {
+        calls.push(v);
+        v * 10
+    });
+
+    assert_eq!(dst, Test([(10, vec![20, 30]), (40, vec![50])].into()));
+    assert_eq!(calls, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn field_of_btree_map_type_is_try_mapped_over_key_and_value_in_single_pass() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(alloc::collections::BTreeMap<T, Vec<T>>);
+
+    let mut calls = Vec::new();
+
+    let src = Test([(1, vec![2, 3]), (4, vec![5])].into());
+    let dst = src.try_func_map(|v| {
+        calls.push(v);
+        if v < 4 {
+            Ok(v * 10)
+        } else {
+            Err(v)
+        }
+    });
+
+    assert_eq!(dst, Err(4));
+    assert_eq!(calls, [1, 2, 3, 4]);
+}
+
+#[test]
+fn field_of_unqualified_btree_map_type_is_mapped_over_keys_before_values() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(BTreeMap<T, Vec<T>>);
+
+    let mut calls = Vec::new();
+
+    let src = Test([(1, vec![2, 3]), (4, vec![5])].into());
+    let dst = src.func_map(|v| {
+        calls.push(v);
+        v * 10
+    });
+
+    assert_eq!(dst, Test([(10, vec![20, 30]), (40, vec![50])].into()));
+    assert_eq!(calls, [1, 4, 2, 3, 5]);
+}
+
 #[test]
 fn field_of_btree_map_into_iter_type_is_mapped_over_key() {
     #[derive(FuncMap, Debug)]
@@ -98,6 +300,16 @@ fn field_of_btree_set_type_is_mapped() {
     assert_eq!(dst, Test([T2].into()));
 }
 
+#[test]
+fn btree_set_is_drained_and_mapped() {
+    let mut src = BTreeSet::from([1, 2]);
+
+    let dst = src.drain_func_map(|v| v * 10);
+
+    assert_eq!(dst, BTreeSet::from([10, 20]));
+    assert!(src.is_empty());
+}
+
 #[test]
 fn field_of_btree_set_into_iter_type_is_mapped() {
     #[derive(FuncMap, Debug)]
@@ -120,6 +332,16 @@ fn field_of_linked_list_type_is_mapped() {
     assert_eq!(dst, Test([T2].into()));
 }
 
+#[test]
+fn linked_list_is_drained_and_mapped() {
+    let mut src = LinkedList::from([1, 2]);
+
+    let dst = src.drain_func_map(|v| v * 10);
+
+    assert_eq!(dst, LinkedList::from([10, 20]));
+    assert!(src.is_empty());
+}
+
 #[test]
 fn field_of_linked_list_into_iter_type_is_mapped() {
     #[derive(FuncMap, Debug)]
@@ -142,6 +364,94 @@ fn field_of_vec_type_is_mapped() {
     assert_eq!(dst, Test(vec![T2, T2]));
 }
 
+#[test]
+fn vec_is_drained_and_mapped_keeping_its_capacity() {
+    let mut src = Vec::with_capacity(16);
+    src.extend([1, 2, 3]);
+
+    let dst = src.drain_func_map(|v| v * 10);
+
+    assert_eq!(dst, [10, 20, 30]);
+    assert!(src.is_empty());
+    assert!(src.capacity() >= 16);
+}
+
+#[test]
+fn vec_can_be_refilled_after_being_drained_and_mapped() {
+    let mut src = vec![1, 2];
+
+    let first = src.drain_func_map(|v| v * 10);
+    src.extend([3, 4]);
+    let second = src.drain_func_map(|v| v * 10);
+
+    assert_eq!(first, [10, 20]);
+    assert_eq!(second, [30, 40]);
+}
+
+#[test]
+fn vec_is_mapped_into_output_of_exact_capacity_with_reserve() {
+    let src = vec![1, 2, 3];
+    let dst = src.try_func_map_reserve(|v| Ok::<_, ()>(v * 10)).unwrap();
+
+    assert_eq!(dst, [10, 20, 30]);
+    assert_eq!(dst.capacity(), 3);
+}
+
+#[test]
+fn vec_mapping_with_reserve_stops_at_first_error() {
+    let mut calls = 0;
+
+    let src = vec![1, 2, 3];
+    let dst = src.try_func_map_reserve(|v| {
+        calls += 1;
+        if v < 2 {
+            Ok(v)
+        } else {
+            Err(v)
+        }
+    });
+
+    assert_eq!(dst, Err(2));
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn vec_is_mapped_in_chunks() {
+    let src = vec![1, 2, 3, 4, 5, 6, 7];
+
+    let mut chunks = Vec::new();
+    let dst = src.func_map_chunked(3, |chunk, output| {
+        chunks.push(chunk.to_vec());
+        output.extend(chunk.iter().map(|v| v * 10));
+    });
+
+    assert_eq!(dst, [10, 20, 30, 40, 50, 60, 70]);
+    assert_eq!(chunks, [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+}
+
+#[test]
+fn empty_vec_is_mapped_in_chunks_without_invoking_closure() {
+    let src: Vec<i32> = Vec::new();
+
+    let dst: Vec<i32> = src.func_map_chunked(3, |_, _| panic!("closure invoked"));
+
+    assert!(dst.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn vec_mapped_in_chunks_of_size_zero_panics() {
+    let _: Vec<i32> = vec![1].func_map_chunked(0, |chunk, output| output.extend_from_slice(chunk));
+}
+
+#[test]
+#[should_panic(
+    expected = "closure passed to `func_map_chunked` must push exactly one value per value of the chunk"
+)]
+fn vec_mapped_in_chunks_with_closure_pushing_too_few_values_panics() {
+    let _: Vec<i32> = vec![1, 2].func_map_chunked(2, |chunk, output| output.push(chunk[0]));
+}
+
 #[test]
 fn field_of_vec_into_iter_type_is_mapped() {
     #[derive(FuncMap, Debug)]
@@ -164,6 +474,35 @@ fn field_of_vec_deque_type_is_mapped() {
     assert_eq!(dst, Test([T2, T2].into()));
 }
 
+#[test]
+fn vec_deque_is_drained_and_mapped_keeping_its_capacity() {
+    let mut src = VecDeque::with_capacity(16);
+    src.extend([2, 3]);
+    src.push_front(1);
+
+    let dst = src.drain_func_map(|v| v * 10);
+
+    assert_eq!(dst, [10, 20, 30]);
+    assert!(src.is_empty());
+    assert!(src.capacity() >= 16);
+}
+
+#[test]
+fn vec_deque_is_mapped_in_chunks() {
+    let mut src = VecDeque::from([3, 4, 5]);
+    src.push_front(2);
+    src.push_front(1);
+
+    let mut chunk_lens = Vec::new();
+    let dst = src.func_map_chunked(2, |chunk, output| {
+        chunk_lens.push(chunk.len());
+        output.extend(chunk.iter().map(|v| v * 10));
+    });
+
+    assert_eq!(dst, [10, 20, 30, 40, 50]);
+    assert_eq!(chunk_lens, [2, 2, 1]);
+}
+
 #[test]
 fn field_of_vec_deque_into_iter_type_is_mapped() {
     #[derive(FuncMap, Debug)]
@@ -175,8 +514,8 @@ fn field_of_vec_deque_into_iter_type_is_mapped() {
     assert_eq!(dst.0.collect::<Vec<_>>(), [T2, T2]);
 }
 
-#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 struct T1;
 
-#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 struct T2;
@@ -1,7 +1,9 @@
-use funcmap::FuncMap;
+use funcmap::{FuncMap, TryFuncMap, TypeParam};
 
 use core::cell::{Cell, RefCell, UnsafeCell};
+use core::cmp::Reverse;
 use core::marker::PhantomData;
+use core::num::Wrapping;
 use core::ops::{Bound, ControlFlow, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
 use core::task::Poll;
 use core::{option, result};
@@ -17,6 +19,17 @@ fn field_of_array_type_is_mapped() {
     assert_eq!(dst, Test([T2, T2]));
 }
 
+#[test]
+fn field_of_array_type_with_const_generic_length_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T, const N: usize>([Option<T>; N], PhantomData<[T; N]>);
+
+    let src = Test([Some(T1), None], PhantomData);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test([Some(T2), None], PhantomData));
+}
+
 #[test]
 fn field_of_bound_type_is_mapped() {
     #[derive(FuncMap, Debug, PartialEq)]
@@ -39,6 +52,17 @@ fn field_of_cell_type_is_mapped() {
     assert_eq!(dst, Test(Cell::new(T2)));
 }
 
+#[test]
+fn field_of_reverse_type_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(Reverse<T>);
+
+    let src = Test(Reverse(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Reverse(T2)));
+}
+
 #[test]
 fn field_of_control_flow_type_is_mapped_over_break() {
     #[derive(FuncMap, Debug, PartialEq)]
@@ -61,6 +85,18 @@ fn field_of_control_flow_type_is_mapped_over_continue() {
     assert_eq!(dst, Test(ControlFlow::Continue(T2)));
 }
 
+#[test]
+fn field_of_wrapping_type_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(Wrapping<T>);
+
+    let src = Test(Wrapping(u8::MAX));
+    let dst = src.func_map(u16::from);
+
+    assert_eq!(dst, Test(Wrapping(255_u16)));
+    assert_eq!(dst.0 + Wrapping(1), Wrapping(256));
+}
+
 #[test]
 fn field_of_option_type_is_mapped() {
     #[derive(FuncMap, Debug, PartialEq)]
@@ -94,6 +130,103 @@ fn field_of_phantom_data_type_is_mapped() {
     assert_eq!(dst, Test(PhantomData::<T2>));
 }
 
+#[test]
+fn field_of_phantom_data_type_with_otherwise_unsupported_type_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<'a, T>(
+        T,
+        PhantomData<&'a T>,
+        PhantomData<fn(T) -> T>,
+        PhantomData<dyn for<'b> Fn(&'b T) + 'a>,
+        PhantomData<[T]>,
+    );
+
+    let src = Test(T1, PhantomData, PhantomData, PhantomData, PhantomData);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test(T2, PhantomData, PhantomData, PhantomData, PhantomData)
+    );
+}
+
+#[test]
+fn type_param_only_occurring_within_phantom_data_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        id: u64,
+        name: String,
+        marker: PhantomData<T>,
+    }
+
+    let src = Test::<T1> {
+        id: 1,
+        name: String::from("name"),
+        marker: PhantomData,
+    };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test::<T2> {
+            id: 1,
+            name: String::from("name"),
+            marker: PhantomData,
+        }
+    );
+
+    let src = Test::<T1> {
+        id: 1,
+        name: String::from("name"),
+        marker: PhantomData,
+    };
+    let dst: Result<Test<T2>, ()> = src.try_func_map(|_| Err(()));
+
+    assert!(dst.is_ok());
+}
+
+#[test]
+fn type_param_only_occurring_within_phantom_data_is_mapped_for_enum() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    enum Test<S, T> {
+        Value(S, PhantomData<T>),
+        Marker(PhantomData<fn() -> T>),
+    }
+
+    let src = Test::<i32, T1>::Value(1, PhantomData);
+    let dst = src.func_map_over::<TypeParam<1>, _>(|_| T2);
+
+    assert_eq!(dst, Test::<i32, T2>::Value(1, PhantomData));
+}
+
+#[test]
+fn type_param_only_occurring_within_phantom_data_is_mapped_with_generic_last_field() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<T, S: ?Sized>(PhantomData<T>, S);
+
+    let src = Test::<T1, _>(PhantomData, 1);
+    let dst = src.func_map(|_: T1| T2);
+
+    assert_eq!(dst, Test::<T2, _>(PhantomData, 1));
+}
+
+#[test]
+fn field_of_phantom_data_type_with_reference_to_higher_ranked_type_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<'a, T>(
+        T,
+        PhantomData<&'a for<'b> fn(&'b T)>,
+        PhantomData<for<'b> fn(&'a &'b T)>,
+        PhantomData<&'a dyn for<'b> Fn(&'b T)>,
+    );
+
+    let src = Test(T1, PhantomData, PhantomData, PhantomData);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, PhantomData, PhantomData, PhantomData));
+}
+
 #[test]
 fn field_of_poll_type_is_mapped() {
     #[derive(FuncMap, Debug, PartialEq)]
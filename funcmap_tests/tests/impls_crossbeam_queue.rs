@@ -0,0 +1,92 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use crossbeam_queue::{ArrayQueue, SegQueue};
+
+#[test]
+fn field_of_array_queue_type_is_mapped() {
+    #[derive(FuncMap, Debug)]
+    struct Test<T>(ArrayQueue<T>);
+
+    let src = Test(array_queue(4, [1, 2, 3]));
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(dst.0.capacity(), 4);
+    assert_eq!(dst.0.into_iter().collect::<Vec<_>>(), [10, 20, 30]);
+}
+
+#[test]
+fn field_of_array_queue_type_is_try_mapped() {
+    #[derive(TryFuncMap, Debug)]
+    struct Test<T>(ArrayQueue<T>);
+
+    let src = Test(array_queue(4, [1, 2, 3]));
+    let dst: Result<_, ()> = src.try_func_map(|v| Ok(v * 10));
+    let dst = dst.unwrap();
+
+    assert_eq!(dst.0.capacity(), 4);
+    assert_eq!(dst.0.into_iter().collect::<Vec<_>>(), [10, 20, 30]);
+}
+
+#[test]
+fn field_of_array_queue_type_fails_with_first_error() {
+    #[derive(TryFuncMap, Debug)]
+    struct Test<T>(ArrayQueue<T>);
+
+    let src = Test(array_queue(4, [1, 2, 3]));
+    let dst: Result<Test<i32>, _> = src.try_func_map(Err);
+
+    assert_eq!(dst.unwrap_err(), 1);
+}
+
+#[test]
+fn field_of_seg_queue_type_is_mapped() {
+    #[derive(FuncMap, Debug)]
+    struct Test<T>(SegQueue<T>);
+
+    let src = Test(seg_queue([1, 2, 3]));
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(dst.0.into_iter().collect::<Vec<_>>(), [10, 20, 30]);
+}
+
+#[test]
+fn field_of_seg_queue_type_is_try_mapped() {
+    #[derive(TryFuncMap, Debug)]
+    struct Test<T>(SegQueue<T>);
+
+    let src = Test(seg_queue([1, 2, 3]));
+    let dst: Result<_, ()> = src.try_func_map(|v| Ok(v * 10));
+
+    assert_eq!(dst.unwrap().0.into_iter().collect::<Vec<_>>(), [10, 20, 30]);
+}
+
+#[test]
+fn field_of_seg_queue_type_fails_with_first_error() {
+    #[derive(TryFuncMap, Debug)]
+    struct Test<T>(SegQueue<T>);
+
+    let src = Test(seg_queue([1, 2, 3]));
+    let dst: Result<Test<i32>, _> = src.try_func_map(Err);
+
+    assert_eq!(dst.unwrap_err(), 1);
+}
+
+fn array_queue<T, const N: usize>(capacity: usize, values: [T; N]) -> ArrayQueue<T> {
+    let queue = ArrayQueue::new(capacity);
+
+    for value in values {
+        queue.push(value).ok().unwrap();
+    }
+
+    queue
+}
+
+fn seg_queue<T, const N: usize>(values: [T; N]) -> SegQueue<T> {
+    let queue = SegQueue::new();
+
+    for value in values {
+        queue.push(value);
+    }
+
+    queue
+}
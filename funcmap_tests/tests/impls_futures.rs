@@ -0,0 +1,90 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use futures::executor::block_on;
+use futures::stream::{self, Empty, Iter, Pending, StreamExt};
+use futures::FutureExt;
+use std::vec;
+
+#[test]
+fn field_of_stream_iter_type_is_mapped() {
+    #[derive(FuncMap, Debug)]
+    struct Test<T>(Iter<vec::IntoIter<T>>);
+
+    let src = Test(stream::iter(vec![1, 2, 3]));
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(block_on(dst.0.collect::<Vec<_>>()), [10, 20, 30]);
+}
+
+#[test]
+fn field_of_stream_iter_type_is_try_mapped() {
+    #[derive(TryFuncMap, Debug)]
+    struct Test<T>(Iter<vec::IntoIter<T>>);
+
+    let src = Test(stream::iter(vec!["1", "2", "3"]));
+    let dst = src.try_func_map(|v| v.parse::<i32>()).unwrap();
+
+    assert_eq!(block_on(dst.0.collect::<Vec<_>>()), [1, 2, 3]);
+}
+
+#[test]
+fn field_of_stream_iter_type_fails_with_first_error() {
+    #[derive(TryFuncMap, Debug)]
+    struct Test<T>(Iter<vec::IntoIter<T>>);
+
+    let src = Test(stream::iter(vec!["1", "x", "y"]));
+    let dst = src.try_func_map(|v| v.parse::<i32>().map_err(|_| v));
+
+    assert_eq!(dst.unwrap_err(), "x");
+}
+
+#[test]
+fn field_of_partially_consumed_stream_iter_type_is_mapped() {
+    #[derive(FuncMap, Debug)]
+    struct Test<T>(Iter<vec::IntoIter<T>>);
+
+    let mut src = Test(stream::iter(vec![1, 2, 3]));
+    assert_eq!(block_on(src.0.next()), Some(1));
+
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(block_on(dst.0.collect::<Vec<_>>()), [20, 30]);
+}
+
+#[test]
+fn field_of_stream_empty_type_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug)]
+    struct Test<T>(Empty<T>);
+
+    let src = Test(stream::empty::<T1>());
+    let dst: Test<T2> = src.func_map(|_| T2);
+
+    assert_eq!(block_on(dst.0.collect::<Vec<_>>()), []);
+
+    let src = Test(stream::empty::<T1>());
+    let dst: Result<Test<T2>, ()> = src.try_func_map(|_| Ok(T2));
+
+    assert_eq!(block_on(dst.unwrap().0.collect::<Vec<_>>()), []);
+}
+
+#[test]
+fn field_of_stream_pending_type_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug)]
+    struct Test<T>(Pending<T>);
+
+    let src = Test(stream::pending::<T1>());
+    let mut dst: Test<T2> = src.func_map(|_| T2);
+
+    assert!(dst.0.next().now_or_never().is_none());
+
+    let src = Test(stream::pending::<T1>());
+    let dst: Result<Test<T2>, ()> = src.try_func_map(|_| Ok(T2));
+
+    assert!(dst.is_ok());
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
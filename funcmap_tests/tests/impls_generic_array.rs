@@ -0,0 +1,48 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use generic_array::typenum::U3;
+use generic_array::GenericArray;
+
+#[test]
+fn field_of_generic_array_type_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(GenericArray<T, U3>);
+
+    let src = Test(GenericArray::from([T1, T1, T1]));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(GenericArray::from([T2, T2, T2])));
+}
+
+#[test]
+fn field_of_generic_array_type_is_try_mapped() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(GenericArray<T, U3>);
+
+    let src = Test(GenericArray::from([T1, T1, T1]));
+    let dst: Result<_, ()> = src.try_func_map(|_| Ok(T2));
+
+    assert_eq!(dst, Ok(Test(GenericArray::from([T2, T2, T2]))));
+}
+
+#[test]
+fn field_of_generic_array_type_fails_with_first_error() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(GenericArray<T, U3>);
+
+    let src = Test(GenericArray::from([1, 2, 3]));
+    let mut calls = 0;
+    let dst: Result<Test<T2>, _> = src.try_func_map(|value| {
+        calls += 1;
+        Err(value)
+    });
+
+    assert_eq!(dst, Err(1));
+    assert_eq!(calls, 1);
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct T1;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct T2;
@@ -0,0 +1,42 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use heapless::Vec;
+
+#[test]
+fn field_of_heapless_vec_type_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(Vec<T, 4>);
+
+    let src = Test(Vec::from_slice(&[T1, T1]).unwrap());
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Vec::from_slice(&[T2, T2]).unwrap()));
+}
+
+#[test]
+fn field_of_heapless_vec_type_is_try_mapped() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(Vec<T, 4>);
+
+    let src = Test(Vec::from_slice(&[T1, T1]).unwrap());
+    let dst: Result<_, ()> = src.try_func_map(|_| Ok(T2));
+
+    assert_eq!(dst, Ok(Test(Vec::from_slice(&[T2, T2]).unwrap())));
+}
+
+#[test]
+fn field_of_heapless_vec_type_fails_with_first_error() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(Vec<T, 4>);
+
+    let src = Test(Vec::from_slice(&[1, 2, 3]).unwrap());
+    let dst: Result<Test<T2>, _> = src.try_func_map(Err);
+
+    assert_eq!(dst, Err(1));
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct T1;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct T2;
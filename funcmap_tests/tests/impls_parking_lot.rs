@@ -0,0 +1,66 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use parking_lot::{Mutex, RwLock};
+
+#[test]
+fn field_of_mutex_type_is_mapped() {
+    #[derive(FuncMap, Debug)]
+    struct Test<T>(Mutex<T>);
+
+    let src = Test(Mutex::new(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst.0.into_inner(), T2);
+}
+
+#[test]
+fn field_of_mutex_type_is_try_mapped() {
+    #[derive(TryFuncMap, Debug)]
+    struct Test<T>(Mutex<T>);
+
+    let src = Test(Mutex::new(T1));
+    let dst: Result<_, ()> = src.try_func_map(|_| Ok(T2));
+
+    assert_eq!(dst.unwrap().0.into_inner(), T2);
+
+    let src = Test(Mutex::new(T1));
+    let dst: Result<Test<T2>, _> = src.try_func_map(|_| Err(TestError));
+
+    assert_eq!(dst.unwrap_err(), TestError);
+}
+
+#[test]
+fn field_of_rw_lock_type_is_mapped() {
+    #[derive(FuncMap, Debug)]
+    struct Test<T>(RwLock<T>);
+
+    let src = Test(RwLock::new(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst.0.into_inner(), T2);
+}
+
+#[test]
+fn field_of_rw_lock_type_is_try_mapped() {
+    #[derive(TryFuncMap, Debug)]
+    struct Test<T>(RwLock<T>);
+
+    let src = Test(RwLock::new(T1));
+    let dst: Result<_, ()> = src.try_func_map(|_| Ok(T2));
+
+    assert_eq!(dst.unwrap().0.into_inner(), T2);
+
+    let src = Test(RwLock::new(T1));
+    let dst: Result<Test<T2>, _> = src.try_func_map(|_| Err(TestError));
+
+    assert_eq!(dst.unwrap_err(), TestError);
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
+
+#[derive(Debug, PartialEq)]
+struct TestError;
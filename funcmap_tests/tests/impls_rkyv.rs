@@ -0,0 +1,118 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use rkyv::{Archive, Archived, Serialize};
+
+#[derive(Archive, Serialize)]
+#[archive(check_bytes)]
+struct Record {
+    values: Vec<u32>,
+    limit: Option<u32>,
+    origin: Box<u32>,
+}
+
+fn archive(record: &Record) -> rkyv::AlignedVec {
+    rkyv::to_bytes::<_, 256>(record).unwrap()
+}
+
+fn access(bytes: &[u8]) -> &Archived<Record> {
+    rkyv::check_archived_root::<Record>(bytes).unwrap()
+}
+
+#[test]
+fn archived_vec_is_mapped() {
+    let bytes = archive(&Record {
+        values: vec![1, 2],
+        limit: None,
+        origin: Box::new(0),
+    });
+
+    let dst = access(&bytes).values.func_map(|v| u64::from(*v) * 10);
+
+    assert_eq!(dst, [10, 20]);
+}
+
+#[test]
+fn archived_option_is_mapped() {
+    let bytes = archive(&Record {
+        values: vec![],
+        limit: Some(1),
+        origin: Box::new(0),
+    });
+
+    let dst = access(&bytes).limit.func_map(|v| u64::from(*v) * 10);
+
+    assert_eq!(dst, Some(10));
+}
+
+#[test]
+fn archived_box_is_mapped() {
+    let bytes = archive(&Record {
+        values: vec![],
+        limit: None,
+        origin: Box::new(1),
+    });
+
+    let dst = access(&bytes).origin.func_map(|v| u64::from(*v) * 10);
+
+    assert_eq!(dst, Box::new(10));
+}
+
+#[test]
+fn archived_types_are_try_mapped() {
+    let bytes = archive(&Record {
+        values: vec![1, 2],
+        limit: Some(3),
+        origin: Box::new(4),
+    });
+    let archived = access(&bytes);
+
+    let values: Result<_, ()> = archived.values.try_func_map(|v| Ok(*v));
+    let limit: Result<_, ()> = archived.limit.try_func_map(|v| Ok(*v));
+    let origin: Result<_, ()> = archived.origin.try_func_map(|v| Ok(*v));
+
+    assert_eq!(values, Ok(vec![1, 2]));
+    assert_eq!(limit, Ok(Some(3)));
+    assert_eq!(origin, Ok(Box::new(4)));
+}
+
+#[test]
+fn archived_vec_fails_with_first_error() {
+    let bytes = archive(&Record {
+        values: vec![1, 2, 3],
+        limit: None,
+        origin: Box::new(0),
+    });
+
+    let dst: Result<Vec<()>, _> = access(&bytes).values.try_func_map(|v| Err(*v));
+
+    assert_eq!(dst, Err(1));
+}
+
+#[test]
+fn archived_data_is_mapped_in_stages() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Working<T> {
+        values: Vec<T>,
+        limit: Option<T>,
+    }
+
+    let bytes = archive(&Record {
+        values: vec![1, 2],
+        limit: Some(3),
+        origin: Box::new(0),
+    });
+    let archived = access(&bytes);
+
+    let working = Working {
+        values: archived.values.func_map(|v| *v),
+        limit: archived.limit.func_map(|v| *v),
+    };
+
+    assert_eq!(
+        working.func_map(|v| v * 10),
+        Working {
+            values: vec![10, 20],
+            limit: Some(30),
+        }
+    );
+}
@@ -1,6 +1,6 @@
 extern crate std;
 
-use funcmap::FuncMap;
+use funcmap::{DrainFuncMap, FuncMap, TryFuncMapReserve, TypeParam};
 
 use std::{
     collections::{hash_map, hash_set, HashMap, HashSet},
@@ -31,6 +31,79 @@ fn field_of_hash_map_type_is_mapped_over_value() {
     assert_eq!(dst, Test([((), T2)].into()));
 }
 
+#[test]
+fn hash_map_is_drained_and_mapped_over_key_keeping_its_capacity() {
+    let mut src = HashMap::with_capacity(16);
+    src.extend([(1, 'a'), (2, 'b')]);
+
+    let dst = DrainFuncMap::<_, _, TypeParam<0>>::drain_func_map(&mut src, |k| -k);
+
+    assert_eq!(dst, HashMap::from([(-1, 'a'), (-2, 'b')]));
+    assert!(src.is_empty());
+    assert!(src.capacity() >= 16);
+}
+
+#[test]
+fn hash_map_is_drained_and_mapped_over_value() {
+    let mut src = HashMap::from([('a', 1), ('b', 2)]);
+
+    let dst = DrainFuncMap::<_, _, TypeParam<1>>::drain_func_map(&mut src, |v| v * 10);
+
+    assert_eq!(dst, HashMap::from([('a', 10), ('b', 20)]));
+    assert!(src.is_empty());
+}
+
+#[test]
+fn hash_map_is_mapped_over_key_into_reserved_output() {
+    let src = HashMap::from([(1, 'a'), (2, 'b')]);
+    let dst =
+        TryFuncMapReserve::<_, _, TypeParam<0>>::try_func_map_reserve(src, |k| Ok::<_, ()>(-k))
+            .unwrap();
+
+    assert_eq!(dst, HashMap::from([(-1, 'a'), (-2, 'b')]));
+    assert!(dst.capacity() >= 2);
+}
+
+#[test]
+fn hash_map_mapping_over_value_with_reserve_fails_on_error() {
+    let src = HashMap::from([('a', 1), ('b', 2)]);
+    let dst = TryFuncMapReserve::<_, _, TypeParam<1>>::try_func_map_reserve(src, |v| {
+        if v < 2 {
+            Ok(v * 10)
+        } else {
+            Err(v)
+        }
+    });
+
+    assert_eq!(dst, Err(2));
+}
+
+#[test]
+fn field_of_hash_map_type_is_mapped_over_key_and_value_in_single_pass() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(std::collections::HashMap<T, Vec<T>>)
+    where
+        T: Eq + Hash;
+
+    let src = Test([(T1, vec![T1, T1])].into());
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test([(T2, vec![T2, T2])].into()));
+}
+
+#[test]
+fn field_of_unqualified_hash_map_type_is_mapped_over_key_and_value() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(HashMap<T, Vec<T>>)
+    where
+        T: Eq + Hash;
+
+    let src = Test([(T1, vec![T1, T1])].into());
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test([(T2, vec![T2, T2])].into()));
+}
+
 #[test]
 fn field_of_hash_map_into_iter_type_is_mapped_over_key() {
     #[derive(FuncMap, Debug)]
@@ -66,6 +139,18 @@ fn field_of_hash_set_type_is_mapped() {
     assert_eq!(dst, Test([T2].into()));
 }
 
+#[test]
+fn hash_set_is_drained_and_mapped_keeping_its_capacity() {
+    let mut src = HashSet::with_capacity(16);
+    src.extend([1, 2]);
+
+    let dst = src.drain_func_map(|v| v * 10);
+
+    assert_eq!(dst, HashSet::from([10, 20]));
+    assert!(src.is_empty());
+    assert!(src.capacity() >= 16);
+}
+
 #[test]
 fn field_of_hash_set_into_iter_type_is_mapped() {
     #[derive(FuncMap, Debug)]
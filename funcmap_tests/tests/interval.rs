@@ -0,0 +1,100 @@
+use funcmap::interval::Interval;
+use funcmap::{FuncMap, TryFuncMap};
+
+use std::collections::BTreeMap;
+use std::ops::{Bound, RangeBounds};
+
+#[test]
+fn bounds_are_mapped_in_order() {
+    let src = Interval(Bound::Included(1), Bound::Excluded(2));
+    let mut values = Vec::new();
+    let dst = src.func_map(|v| {
+        values.push(v);
+        T1(v)
+    });
+
+    assert_eq!(
+        dst,
+        Interval(Bound::Included(T1(1)), Bound::Excluded(T1(2)))
+    );
+    assert_eq!(values, [1, 2]);
+}
+
+#[test]
+fn bounds_are_try_mapped_until_first_error() {
+    let src = Interval(Bound::Excluded(-1), Bound::Included(-2));
+    let dst: Result<Interval<i32>, i32> = src.try_func_map(|v| if v > 0 { Ok(v) } else { Err(v) });
+
+    assert_eq!(dst, Err(-1));
+}
+
+#[test]
+fn unbounded_bounds_are_kept() {
+    let src: Interval<i32> = Interval(Bound::Unbounded, Bound::Unbounded);
+    let dst = src.func_map(T1);
+
+    assert_eq!(dst, Interval(Bound::Unbounded, Bound::Unbounded));
+}
+
+#[test]
+fn interval_is_mapped_within_derived_type() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        interval: Interval<T>,
+    }
+
+    let src = Test {
+        value: 1,
+        interval: Interval::from_range_bounds(&(2..=3)),
+    };
+    let dst = src.func_map(T1);
+
+    assert_eq!(
+        dst,
+        Test {
+            value: T1(1),
+            interval: Interval(Bound::Included(T1(2)), Bound::Included(T1(3))),
+        }
+    );
+}
+
+#[test]
+fn interval_is_created_from_range_bounds() {
+    assert_eq!(
+        Interval::from_range_bounds(&(1..2)),
+        Interval(Bound::Included(1), Bound::Excluded(2))
+    );
+    assert_eq!(
+        Interval::<i32>::from_range_bounds(&(..)),
+        Interval(Bound::Unbounded, Bound::Unbounded)
+    );
+}
+
+#[test]
+fn interval_can_be_used_as_range() {
+    let interval = Interval::new(Bound::Excluded(1), Bound::Included(3));
+    let map: BTreeMap<_, _> = (0..5).map(|v| (v, v * 10)).collect();
+
+    assert!(!interval.contains(&1));
+    assert!(interval.contains(&3));
+    assert_eq!(
+        map.range(interval).map(|(_, v)| *v).collect::<Vec<_>>(),
+        [20, 30]
+    );
+}
+
+#[test]
+fn interval_is_converted_from_and_into_bounds() {
+    let bounds = (Bound::Included(1), Bound::Unbounded);
+    let interval = Interval::from(bounds);
+
+    assert_eq!(
+        interval.as_ref(),
+        Interval(Bound::Included(&1), Bound::Unbounded)
+    );
+    assert_eq!(<(Bound<i32>, Bound<i32>)>::from(interval), bounds);
+}
+
+#[derive(Debug, PartialEq)]
+struct T1(i32);
@@ -0,0 +1,54 @@
+use funcmap::{FuncMap, FuncMapInto, TypeParam};
+
+#[test]
+fn field_of_generic_param_type_is_mapped_into_target_type() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, i32);
+
+    let src = Test(1_u8, 42);
+    let dst = src.func_map_into::<u64>();
+
+    assert_eq!(dst, Test(1_u64, 42));
+}
+
+#[test]
+fn nested_fields_are_mapped_into_target_type() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<Option<T>>,
+    }
+
+    let src = Test {
+        value: "a",
+        values: vec![Some("b"), None],
+    };
+    let dst = src.func_map_into::<String>();
+
+    assert_eq!(
+        dst,
+        Test {
+            value: "a".to_owned(),
+            values: vec![Some("b".to_owned()), None],
+        }
+    );
+}
+
+#[test]
+fn type_param_to_map_into_target_type_can_be_specified() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(1_u8, 2_u8);
+    let dst = FuncMapInto::<_, TypeParam<1>>::func_map_into::<u64>(src);
+
+    assert_eq!(dst, Test(1_u8, 2_u64));
+}
+
+#[test]
+fn foreign_type_is_mapped_into_target_type() {
+    let src = vec![1_u8, 2];
+    let dst = src.func_map_into::<u64>();
+
+    assert_eq!(dst, vec![1_u64, 2]);
+}
@@ -0,0 +1,136 @@
+use funcmap::{FuncMap, JoinParams, TypeParam};
+
+#[test]
+fn both_type_params_are_mapped_with_the_same_closure() {
+    #[derive(JoinParams, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = src.join_params(|_| T2);
+
+    assert_eq!(dst, Test(T2, T2));
+}
+
+#[test]
+fn type_params_are_mapped_within_nested_types() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Inner<S, T>(S, T);
+
+    #[derive(JoinParams, Debug, PartialEq)]
+    struct Test<S, T> {
+        values: Vec<(S, T)>,
+        inner: Inner<T, S>,
+        option: Option<T>,
+    }
+
+    let src = Test {
+        values: vec![(1, 2), (3, 4)],
+        inner: Inner(5, 6),
+        option: Some(7),
+    };
+    let dst = src.join_params(|v| v * 10);
+
+    assert_eq!(
+        dst,
+        Test {
+            values: vec![(10, 20), (30, 40)],
+            inner: Inner(50, 60),
+            option: Some(70),
+        }
+    );
+}
+
+#[test]
+fn closure_is_called_in_order_of_fields() {
+    #[derive(JoinParams, Debug, PartialEq)]
+    struct Test<S, T> {
+        first: T,
+        second: S,
+        third: [T; 2],
+    }
+
+    let src = Test {
+        first: 1,
+        second: 2,
+        third: [3, 4],
+    };
+    let mut input = Vec::new();
+    let _ = src.join_params(|v| input.push(v));
+
+    assert_eq!(input, [1, 2, 3, 4]);
+}
+
+#[test]
+fn enum_variants_are_mapped() {
+    #[derive(JoinParams, Debug, PartialEq)]
+    enum Test<S, T> {
+        Unit,
+        Left(S),
+        Right { value: T },
+    }
+
+    assert_eq!(Test::<T1, T1>::Unit.join_params(|_| T2), Test::Unit);
+    assert_eq!(Test::<T1, T1>::Left(T1).join_params(|_| T2), Test::Left(T2));
+    assert_eq!(
+        Test::<T1, T1>::Right { value: T1 }.join_params(|_| T2),
+        Test::Right { value: T2 }
+    );
+}
+
+#[test]
+fn other_params_are_kept() {
+    #[derive(JoinParams, Debug, PartialEq)]
+    #[funcmap(params(S, T))]
+    struct Test<'a, S, T, X, const N: usize>(&'a str, S, [T; N], X)
+    where
+        X: Clone;
+
+    let src = Test("a", 1, [2, 3], 'x');
+    let dst = src.join_params(|v| v + 1);
+
+    assert_eq!(dst, Test("a", 2, [3, 4], 'x'));
+}
+
+#[test]
+fn params_can_be_configured() {
+    #[derive(JoinParams, Debug, PartialEq)]
+    #[funcmap(params(S, U))]
+    struct Test<S, T, U>(S, T, U);
+
+    let src = Test(1, T1, 2);
+    let dst = JoinParams::<_, _, TypeParam<0>, TypeParam<2>>::join_params(src, |v| v * 2);
+
+    assert_eq!(dst, Test(2, T1, 4));
+}
+
+#[test]
+fn bounds_of_both_params_are_joined() {
+    #[derive(JoinParams, Debug, PartialEq)]
+    struct Test<S: Copy, T: Default>(S, T);
+
+    let src = Test(1, 2);
+    let dst = src.join_params(|v| v + 1);
+
+    assert_eq!(dst, Test(2, 3));
+}
+
+#[test]
+fn join_params_is_equivalent_to_mapping_both_params() {
+    #[derive(FuncMap, JoinParams, Clone, Debug, PartialEq)]
+    struct Test<S, T>(S, Vec<T>, Option<S>);
+
+    let src = Test(1, vec![2, 3], Some(4));
+
+    let joined = src.clone().join_params(|v| v + 1);
+    let mapped = src
+        .func_map_over::<TypeParam<0>, _>(|v| v + 1)
+        .func_map_over::<TypeParam<1>, _>(|v| v + 1);
+
+    assert_eq!(joined, mapped);
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
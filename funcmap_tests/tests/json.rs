@@ -0,0 +1,106 @@
+use funcmap::{DynLeaf, FuncMap, TryFuncMap};
+
+use serde_json::{json, Value};
+
+#[test]
+fn scalar_values_become_leaves() {
+    let tree = DynLeaf::from_value(json!([null, true, 1, "a", [], {}]));
+
+    assert_eq!(
+        tree,
+        DynLeaf::Array(vec![
+            DynLeaf::Leaf(Value::Null),
+            DynLeaf::Leaf(json!(true)),
+            DynLeaf::Leaf(json!(1)),
+            DynLeaf::Leaf(json!("a")),
+            DynLeaf::Array(Vec::new()),
+            DynLeaf::Object(Vec::new()),
+        ])
+    );
+}
+
+#[test]
+fn value_is_restored_after_roundtrip() {
+    let value = json!({ "a": [1, { "b": null }], "c": "d", "e": {} });
+
+    assert_eq!(DynLeaf::from(value.clone()).into_value(), value);
+}
+
+#[test]
+fn leaves_are_mapped_in_document_order() {
+    let src = DynLeaf::from_value(json!({ "a": [1, 2], "b": { "c": 3 }, "d": 4 }));
+    let mut values = Vec::new();
+    let dst = src.func_map(|v| {
+        values.push(v.clone());
+        T1(v)
+    });
+
+    assert_eq!(
+        dst,
+        DynLeaf::Object(vec![
+            (
+                String::from("a"),
+                DynLeaf::Array(vec![
+                    DynLeaf::Leaf(T1(json!(1))),
+                    DynLeaf::Leaf(T1(json!(2)))
+                ])
+            ),
+            (
+                String::from("b"),
+                DynLeaf::Object(vec![(String::from("c"), DynLeaf::Leaf(T1(json!(3))))])
+            ),
+            (String::from("d"), DynLeaf::Leaf(T1(json!(4)))),
+        ])
+    );
+    assert_eq!(values, [json!(1), json!(2), json!(3), json!(4)]);
+}
+
+#[test]
+fn leaves_are_converted_into_value() {
+    let src = DynLeaf::from_value(json!({ "a": [1, "b"] }));
+    let dst = src.func_map(|v| v.to_string());
+
+    assert_eq!(Value::from(dst), json!({ "a": ["1", "\"b\""] }));
+}
+
+#[test]
+fn leaves_are_try_mapped_until_first_error() {
+    let src = DynLeaf::from_value(json!([1, [-2, 3], -4]));
+    let mut values = Vec::new();
+    let dst: Result<DynLeaf<u64>, Value> = src.try_func_map(|v| {
+        values.push(v.clone());
+        v.as_u64().ok_or(v)
+    });
+
+    assert_eq!(dst, Err(json!(-2)));
+    assert_eq!(values, [json!(1), json!(-2)]);
+}
+
+#[test]
+fn leaves_are_mapped_within_derived_type() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        extra: DynLeaf<T>,
+    }
+
+    let src = Test {
+        value: json!(1),
+        extra: DynLeaf::from_value(json!({ "a": [2, 3] })),
+    };
+    let dst: Result<Test<u64>, Value> = src.try_func_map(|v| v.as_u64().ok_or(v));
+
+    assert_eq!(
+        dst,
+        Ok(Test {
+            value: 1,
+            extra: DynLeaf::Object(vec![(
+                String::from("a"),
+                DynLeaf::Array(vec![DynLeaf::Leaf(2), DynLeaf::Leaf(3)])
+            )]),
+        })
+    );
+}
+
+#[derive(Debug, PartialEq)]
+struct T1(Value);
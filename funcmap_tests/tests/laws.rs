@@ -0,0 +1,693 @@
+//! Property-based tests of the functor laws for all provided implementations
+//! and a corpus of derived implementations
+//!
+//! For every type, this checks that
+//! - mapping with the identity function doesn't change the value (identity
+//!   law),
+//! - mapping with `f` and then with `g` is the same as mapping with the
+//!   composition of `f` and `g` (composition law),
+//! - mapping invokes the closure exactly once for every value, in traversal
+//!   order,
+//! - fallible mapping fails with the first error in traversal order, invoking
+//!   the closure exactly for the values up to and including the failing one.
+//!
+//! By default, every property is checked for a small number of random inputs.
+//! Enable the `heavy` feature to check many more, e.g. via
+//! `cargo test -p funcmap_tests --features heavy --test laws`.
+
+use funcmap::interval::Interval;
+use funcmap::{FuncMap, TryFuncMap, TypeParam};
+use proptest::collection;
+use proptest::prelude::*;
+
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::cmp::Reverse;
+use std::collections::{
+    binary_heap, btree_map, btree_set, linked_list, vec_deque, BTreeMap, BTreeSet, BinaryHeap,
+    HashMap, HashSet, LinkedList, VecDeque,
+};
+use std::marker::PhantomData;
+use std::num::Wrapping;
+use std::ops::{Bound, ControlFlow, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+use std::task::Poll;
+use std::{option, result, vec};
+
+fn config() -> ProptestConfig {
+    ProptestConfig {
+        cases: if cfg!(feature = "heavy") { 4096 } else { 32 },
+        ..ProptestConfig::default()
+    }
+}
+
+/// Injective function used as the first mapping
+fn f(value: i32) -> i64 {
+    i64::from(value) * 2 + 1
+}
+
+/// Injective function used as the second mapping
+fn g(value: i64) -> String {
+    value.to_string()
+}
+
+/// Fallible function failing for multiples of 3
+fn h(value: i32) -> Result<i32, i32> {
+    if value % 3 == 0 {
+        Err(value)
+    } else {
+        Ok(value)
+    }
+}
+
+fn small() -> impl Strategy<Value = i32> {
+    -100..100
+}
+
+fn values() -> impl Strategy<Value = Vec<i32>> {
+    collection::vec(small(), 0..8)
+}
+
+/// Generates a property test for a type
+///
+/// - `$strategy` generates a seed, from which `$make` creates the value
+/// - `$norm` turns a (mapped) value into something comparable
+/// - `$marker` is the marker type of the type parameter to map over
+/// - `$order` returns the values of the type parameter in traversal order, or
+///   is `unordered` if there is no deterministic order, in which case only
+///   the error itself is checked, not that it is the first one
+macro_rules! laws {
+    ($name:ident, $strategy:expr, $make:expr, $norm:expr, $marker:ty, $($order:tt)*) => {
+        proptest! {
+            #![proptest_config(config())]
+
+            #[test]
+            fn $name(seed in $strategy) {
+                let make = $make;
+
+                prop_assert_eq!(
+                    ($norm)(make(seed.clone()).func_map_over::<$marker, _>(|v: i32| v)),
+                    ($norm)(make(seed.clone()))
+                );
+
+                prop_assert_eq!(
+                    ($norm)(make(seed.clone()).func_map_over::<$marker, _>(f).func_map_over::<$marker, _>(g)),
+                    ($norm)(make(seed.clone()).func_map_over::<$marker, _>(|v| g(f(v))))
+                );
+
+                laws!(@order seed, make, $norm, $marker, $($order)*);
+            }
+        }
+    };
+
+    (@order $seed:ident, $make:ident, $norm:expr, $marker:ty, unordered) => {
+        let mut calls = 0;
+        let result = $make($seed.clone()).try_func_map_over::<$marker, _, _>(|v| {
+            calls += 1;
+            h(v)
+        });
+        let len = ($norm)($make($seed.clone())).into_iter().count();
+
+        prop_assert!(calls <= len);
+        match result {
+            Ok(mapped) => prop_assert_eq!(($norm)(mapped), ($norm)($make($seed))),
+            Err(err) => prop_assert!(h(err).is_err()),
+        }
+    };
+
+    (@order $seed:ident, $make:ident, $norm:expr, $marker:ty, $order:expr) => {
+        let order: Vec<i32> = ($order)($make($seed.clone()));
+        let first_err = order.iter().position(|&v| h(v).is_err());
+
+        let mut visited = Vec::new();
+        let _ = $make($seed.clone()).func_map_over::<$marker, _>(|v| {
+            visited.push(v);
+            v
+        });
+
+        prop_assert_eq!(&visited, &order);
+
+        let mut calls = 0;
+        let result = $make($seed.clone()).try_func_map_over::<$marker, _, _>(|v| {
+            calls += 1;
+            h(v)
+        });
+
+        match first_err {
+            Some(idx) => {
+                prop_assert_eq!(result.map(|_| ()), Err(order[idx]));
+                prop_assert_eq!(calls, idx + 1);
+            }
+            None => {
+                prop_assert_eq!(result.map($norm), Ok(($norm)($make($seed))));
+                prop_assert_eq!(calls, order.len());
+            }
+        }
+    };
+}
+
+fn any_bound() -> impl Strategy<Value = Bound<i32>> {
+    prop_oneof![
+        small().prop_map(Bound::Included),
+        small().prop_map(Bound::Excluded),
+        Just(Bound::Unbounded)
+    ]
+}
+
+fn bound_values(bound: Bound<i32>) -> Vec<i32> {
+    match bound {
+        Bound::Included(v) | Bound::Excluded(v) => vec![v],
+        Bound::Unbounded => vec![],
+    }
+}
+
+fn id<T>(value: T) -> T {
+    value
+}
+
+fn collect<T>(iter: impl Iterator<Item = T>) -> Vec<T> {
+    iter.collect()
+}
+
+fn sorted<T: Ord>(iter: impl Iterator<Item = T>) -> Vec<T> {
+    let mut values: Vec<_> = iter.collect();
+    values.sort();
+    values
+}
+
+fn to_btree_map<K: Ord, V>(map: HashMap<K, V>) -> BTreeMap<K, V> {
+    map.into_iter().collect()
+}
+
+fn to_btree_set<T: Ord>(set: HashSet<T>) -> BTreeSet<T> {
+    set.into_iter().collect()
+}
+
+// core
+
+laws!(
+    array,
+    proptest::array::uniform3(small()),
+    id,
+    id,
+    TypeParam<0>,
+    |v: [i32; 3]| v.to_vec()
+);
+
+laws!(bound, any_bound(), id, id, TypeParam<0>, bound_values);
+
+laws!(
+    cell,
+    small(),
+    Cell::new,
+    Cell::into_inner,
+    TypeParam<0>,
+    |v: Cell<i32>| vec![v.get()]
+);
+
+laws!(
+    control_flow_break,
+    any::<Result<i32, i32>>().prop_map(|v| v.map(|v| v.rem_euclid(200) - 100)),
+    |v: Result<i32, i32>| match v {
+        Ok(v) => ControlFlow::Break(v),
+        Err(v) => ControlFlow::<i32, i32>::Continue(v),
+    },
+    id,
+    TypeParam<0>,
+    |v: ControlFlow<i32, i32>| match v {
+        ControlFlow::Break(v) => vec![v],
+        ControlFlow::Continue(_) => vec![],
+    }
+);
+
+laws!(
+    control_flow_continue,
+    any::<Result<i32, i32>>().prop_map(|v| v.map(|v| v.rem_euclid(200) - 100)),
+    |v: Result<i32, i32>| match v {
+        Ok(v) => ControlFlow::<i32, i32>::Break(v),
+        Err(v) => ControlFlow::Continue(v),
+    },
+    id,
+    TypeParam<1>,
+    |v: ControlFlow<i32, i32>| match v {
+        ControlFlow::Break(_) => vec![],
+        ControlFlow::Continue(v) => vec![v],
+    }
+);
+
+laws!(
+    interval,
+    (any_bound(), any_bound()),
+    |(start, end)| Interval(start, end),
+    id,
+    TypeParam<0>,
+    |v: Interval<i32>| bound_values(v.0)
+        .into_iter()
+        .chain(bound_values(v.1))
+        .collect()
+);
+
+laws!(
+    option,
+    proptest::option::of(small()),
+    id,
+    id,
+    TypeParam<0>,
+    |v: Option<i32>| v.into_iter().collect()
+);
+
+laws!(
+    option_into_iter,
+    proptest::option::of(small()),
+    |v: Option<i32>| v.into_iter(),
+    collect,
+    TypeParam<0>,
+    |v: option::IntoIter<i32>| v.collect()
+);
+
+laws!(
+    phantom_data,
+    Just(()),
+    |()| PhantomData::<i32>,
+    id,
+    TypeParam<0>,
+    |_| vec![]
+);
+
+laws!(
+    poll,
+    proptest::option::of(small()),
+    |v: Option<i32>| v.map_or(Poll::Pending, Poll::Ready),
+    id,
+    TypeParam<0>,
+    |v: Poll<i32>| match v {
+        Poll::Ready(v) => vec![v],
+        Poll::Pending => vec![],
+    }
+);
+
+laws!(
+    range,
+    (small(), small()),
+    |(start, end)| Range { start, end },
+    id,
+    TypeParam<0>,
+    |v: Range<i32>| vec![v.start, v.end]
+);
+
+laws!(
+    range_from,
+    small(),
+    |start| RangeFrom { start },
+    id,
+    TypeParam<0>,
+    |v: RangeFrom<i32>| vec![v.start]
+);
+
+laws!(
+    range_inclusive,
+    (small(), small()),
+    |(start, end)| RangeInclusive::new(start, end),
+    id,
+    TypeParam<0>,
+    |v: RangeInclusive<i32>| vec![*v.start(), *v.end()]
+);
+
+laws!(
+    range_to,
+    small(),
+    |end| RangeTo { end },
+    id,
+    TypeParam<0>,
+    |v: RangeTo<i32>| vec![v.end]
+);
+
+laws!(
+    range_to_inclusive,
+    small(),
+    |end| RangeToInclusive { end },
+    id,
+    TypeParam<0>,
+    |v: RangeToInclusive<i32>| vec![v.end]
+);
+
+laws!(reverse, small(), Reverse, id, TypeParam<0>, |v: Reverse<
+    i32,
+>| vec![v.0]);
+
+laws!(
+    ref_cell,
+    small(),
+    RefCell::new,
+    RefCell::into_inner,
+    TypeParam<0>,
+    |v: RefCell<i32>| vec![v.into_inner()]
+);
+
+laws!(
+    result_ok,
+    any::<Result<i32, i32>>().prop_map(|v| v.map(|v| v.rem_euclid(200) - 100)),
+    id,
+    id,
+    TypeParam<0>,
+    |v: Result<i32, i32>| v.into_iter().collect()
+);
+
+laws!(
+    result_err,
+    any::<Result<i32, i32>>().prop_map(|v| v.map_err(|v| v.rem_euclid(200) - 100)),
+    id,
+    id,
+    TypeParam<1>,
+    |v: Result<i32, i32>| v.err().into_iter().collect()
+);
+
+laws!(
+    result_into_iter,
+    any::<Result<i32, i32>>().prop_map(|v| v.map(|v| v.rem_euclid(200) - 100)),
+    |v: Result<i32, i32>| v.into_iter(),
+    collect,
+    TypeParam<0>,
+    |v: result::IntoIter<i32>| v.collect()
+);
+
+laws!(
+    unsafe_cell,
+    small(),
+    UnsafeCell::new,
+    UnsafeCell::into_inner,
+    TypeParam<0>,
+    |v: UnsafeCell<i32>| vec![v.into_inner()]
+);
+
+laws!(
+    wrapping,
+    small(),
+    Wrapping,
+    id,
+    TypeParam<0>,
+    |v: Wrapping<i32>| vec![v.0]
+);
+
+// alloc
+
+laws!(
+    binary_heap,
+    values(),
+    BinaryHeap::from,
+    BinaryHeap::into_sorted_vec,
+    TypeParam<0>,
+    BinaryHeap::into_vec
+);
+
+laws!(
+    binary_heap_into_iter,
+    values(),
+    |v: Vec<i32>| BinaryHeap::from(v).into_iter(),
+    sorted,
+    TypeParam<0>,
+    |v: binary_heap::IntoIter<i32>| v.collect()
+);
+
+laws!(
+    boxed,
+    small(),
+    Box::new,
+    id,
+    TypeParam<0>,
+    |v: Box<i32>| vec![*v]
+);
+
+laws!(
+    cow_slice,
+    values(),
+    |v: Vec<i32>| Cow::<[i32]>::Owned(v),
+    Cow::into_owned,
+    TypeParam<0>,
+    Cow::into_owned
+);
+
+laws!(
+    btree_map_key,
+    collection::btree_map(small(), small(), 0..8),
+    id,
+    id,
+    TypeParam<0>,
+    |v: BTreeMap<i32, i32>| v.into_keys().collect()
+);
+
+laws!(
+    btree_map_value,
+    collection::btree_map(small(), small(), 0..8),
+    id,
+    id,
+    TypeParam<1>,
+    |v: BTreeMap<i32, i32>| v.into_values().collect()
+);
+
+laws!(
+    btree_map_into_iter_key,
+    collection::btree_map(small(), small(), 0..8),
+    |v: BTreeMap<i32, i32>| v.into_iter(),
+    collect,
+    TypeParam<0>,
+    |v: btree_map::IntoIter<i32, i32>| v.map(|(k, _)| k).collect()
+);
+
+laws!(
+    btree_map_into_iter_value,
+    collection::btree_map(small(), small(), 0..8),
+    |v: BTreeMap<i32, i32>| v.into_iter(),
+    collect,
+    TypeParam<1>,
+    |v: btree_map::IntoIter<i32, i32>| v.map(|(_, v)| v).collect()
+);
+
+laws!(
+    btree_set,
+    collection::btree_set(small(), 0..8),
+    id,
+    id,
+    TypeParam<0>,
+    |v: BTreeSet<i32>| v.into_iter().collect()
+);
+
+laws!(
+    btree_set_into_iter,
+    collection::btree_set(small(), 0..8),
+    |v: BTreeSet<i32>| v.into_iter(),
+    collect,
+    TypeParam<0>,
+    |v: btree_set::IntoIter<i32>| v.collect()
+);
+
+laws!(
+    linked_list,
+    values(),
+    |v: Vec<i32>| v.into_iter().collect::<LinkedList<_>>(),
+    id,
+    TypeParam<0>,
+    |v: LinkedList<i32>| v.into_iter().collect()
+);
+
+laws!(
+    linked_list_into_iter,
+    values(),
+    |v: Vec<i32>| v.into_iter().collect::<LinkedList<_>>().into_iter(),
+    collect,
+    TypeParam<0>,
+    |v: linked_list::IntoIter<i32>| v.collect()
+);
+
+laws!(vec, values(), id, id, TypeParam<0>, id);
+
+laws!(
+    vec_into_iter,
+    values(),
+    |v: Vec<i32>| v.into_iter(),
+    collect,
+    TypeParam<0>,
+    |v: vec::IntoIter<i32>| v.collect()
+);
+
+laws!(
+    vec_deque,
+    values(),
+    VecDeque::from,
+    id,
+    TypeParam<0>,
+    Vec::from
+);
+
+laws!(
+    vec_deque_into_iter,
+    values(),
+    |v: Vec<i32>| VecDeque::from(v).into_iter(),
+    collect,
+    TypeParam<0>,
+    |v: vec_deque::IntoIter<i32>| v.collect()
+);
+
+// std
+
+laws!(
+    hash_map_key,
+    collection::hash_map(small(), small(), 0..8),
+    id,
+    to_btree_map,
+    TypeParam<0>,
+    unordered
+);
+
+laws!(
+    hash_map_value,
+    collection::hash_map(small(), small(), 0..8),
+    id,
+    to_btree_map,
+    TypeParam<1>,
+    unordered
+);
+
+laws!(
+    hash_map_into_iter_key,
+    collection::hash_map(small(), small(), 0..8),
+    |v: HashMap<i32, i32>| v.into_iter(),
+    sorted,
+    TypeParam<0>,
+    unordered
+);
+
+laws!(
+    hash_map_into_iter_value,
+    collection::hash_map(small(), small(), 0..8),
+    |v: HashMap<i32, i32>| v.into_iter(),
+    sorted,
+    TypeParam<1>,
+    unordered
+);
+
+laws!(
+    hash_set,
+    collection::hash_set(small(), 0..8),
+    id,
+    to_btree_set,
+    TypeParam<0>,
+    unordered
+);
+
+laws!(
+    hash_set_into_iter,
+    collection::hash_set(small(), 0..8),
+    |v: HashSet<i32>| v.into_iter(),
+    sorted,
+    TypeParam<0>,
+    unordered
+);
+
+// derived
+
+#[derive(FuncMap, TryFuncMap, Clone, Debug, PartialEq)]
+struct Nested<T> {
+    value: T,
+    values: Vec<Option<T>>,
+    map: std::collections::BTreeMap<T, Vec<T>>,
+    array: [T; 2],
+    tuple: (T, i32),
+}
+
+laws!(
+    derived_struct,
+    (
+        small(),
+        collection::vec(proptest::option::of(small()), 0..4),
+        collection::btree_map(small(), values(), 0..4),
+        proptest::array::uniform2(small()),
+        small()
+    ),
+    |(value, values, map, array, other)| Nested {
+        value,
+        values,
+        map,
+        array,
+        tuple: (other, other),
+    },
+    id,
+    TypeParam<0>,
+    |v: Nested<i32>| {
+        let mut order = vec![v.value];
+        order.extend(v.values.into_iter().flatten());
+        for (key, values) in v.map {
+            order.push(key);
+            order.extend(values);
+        }
+        order.extend(v.array);
+        order.push(v.tuple.0);
+        order
+    }
+);
+
+#[derive(FuncMap, TryFuncMap, Clone, Debug, PartialEq)]
+enum Either<S, T> {
+    Left(S, Box<T>),
+    Right {
+        values: VecDeque<T>,
+        other: Option<S>,
+    },
+}
+
+laws!(
+    derived_enum,
+    prop_oneof![
+        (small(), small()).prop_map(|(s, t)| Either::Left(s, Box::new(t))),
+        (values(), proptest::option::of(small())).prop_map(|(values, other)| Either::Right {
+            values: values.into(),
+            other
+        }),
+    ],
+    id,
+    id,
+    TypeParam<1>,
+    |v: Either<i32, i32>| match v {
+        Either::Left(_, t) => vec![*t],
+        Either::Right { values, .. } => values.into(),
+    }
+);
+
+#[derive(FuncMap, TryFuncMap, Clone, Debug, PartialEq)]
+struct Combo<T> {
+    ranges: Vec<Range<T>>,
+    interval: Option<Interval<T>>,
+    nested: ([T; 2], (T, Vec<T>)),
+}
+
+laws!(
+    derived_combo,
+    (
+        collection::vec((small(), small()).prop_map(|(start, end)| start..end), 0..4),
+        proptest::option::of(
+            (any_bound(), any_bound()).prop_map(|(start, end)| Interval(start, end))
+        ),
+        (proptest::array::uniform2(small()), (small(), values()))
+    ),
+    |(ranges, interval, nested)| Combo {
+        ranges,
+        interval,
+        nested,
+    },
+    id,
+    TypeParam<0>,
+    |v: Combo<i32>| {
+        let mut order = Vec::new();
+        for range in v.ranges {
+            order.extend([range.start, range.end]);
+        }
+        if let Some(Interval(start, end)) = v.interval {
+            order.extend(bound_values(start));
+            order.extend(bound_values(end));
+        }
+        let (array, (value, values)) = v.nested;
+        order.extend(array);
+        order.push(value);
+        order.extend(values);
+        order
+    }
+);
@@ -0,0 +1,258 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use std::borrow::Cow;
+use std::cell::{Cell, OnceCell};
+use std::marker::PhantomData;
+
+#[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+struct Borrowing<'a, T: 'a> {
+    value: T,
+    name: &'a str,
+}
+
+#[test]
+fn field_of_type_requiring_outlives_bound_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<'a, T>(Borrowing<'a, T>);
+
+    let name = String::from("name");
+    let src = Test(Borrowing {
+        value: 1,
+        name: &name,
+    });
+    let dst = src.func_map(|v| v + 1);
+
+    assert_eq!(
+        dst,
+        Test(Borrowing {
+            value: 2,
+            name: &name,
+        })
+    );
+}
+
+#[test]
+fn field_of_type_requiring_outlives_bound_is_mapped_to_borrowed_type() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<'a, T>(Borrowing<'a, T>);
+
+    let name = String::from("name");
+    let values = [String::from("a"), String::from("b")];
+    let src = Test(Borrowing {
+        value: 1,
+        name: &name,
+    });
+    let dst = src.func_map(|v| values[v].as_str());
+
+    assert_eq!(
+        dst,
+        Test(Borrowing {
+            value: "b",
+            name: &name,
+        })
+    );
+}
+
+#[test]
+fn nested_fields_of_types_requiring_outlives_bounds_are_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<'a, 'b, T>(Vec<Borrowing<'a, Option<Borrowing<'b, T>>>>);
+
+    let src = Test(vec![Borrowing {
+        value: Some(Borrowing {
+            value: 1,
+            name: "inner",
+        }),
+        name: "outer",
+    }]);
+    let dst = src.func_map(|v| v + 1);
+
+    assert_eq!(
+        dst,
+        Test(vec![Borrowing {
+            value: Some(Borrowing {
+                value: 2,
+                name: "inner",
+            }),
+            name: "outer",
+        }])
+    );
+}
+
+#[test]
+fn field_of_type_requiring_outlives_bound_is_try_mapped() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<'a, T>(Option<Borrowing<'a, T>>);
+
+    let src = Test(Some(Borrowing {
+        value: 1,
+        name: "name",
+    }));
+    let dst: Result<_, ()> = src.try_func_map(|v| Ok(v + 1));
+
+    assert_eq!(
+        dst,
+        Ok(Test(Some(Borrowing {
+            value: 2,
+            name: "name",
+        })))
+    );
+}
+
+#[test]
+fn field_with_multiple_lifetimes_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<'a, 'b: 'a, T: 'b>(Borrowing<'a, (T, i32)>, PhantomData<&'b T>);
+
+    let src = Test(
+        Borrowing {
+            value: (1, 42),
+            name: "name",
+        },
+        PhantomData,
+    );
+    let dst = src.func_map(|v| v + 1);
+
+    assert_eq!(
+        dst,
+        Test(
+            Borrowing {
+                value: (2, 42),
+                name: "name",
+            },
+            PhantomData
+        )
+    );
+}
+
+#[test]
+fn field_with_static_lifetime_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T: 'static>(Borrowing<'static, T>);
+
+    let src = Test(Borrowing {
+        value: 1,
+        name: "name",
+    });
+    let dst = src.func_map(|v| v + 1);
+
+    assert_eq!(
+        dst,
+        Test(Borrowing {
+            value: 2,
+            name: "name",
+        })
+    );
+}
+
+#[test]
+fn owned_values_are_mapped_to_values_borrowed_from_arena() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+        option: Option<Box<T>>,
+    }
+
+    let arena = Arena::new(4);
+    let src = Test {
+        value: String::from("a"),
+        values: vec![String::from("b"), String::from("c")],
+        option: Some(Box::new(String::from("d"))),
+    };
+    let dst = src.func_map(|v| arena.alloc(v));
+
+    assert_eq!(
+        dst,
+        Test {
+            value: "a",
+            values: vec!["b", "c"],
+            option: Some(Box::new("d")),
+        }
+    );
+}
+
+#[test]
+fn owned_values_are_try_mapped_to_values_borrowed_from_arena() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let arena = Arena::new(2);
+    let src = Test(String::from("a"), vec![String::from("b")]);
+    let dst: Result<_, ()> = src.try_func_map(|v| Ok(arena.alloc(v)));
+
+    assert_eq!(dst, Ok(Test("a", vec!["b"])));
+}
+
+#[test]
+fn owned_values_are_mapped_to_values_borrowed_from_arena_via_free_fn() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(free_fn = "map_test")]
+    struct Test<'a, T>(Borrowing<'a, T>, Option<T>);
+
+    let arena = Arena::new(2);
+    let src = Test(
+        Borrowing {
+            value: String::from("a"),
+            name: "name",
+        },
+        Some(String::from("b")),
+    );
+    let dst = map_test(src, |v| arena.alloc(v));
+
+    assert_eq!(
+        dst,
+        Test(
+            Borrowing {
+                value: "a",
+                name: "name",
+            },
+            Some("b")
+        )
+    );
+}
+
+#[test]
+fn owned_values_are_mapped_to_cow() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Vec<T>);
+
+    let default = String::from("default");
+    let src = Test(String::new(), vec![String::from("a"), String::new()]);
+    let dst: Test<Cow<'_, str>> = src.func_map(|v| {
+        if v.is_empty() {
+            Cow::Borrowed(default.as_str())
+        } else {
+            Cow::Owned(v)
+        }
+    });
+
+    assert_eq!(
+        dst,
+        Test(
+            Cow::Borrowed("default"),
+            vec![Cow::Owned(String::from("a")), Cow::Borrowed("default")]
+        )
+    );
+}
+
+/// Minimal arena handing out string slices borrowed from itself
+struct Arena {
+    slots: Vec<OnceCell<String>>,
+    len: Cell<usize>,
+}
+
+impl Arena {
+    fn new(capacity: usize) -> Self {
+        Self {
+            slots: (0..capacity).map(|_| OnceCell::new()).collect(),
+            len: Cell::new(0),
+        }
+    }
+
+    fn alloc(&self, value: String) -> &str {
+        let slot = &self.slots[self.len.get()];
+        self.len.set(self.len.get() + 1);
+        slot.get_or_init(|| value)
+    }
+}
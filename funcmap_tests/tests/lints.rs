@@ -20,6 +20,15 @@ fn unused_qualifications_lint_is_allowed_on_derived_impl() {
     struct Test<T>(core::option::Option<T>);
 }
 
+#[test]
+fn unused_parens_lint_is_allowed_on_derived_impl() {
+    #![deny(unused_parens)]
+
+    #[allow(unused_parens)]
+    #[derive(FuncMap)]
+    struct Test<T>((T), ((T, T)));
+}
+
 #[test]
 fn deprecated_lint_is_allowed_on_derived_impl() {
     #![deny(deprecated)]
@@ -58,20 +67,20 @@ fn dyn_drop_lint_is_allowed_on_derived_impl() {
 
 #[test]
 fn clippy_disallowed_method_lint_is_allowed_on_derived_impl() {
-    #![deny(clippy::disallowed_method)]
+    #![deny(clippy::disallowed_methods)]
 
     // methods `func_map` and `func_map_over` are disallowed via `clippy.toml`
-    #[allow(clippy::disallowed_method)]
+    #[allow(clippy::disallowed_methods)]
     #[derive(FuncMap)]
     struct Test<T>(Option<T>);
 }
 
 #[test]
 fn clippy_disallowed_type_lint_is_allowed_on_derived_impl() {
-    #![deny(clippy::disallowed_type)]
+    #![deny(clippy::disallowed_types)]
 
     // type `Option` is disallowed via `clippy.toml`
-    #[allow(clippy::disallowed_type)]
+    #[allow(clippy::disallowed_types)]
     #[derive(FuncMap)]
     struct Test<T>(Option<T>);
 }
@@ -0,0 +1,96 @@
+use funcmap::{FuncMap, FuncMapParams, TryFuncMap};
+
+use std::convert::Infallible;
+
+#[test]
+fn identically_named_types_local_to_different_functions_are_mapped() {
+    fn first() -> (i32, i32) {
+        #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+        #[funcmap(assert_auto_traits(Send))]
+        enum Test<T> {
+            Value(T),
+        }
+
+        let Test::Value(mapped) = Test::Value(1).func_map(|v| v + 1);
+        let Ok::<_, Infallible>(Test::Value(try_mapped)) =
+            Test::Value(1).try_func_map(|v| Ok(v + 2));
+
+        (mapped, try_mapped)
+    }
+
+    fn second() -> (i32, i32) {
+        #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+        #[funcmap(assert_auto_traits(Send))]
+        enum Test<T> {
+            Value(T),
+        }
+
+        let Test::Value(mapped) = Test::Value(1).func_map(|v| v + 3);
+        let Ok::<_, Infallible>(Test::Value(try_mapped)) =
+            Test::Value(1).try_func_map(|v| Ok(v + 4));
+
+        (mapped, try_mapped)
+    }
+
+    assert_eq!(first(), (2, 3));
+    assert_eq!(second(), (4, 5));
+}
+
+#[test]
+fn identically_named_types_local_to_nested_blocks_are_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T);
+
+    let outer = Test(1).func_map(|v| v + 1);
+
+    let inner = {
+        #[derive(FuncMap, Debug, PartialEq)]
+        #[funcmap(strict)]
+        struct Test<T>(Inner<T>);
+
+        #[derive(FuncMap, Debug, PartialEq)]
+        struct Inner<T>(T);
+
+        Test(Inner(1)).func_map(|v| v + 2).0 .0
+    };
+
+    assert_eq!(outer, Test(2));
+    assert_eq!(inner, 3);
+}
+
+#[test]
+fn params_of_identically_named_local_types_are_kept_apart() {
+    fn first() -> &'static [usize] {
+        #[derive(FuncMap)]
+        struct Test<T>(T);
+
+        <Test<()> as FuncMapParams>::PARAMS
+    }
+
+    fn second() -> &'static [usize] {
+        #[derive(FuncMap)]
+        struct Test<S, T>(S, T);
+
+        <Test<(), ()> as FuncMapParams>::PARAMS
+    }
+
+    assert_eq!(first(), [0]);
+    assert_eq!(second(), [0, 1]);
+}
+
+mod private {
+    use funcmap::FuncMap;
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(free_fn = "map_test")]
+    struct Test<T>(T);
+
+    pub(super) fn map() -> i32 {
+        map_test(Test(1), |v| v + 1).0
+    }
+}
+
+#[test]
+fn private_type_with_free_fn_is_mapped() {
+    assert_eq!(private::map(), 2);
+}
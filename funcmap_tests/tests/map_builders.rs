@@ -0,0 +1,154 @@
+#![deny(missing_docs)]
+
+//! Tests for the `MapBuilders` derive macro
+
+use funcmap::{FuncMap, MapBuilders};
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+#[test]
+fn methods_are_named_after_type_params() {
+    #[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+    struct Size<W, H> {
+        width: W,
+        height: H,
+    }
+
+    let src = Size {
+        width: 1,
+        height: 2,
+    };
+    let dst = src.map_w(|w| w * 10).map_h(|h| h.to_string());
+
+    assert_eq!(
+        dst,
+        Size {
+            width: 10,
+            height: String::from("2"),
+        }
+    );
+}
+
+#[test]
+fn method_names_are_snake_case() {
+    #[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+    struct Test<KeyType, Value>(KeyType, Value);
+
+    let src = Test(1, 2);
+    let dst = src.map_key_type(|k| k + 1).map_value(|v| v * 10);
+
+    assert_eq!(dst, Test(2, 20));
+}
+
+#[test]
+fn destination_type_can_be_specified_explicitly() {
+    #[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+    struct Test<T>(T);
+
+    let src = Test(1_u8);
+    let dst = src.map_t::<u64>(Into::into);
+
+    assert_eq!(dst, Test(1_u64));
+}
+
+#[test]
+fn methods_are_generated_only_for_mapped_type_params() {
+    #[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<S, T>(PhantomData<S>, T);
+
+    impl<S, T> Test<S, T> {
+        #[allow(dead_code)]
+        fn map_s(self) -> Self {
+            self
+        }
+    }
+
+    let src = Test::<(), _>(PhantomData, 1);
+    let dst = src.map_t(|v| v + 1);
+
+    assert_eq!(dst, Test(PhantomData, 2));
+}
+
+#[test]
+fn bounds_of_original_type_are_kept() {
+    trait TestTrait<T> {}
+
+    impl<T> TestTrait<T> for () {}
+
+    #[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+    struct Test<S: TestTrait<T>, T: Clone>(PhantomData<S>, T)
+    where
+        T: Debug;
+
+    let src = Test::<(), _>(PhantomData, 1);
+    let dst = src.map_t(|v| v.to_string());
+
+    assert_eq!(dst, Test(PhantomData, String::from("1")));
+}
+
+#[test]
+fn lifetimes_and_const_generics_are_supported() {
+    #[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+    struct Test<'a, T, const N: usize>(&'a str, [T; N]);
+
+    let src = Test("a", [1, 2]);
+    let dst = src.map_t(|v| v * 10);
+
+    assert_eq!(dst, Test("a", [10, 20]));
+}
+
+#[test]
+fn enums_are_supported() {
+    #[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+    enum Test<T> {
+        Some(T),
+        None,
+    }
+
+    assert_eq!(Test::Some(1).map_t(|v| v + 1), Test::Some(2));
+    assert_eq!(Test::<i32>::None.map_t(|v| v + 1), Test::None);
+}
+
+#[test]
+fn conflicting_destination_type_ident_is_avoided() {
+    #[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+    struct Test<T, T2>(T, T2);
+
+    let src = Test(1, 2);
+    let dst = src.map_t(|v| v + 1).map_t2(|v| v * 10);
+
+    assert_eq!(dst, Test(2, 20));
+}
+
+#[test]
+fn type_param_bound_to_assoc_type_is_mapped_to_itself() {
+    trait Mode {
+        type Out;
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Fast;
+
+    impl Mode for Fast {
+        type Out = i32;
+    }
+
+    #[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+    struct Test<T, M: Mode<Out = T>>(T, PhantomData<M>);
+
+    let src = Test::<_, Fast>(1, PhantomData);
+    let dst = src.map_t(|v| v + 1);
+
+    assert_eq!(dst, Test(2, PhantomData));
+}
+
+/// Type with public generated methods, which need to be documented
+#[derive(FuncMap, MapBuilders, Debug, PartialEq)]
+pub struct Documented<T>(pub T);
+
+#[test]
+fn generated_methods_are_documented() {
+    assert_eq!(Documented(1).map_t(|v| v + 1), Documented(2));
+}
@@ -0,0 +1,130 @@
+use funcmap::{func_map_variant, try_func_map_variant, FuncMap, TryFuncMap};
+
+#[test]
+fn func_map_variant_maps_chosen_variant() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    enum Test<T> {
+        First(T, T),
+        Second { value: T },
+        Third,
+    }
+
+    let src = Test::First(1, 2);
+    let dst = func_map_variant!(src, First, |v| v * 10);
+
+    assert_eq!(dst, Test::First(10, 20));
+
+    let src = Test::Second { value: 1 };
+    let dst = func_map_variant!(src, Second, |v| v * 10);
+
+    assert_eq!(dst, Test::Second { value: 10 });
+}
+
+#[test]
+fn func_map_variant_leaves_other_variants_untouched() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    enum Test<T> {
+        First(T, T),
+        Second { value: T },
+        Third,
+    }
+
+    let src = Test::Second { value: 1 };
+    let dst = func_map_variant!(src, First, |_: i32| -> i32 { unreachable!() });
+
+    assert_eq!(dst, Test::Second { value: 1 });
+
+    let src = Test::Third;
+    let dst = func_map_variant!(src, Second, |_: i32| -> i32 { unreachable!() });
+
+    assert_eq!(dst, Test::Third);
+}
+
+#[test]
+fn func_map_variant_resolves_raw_variant_by_name() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    enum Test<T> {
+        r#Type(T),
+        Other(T),
+    }
+
+    let src = Test::r#Type(1);
+    let dst = func_map_variant!(src, Type, |v| v + 1);
+
+    assert_eq!(dst, Test::r#Type(2));
+
+    let src = Test::r#Type(1);
+    let dst = func_map_variant!(src, r#Type, |v| v + 1);
+
+    assert_eq!(dst, Test::r#Type(2));
+}
+
+#[test]
+fn func_map_variant_maps_over_type_param_of_closure() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    enum Test<S, T> {
+        First(S, T),
+        Second(S, T),
+    }
+
+    let src = Test::First("a", 1);
+    let dst = func_map_variant!(src, First, |v: i32| v + 1);
+
+    assert_eq!(dst, Test::First("a", 2));
+}
+
+#[test]
+fn func_map_variant_works_with_configured_crate_path() {
+    extern crate funcmap as fm;
+
+    #[derive(fm::FuncMap, Debug, PartialEq)]
+    #[funcmap(crate = "fm")]
+    enum Test<T> {
+        First(T),
+        Second(T),
+    }
+
+    let src = Test::Second(1);
+    let dst = fm::func_map_variant!(src, Second, |v| v + 1);
+
+    assert_eq!(dst, Test::Second(2));
+}
+
+#[test]
+fn try_func_map_variant_maps_chosen_variant() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    enum Test<T> {
+        First(T),
+        Second(T),
+    }
+
+    let src = Test::First("1");
+    let dst = try_func_map_variant!(src, First, |v: &str| v.parse::<i32>().map(|_| "ok"));
+
+    assert_eq!(dst, Ok(Test::First("ok")));
+}
+
+#[test]
+fn try_func_map_variant_fails_if_closure_fails_on_chosen_variant() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    enum Test<T> {
+        First(T),
+        Second(T),
+    }
+
+    let src = Test::First(T1);
+    let dst = try_func_map_variant!(src, First, |_| Err::<T1, _>(TestError));
+
+    assert_eq!(dst, Err(TestError));
+
+    let src = Test::Second(T1);
+    let dst = try_func_map_variant!(src, First, |_| Err::<T1, _>(TestError));
+
+    assert_eq!(dst, Ok(Test::Second(T1)));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct TestError;
@@ -0,0 +1,103 @@
+use funcmap::markers::{P0, P1, P2, P7};
+use funcmap::{FuncMap, FuncMarker, Param, TryFuncMap, TypeParam};
+
+#[test]
+fn marker_names_are_type_params() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = src.func_map_over::<P1, _>(|_| T2);
+
+    assert_eq!(dst, Test(T1, T2));
+}
+
+#[test]
+fn marker_names_are_param_values() {
+    assert_eq!(P0, Param::<0>);
+    assert_eq!(P2.index(), 2);
+    assert_eq!(P7.index(), 7);
+}
+
+#[test]
+fn marker_names_are_available_at_crate_root() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = src.func_map_over::<funcmap::P1, _>(|_| T2);
+
+    assert_eq!(dst, Test(T1, T2));
+    assert_eq!(funcmap::P9, Param::<9>);
+}
+
+#[test]
+fn func_map_at_maps_over_given_param() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = src.func_map_at(P0, |_| T2);
+
+    assert_eq!(dst, Test(T2, T1));
+
+    let src = Test(T1, T1);
+    let dst = src.func_map_at(Param::<1>, |_| T2);
+
+    assert_eq!(dst, Test(T1, T2));
+}
+
+#[test]
+fn func_map_at_counts_const_generics() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<'a, const N: usize, S, T>([S; N], T, std::marker::PhantomData<&'a ()>);
+
+    let src = Test([T1], T1, std::marker::PhantomData);
+    let dst = src.func_map_at(P2, |_| T2);
+
+    assert_eq!(dst, Test([T1], T2, std::marker::PhantomData));
+}
+
+#[test]
+fn func_map_at_accepts_param_from_generic_code() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    fn map_twice<X, const N: usize>(value: X, param: Param<N>) -> X
+    where
+        X: FuncMap<i32, i32, TypeParam<N>, Output = X>,
+        TypeParam<N>: FuncMarker<TypeParam<N>>,
+    {
+        value
+            .func_map_at(param, |v| v * 2)
+            .func_map_at(param, |v| v * 2)
+    }
+
+    assert_eq!(map_twice(Test(1, 1), P0), Test(4, 1));
+    assert_eq!(map_twice(Test(1, 1), P1), Test(1, 4));
+}
+
+#[test]
+fn try_func_map_at_maps_over_given_param() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst: Result<_, TestError> = src.try_func_map_at(P1, |_| Ok(T2));
+
+    assert_eq!(dst, Ok(Test(T1, T2)));
+
+    let src = Test(T1, T1);
+    let dst: Result<Test<T2, T1>, _> = src.try_func_map_at(P0, |_| Err(TestError));
+
+    assert_eq!(dst, Err(TestError));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
+
+#[derive(Debug, PartialEq)]
+struct TestError;
@@ -0,0 +1,106 @@
+use funcmap::{FuncMap, TryFuncMap};
+use funcmap_testing::{
+    assert_func_map_matches_model, assert_try_func_map_matches_model, model_func_map, Leaf,
+};
+use serde::{Deserialize, Serialize};
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::hash::Hash;
+
+#[derive(FuncMap, TryFuncMap, Serialize, Deserialize, Debug, PartialEq)]
+struct Test<T: Ord + Hash> {
+    value: T,
+    optional: Option<T>,
+    values: Vec<(T, i32)>,
+    queue: VecDeque<T>,
+    set: BTreeSet<T>,
+    keys: BTreeMap<T, i32>,
+    entries: HashMap<T, T>,
+    nested: Option<Box<Nested<T>>>,
+}
+
+#[derive(FuncMap, TryFuncMap, Serialize, Deserialize, Debug, PartialEq)]
+enum Nested<T> {
+    Unit,
+    Tuple(T, String),
+    Struct { value: T, values: [T; 2] },
+}
+
+fn test_value() -> Test<Leaf<i32>> {
+    Test {
+        value: Leaf(1),
+        optional: Some(Leaf(2)),
+        values: vec![(Leaf(3), 30), (Leaf(4), 40)],
+        queue: vec![Leaf(5), Leaf(6)].into_iter().collect(),
+        set: vec![Leaf(7), Leaf(-7)].into_iter().collect(),
+        keys: vec![(Leaf(8), 80)].into_iter().collect(),
+        entries: vec![(Leaf(9), Leaf(90)), (Leaf(-9), Leaf(-90))]
+            .into_iter()
+            .collect(),
+        nested: Some(Box::new(Nested::Struct {
+            value: Leaf(10),
+            values: [Leaf(11), Leaf(12)],
+        })),
+    }
+}
+
+#[test]
+fn model_maps_all_leaves() {
+    let src = Nested::Struct {
+        value: Leaf(1),
+        values: [Leaf(2), Leaf(3)],
+    };
+
+    let dst: Nested<Leaf<String>> = model_func_map(&src, |v: i32| v.to_string()).unwrap();
+
+    assert_eq!(
+        dst,
+        Nested::Struct {
+            value: Leaf("1".into()),
+            values: [Leaf("2".into()), Leaf("3".into())],
+        }
+    );
+}
+
+#[test]
+fn model_leaves_other_values_untouched() {
+    let src = Nested::Tuple(Leaf(1), "$funcmap".into());
+
+    let dst: Nested<Leaf<i32>> = model_func_map(&src, |v: i32| v + 1).unwrap();
+
+    assert_eq!(dst, Nested::Tuple(Leaf(2), "$funcmap".into()));
+}
+
+#[test]
+fn derived_func_map_matches_model() {
+    assert_func_map_matches_model(test_value(), |v: i32| v.to_string());
+}
+
+#[test]
+fn derived_func_map_matches_model_for_non_injective_closure() {
+    assert_func_map_matches_model(test_value(), |v: i32| v.abs());
+}
+
+#[test]
+fn derived_func_map_matches_model_for_unit_variant() {
+    assert_func_map_matches_model(Nested::<Leaf<i32>>::Unit, |v: i32| v + 1);
+}
+
+#[test]
+fn derived_try_func_map_matches_model_on_success() {
+    assert_try_func_map_matches_model(test_value(), |v: i32| Ok::<_, ()>(v * 2));
+}
+
+#[test]
+fn derived_try_func_map_matches_model_on_failure() {
+    assert_try_func_map_matches_model(
+        test_value(),
+        |v: i32| {
+            if v == 11 {
+                Err(v)
+            } else {
+                Ok(v * 2)
+            }
+        },
+    );
+}
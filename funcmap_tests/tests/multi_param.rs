@@ -69,6 +69,60 @@ fn field_of_repeated_generic_type_is_mapped() {
     assert_eq!(dst, Test::<'_, _, 42>(Inner(T2, T2, PhantomData)));
 }
 
+#[test]
+fn field_of_generic_type_with_differently_nested_params_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(Result<Vec<T>, Box<T>>);
+
+    let src = Test(Ok(vec![T1, T1]));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Ok(vec![T2, T2])));
+
+    let src = Test(Err(Box::new(T1)));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Err(Box::new(T2))));
+}
+
+#[test]
+fn field_of_type_alias_preserving_param_order_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Inner<'a, S, T>(S, T, PhantomData<&'a ()>);
+
+    type Alias<S, T> = Inner<'static, S, T>;
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(Alias<T, T>);
+
+    let src = Test(Inner(T1, T1, PhantomData));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Inner(T2, T2, PhantomData)));
+}
+
+#[test]
+fn field_of_reexported_type_alias_preserving_order_of_many_params_is_mapped() {
+    mod foreign {
+        use funcmap::FuncMap;
+
+        #[derive(FuncMap, Debug, PartialEq)]
+        pub struct Quad<W, X, Y, Z>(pub W, pub X, pub Y, pub Z);
+
+        pub mod reexport {
+            pub type Quad<W, X, Y, Z> = super::Quad<W, X, Y, Z>;
+        }
+    }
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(foreign::reexport::Quad<i32, T, i32, T>);
+
+    let src = Test(foreign::Quad(1, T1, 2, T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(foreign::Quad(1, T2, 2, T2)));
+}
+
 #[test]
 fn field_of_generic_type_with_const_literal_before_generic_type_is_mapped() {
     #[derive(FuncMap, Debug, PartialEq)]
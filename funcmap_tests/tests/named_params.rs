@@ -0,0 +1,109 @@
+use funcmap::{func_map_over, try_func_map_over, FuncMap, TryFuncMap};
+
+use std::marker::PhantomData;
+
+#[test]
+fn func_map_over_resolves_type_param_by_name() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = func_map_over!(src, T, |_| T2);
+
+    assert_eq!(dst, Test(T1, T2));
+}
+
+#[test]
+fn func_map_over_skips_lifetimes_and_const_generics() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<'a, const N: usize, S, const M: usize, T>([S; N], [T; M], PhantomData<&'a ()>);
+
+    let src = Test([T1], [T1, T1], PhantomData);
+    let dst = func_map_over!(src, T, |_| T2);
+
+    assert_eq!(dst, Test([T1], [T2, T2], PhantomData));
+}
+
+#[test]
+fn func_map_over_resolves_raw_type_param_by_name() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, r#T>(S, r#T);
+
+    let src = Test(T1, T1);
+    let dst = func_map_over!(src, T, |_| T2);
+
+    assert_eq!(dst, Test(T1, T2));
+
+    let src = Test(T1, T1);
+    let dst = func_map_over!(src, r#T, |_| T2);
+
+    assert_eq!(dst, Test(T1, T2));
+}
+
+#[test]
+fn func_map_over_can_be_chained() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = func_map_over!(func_map_over!(src, S, |_| T2), T, |_| T2);
+
+    assert_eq!(dst, Test(T2, T2));
+}
+
+#[test]
+fn func_map_over_works_with_restricted_params() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = func_map_over!(src, T, |_| T2);
+
+    assert_eq!(dst, Test(T1, T2));
+}
+
+#[test]
+fn func_map_over_works_with_configured_crate_path() {
+    extern crate funcmap as fm;
+
+    #[derive(fm::FuncMap, Debug, PartialEq)]
+    #[funcmap(crate = "fm")]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = fm::func_map_over!(src, T, |_| T2);
+
+    assert_eq!(dst, Test(T1, T2));
+}
+
+#[test]
+fn try_func_map_over_resolves_type_param_by_name() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<const N: usize, S, T>([S; N], T);
+
+    let src = Test([T1], T1);
+    let dst: Result<_, TestError> = try_func_map_over!(src, T, |_| Ok(T2));
+
+    assert_eq!(dst, Ok(Test([T1], T2)));
+}
+
+#[test]
+fn try_func_map_over_fails_if_closure_fails() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst: Result<Test<T1, T2>, _> = try_func_map_over!(src, T, |_| Err(TestError));
+
+    assert_eq!(dst, Err(TestError));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
+
+#[derive(Debug, PartialEq)]
+struct TestError;
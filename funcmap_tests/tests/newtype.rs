@@ -0,0 +1,80 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use std::collections::{BTreeSet, VecDeque};
+
+#[derive(Debug, PartialEq)]
+struct Stack<T>(Vec<T>);
+
+funcmap::impl_funcmap_newtype!(Stack { 0: Vec });
+
+#[derive(Debug, PartialEq)]
+struct Queue<T> {
+    items: VecDeque<T>,
+}
+
+funcmap::impl_funcmap_newtype!(Queue {
+    items: std::collections::VecDeque
+});
+
+#[derive(Debug, PartialEq)]
+struct Set<T>(BTreeSet<T>);
+
+funcmap::impl_funcmap_newtype!(Set { 0: BTreeSet } where B: Ord);
+
+#[test]
+fn tuple_newtype_is_mapped() {
+    let src = Stack(vec![1, 2, 3]);
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(dst, Stack(vec![10, 20, 30]));
+}
+
+#[test]
+fn named_newtype_is_mapped() {
+    let src = Queue {
+        items: VecDeque::from(vec![1, 2, 3]),
+    };
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(
+        dst,
+        Queue {
+            items: VecDeque::from(vec![10, 20, 30])
+        }
+    );
+}
+
+#[test]
+fn newtype_with_bounds_is_mapped() {
+    let src = Set(vec![1, 2, 3].into_iter().collect());
+    let dst = src.func_map(|v| v % 2);
+
+    assert_eq!(dst, Set(vec![0, 1].into_iter().collect()));
+}
+
+#[test]
+fn newtype_is_try_mapped() {
+    let src = Stack(vec![1, 2, 3]);
+    let dst: Result<_, ()> = src.try_func_map(|v| Ok(v * 10));
+
+    assert_eq!(dst, Ok(Stack(vec![10, 20, 30])));
+}
+
+#[test]
+fn newtype_fails_with_first_error() {
+    let src = Stack(vec![1, 2, 3]);
+    let dst: Result<Stack<i32>, _> = src.try_func_map(Err);
+
+    assert_eq!(dst, Err(1));
+}
+
+#[test]
+fn newtype_is_mapped_as_field_of_derived_type() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(Stack<T>);
+
+    let src = Test(Stack(vec![1, 2]));
+    let dst = src.func_map(|v| v + 1);
+
+    assert_eq!(dst, Test(Stack(vec![2, 3])));
+}
@@ -0,0 +1,56 @@
+#![deny(unknown_lints)]
+
+use funcmap::{FuncMap, JoinParams, StructuralFrom, TryFuncMap};
+
+#[test]
+fn type_with_allowed_lints_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(allow(unused_results, clippy::used_underscore_binding))]
+    struct Test<T>(T, Option<T>);
+
+    let src = Test(T1, Some(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, Some(T2)));
+
+    let src = Test(T1, Some(T1));
+    let dst: Result<Test<T2>, TestError> = src.try_func_map(|_| Err(TestError));
+
+    assert_eq!(dst, Err(TestError));
+}
+
+#[test]
+fn allowed_lints_can_be_configured_via_multiple_attrs() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(allow(unused_results))]
+    #[funcmap(allow(clippy::used_underscore_binding), free_fn = "map_test")]
+    struct Test<T>(T);
+
+    let src = Test(T1);
+    let dst = map_test(src, |_| T2);
+
+    assert_eq!(dst, Test(T2));
+}
+
+#[test]
+fn allowed_lints_are_supported_by_other_derives() {
+    struct Source<S, T>(S, T);
+
+    #[derive(StructuralFrom, JoinParams, Debug, PartialEq)]
+    #[funcmap(allow(unused_results), from = "Source")]
+    struct Test<S, T>(S, T);
+
+    let src = Test::from(Source(T1, T1));
+    let dst = src.join_params(|_| T2);
+
+    assert_eq!(dst, Test(T2, T2));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
+
+#[derive(Debug, PartialEq)]
+struct TestError;
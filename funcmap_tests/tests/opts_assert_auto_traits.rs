@@ -0,0 +1,54 @@
+use funcmap::FuncMap;
+
+use std::{
+    marker::PhantomData,
+    panic::{RefUnwindSafe, UnwindSafe},
+    sync::Arc,
+};
+
+#[test]
+fn type_preserving_send_and_sync_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(assert_auto_traits(Send, Sync))]
+    struct Test<T>(T, Vec<T>, Arc<i32>);
+
+    let src = Test(T1, vec![T1], Arc::new(42));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, vec![T2], Arc::new(42)));
+}
+
+#[test]
+fn type_with_multiple_params_preserving_auto_traits_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T), assert_auto_traits(Send, Sync, Unpin))]
+    struct Test<'a, S, T>(&'a S, Option<T>);
+
+    let value = 42;
+    let src = Test(&value, Some(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(&value, Some(T2)));
+}
+
+#[test]
+fn enum_preserving_auto_traits_given_by_path_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(assert_auto_traits(std::marker::Send))]
+    #[funcmap(assert_auto_traits(UnwindSafe, RefUnwindSafe))]
+    enum Test<T> {
+        Value(T),
+        Marker(PhantomData<T>),
+    }
+
+    let src = Test::Value(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test::Value(T2));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
@@ -0,0 +1,64 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+#[test]
+fn type_implementing_drop_is_mapped_via_with_and_rebuild() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(
+        assume_no_drop,
+        with = "Self::into_parts",
+        rebuild = "Self::from_parts"
+    )]
+    struct Test<T> {
+        value: Option<T>,
+    }
+
+    impl<T> Test<T> {
+        fn into_parts(mut self) -> (Option<T>,) {
+            (self.value.take(),)
+        }
+
+        fn from_parts((value,): (Option<T>,)) -> Self {
+            Self { value }
+        }
+    }
+
+    impl<T> Drop for Test<T> {
+        fn drop(&mut self) {}
+    }
+
+    let src = Test { value: Some(T1) };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test { value: Some(T2) });
+
+    let src = Test { value: Some(T1) };
+    let dst: Result<Test<T2>, TestError> = src.try_func_map(|_| Err(TestError));
+
+    assert_eq!(dst, Err(TestError));
+}
+
+#[test]
+fn type_with_disabled_drop_impl_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(assume_no_drop)]
+    struct Test<T>(T);
+
+    #[cfg(any())]
+    impl<T> Drop for Test<T> {
+        fn drop(&mut self) {}
+    }
+
+    let src = Test(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
+
+#[derive(Debug, PartialEq)]
+struct TestError;
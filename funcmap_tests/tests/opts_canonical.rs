@@ -0,0 +1,54 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+#[test]
+fn struct_with_canonical_order_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(canonical)]
+    struct Test<'a, S: Clone + 'a, T>
+    where
+        T: PartialEq + Clone,
+    {
+        value: T,
+        values: Vec<T>,
+        other: Option<S>,
+        name: &'a str,
+    }
+
+    let src = Test {
+        value: 1,
+        values: vec![2, 3],
+        other: Some(true),
+        name: "test",
+    };
+    let dst = src.func_map(|v: i32| v * 10);
+
+    assert_eq!(
+        dst,
+        Test {
+            value: 10,
+            values: vec![20, 30],
+            other: Some(true),
+            name: "test",
+        }
+    );
+}
+
+#[test]
+fn enum_with_canonical_order_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(canonical)]
+    enum Test<T> {
+        Unit,
+        Tuple(T, Option<T>),
+        Struct { value: T },
+    }
+
+    assert_eq!(
+        Test::Tuple(1, Some(2)).try_func_map(|v: i32| v.checked_mul(10).ok_or(v)),
+        Ok(Test::Tuple(10, Some(20)))
+    );
+    assert_eq!(
+        Test::Struct { value: 3 }.func_map(|v| v * 10),
+        Test::Struct { value: 30 }
+    );
+}
@@ -0,0 +1,87 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+#[test]
+fn impls_are_generated_if_cfg_predicate_holds() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(cfg(test))]
+    struct Test<T>(T, Option<T>);
+
+    let src = Test(T1, Some(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, Some(T2)));
+
+    let src = Test(T1, Some(T1));
+    let dst: Result<Test<T2>, TestError> = src.try_func_map(|_| Err(TestError));
+
+    assert_eq!(dst, Err(TestError));
+}
+
+#[test]
+fn impls_are_not_generated_if_cfg_predicate_does_not_hold() {
+    // the derived implementation would conflict with the manual one
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(cfg(not(test)))]
+    struct Test<T>(T);
+
+    impl<A, B> FuncMap<A, B> for Test<A> {
+        type Output = Test<Option<B>>;
+
+        fn func_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            Test(Some(f(self.0)))
+        }
+    }
+
+    let src = Test(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Some(T2)));
+}
+
+#[test]
+fn impls_are_generated_if_all_cfg_predicates_hold() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(cfg(test), cfg(any(unix, not(unix))), free_fn = "map_test")]
+    struct Test<T>(T);
+
+    let src = Test(T1);
+    let dst = map_test(src, |_| T2);
+
+    assert_eq!(dst, Test(T2));
+}
+
+#[test]
+fn impls_are_not_generated_if_any_cfg_predicate_does_not_hold() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(cfg(test))]
+    #[funcmap(cfg(any()))]
+    struct Test<T>(T);
+
+    impl<A, B> FuncMap<A, B> for Test<A> {
+        type Output = Test<Option<B>>;
+
+        fn func_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            Test(Some(f(self.0)))
+        }
+    }
+
+    let src = Test(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Some(T2)));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
+
+#[derive(Debug, PartialEq)]
+struct TestError;
@@ -25,6 +25,75 @@ fn crate_path_can_be_configured() {
     fake_funcmap::assert::<Test<T1>, T1, T2, fake_funcmap::TypeParam<0>>();
 }
 
+#[test]
+fn crate_path_can_be_relative() {
+    use funcmap::FuncMap;
+
+    #[derive(FuncMap)]
+    #[funcmap(crate = "reexports::funcmap")]
+    struct Test<T>(T, Vec<T>);
+
+    funcmap_assert::<Test<T1>, T1, T2>();
+}
+
+#[test]
+fn crate_path_can_start_with_self() {
+    use funcmap::FuncMap;
+
+    #[derive(FuncMap)]
+    #[funcmap(crate = "self::reexports::funcmap")]
+    struct Test<T>(T, Vec<T>);
+
+    funcmap_assert::<Test<T1>, T1, T2>();
+}
+
+#[test]
+fn crate_path_can_start_with_crate() {
+    use funcmap::FuncMap;
+
+    #[derive(FuncMap)]
+    #[funcmap(crate = "crate::reexports::funcmap")]
+    struct Test<T>(T, Vec<T>);
+
+    funcmap_assert::<Test<T1>, T1, T2>();
+}
+
+#[test]
+fn crate_path_can_start_with_super() {
+    mod inner {
+        use funcmap::FuncMap;
+
+        #[derive(FuncMap)]
+        #[funcmap(crate = "super::reexports::funcmap")]
+        pub(super) struct Test<T>(pub(super) T, pub(super) Vec<T>);
+    }
+
+    funcmap_assert::<inner::Test<T1>, T1, T2>();
+}
+
+#[test]
+fn crate_path_can_have_leading_colon() {
+    use funcmap::FuncMap;
+
+    #[derive(FuncMap)]
+    #[funcmap(crate = "::funcmap")]
+    struct Test<T>(T, Vec<T>);
+
+    funcmap_assert::<Test<T1>, T1, T2>();
+}
+
+mod reexports {
+    pub(crate) mod funcmap {
+        pub use funcmap::*;
+    }
+}
+
+fn funcmap_assert<T, A, B>()
+where
+    T: funcmap::FuncMap<A, B>,
+{
+}
+
 mod fake_funcmap {
     pub use funcmap::*;
 
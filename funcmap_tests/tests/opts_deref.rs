@@ -0,0 +1,112 @@
+use funcmap::{FuncMap, TryFuncMap, TypeParam};
+
+use std::ops::{Deref, DerefMut};
+
+#[test]
+fn field_is_mapped_through_deref_mut() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        #[funcmap(deref, rebuild = "Tracked::new")]
+        values: Tracked<Vec<T>>,
+        value: T,
+    }
+
+    let src = Test {
+        values: Tracked::new(vec![T1, T1]),
+        value: T1,
+    };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test {
+            values: Tracked::new(vec![T2, T2]),
+            value: T2,
+        }
+    );
+}
+
+#[test]
+fn field_is_try_mapped_through_deref_mut() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        #[funcmap(deref, rebuild = "Tracked::new")]
+        values: Tracked<Vec<T>>,
+    }
+
+    let src = Test {
+        values: Tracked::new(vec![1, -2, 3]),
+    };
+    let dst: Result<Test<i32>, i32> = src.try_func_map(|v| if v > 0 { Ok(v) } else { Err(v) });
+
+    assert_eq!(dst, Err(-2));
+}
+
+#[test]
+fn nested_target_is_mapped_through_deref_mut() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    enum Test<T> {
+        Tracked(#[funcmap(deref, rebuild = "Tracked::new")] Tracked<Option<(T, i32)>>),
+    }
+
+    let src = Test::Tracked(Tracked::new(Some((T1, 42))));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test::Tracked(Tracked::new(Some((T2, 42)))));
+}
+
+#[test]
+fn field_not_depending_on_mapped_type_param_is_moved() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T> {
+        #[funcmap(deref, rebuild = "Tracked::new")]
+        values: Tracked<Vec<S>>,
+        value: T,
+    }
+
+    let mut values = Tracked::new(vec![T1]);
+    values.push(T1);
+
+    let src = Test { values, value: T1 };
+    let dst = src.func_map_over::<TypeParam<1>, _>(|_| T2);
+
+    assert!(dst.values.changed);
+    assert_eq!(*dst.values, [T1, T1]);
+    assert_eq!(dst.value, T2);
+}
+
+#[derive(Debug, PartialEq)]
+struct Tracked<T> {
+    value: T,
+    changed: bool,
+}
+
+impl<T> Tracked<T> {
+    fn new(value: T) -> Self {
+        Self {
+            value,
+            changed: false,
+        }
+    }
+}
+
+impl<T> Deref for Tracked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Tracked<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.changed = true;
+        &mut self.value
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
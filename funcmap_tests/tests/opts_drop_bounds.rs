@@ -0,0 +1,73 @@
+#![allow(dead_code)]
+
+use funcmap::{FuncMap, TryFuncMap, TypeParam};
+
+#[test]
+fn bounds_implied_by_other_bounds_can_be_dropped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(drop_bounds(A: sealed::Marker, B: sealed::Marker))]
+    struct Test<T: Scalar + sealed::Marker>(T);
+
+    let src = Test(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2));
+}
+
+#[test]
+fn bounds_can_be_dropped_from_fallible_impl() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(drop_bounds(A: sealed::Marker, B: sealed::Marker))]
+    struct Test<T>(T)
+    where
+        T: Scalar + sealed::Marker;
+
+    let src = Test(T1);
+    let dst: Result<_, ()> = src.try_func_map(|_| Ok(T2));
+
+    assert_eq!(dst, Ok(Test(T2)));
+}
+
+#[test]
+fn bounds_can_be_dropped_for_destination_type_only() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(drop_bounds(B: sealed::Marker))]
+    struct Test<T: Scalar + sealed::Marker>(T);
+
+    let src = Test(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2));
+}
+
+#[test]
+fn bounds_can_be_dropped_for_some_params() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(drop_bounds(A: sealed::Marker, B: sealed::Marker))]
+    struct Test<S, T: Scalar + sealed::Marker>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = src
+        .func_map_over::<TypeParam<0>, _>(|_| T2)
+        .func_map_over::<TypeParam<1>, _>(|_| T2);
+
+    assert_eq!(dst, Test(T2, T2));
+}
+
+mod sealed {
+    pub trait Marker {}
+
+    impl Marker for super::T1 {}
+    impl Marker for super::T2 {}
+}
+
+trait Scalar: sealed::Marker {}
+
+impl Scalar for T1 {}
+impl Scalar for T2 {}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
@@ -0,0 +1,122 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use std::fmt::Debug;
+
+#[test]
+fn field_of_boxed_trait_object_type_is_mapped_via_wrapper() {
+    #[derive(FuncMap, Debug)]
+    #[funcmap(dyn_as = "ValuesBox")]
+    struct Test<T> {
+        values: Box<dyn Values<T>>,
+        value: T,
+    }
+
+    let src = Test {
+        values: Box::new(vec![T1, T1]),
+        value: T1,
+    };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst.values.into_vec(), [T2, T2]);
+    assert_eq!(dst.value, T2);
+}
+
+#[test]
+fn nested_field_of_boxed_trait_object_type_is_mapped_via_wrapper() {
+    #[derive(FuncMap, Debug)]
+    #[funcmap(dyn_as = "ValuesBox")]
+    struct Test<T>(Vec<Box<dyn Values<T>>>);
+
+    let src = Test(vec![Box::new(vec![T1]), Box::new(vec![T1, T1])]);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst.0.into_iter().map(Values::into_vec).collect::<Vec<_>>(),
+        [vec![T2], vec![T2, T2]]
+    );
+}
+
+#[test]
+fn field_of_boxed_trait_object_type_is_try_mapped_via_wrapper() {
+    #[derive(TryFuncMap, Debug)]
+    #[funcmap(dyn_as = "ValuesBox")]
+    struct Test<T>(Box<dyn Values<T>>);
+
+    let src = Test(Box::new(vec![1, 2, 3]));
+    let dst = src.try_func_map(|v| if v < 2 { Ok(v) } else { Err(v) });
+
+    assert_eq!(dst.unwrap_err(), 2);
+}
+
+#[test]
+fn wrapper_can_be_given_as_path() {
+    mod wrapper {
+        pub(super) use super::ValuesBox;
+    }
+
+    #[derive(FuncMap, Debug)]
+    #[funcmap(dyn_as = "wrapper::ValuesBox")]
+    struct Test<T>(Box<dyn Values<T>>);
+
+    let src = Test(Box::new(vec![T1]));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst.0.into_vec(), [T2]);
+}
+
+trait Values<T>: Debug {
+    fn into_vec(self: Box<Self>) -> Vec<T>;
+}
+
+impl<T> Values<T> for Vec<T>
+where
+    T: Debug,
+{
+    fn into_vec(self: Box<Self>) -> Vec<T> {
+        *self
+    }
+}
+
+struct ValuesBox<T>(Box<dyn Values<T>>);
+
+impl<A, B> FuncMap<A, B> for ValuesBox<A>
+where
+    B: Debug + 'static,
+{
+    type Output = ValuesBox<B>;
+
+    fn func_map<F>(self, f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+    {
+        ValuesBox(Box::new(
+            self.0.into_vec().into_iter().map(f).collect::<Vec<_>>(),
+        ))
+    }
+}
+
+impl<A, B> TryFuncMap<A, B> for ValuesBox<A>
+where
+    B: Debug + 'static,
+{
+    type Output = ValuesBox<B>;
+
+    fn try_func_map<E, F>(self, f: F) -> Result<Self::Output, E>
+    where
+        F: FnMut(A) -> Result<B, E>,
+    {
+        Ok(ValuesBox(Box::new(
+            self.0
+                .into_vec()
+                .into_iter()
+                .map(f)
+                .collect::<Result<Vec<_>, _>>()?,
+        )))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
@@ -0,0 +1,163 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+#[test]
+fn struct_with_dyn_body_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(dyn_body)]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+        other_values: Vec<T>,
+        flag: bool,
+    }
+
+    let src = Test {
+        value: 1,
+        values: vec![2, 3],
+        other_values: vec![4],
+        flag: true,
+    };
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(
+        dst,
+        Test {
+            value: 10,
+            values: vec![20, 30],
+            other_values: vec![40],
+            flag: true,
+        }
+    );
+}
+
+#[test]
+fn enum_with_dyn_body_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(dyn_body)]
+    enum Test<T> {
+        Unit,
+        Tuple(T, Option<T>),
+        Struct { value: T },
+    }
+
+    assert_eq!(Test::<i32>::Unit.func_map(|v| v * 10), Test::Unit);
+    assert_eq!(
+        Test::Tuple(1, Some(2)).func_map(|v| v * 10),
+        Test::Tuple(10, Some(20))
+    );
+    assert_eq!(
+        Test::Struct { value: 1 }.func_map(|v| v * 10),
+        Test::Struct { value: 10 }
+    );
+}
+
+#[test]
+fn struct_with_dyn_body_is_try_mapped() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(dyn_body)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test(1, vec![2, 3]);
+    let dst: Result<_, ()> = src.try_func_map(|v| Ok(v * 10));
+
+    assert_eq!(dst, Ok(Test(10, vec![20, 30])));
+}
+
+#[test]
+fn struct_with_dyn_body_fails_with_first_error() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(dyn_body)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test(1, vec![2, 3]);
+    let dst: Result<Test<()>, _> = src.try_func_map(|v| if v > 1 { Err(v) } else { Ok(()) });
+
+    assert_eq!(dst, Err(2));
+}
+
+#[test]
+fn dyn_body_is_combined_with_multiple_params_and_lifetimes() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(dyn_body)]
+    struct Test<'a, S, T, const N: usize>(&'a str, [S; N], Vec<T>);
+
+    let src = Test("a", [1], vec![2]);
+
+    assert_eq!(
+        src.func_map_over::<funcmap::TypeParam<0>, _>(|v| v * 10),
+        Test("a", [10], vec![2])
+    );
+}
+
+#[test]
+fn dyn_body_is_combined_with_free_fn_and_infallible_via_try() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(dyn_body, free_fn = "map_test")]
+    struct Test<T>(T);
+
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(dyn_body, infallible_via_try)]
+    struct TestViaTry<T>(T);
+
+    assert_eq!(map_test(Test(1), |v| v * 10), Test(10));
+    assert_eq!(TestViaTry(1).func_map(|v| v * 10), TestViaTry(10));
+}
+
+// `Probe` records the address of the instance of its mapping function for
+// each closure type it is mapped with, which measures how many copies of the
+// mapping code of a type containing it are instantiated for different closures
+#[derive(Debug, PartialEq)]
+struct Probe<T>(T);
+
+thread_local! {
+    static INSTANCES: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+}
+
+impl<A, B> FuncMap<A, B> for Probe<A> {
+    type Output = Probe<B>;
+
+    fn func_map<F>(self, mut f: F) -> Self::Output
+    where
+        F: FnMut(A) -> B,
+    {
+        let instance = <Self as FuncMap<A, B>>::func_map::<F> as fn(Self, F) -> Probe<B>;
+        INSTANCES.with(|instances| instances.borrow_mut().insert(instance as usize));
+        Probe(f(self.0))
+    }
+}
+
+fn count_instances<T>(map: impl FnOnce(T) -> T, value: T) -> usize {
+    INSTANCES.with(|instances| instances.borrow_mut().clear());
+    map(value);
+    INSTANCES.with(|instances| instances.borrow().len())
+}
+
+#[test]
+fn dyn_body_instantiates_nested_mappings_once_for_all_closures() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Generic<T>(Probe<T>);
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(dyn_body)]
+    struct Dyn<T>(Probe<T>);
+
+    let offset = 3;
+
+    let map_generic = |value: Generic<i32>| {
+        let value = value.func_map(|v| v + 1);
+        let value = value.func_map(|v| v * 2);
+        value.func_map(|v| v - offset)
+    };
+
+    let map_dyn = |value: Dyn<i32>| {
+        let value = value.func_map(|v| v + 1);
+        let value = value.func_map(|v| v * 2);
+        value.func_map(|v| v - offset)
+    };
+
+    assert_eq!(count_instances(map_generic, Generic(Probe(1))), 3);
+    assert_eq!(count_instances(map_dyn, Dyn(Probe(1))), 1);
+}
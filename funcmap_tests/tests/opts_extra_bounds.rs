@@ -0,0 +1,75 @@
+#![allow(dead_code)]
+
+use funcmap::{FuncMap, TryFuncMap, TypeParam};
+
+#[test]
+fn extra_bounds_are_added_to_impl() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(extra_bounds = "A: Clone, B: Default")]
+    struct Test<T>(T);
+
+    let src = Test(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2));
+}
+
+#[test]
+fn extra_bounds_are_added_to_fallible_impl() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(extra_bounds = "A: Clone, B: Default")]
+    struct Test<T>(T);
+
+    let src = Test(T1);
+    let dst: Result<_, ()> = src.try_func_map(|_| Ok(T2));
+
+    assert_eq!(dst, Ok(Test(T2)));
+}
+
+#[test]
+fn extra_bounds_refer_to_mapped_type_for_each_param() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(extra_bounds = "B: Default")]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = src
+        .func_map_over::<TypeParam<0>, _>(|_| T2)
+        .func_map_over::<TypeParam<1>, _>(|_| T2);
+
+    assert_eq!(dst, Test(T2, T2));
+}
+
+#[test]
+fn extra_bounds_can_be_combined_from_multiple_attrs() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(extra_bounds = "A: Clone")]
+    #[funcmap(extra_bounds = "B: Default,")]
+    struct Test<T>(T);
+
+    let src = Test(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2));
+}
+
+#[test]
+fn extra_bounds_refer_to_mapped_type_despite_conflicting_idents() {
+    #[derive(Debug, PartialEq)]
+    struct A;
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(extra_bounds = "A: Clone, B: Default")]
+    struct Test<T>(T, A);
+
+    let src = Test(T1, A);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, A));
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct T1;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct T2;
@@ -0,0 +1,95 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+#[test]
+fn free_fn_maps_struct() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(free_fn = "map_test")]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+    }
+
+    let src = Test {
+        value: T1,
+        values: vec![T1, T1],
+    };
+    let dst = map_test(src, |_| T2);
+
+    assert_eq!(
+        dst,
+        Test {
+            value: T2,
+            values: vec![T2, T2],
+        }
+    );
+}
+
+#[test]
+fn free_fn_maps_enum() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(free_fn = "map_test")]
+    enum Test<T> {
+        Unit,
+        Tuple(T, i32),
+        Struct { value: Option<T> },
+    }
+
+    assert_eq!(map_test(Test::<T1>::Unit, |_| T2), Test::Unit);
+    assert_eq!(map_test(Test::Tuple(T1, 42), |_| T2), Test::Tuple(T2, 42));
+    assert_eq!(
+        map_test(Test::Struct { value: Some(T1) }, |_| T2),
+        Test::Struct { value: Some(T2) }
+    );
+}
+
+#[test]
+fn free_fn_maps_selected_param() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(params(T), free_fn = "map_test")]
+    struct Test<'a, S, T, const N: usize>(&'a S, [T; N]);
+
+    let src = Test(&T1, [T1, T1]);
+    let dst = map_test(src, |_| T2);
+
+    assert_eq!(dst, Test(&T1, [T2, T2]));
+}
+
+#[test]
+fn free_fn_carries_bounds_of_implementation() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(free_fn = "map_test", extra_bounds = "B: Default")]
+    struct Test<T>
+    where
+        T: Clone,
+    {
+        value: T,
+    }
+
+    let src = Test { value: 1 };
+    let dst = map_test(src, |v| v.to_string());
+
+    assert_eq!(
+        dst,
+        Test {
+            value: String::from("1")
+        }
+    );
+}
+
+#[test]
+fn free_fn_is_ignored_by_try_func_map() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(free_fn = "map_test")]
+    struct Test<T>(T);
+
+    let dst: Result<_, ()> = Test(T1).try_func_map(|_| Ok(T2));
+
+    assert_eq!(dst, Ok(Test(T2)));
+    assert_eq!(map_test(Test(T1), |_| T2), Test(T2));
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
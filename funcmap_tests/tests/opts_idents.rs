@@ -0,0 +1,67 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+#[test]
+fn configured_idents_are_used_for_mapping() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(idents(src = "Src", dst = "Dst"))]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+    }
+
+    let src = Test {
+        value: T1,
+        values: vec![T1],
+    };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test {
+            value: T2,
+            values: vec![T2],
+        }
+    );
+}
+
+#[test]
+fn configured_idents_may_be_single_letters_otherwise_reserved_automatically() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(idents(src = "B", dst = "A"))]
+    struct Test<T>(T);
+
+    let dst: Result<_, ()> = Test(T1).try_func_map(|_| Ok(T2));
+
+    assert_eq!(dst, Ok(Test(T2)));
+}
+
+#[test]
+fn configured_src_ident_can_be_combined_with_automatic_dst_ident() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(idents(src = "Src"))]
+    struct Test<A, B>(A, B);
+
+    let dst = Test(T1, T1).func_map_over::<funcmap::TypeParam<1>, _>(|_| T2);
+
+    assert_eq!(dst, Test(T1, T2));
+}
+
+#[test]
+fn extra_bounds_refer_to_configured_idents_via_a_and_b() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(
+        idents(src = "Src", dst = "Dst"),
+        extra_bounds = "A: Clone, B: Default"
+    )]
+    struct Test<T>(T);
+
+    let dst = Test(1).func_map(|v| v.to_string());
+
+    assert_eq!(dst, Test(String::from("1")));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
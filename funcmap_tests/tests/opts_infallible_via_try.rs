@@ -0,0 +1,66 @@
+use funcmap::{func_map_over, FuncMap, FuncMapParams, TryFuncMap, TypeParam};
+
+#[test]
+fn func_map_is_implemented_via_try_func_map() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(infallible_via_try)]
+    struct Test<T>(T, Vec<T>);
+
+    let src = Test(T1, vec![T1, T1]);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2, vec![T2, T2]));
+}
+
+#[test]
+fn try_func_map_is_still_implemented() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(infallible_via_try)]
+    struct Test<T>(T, Option<T>);
+
+    let src = Test(1, Some(-2));
+    let dst: Result<Test<i32>, i32> = src.try_func_map(|v| if v > 0 { Ok(v) } else { Err(v) });
+
+    assert_eq!(dst, Err(-2));
+}
+
+#[test]
+fn func_map_is_implemented_for_every_type_param() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(infallible_via_try)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = src
+        .func_map_over::<TypeParam<0>, _>(|_| T2)
+        .func_map_over::<TypeParam<1>, _>(|_| T2);
+
+    assert_eq!(dst, Test(T2, T2));
+}
+
+#[test]
+fn func_map_over_macro_is_supported() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(infallible_via_try)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = func_map_over!(src, T, |_| T2);
+
+    assert_eq!(dst, Test(T1, T2));
+}
+
+#[test]
+fn params_are_listed_for_func_map() {
+    #[derive(TryFuncMap)]
+    #[funcmap(infallible_via_try, params(T))]
+    struct Test<S, T>(S, T);
+
+    assert_eq!(<Test<(), ()> as FuncMapParams>::PARAMS, [1]);
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
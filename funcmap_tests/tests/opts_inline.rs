@@ -0,0 +1,54 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+#[test]
+fn type_with_always_inline_hint_is_mapped() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(inline = "always")]
+    struct Test<T>(T);
+
+    let src = Test(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(T2));
+
+    let src = Test(T1);
+    let dst: Result<Test<T2>, TestError> = src.try_func_map(|_| Err(TestError));
+
+    assert_eq!(dst, Err(TestError));
+}
+
+#[test]
+fn type_with_inline_hint_and_free_fn_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(inline = "hint", free_fn = "map_test")]
+    struct Test<T>(T, Option<T>);
+
+    let src = Test(T1, Some(T1));
+    let dst = map_test(src, |_| T2);
+
+    assert_eq!(dst, Test(T2, Some(T2)));
+}
+
+#[test]
+fn type_with_never_inline_hint_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(inline = "never")]
+    enum Test<T> {
+        Some(T),
+        None,
+    }
+
+    let src = Test::Some(T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test::Some(T2));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
+
+#[derive(Debug, PartialEq)]
+struct TestError;
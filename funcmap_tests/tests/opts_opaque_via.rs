@@ -0,0 +1,92 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+#[test]
+fn field_is_mapped_via_function() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        #[funcmap(opaque_via = "map_opaque")]
+        opaque: OpaqueAlias<T>,
+        value: T,
+    }
+
+    let src = Test {
+        opaque: Opaque(T1),
+        value: T1,
+    };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test {
+            opaque: Opaque(T2),
+            value: T2,
+        }
+    );
+}
+
+#[test]
+fn fields_of_same_type_are_mapped_via_function() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    enum Test<T> {
+        First(#[funcmap(opaque_via = "map_opaque")] OpaqueAlias<T>),
+        Second(#[funcmap(opaque_via = "map_opaque")] OpaqueAlias<T>),
+    }
+
+    let src = Test::Second(Opaque(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test::Second(Opaque(T2)));
+}
+
+#[test]
+fn field_is_try_mapped_via_function() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(#[funcmap(opaque_via = "try_map_opaque")] OpaqueAlias<T>);
+
+    let src = Test(Opaque(1));
+
+    assert_eq!(
+        src.try_func_map(|v| Ok::<_, ()>(v + 1)),
+        Ok(Test(Opaque(2)))
+    );
+    assert_eq!(Test(Opaque(1)).try_func_map(|_| Err::<(), _>(42)), Err(42));
+}
+
+#[test]
+fn mapping_function_can_be_given_as_path() {
+    mod mapping {
+        pub(super) use super::map_opaque;
+    }
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(#[funcmap(opaque_via = "mapping::map_opaque")] OpaqueAlias<T>);
+
+    let src = Test(Opaque(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Opaque(T2)));
+}
+
+/// Stand-in for an opaque type, e.g. a type alias for `impl Trait`, which
+/// cannot be mapped over by the derive macros
+#[derive(Debug, PartialEq)]
+struct Opaque<T>(T);
+
+type OpaqueAlias<T> = Opaque<T>;
+
+fn map_opaque<A, B>(opaque: OpaqueAlias<A>, mut f: impl FnMut(A) -> B) -> OpaqueAlias<B> {
+    Opaque(f(opaque.0))
+}
+
+fn try_map_opaque<A, B, E>(
+    opaque: OpaqueAlias<A>,
+    mut f: impl FnMut(A) -> Result<B, E>,
+) -> Result<OpaqueAlias<B>, E> {
+    Ok(Opaque(f(opaque.0)?))
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
@@ -6,6 +6,7 @@ use funcmap::{FuncMap, TypeParam};
 fn generics_to_be_mapped_can_be_configured() {
     fn noop() {}
 
+    #[allow(unpredictable_function_pointer_comparisons)]
     #[derive(FuncMap, Debug, PartialEq)]
     #[funcmap(params(S, U))]
     struct Test<S, T, U> {
@@ -47,6 +48,105 @@ fn params_opt_accepts_trailing_comma() {
     struct Test<S, T>(S, T);
 }
 
+#[test]
+fn generics_to_be_skipped_can_be_configured() {
+    fn noop() {}
+
+    #[allow(unpredictable_function_pointer_comparisons)]
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(skip_params(T))]
+    struct Test<'a, S, T, U, const N: usize> {
+        value1: S,
+        not_mappable: fn() -> T,
+        value2: [U; N],
+        reference: &'a str,
+    }
+
+    let src = Test {
+        value1: T1,
+        not_mappable: noop,
+        value2: [T1],
+        reference: "a",
+    };
+    let dst = src
+        .func_map_over::<TypeParam<0>, _>(|_| T2)
+        .func_map_over::<TypeParam<2>, _>(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test {
+            value1: T2,
+            not_mappable: noop,
+            value2: [T2],
+            reference: "a",
+        }
+    );
+}
+
+#[test]
+fn generics_to_be_skipped_can_be_configured_across_opts() {
+    #[derive(FuncMap)]
+    #[funcmap(skip_params(S))]
+    #[funcmap(skip_params(U,))]
+    struct Test<S, T, U>(fn() -> S, T, fn() -> U);
+
+    fn noop() {}
+
+    let src = Test(noop as fn(), T1, noop as fn());
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst.1, T2);
+}
+
+#[test]
+fn opts_within_applying_cfg_attr_are_used() {
+    fn noop() {}
+
+    #[allow(unpredictable_function_pointer_comparisons)]
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[cfg_attr(all(), funcmap(params(T)))]
+    struct Test<S, T> {
+        not_mappable: fn() -> S,
+        value: T,
+    }
+
+    let src = Test {
+        not_mappable: noop,
+        value: T1,
+    };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test {
+            not_mappable: noop,
+            value: T2,
+        }
+    );
+}
+
+#[test]
+fn opts_within_nested_cfg_attr_with_multiple_attrs_are_used() {
+    #[derive(FuncMap)]
+    #[cfg_attr(all(), cfg_attr(all(), funcmap(params(S)), funcmap(params(U))))]
+    struct Test<S, T, U>(S, fn() -> T, U);
+}
+
+#[test]
+fn opts_within_non_applying_cfg_attr_are_ignored() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[cfg_attr(any(), funcmap(params(T)))]
+    #[cfg_attr(any(), funcmap(unknown))]
+    struct Test<S, T>(S, T);
+
+    let src = Test(T1, T1);
+    let dst = src
+        .func_map_over::<TypeParam<0>, _>(|_| T2)
+        .func_map_over::<TypeParam<1>, _>(|_| T2);
+
+    assert_eq!(dst, Test(T2, T2));
+}
+
 #[derive(Debug, PartialEq)]
 struct T1;
 
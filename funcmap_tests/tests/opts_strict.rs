@@ -0,0 +1,126 @@
+use funcmap::{FuncMap, FuncMapParams, TryFuncMap};
+
+use std::convert::Infallible;
+
+#[test]
+fn field_of_local_type_deriving_func_map_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Inner<T>(T);
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(strict)]
+    struct Test<T>(Inner<T>);
+
+    let src = Test(Inner(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Inner(T2)));
+}
+
+#[test]
+fn local_types_nested_within_field_types_are_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Inner<T>(T);
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(strict)]
+    enum Test<T> {
+        Vec(Vec<Inner<T>>),
+        Tuple((Inner<T>, Option<Inner<T>>)),
+        Array([Inner<T>; 1]),
+    }
+
+    assert_eq!(
+        Test::Vec(vec![Inner(T1)]).func_map(|_| T2),
+        Test::Vec(vec![Inner(T2)])
+    );
+    assert_eq!(
+        Test::Tuple((Inner(T1), Some(Inner(T1)))).func_map(|_| T2),
+        Test::Tuple((Inner(T2), Some(Inner(T2))))
+    );
+    assert_eq!(
+        Test::Array([Inner(T1)]).func_map(|_| T2),
+        Test::Array([Inner(T2)])
+    );
+}
+
+#[test]
+fn local_type_given_by_crate_path_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(strict)]
+    struct Test<T>(crate::Outer<T>);
+
+    let src = Test(Outer(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Outer(T2)));
+}
+
+#[test]
+fn local_type_with_manual_impl_and_params_impl_is_mapped() {
+    #[derive(Debug, PartialEq)]
+    struct Inner<T>(T);
+
+    impl<A, B> FuncMap<A, B> for Inner<A> {
+        type Output = Inner<B>;
+
+        fn func_map<F>(self, mut f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            Inner(f(self.0))
+        }
+    }
+
+    impl<T> FuncMapParams for Inner<T> {
+        const PARAMS: &'static [usize] = &[0];
+    }
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(strict)]
+    struct Test<T>(Inner<T>);
+
+    let src = Test(Inner(T1));
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Inner(T2)));
+}
+
+#[test]
+fn local_type_not_depending_on_mapped_param_is_not_checked() {
+    #[derive(Debug, PartialEq)]
+    struct Unmapped<T>(T);
+
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(strict, params(T))]
+    struct Test<S, T>(Unmapped<S>, T);
+
+    let src = Test(Unmapped(1), T1);
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(dst, Test(Unmapped(1), T2));
+}
+
+#[test]
+fn field_of_local_type_deriving_try_func_map_is_mapped() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Inner<T>(T);
+
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(strict)]
+    struct Test<T>(Inner<T>);
+
+    let src = Test(Inner(T1));
+    let dst: Result<_, Infallible> = src.try_func_map(|_| Ok(T2));
+
+    assert_eq!(dst, Ok(Test(Inner(T2))));
+}
+
+#[derive(FuncMap, Debug, PartialEq)]
+struct Outer<T>(T);
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
@@ -0,0 +1,123 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+#[test]
+fn validate_is_applied_by_func_map() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(validate = "sorted", extra_bounds = "B: Ord")]
+    struct Test<T> {
+        values: Vec<T>,
+    }
+
+    fn sorted<T: Ord, E>(mut value: Test<T>) -> Result<Test<T>, E> {
+        value.values.sort();
+        Ok(value)
+    }
+
+    let src = Test {
+        values: vec![3, 1, 2],
+    };
+    let dst = src.func_map(|v| -v);
+
+    assert_eq!(
+        dst,
+        Test {
+            values: vec![-3, -2, -1]
+        }
+    );
+}
+
+#[test]
+fn validate_is_applied_by_try_func_map() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(validate = "sorted", extra_bounds = "B: Ord")]
+    struct Test<T> {
+        values: Vec<T>,
+    }
+
+    fn sorted<T: Ord, E>(mut value: Test<T>) -> Result<Test<T>, E> {
+        value.values.sort();
+        Ok(value)
+    }
+
+    let src = Test {
+        values: vec!["3", "1", "2"],
+    };
+    let dst = src.try_func_map(|v| v.parse::<i32>());
+
+    assert_eq!(
+        dst,
+        Ok(Test {
+            values: vec![1, 2, 3]
+        })
+    );
+}
+
+#[test]
+fn validate_is_not_called_if_mapping_fails() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(validate = "unreachable")]
+    struct Test<T>(T, T);
+
+    fn unreachable<T, E>(_: Test<T>) -> Result<Test<T>, E> {
+        panic!("validation function called");
+    }
+
+    let dst: Result<Test<T2>, _> = Test(T1, T1).try_func_map(|_| Err(()));
+
+    assert_eq!(dst, Err(()));
+}
+
+#[test]
+fn validate_is_applied_to_every_variant() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(validate = "normalize")]
+    enum Test<T> {
+        Empty,
+        Values(Vec<T>),
+    }
+
+    fn normalize<T, E>(value: Test<T>) -> Result<Test<T>, E> {
+        Ok(match value {
+            Test::Values(values) if values.is_empty() => Test::Empty,
+            value => value,
+        })
+    }
+
+    assert_eq!(Test::<T1>::Empty.func_map(|_| T2), Test::Empty);
+    assert_eq!(Test::Values(Vec::<T1>::new()).func_map(|_| T2), Test::Empty);
+    assert_eq!(
+        Test::Values(vec![T1]).func_map(|_| T2),
+        Test::Values(vec![T2])
+    );
+}
+
+#[test]
+fn validate_is_applied_by_free_fn() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(
+        free_fn = "map_test",
+        validate = "deduped",
+        extra_bounds = "B: PartialEq"
+    )]
+    struct Test<T> {
+        values: Vec<T>,
+    }
+
+    fn deduped<T: PartialEq, E>(mut value: Test<T>) -> Result<Test<T>, E> {
+        value.values.dedup();
+        Ok(value)
+    }
+
+    let src = Test {
+        values: vec![1, 2, 3],
+    };
+    let dst = map_test(src, |v| v / 2);
+
+    assert_eq!(dst, Test { values: vec![0, 1] });
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
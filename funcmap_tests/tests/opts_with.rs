@@ -0,0 +1,169 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+mod sorted {
+    use funcmap::{FuncMap, TryFuncMap};
+
+    /// Vector that is always sorted, so its field must not be accessed directly
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(
+        with = "Self::into_parts",
+        rebuild = "Self::from_parts",
+        extra_bounds = "B: Ord"
+    )]
+    pub struct SortedVec<T> {
+        values: Vec<T>,
+    }
+
+    impl<T> SortedVec<T>
+    where
+        T: Ord,
+    {
+        pub fn new(mut values: Vec<T>) -> Self {
+            values.sort();
+            Self { values }
+        }
+
+        fn from_parts((values,): (Vec<T>,)) -> Self {
+            Self::new(values)
+        }
+    }
+
+    impl<T> SortedVec<T> {
+        pub fn as_slice(&self) -> &[T] {
+            &self.values
+        }
+
+        fn into_parts(self) -> (Vec<T>,) {
+            (self.values,)
+        }
+    }
+}
+
+use sorted::SortedVec;
+
+#[test]
+fn with_and_rebuild_are_applied_by_func_map() {
+    let src = SortedVec::new(vec![3, 1, 2]);
+    let dst = src.func_map(|v| -v);
+
+    assert_eq!(dst.as_slice(), [-3, -2, -1]);
+}
+
+#[test]
+fn with_and_rebuild_are_applied_by_try_func_map() {
+    let src = SortedVec::new(vec!["3", "10", "2"]);
+    let dst = src.try_func_map(|v| v.parse::<i32>());
+
+    assert_eq!(dst.unwrap().as_slice(), [2, 3, 10]);
+}
+
+#[test]
+fn try_func_map_with_rebuild_fails_if_closure_fails() {
+    let src = SortedVec::new(vec!["1", "x"]);
+    let dst = src.try_func_map(|v| v.parse::<i32>());
+
+    assert!(dst.is_err());
+}
+
+#[test]
+fn with_and_rebuild_can_be_free_functions() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(with = "into_parts", rebuild = "from_parts")]
+    struct Test<S, T>(S, T, i32);
+
+    fn into_parts<S, T>(Test(s, t, n): Test<S, T>) -> (S, T, i32) {
+        (s, t, n + 1)
+    }
+
+    fn from_parts<S, T>((s, t, n): (S, T, i32)) -> Test<S, T> {
+        Test(s, t, n * 10)
+    }
+
+    let src = Test(T1, T1, 1);
+    let dst = FuncMap::<_, _, funcmap::TypeParam<1>>::func_map(src, |_| T2);
+
+    assert_eq!(dst, Test(T1, T2, 20));
+}
+
+#[test]
+fn with_and_rebuild_are_combined_with_validate() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(
+        with = "Self::into_parts",
+        rebuild = "Self::from_parts",
+        validate = "validate"
+    )]
+    struct Test<T> {
+        value: T,
+        rebuilt: bool,
+        validated: bool,
+    }
+
+    impl<T> Test<T> {
+        fn into_parts(self) -> (T, bool, bool) {
+            (self.value, self.rebuilt, self.validated)
+        }
+
+        fn from_parts((value, _, validated): (T, bool, bool)) -> Self {
+            Self {
+                value,
+                rebuilt: true,
+                validated,
+            }
+        }
+    }
+
+    fn validate<T, E>(value: Test<T>) -> Result<Test<T>, E> {
+        Ok(Test {
+            validated: value.rebuilt,
+            ..value
+        })
+    }
+
+    let src = Test {
+        value: T1,
+        rebuilt: false,
+        validated: false,
+    };
+    let dst = src.func_map(|_| T2);
+
+    assert_eq!(
+        dst,
+        Test {
+            value: T2,
+            rebuilt: true,
+            validated: true,
+        }
+    );
+}
+
+#[test]
+fn with_and_rebuild_are_applied_by_free_fn() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    #[funcmap(
+        with = "Self::into_parts",
+        rebuild = "Self::from_parts",
+        free_fn = "map_test"
+    )]
+    struct Test<T>(T, usize);
+
+    impl<T> Test<T> {
+        fn into_parts(self) -> (T, usize) {
+            (self.0, self.1)
+        }
+
+        fn from_parts((value, count): (T, usize)) -> Self {
+            Self(value, count + 1)
+        }
+    }
+
+    let dst = map_test(Test(T1, 0), |_| T2);
+
+    assert_eq!(dst, Test(T2, 1));
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
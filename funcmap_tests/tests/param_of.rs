@@ -0,0 +1,96 @@
+use funcmap::{param_of, FuncMap, TypeParam};
+
+#[test]
+fn param_of_refers_to_position_among_generic_args() {
+    fn assert_marker<P: 'static, Q: 'static>() {
+        assert_eq!(std::any::TypeId::of::<P>(), std::any::TypeId::of::<Q>());
+    }
+
+    assert_marker::<param_of!(A in Foo<A>), TypeParam<0>>();
+    assert_marker::<param_of!(A in Foo<S, A>), TypeParam<1>>();
+    assert_marker::<param_of!(A in Foo<S, A,>), TypeParam<1>>();
+    assert_marker::<param_of!(A in Foo<'a, S, A>), TypeParam<1>>();
+    assert_marker::<param_of!(A in Foo<'a, 'b, A, S>), TypeParam<0>>();
+    assert_marker::<param_of!(A in Foo<Vec<A>, A>), TypeParam<1>>();
+    assert_marker::<param_of!(A in Foo<S, Vec<A>, A>), TypeParam<2>>();
+    assert_marker::<param_of!(A in Foo<3, { 1 + 2 }, N, A>), TypeParam<3>>();
+    assert_marker::<param_of!(A in foo::Foo<S, A>), TypeParam<1>>();
+    assert_marker::<param_of!(A in ::foo::Foo<S, A>), TypeParam<1>>();
+}
+
+#[test]
+fn param_of_routes_mapping_of_nested_type_with_swapped_params() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Inner<S, T>(S, T);
+
+    #[derive(Debug, PartialEq)]
+    struct Outer<S, T> {
+        inner: Inner<T, S>,
+    }
+
+    impl<S, A, B> FuncMap<A, B, TypeParam<0>> for Outer<A, S> {
+        type Output = Outer<B, S>;
+
+        fn func_map<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            Outer {
+                inner: self
+                    .inner
+                    .func_map_over::<param_of!(A in Inner<S, A>), _>(f),
+            }
+        }
+    }
+
+    impl<S, A, B> FuncMap<A, B, TypeParam<1>> for Outer<S, A> {
+        type Output = Outer<S, B>;
+
+        fn func_map<F>(self, f: F) -> Self::Output
+        where
+            F: FnMut(A) -> B,
+        {
+            Outer {
+                inner: self
+                    .inner
+                    .func_map_over::<param_of!(A in Inner<A, S>), _>(f),
+            }
+        }
+    }
+
+    let src = Outer { inner: Inner(1, 2) };
+    let dst = src
+        .func_map_over::<TypeParam<0>, _>(|x| x * 10)
+        .func_map_over::<TypeParam<1>, _>(|x| x * 100);
+
+    assert_eq!(
+        dst,
+        Outer {
+            inner: Inner(100, 20)
+        }
+    );
+}
+
+#[test]
+fn param_of_refers_to_named_type_param() {
+    #[derive(FuncMap)]
+    struct Test<'a, const N: usize, S, T>([S; N], &'a str, T);
+
+    fn assert_marker<P: 'static, Q: 'static>() {
+        assert_eq!(std::any::TypeId::of::<P>(), std::any::TypeId::of::<Q>());
+    }
+
+    assert_marker::<param_of!(Test<'static, 1, (), ()>, S), TypeParam<1>>();
+    assert_marker::<param_of!(Test<'static, 1, (), ()>, T), TypeParam<2>>();
+}
+
+#[test]
+fn param_of_named_type_param_routes_mapping() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(1, 2);
+    let dst = src.func_map_over::<param_of!(Test<i32, i32>, S), _>(|x| x * 10);
+
+    assert_eq!(dst, Test(10, 2));
+}
@@ -0,0 +1,69 @@
+use funcmap::{FuncMap, TryFuncMapPartition, TypeParam};
+
+#[test]
+fn successes_and_failures_are_partitioned() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+        other: i32,
+    }
+
+    let src = Test {
+        value: 1,
+        values: vec![-2, 3, -4],
+        other: 42,
+    };
+    let (dst, errors) = src.try_func_map_partition(|v| if v > 0 { Ok(v) } else { Err(v) });
+
+    assert_eq!(errors, [-2, -4]);
+    assert_eq!(
+        dst,
+        Test {
+            value: Some(1),
+            values: vec![None, Some(3), None],
+            other: 42,
+        }
+    );
+}
+
+#[test]
+fn closure_is_invoked_for_every_value_in_order() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, T, T);
+
+    let mut visited = Vec::new();
+    let (dst, errors) = Test("a", "b", "c").try_func_map_partition(|v| {
+        visited.push(v);
+        Err::<(), _>(v.len())
+    });
+
+    assert_eq!(visited, ["a", "b", "c"]);
+    assert_eq!(errors, [1, 1, 1]);
+    assert_eq!(dst, Test(None, None, None));
+}
+
+#[test]
+fn no_errors_are_collected_when_closure_succeeds() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(Vec<T>);
+
+    let (dst, errors) = Test(vec![1, 2]).try_func_map_partition(|v| Ok::<_, ()>(v * 10));
+
+    assert!(errors.is_empty());
+    assert_eq!(dst, Test(vec![Some(10), Some(20)]));
+}
+
+#[test]
+fn partition_works_over_specific_type_param() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let (dst, errors) = TryFuncMapPartition::<_, _, TypeParam<1>>::try_func_map_partition(
+        Test("a", "x"),
+        |v: &str| v.parse::<i32>().map_err(|_| v),
+    );
+
+    assert_eq!(errors, ["x"]);
+    assert_eq!(dst, Test("a", None));
+}
@@ -0,0 +1,210 @@
+use funcmap::{RefFuncMap, TypeParam};
+
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::marker::PhantomData;
+use std::ops::Bound;
+
+#[test]
+fn core_types_are_mapped_by_reference() {
+    assert_eq!([1, 2].ref_func_map(|v| v + 1), [2, 3]);
+    assert_eq!(
+        Bound::Excluded(1).ref_func_map(|v| v + 1),
+        Bound::Excluded(2)
+    );
+    assert_eq!(Some(1).ref_func_map(|v| v + 1), Some(2));
+    assert_eq!(
+        PhantomData::<i32>.ref_func_map(|v: &i32| v + 1),
+        PhantomData::<i32>
+    );
+
+    let ok = Ok::<i32, String>(1);
+    assert_eq!(
+        RefFuncMap::<_, _, TypeParam<0>>::ref_func_map(&ok, |v| v + 1),
+        Ok(2)
+    );
+
+    let err = Err::<String, i32>(1);
+    assert_eq!(
+        RefFuncMap::<_, _, TypeParam<1>>::ref_func_map(&err, |v| v + 1),
+        Err(2)
+    );
+}
+
+#[test]
+fn collections_are_mapped_by_reference() {
+    assert_eq!(Box::new(1).ref_func_map(|v| v + 1), Box::new(2));
+    assert_eq!(vec![1, 2].ref_func_map(|v| v + 1), [2, 3]);
+    assert_eq!(
+        VecDeque::from(vec![1, 2]).ref_func_map(|v| v + 1),
+        VecDeque::from(vec![2, 3])
+    );
+    assert_eq!(
+        LinkedList::from([1, 2]).ref_func_map(|v| v + 1),
+        LinkedList::from([2, 3])
+    );
+    assert_eq!(
+        BinaryHeap::from(vec![1, 2])
+            .ref_func_map(|v| v + 1)
+            .into_sorted_vec(),
+        [2, 3]
+    );
+    assert_eq!(
+        BTreeSet::from([1, 2]).ref_func_map(|v| v + 1),
+        BTreeSet::from([2, 3])
+    );
+    assert_eq!(
+        HashSet::from([1, 2]).ref_func_map(|v| v + 1),
+        HashSet::from([2, 3])
+    );
+
+    let btree_map = BTreeMap::from([(1, 10), (2, 20)]);
+    assert_eq!(
+        RefFuncMap::<_, _, TypeParam<0>>::ref_func_map(&btree_map, |k| k + 1),
+        BTreeMap::from([(2, 10), (3, 20)])
+    );
+    assert_eq!(
+        RefFuncMap::<_, _, TypeParam<1>>::ref_func_map(&btree_map, |v| v + 1),
+        BTreeMap::from([(1, 11), (2, 21)])
+    );
+
+    let hash_map = HashMap::from([(1, 10), (2, 20)]);
+    assert_eq!(
+        RefFuncMap::<_, _, TypeParam<0>>::ref_func_map(&hash_map, |k| k + 1),
+        HashMap::from([(2, 10), (3, 20)])
+    );
+    assert_eq!(
+        RefFuncMap::<_, _, TypeParam<1>>::ref_func_map(&hash_map, |v| v + 1),
+        HashMap::from([(1, 11), (2, 21)])
+    );
+}
+
+#[test]
+fn output_can_borrow_from_source() {
+    let names = vec![String::from("a"), String::from("bc")];
+    let borrowed: Vec<&str> = names.ref_func_map(String::as_str);
+
+    assert_eq!(borrowed, ["a", "bc"]);
+    assert_eq!(names, ["a", "bc"]);
+}
+
+#[test]
+fn fields_are_mapped_or_cloned() {
+    #[derive(RefFuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        name: String,
+        value: T,
+        nested: Vec<Option<T>>,
+        tuple: (T, i32),
+        array: [T; 2],
+        boxed: Box<[Option<T>; 1]>,
+    }
+
+    let src = Test {
+        name: String::from("test"),
+        value: String::from("a"),
+        nested: vec![Some(String::from("b")), None],
+        tuple: (String::from("c"), 1),
+        array: [String::from("d"), String::from("e")],
+        boxed: Box::new([Some(String::from("f"))]),
+    };
+    let dst: Test<&str> = src.ref_func_map(String::as_str);
+
+    assert_eq!(
+        dst,
+        Test {
+            name: String::from("test"),
+            value: "a",
+            nested: vec![Some("b"), None],
+            tuple: ("c", 1),
+            array: ["d", "e"],
+            boxed: Box::new([Some("f")]),
+        }
+    );
+}
+
+#[test]
+fn values_are_visited_in_mapping_order() {
+    #[derive(RefFuncMap, Debug)]
+    struct Test<T>(T, Vec<T>, (T, T));
+
+    let src = Test(1, vec![2, 3], (4, 5));
+    let mut values = Vec::new();
+    let _ = src.ref_func_map(|v| values.push(*v));
+
+    assert_eq!(values, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn enum_variants_are_mapped() {
+    #[derive(RefFuncMap, Debug, PartialEq)]
+    enum Test<T> {
+        Single(T),
+        Many { values: Vec<T>, name: &'static str },
+        Empty,
+    }
+
+    assert_eq!(Test::Single(1).ref_func_map(|v| v + 1), Test::Single(2));
+    assert_eq!(
+        Test::Many {
+            values: vec![1, 2],
+            name: "many"
+        }
+        .ref_func_map(|v| v + 1),
+        Test::Many {
+            values: vec![2, 3],
+            name: "many"
+        }
+    );
+    assert_eq!(Test::<i32>::Empty.ref_func_map(|v| v + 1), Test::Empty);
+}
+
+#[test]
+fn multiple_type_params_are_mapped_separately() {
+    #[derive(RefFuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, Vec<T>, Result<S, String>);
+
+    let src = Test(1, vec![2, 3], Ok(4));
+
+    assert_eq!(
+        RefFuncMap::<_, _, TypeParam<0>>::ref_func_map(&src, |v| v + 1),
+        Test(2, vec![2, 3], Ok(5))
+    );
+    assert_eq!(
+        RefFuncMap::<_, _, TypeParam<1>>::ref_func_map(&src, |v| v + 1),
+        Test(1, vec![3, 4], Ok(4))
+    );
+}
+
+#[test]
+fn field_types_of_other_params_are_cloned() {
+    #[derive(RefFuncMap, Debug, PartialEq)]
+    #[funcmap(params(T))]
+    struct Test<S, T>(S, T);
+
+    let src = Test(String::from("s"), 1);
+
+    assert_eq!(src.ref_func_map(|v| v + 1), Test(String::from("s"), 2));
+}
+
+#[test]
+fn phantom_data_is_mapped_without_closure() {
+    #[derive(RefFuncMap, Debug, PartialEq)]
+    struct Test<T>(PhantomData<fn() -> T>, i32);
+
+    let src = Test::<u8>(PhantomData, 1);
+    let dst: Test<u16> = src.ref_func_map(|v| u16::from(*v));
+
+    assert_eq!(dst, Test(PhantomData, 1));
+}
+
+#[test]
+fn lifetime_params_and_conflicting_idents_are_supported() {
+    #[derive(RefFuncMap, Debug, PartialEq)]
+    #[funcmap(params(A))]
+    struct Test<'a, A, B>(&'a str, Option<A>, B);
+
+    let src = Test("x", Some(1), 'b');
+    let dst: Test<'_, i64, char> = src.ref_func_map(|v| i64::from(*v));
+
+    assert_eq!(dst, Test("x", Some(1), 'b'));
+}
@@ -0,0 +1,47 @@
+use funcmap::{FuncMap, FuncMapParams, TryFuncMap, TryFuncMapParams};
+
+#[test]
+fn params_of_single_param_type_are_listed() {
+    #[derive(FuncMap, TryFuncMap)]
+    struct Test<T>(T);
+
+    assert_eq!(<Test<()> as FuncMapParams>::PARAMS, [0]);
+    assert_eq!(<Test<()> as TryFuncMapParams>::PARAMS, [0]);
+}
+
+#[test]
+fn params_of_multi_param_type_are_listed() {
+    #[derive(FuncMap, TryFuncMap)]
+    struct Test<S, T, U>(S, T, U);
+
+    assert_eq!(<Test<(), (), ()> as FuncMapParams>::PARAMS, [0, 1, 2]);
+    assert_eq!(<Test<(), (), ()> as TryFuncMapParams>::PARAMS, [0, 1, 2]);
+}
+
+#[test]
+fn only_configured_params_are_listed() {
+    #[derive(FuncMap)]
+    #[funcmap(params(S, U))]
+    struct Test<S, T, U>(S, T, U);
+
+    assert_eq!(<Test<(), (), ()> as FuncMapParams>::PARAMS, [0, 2]);
+}
+
+#[test]
+fn lifetime_params_are_not_counted() {
+    #[derive(FuncMap)]
+    #[funcmap(params(T))]
+    struct Test<'a, S, T, const N: usize>(&'a S, [T; N]);
+
+    assert_eq!(<Test<'_, (), (), 1> as FuncMapParams>::PARAMS, [1]);
+}
+
+#[test]
+fn params_are_available_in_const_context() {
+    #[derive(FuncMap)]
+    struct Test<S, T>(S, T);
+
+    const COUNT: usize = <Test<(), ()> as FuncMapParams>::PARAMS.len();
+
+    assert_eq!(COUNT, 2);
+}
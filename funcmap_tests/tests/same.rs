@@ -0,0 +1,87 @@
+use funcmap::{FuncMap, FuncMapSame, TypeParam};
+
+#[test]
+fn field_of_generic_param_type_is_mapped_to_same_type() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, i32);
+
+    let src = Test(1, 42);
+    let dst = src.func_map_same(|v| v + 1);
+
+    assert_eq!(dst, Test(2, 42));
+}
+
+#[test]
+fn nested_fields_are_mapped_to_same_type() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<Option<T>>,
+    }
+
+    let src = Test {
+        value: 1,
+        values: vec![Some(2), None],
+    };
+    let dst = src.func_map_same(|v| v * 10);
+
+    assert_eq!(
+        dst,
+        Test {
+            value: 10,
+            values: vec![Some(20), None],
+        }
+    );
+}
+
+#[test]
+fn type_param_to_map_to_same_type_can_be_specified() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(1, 2);
+    let dst = FuncMapSame::<_, TypeParam<1>>::func_map_same(src, |v| v + 1);
+
+    assert_eq!(dst, Test(1, 3));
+}
+
+#[test]
+fn foreign_type_is_mapped_to_same_type() {
+    let src = vec![1, 2];
+    let dst = src.func_map_same(|v| v + 1);
+
+    assert_eq!(dst, vec![2, 3]);
+}
+
+#[test]
+fn derived_output_is_self_for_same_type() {
+    #[derive(FuncMap)]
+    struct Struct<T>(T);
+
+    #[derive(FuncMap)]
+    enum Enum<T> {
+        Unit,
+        Value(T),
+    }
+
+    #[derive(FuncMap)]
+    struct MultiParam<'a, S, const N: usize, T>(&'a (), S, [T; N]);
+
+    fn assert_output_is_self<T, A, P>()
+    where
+        T: FuncMap<A, A, P, Output = T>,
+        P: funcmap::FuncMarker<P>,
+    {
+    }
+
+    assert_output_is_self::<Struct<T1>, T1, TypeParam<0>>();
+    assert_output_is_self::<Enum<T1>, T1, TypeParam<0>>();
+    assert_output_is_self::<MultiParam<T1, 1, T2>, T1, TypeParam<0>>();
+    assert_output_is_self::<MultiParam<T1, 1, T2>, T2, TypeParam<2>>();
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
+
+#[derive(Debug, PartialEq)]
+struct T2;
@@ -39,6 +39,46 @@ fn field_of_nested_generic_type_is_mapped() {
     assert_eq!(dst, Test(Inner(Inner(T2))));
 }
 
+#[test]
+fn field_of_deeply_nested_std_container_type_is_mapped() {
+    use funcmap::TryFuncMap;
+    use std::collections::BTreeMap;
+
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(
+        std::vec::Vec<BTreeMap<u8, std::vec::Vec<std::option::Option<std::boxed::Box<T>>>>>,
+        std::boxed::Box<std::option::Option<std::vec::Vec<T>>>,
+    );
+
+    // the derived implementations are supposed to require no bounds on `T`, as
+    // `Vec`, `Option`, `Box` and `BTreeMap` with unmapped keys can always be
+    // mapped, and they omit the bounds for `Vec`, `Option` and `Box` entirely
+    // as they are referred to through fully qualified paths
+    fn map<S, T>(value: Test<S>, f: impl FnMut(S) -> T) -> Test<T> {
+        value.func_map(f)
+    }
+
+    fn try_map<S, T, E>(value: Test<S>, f: impl FnMut(S) -> Result<T, E>) -> Result<Test<T>, E> {
+        value.try_func_map(f)
+    }
+
+    let src = || {
+        Test(
+            vec![BTreeMap::from([(1, vec![Some(Box::new(T1)), None])])],
+            Box::new(Some(vec![T1])),
+        )
+    };
+
+    assert_eq!(
+        map(src(), |_| T2),
+        Test(
+            vec![BTreeMap::from([(1, vec![Some(Box::new(T2)), None])])],
+            Box::new(Some(vec![T2])),
+        )
+    );
+    assert_eq!(try_map(src(), |_| Err::<T2, _>(())), Err(()));
+}
+
 #[test]
 fn field_of_non_generic_type_is_not_mapped() {
     #[derive(FuncMap, Debug, PartialEq)]
@@ -0,0 +1,176 @@
+use funcmap::StructuralFrom;
+
+#[test]
+fn struct_is_converted() {
+    struct Source<T> {
+        value: T,
+        values: Vec<T>,
+        other: i32,
+    }
+
+    #[derive(StructuralFrom, Debug, PartialEq)]
+    #[funcmap(from = "Source")]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+        other: i32,
+    }
+
+    let src = Source {
+        value: T1,
+        values: vec![T1],
+        other: 42,
+    };
+    let dst = Test::from(src);
+
+    assert_eq!(
+        dst,
+        Test {
+            value: T1,
+            values: vec![T1],
+            other: 42,
+        }
+    );
+}
+
+#[test]
+fn tuple_struct_is_converted() {
+    struct Source<T>(T, i32);
+
+    #[derive(StructuralFrom, Debug, PartialEq)]
+    #[funcmap(from = "Source")]
+    struct Test<T>(T, i32);
+
+    let dst: Test<T1> = Source(T1, 42).into();
+
+    assert_eq!(dst, Test(T1, 42));
+}
+
+#[test]
+fn enum_is_converted() {
+    enum Source<T> {
+        Unit,
+        Tuple(T),
+        Named { value: T },
+    }
+
+    #[derive(StructuralFrom, Debug, PartialEq)]
+    #[funcmap(from = "Source")]
+    enum Test<T> {
+        Unit,
+        Tuple(T),
+        Named { value: T },
+    }
+
+    assert_eq!(Test::from(Source::<T1>::Unit), Test::Unit);
+    assert_eq!(Test::from(Source::Tuple(T1)), Test::Tuple(T1));
+    assert_eq!(
+        Test::from(Source::Named { value: T1 }),
+        Test::Named { value: T1 }
+    );
+}
+
+#[test]
+fn source_type_can_have_path() {
+    mod v1 {
+        pub(super) struct Source<T>(pub(super) T);
+    }
+
+    #[derive(StructuralFrom, Debug, PartialEq)]
+    #[funcmap(from = "v1::Source")]
+    struct Test<T>(T);
+
+    assert_eq!(Test::from(v1::Source(T1)), Test(T1));
+}
+
+#[test]
+fn multiple_source_types_are_supported() {
+    struct Source1<T>(T);
+    struct Source2<T>(T);
+
+    #[derive(StructuralFrom, Debug, PartialEq)]
+    #[funcmap(from = "Source1", from = "Source2")]
+    struct Test<T>(T);
+
+    assert_eq!(Test::from(Source1(T1)), Test(T1));
+    assert_eq!(Test::from(Source2(T1)), Test(T1));
+}
+
+#[test]
+fn lifetimes_const_generics_and_bounds_are_supported() {
+    struct Source<'a, T: Clone, const N: usize>(&'a str, [T; N])
+    where
+        T: Default;
+
+    #[derive(StructuralFrom, Debug, PartialEq)]
+    #[funcmap(from = "Source")]
+    struct Test<'a, T: Clone, const N: usize>(&'a str, [T; N])
+    where
+        T: Default;
+
+    assert_eq!(Test::from(Source("a", [1, 2])), Test("a", [1, 2]));
+}
+
+#[test]
+fn fields_are_converted_via_into() {
+    mod v1 {
+        pub(super) struct Inner<T>(pub(super) T);
+
+        pub(super) enum Source<T> {
+            Tuple(Inner<T>, u8),
+            Named { inner: Inner<T>, values: Vec<T> },
+        }
+    }
+
+    #[derive(StructuralFrom, Debug, PartialEq)]
+    #[funcmap(from = "v1::Inner")]
+    struct Inner<T>(T);
+
+    #[derive(StructuralFrom, Debug, PartialEq)]
+    #[funcmap(from = "v1::Source")]
+    enum Test<T> {
+        Tuple(Inner<T>, u32),
+        Named { inner: Inner<T>, values: Vec<T> },
+    }
+
+    assert_eq!(
+        Test::from(v1::Source::Tuple(v1::Inner(T1), 42)),
+        Test::Tuple(Inner(T1), 42)
+    );
+    assert_eq!(
+        Test::from(v1::Source::Named {
+            inner: v1::Inner(T1),
+            values: vec![T1],
+        }),
+        Test::Named {
+            inner: Inner(T1),
+            values: vec![T1],
+        }
+    );
+}
+
+#[test]
+fn conversions_of_fields_can_rely_on_bounds_of_target_type() {
+    struct Source<T>(T);
+
+    struct Wrapper<T>(T);
+
+    impl<T: Clone> From<Source<T>> for Wrapper<T> {
+        fn from(source: Source<T>) -> Self {
+            Self(source.0.clone())
+        }
+    }
+
+    struct Outer<T>(Source<T>);
+
+    #[derive(StructuralFrom)]
+    #[funcmap(from = "Outer")]
+    struct Test<T: Clone>(Wrapper<T>);
+
+    let Test(Wrapper(value)) = Test::from(Outer(Source(42)));
+
+    assert_eq!(value, 42);
+}
+
+#[derive(Debug, PartialEq)]
+struct T1;
@@ -0,0 +1,84 @@
+use funcmap::tagged::Tagged;
+use funcmap::{FuncMap, TryFuncMap};
+
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+#[test]
+fn value_is_mapped_keeping_tag() {
+    let src: Tagged<_, Tag> = Tagged::new(1);
+    let dst: Tagged<T1<i32>, Tag> = src.func_map(T1);
+
+    assert_eq!(dst, Tagged::new(T1(1)));
+}
+
+#[test]
+fn value_is_try_mapped_keeping_tag() {
+    let src: Tagged<_, Tag> = Tagged::new(-1);
+    let dst: Result<Tagged<u8, Tag>, i32> =
+        src.try_func_map(|v| if v > 0 { Ok(1) } else { Err(v) });
+
+    assert_eq!(dst, Err(-1));
+}
+
+#[test]
+fn traits_are_implemented_without_bounds_on_tag() {
+    let a: Tagged<_, Tag> = Tagged::new(1);
+    let b = a;
+
+    assert_eq!(a, b);
+    assert!(a < Tagged::new(2));
+    assert_eq!(format!("{:?}", a), "Tagged(1)");
+    assert_eq!(Tagged::<i32, Tag>::default(), Tagged::new(0));
+    assert_eq!(vec![a, b].into_iter().collect::<HashSet<_>>().len(), 1);
+}
+
+#[test]
+fn tag_is_replaced() {
+    let src: Tagged<_, Tag> = Tagged::new(1);
+    let dst: Tagged<_, OtherTag> = src.retag();
+
+    assert_eq!(dst.into_inner(), 1);
+}
+
+#[test]
+fn tagged_values_are_mapped_within_derived_type() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        tagged: Tagged<T, Tag>,
+        other_tagged: Tagged<T, OtherTag>,
+    }
+
+    let src = Test {
+        tagged: Tagged::new(1),
+        other_tagged: Tagged::new(2),
+    };
+    let dst = src.func_map(T1);
+
+    assert_eq!(
+        dst,
+        Test {
+            tagged: Tagged::new(T1(1)),
+            other_tagged: Tagged::new(T1(2)),
+        }
+    );
+}
+
+#[test]
+fn tag_of_user_defined_equivalent_is_not_mapped() {
+    #[derive(FuncMap, TryFuncMap)]
+    #[funcmap(params(T))]
+    struct Test<T, U>(T, PhantomData<U>);
+
+    let src: Test<_, Tag> = Test(1, PhantomData);
+    let dst: Test<T1<i32>, Tag> = src.func_map(T1);
+
+    assert_eq!(dst.0, T1(1));
+}
+
+enum Tag {}
+
+enum OtherTag {}
+
+#[derive(Debug, PartialEq)]
+struct T1<T>(T);
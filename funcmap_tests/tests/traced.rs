@@ -0,0 +1,248 @@
+use funcmap::{traced, FuncMap, TryFuncMap};
+
+use std::fmt::{self, Debug, Display, Formatter};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[test]
+fn error_is_reported_within_spans_of_fields_leading_to_it() {
+    #[derive(TryFuncMap, Debug)]
+    #[funcmap(traced)]
+    struct Outer<T> {
+        inner: Inner<T>,
+    }
+
+    #[derive(TryFuncMap, Debug)]
+    #[funcmap(traced)]
+    enum Inner<T> {
+        Values { values: Vec<T> },
+    }
+
+    let src = Outer {
+        inner: Inner::Values {
+            values: vec![1, -1],
+        },
+    };
+
+    let (result, events) = capture(|| src.try_func_map(traced(positive)));
+
+    assert_eq!(result.unwrap_err(), NotPositive(-1));
+    assert_eq!(
+        events,
+        [CapturedEvent {
+            error: "-1 is not positive".into(),
+            paths: vec!["Outer.inner".into(), "Inner::Values.values".into()],
+        }]
+    );
+}
+
+#[test]
+fn no_event_is_emitted_on_success() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    #[funcmap(traced)]
+    struct Test<T>(T, Vec<T>);
+
+    let (result, events) = capture(|| Test(1, vec![2]).try_func_map(traced(positive)));
+
+    assert_eq!(result, Ok(Test(1, vec![2])));
+    assert_eq!(events, []);
+}
+
+#[test]
+fn tuple_struct_fields_are_identified_by_index() {
+    #[derive(TryFuncMap, Debug)]
+    #[funcmap(traced)]
+    struct Test<T>(T, T);
+
+    let (_, events) = capture(|| Test(1, -1).try_func_map(traced(positive)));
+
+    assert_eq!(events[0].paths, ["Test.1"]);
+}
+
+#[test]
+fn raw_identifiers_are_unraw_in_paths() {
+    #[derive(TryFuncMap, Debug)]
+    #[funcmap(traced)]
+    struct r#Test<T> {
+        r#type: T,
+    }
+
+    let (_, events) = capture(|| Test { r#type: -1 }.try_func_map(traced(positive)));
+
+    assert_eq!(events[0].paths, ["Test.type"]);
+}
+
+#[test]
+fn fields_not_depending_on_type_param_are_not_traced() {
+    #[derive(TryFuncMap, Debug)]
+    #[funcmap(traced)]
+    struct Test<T> {
+        value: T,
+        label: &'static str,
+        marker: PhantomData<T>,
+    }
+
+    let src = Test {
+        value: 1,
+        label: "test",
+        marker: PhantomData,
+    };
+
+    let subscriber = CapturingSubscriber::default();
+    let state = Arc::clone(&subscriber.state);
+
+    tracing::subscriber::with_default(subscriber, || src.try_func_map(traced(positive))).unwrap();
+
+    assert_eq!(state.lock().unwrap().span_paths, ["Test.value"]);
+}
+
+#[test]
+fn spans_are_not_entered_without_option() {
+    #[derive(TryFuncMap, Debug)]
+    struct Test<T>(T);
+
+    let (result, events) = capture(|| Test(-1).try_func_map(traced(positive)));
+
+    assert_eq!(result.unwrap_err(), NotPositive(-1));
+    assert_eq!(
+        events,
+        [CapturedEvent {
+            error: "-1 is not positive".into(),
+            paths: Vec::new(),
+        }]
+    );
+}
+
+#[test]
+fn func_map_is_not_affected() {
+    #[derive(FuncMap, TryFuncMap, Debug, PartialEq)]
+    #[funcmap(traced)]
+    struct Test<T>(T);
+
+    let (dst, events) = capture(|| Test(1).func_map(|v| v + 1));
+
+    assert_eq!(dst, Test(2));
+    assert_eq!(events, []);
+}
+
+#[derive(Debug, PartialEq)]
+struct NotPositive(i32);
+
+impl Display for NotPositive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not positive", self.0)
+    }
+}
+
+fn positive(value: i32) -> Result<u32, NotPositive> {
+    if value > 0 {
+        Ok(value as u32)
+    } else {
+        Err(NotPositive(value))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct CapturedEvent {
+    error: String,
+    paths: Vec<String>,
+}
+
+/// Runs `f` with a subscriber capturing the error events emitted within it
+/// along with the paths of the spans they are emitted in
+fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<CapturedEvent>) {
+    let subscriber = CapturingSubscriber::default();
+    let state = Arc::clone(&subscriber.state);
+
+    let output = tracing::subscriber::with_default(subscriber, f);
+
+    let events = state.lock().unwrap().events.drain(..).collect();
+
+    (output, events)
+}
+
+#[derive(Debug, Default)]
+struct CapturingSubscriber {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    span_paths: Vec<String>,
+    stack: Vec<usize>,
+    events: Vec<CapturedEvent>,
+}
+
+#[derive(Debug, Default)]
+struct StrVisitor {
+    name: &'static str,
+    value: String,
+}
+
+impl Visit for StrVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == self.name {
+            self.value = value.into();
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == self.name {
+            self.value = format!("{:?}", value);
+        }
+    }
+}
+
+impl Subscriber for CapturingSubscriber {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut visitor = StrVisitor {
+            name: "path",
+            ..StrVisitor::default()
+        };
+        span.record(&mut visitor);
+
+        let mut state = self.state.lock().unwrap();
+        state.span_paths.push(visitor.value);
+        Id::from_u64(state.span_paths.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut visitor = StrVisitor {
+            name: "error",
+            ..StrVisitor::default()
+        };
+        event.record(&mut visitor);
+
+        let mut state = self.state.lock().unwrap();
+        let paths = state
+            .stack
+            .iter()
+            .map(|&idx| state.span_paths[idx].clone())
+            .collect();
+
+        state.events.push(CapturedEvent {
+            error: visitor.value,
+            paths,
+        });
+    }
+
+    fn enter(&self, span: &Id) {
+        let idx = span.into_u64() as usize - 1;
+        self.state.lock().unwrap().stack.push(idx);
+    }
+
+    fn exit(&self, _: &Id) {
+        self.state.lock().unwrap().stack.pop();
+    }
+}
@@ -0,0 +1,50 @@
+use std::convert::TryFrom;
+
+use funcmap::{TryFuncMap, TryFuncMapInto, TypeParam};
+
+#[test]
+fn field_of_generic_param_type_is_mapped_into_target_type() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T>(T, i32);
+
+    let src = Test(1_u64, 42);
+    let dst = src.try_func_map_into::<u8>();
+
+    assert_eq!(dst, Ok(Test(1_u8, 42)));
+}
+
+#[test]
+fn first_conversion_error_is_returned() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<Option<T>>,
+    }
+
+    let src = Test {
+        value: 1_i32,
+        values: vec![Some(-2), None, Some(-3)],
+    };
+    let dst = src.try_func_map_into::<u32>();
+
+    assert_eq!(dst, Err(u32::try_from(-2_i32).unwrap_err()));
+}
+
+#[test]
+fn type_param_to_map_into_target_type_can_be_specified() {
+    #[derive(TryFuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T);
+
+    let src = Test(1_u64, 2_u64);
+    let dst = TryFuncMapInto::<_, TypeParam<1>>::try_func_map_into::<u8>(src);
+
+    assert_eq!(dst, Ok(Test(1_u64, 2_u8)));
+}
+
+#[test]
+fn foreign_type_is_mapped_into_target_type() {
+    let src = vec![1_u64, 2];
+    let dst = src.try_func_map_into::<u8>();
+
+    assert_eq!(dst, Ok(vec![1_u8, 2]));
+}
@@ -0,0 +1,13 @@
+use funcmap::FuncMap;
+
+use std::{cell::Cell, rc::Rc};
+
+#[derive(FuncMap)]
+#[funcmap(assert_auto_traits(Send))]
+struct TestSend<T>(T, Rc<i32>);
+
+#[derive(FuncMap)]
+#[funcmap(assert_auto_traits(Sync))]
+struct TestSync<T>(Cell<T>);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(drop_bounds(B: Copy))]
+struct Test<T: Copy>(T);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(extra_bounds = "B: Clone")]
+struct Test<T>(T);
+
+struct NotClone;
+
+fn main() {
+    let _ = Test(()).func_map(|_| NotClone);
+}
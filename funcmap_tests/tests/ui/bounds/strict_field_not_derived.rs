@@ -0,0 +1,12 @@
+use funcmap::FuncMap;
+
+struct Inner<T>(T);
+
+#[derive(FuncMap)]
+#[funcmap(strict)]
+struct Test<T> {
+    value: T,
+    inner: Option<Inner<T>>,
+}
+
+fn main() {}
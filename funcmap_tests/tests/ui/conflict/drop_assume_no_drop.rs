@@ -0,0 +1,11 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(assume_no_drop)]
+struct Test<T>(T);
+
+impl<T> Drop for Test<T> {
+    fn drop(&mut self) {}
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(skip_params(S, T))]
+struct Test<S, T>(S, T);
+
+fn main() {}
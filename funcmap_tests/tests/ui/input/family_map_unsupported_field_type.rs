@@ -0,0 +1,12 @@
+use funcmap::{Family, FamilyMap};
+
+#[derive(FamilyMap)]
+struct TestMixed<F: Family>((F, F::Of<i32>));
+
+#[derive(FamilyMap)]
+struct TestNested<F: Family>(F::Of<F::Of<i32>>);
+
+#[derive(FamilyMap)]
+struct TestMultiple<F: Family>((F::Of<i32>, F::Of<bool>));
+
+fn main() {}
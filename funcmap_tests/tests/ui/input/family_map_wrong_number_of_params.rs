@@ -0,0 +1,9 @@
+use funcmap::{Family, FamilyMap};
+
+#[derive(FamilyMap)]
+struct Test;
+
+#[derive(FamilyMap)]
+struct TestTwo<F: Family, G: Family>(F::Of<i32>, G::Of<i32>);
+
+fn main() {}
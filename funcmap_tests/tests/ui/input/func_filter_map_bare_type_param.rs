@@ -0,0 +1,6 @@
+use funcmap::FuncFilterMap;
+
+#[derive(FuncFilterMap)]
+struct Test<T>(T, Vec<T>);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use funcmap::FuncFilterMap;
+
+#[derive(FuncFilterMap)]
+struct Test;
+
+#[derive(FuncFilterMap)]
+struct TestTwo<S, T>(Vec<S>, Vec<T>);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use funcmap::FuncMapFields;
+
+#[derive(FuncMapFields)]
+enum Test<T> {
+    Some(T),
+    None,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use funcmap::JoinParams;
+
+#[derive(JoinParams)]
+struct Test<T>(T);
+
+#[derive(JoinParams)]
+struct TestThree<S, T, U>(S, T, U);
+
+fn main() {}
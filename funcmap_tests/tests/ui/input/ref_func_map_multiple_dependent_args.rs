@@ -0,0 +1,6 @@
+use funcmap::RefFuncMap;
+
+#[derive(RefFuncMap)]
+struct Test<T>(Result<T, Vec<T>>);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(skip_params(N))]
+struct Test<T, const N: usize>([T; N]);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(skip_params('a))]
+struct Test<'a, T>(&'a (), T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(skip_params(U))]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![deny(unused_must_use)]
+
+use funcmap::{FuncMap, MapBuilders, TypeParam};
+
+#[derive(FuncMap, MapBuilders)]
+#[funcmap(free_fn = "map_test")]
+struct Test<T>(Vec<T>);
+
+fn main() {
+    Test(vec![1]).func_map(|v| v + 1);
+    Test(vec![1]).func_map_over::<TypeParam<0>, _>(|v| v + 1);
+    vec![1].func_map(|v| v + 1);
+    map_test(Test(vec![1]), |v| v + 1);
+    Test(vec![1]).map_t(|v| v + 1);
+}
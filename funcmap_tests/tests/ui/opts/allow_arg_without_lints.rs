@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(allow())]
+struct Test<T>(T);
+
+fn main() {}
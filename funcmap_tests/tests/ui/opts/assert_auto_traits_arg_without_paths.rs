@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(assert_auto_traits())]
+struct Test<T>(T);
+
+fn main() {}
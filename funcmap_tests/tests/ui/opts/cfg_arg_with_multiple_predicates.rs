@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(cfg(test, unix))]
+struct Test<T>(T);
+
+fn main() {}
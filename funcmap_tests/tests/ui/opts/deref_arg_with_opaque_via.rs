@@ -0,0 +1,10 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+struct Test<T>(#[funcmap(deref, rebuild = "Box::new", opaque_via = "map")] Box<T>);
+
+fn map<A, B>(value: Box<A>, f: impl FnMut(A) -> B) -> Box<B> {
+    value.func_map(f)
+}
+
+fn main() {}
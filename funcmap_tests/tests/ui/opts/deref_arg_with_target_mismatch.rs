@@ -0,0 +1,32 @@
+use funcmap::FuncMap;
+
+use std::ops::{Deref, DerefMut};
+
+#[derive(FuncMap)]
+struct Test<T>(#[funcmap(deref, rebuild = "Wrapper::new")] Wrapper<T>);
+
+struct Wrapper<T>(Vec<T>);
+
+impl<T> Wrapper<T> {
+    fn new(value: T) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl<T> Deref for Wrapper<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Wrapper<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+fn main() {
+    let _ = Test(Wrapper::new(1)).func_map(|v| v);
+}
@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+struct Test<T>(#[funcmap(deref, rebuild = "Wrapper::new")] Wrapper<T, T>);
+
+struct Wrapper<S, T>(S, T);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+struct Test<T>(#[funcmap(deref)] Box<T>);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(drop_bounds('a: 'static))]
+struct Test<'a, T>(&'a (), T);
+
+fn main() {}
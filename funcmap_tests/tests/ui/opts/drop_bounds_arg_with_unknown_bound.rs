@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(drop_bounds(A: Clone + Copy, B: Default, T: Copy))]
+struct Test<T: Copy>(T);
+
+fn main() {}
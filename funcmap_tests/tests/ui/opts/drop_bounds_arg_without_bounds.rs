@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(drop_bounds())]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(allow(unused_results, clippy::pedantic))]
+#[funcmap(allow(clippy::pedantic))]
+struct Test<T>(T);
+
+fn main() {}
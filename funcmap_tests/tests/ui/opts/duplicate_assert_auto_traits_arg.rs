@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(assert_auto_traits(Send, Sync))]
+#[funcmap(assert_auto_traits(Send))]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(assume_no_drop)]
+#[funcmap(assume_no_drop)]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(canonical)]
+#[funcmap(canonical)]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use funcmap::FuncMap;
+
+trait Trait<T> {}
+
+struct Wrapper<T>(Box<dyn Trait<T>>);
+
+#[derive(FuncMap)]
+#[funcmap(dyn_as = "Wrapper", dyn_as = "Wrapper")]
+struct Test<T>(Box<dyn Trait<T>>);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(dyn_body)]
+#[funcmap(dyn_body)]
+struct Test<T>(T);
+
+fn main() {}
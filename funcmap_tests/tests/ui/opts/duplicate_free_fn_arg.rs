@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(free_fn = "map_test", free_fn = "map_test_again")]
+struct Test<T>(T);
+
+fn main() {}
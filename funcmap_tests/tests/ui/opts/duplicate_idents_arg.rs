@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(idents(src = "Src"), idents(src = "Source"))]
+struct Test<T>(T);
+
+fn main() {}
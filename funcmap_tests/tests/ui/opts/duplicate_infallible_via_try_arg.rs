@@ -0,0 +1,8 @@
+use funcmap::TryFuncMap;
+
+#[derive(TryFuncMap)]
+#[funcmap(infallible_via_try)]
+#[funcmap(infallible_via_try)]
+struct Test<T>(T);
+
+fn main() {}
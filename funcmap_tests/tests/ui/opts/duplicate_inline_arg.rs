@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(inline = "always")]
+#[funcmap(inline = "never")]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use funcmap::FuncMap;
+
+fn map<A, B>(value: A, mut f: impl FnMut(A) -> B) -> B {
+    f(value)
+}
+
+#[derive(FuncMap)]
+struct Test<T>(#[funcmap(opaque_via = "map", opaque_via = "map")] T);
+
+fn main() {}
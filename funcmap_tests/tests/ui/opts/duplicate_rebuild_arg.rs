@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(with = "into_parts", rebuild = "from_parts", rebuild = "from_parts_again")]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(skip_params(S, S))]
+struct Test<S, T>(S, T);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(strict)]
+#[funcmap(strict)]
+struct Test<T>(T);
+
+fn main() {}
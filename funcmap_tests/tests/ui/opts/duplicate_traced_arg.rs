@@ -0,0 +1,8 @@
+use funcmap::TryFuncMap;
+
+#[derive(TryFuncMap)]
+#[funcmap(traced)]
+#[funcmap(traced)]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(validate = "validate", validate = "validate_again")]
+struct Test<T>(T);
+
+fn main() {}
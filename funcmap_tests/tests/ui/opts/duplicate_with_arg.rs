@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(with = "into_parts", with = "into_parts_again", rebuild = "from_parts")]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(extra_bounds = "A Clone")]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(params(T), extra_bounds = "A: Clone, T: Default")]
+struct Test<A, T>(A, T);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+struct Test<T>(#[funcmap(params(T))] T);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+struct Test<T>(#[funcmap(rebuild = "Box::new")] Box<T>);
+
+fn main() {}
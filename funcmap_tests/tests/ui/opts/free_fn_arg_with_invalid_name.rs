@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(free_fn = "map test")]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(free_fn = "map_test")]
+struct Test<S, T>(S, T);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use funcmap::StructuralFrom;
+
+#[derive(StructuralFrom)]
+struct Test<T>(T);
+
+fn main() {}
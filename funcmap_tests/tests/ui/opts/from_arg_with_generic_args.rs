@@ -0,0 +1,9 @@
+use funcmap::StructuralFrom;
+
+struct Source<T>(T);
+
+#[derive(StructuralFrom)]
+#[funcmap(from = "Source<T>")]
+struct Test<T>(T);
+
+fn main() {}
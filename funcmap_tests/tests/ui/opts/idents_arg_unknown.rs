@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(idents(fn = "Fn"))]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(idents(src = "T"))]
+struct Test<T>(T);
+
+#[derive(FuncMap)]
+#[funcmap(idents(src = "Same", dst = "Same"))]
+struct Test2<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(idents(src = "not an ident"))]
+struct Test<T>(T);
+
+fn main() {}
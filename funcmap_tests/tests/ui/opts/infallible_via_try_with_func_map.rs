@@ -0,0 +1,7 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+#[derive(FuncMap, TryFuncMap)]
+#[funcmap(infallible_via_try)]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(inline = "sometimes")]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(rebuild = "from_parts")]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(params(S, T))]
+#[funcmap(skip_params(T, U, S))]
+struct Test<S, T, U>(S, T, U);
+
+fn main() {}
@@ -0,0 +1,8 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(params(S))]
+#[funcmap(skip_params(T, U))]
+struct Test<S, T, U>(S, T, U);
+
+fn main() {}
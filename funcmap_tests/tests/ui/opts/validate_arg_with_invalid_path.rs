@@ -0,0 +1,7 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(validate = "not a path")]
+struct Test<T>(T);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+#[funcmap(with = "into_parts", rebuild = "from_parts")]
+enum Test<T> {
+    Value(T),
+}
+
+fn main() {}
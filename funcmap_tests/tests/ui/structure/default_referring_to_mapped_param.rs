@@ -0,0 +1,9 @@
+use funcmap::{FuncMap, TypeParam};
+
+#[derive(FuncMap)]
+struct Test<S, T = Vec<S>>(S, T);
+
+fn main() {
+    let src: Test<i32> = Test(1, vec![2]);
+    let _: Test<String> = src.func_map_over::<TypeParam<0>, _>(|v| v.to_string());
+}
@@ -0,0 +1,9 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+enum Test<'a, T> {
+    Named { reference_type: &'a T },
+    Unnamed(fn(T), Box<[T]>),
+}
+
+fn main() {}
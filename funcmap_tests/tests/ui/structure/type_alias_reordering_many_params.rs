@@ -0,0 +1,17 @@
+use funcmap::FuncMap;
+
+mod foreign {
+    use funcmap::FuncMap;
+
+    #[derive(FuncMap)]
+    pub struct Quad<W, X, Y, Z>(pub W, pub X, pub Y, pub Z);
+}
+
+mod reexport {
+    pub type Quad<W, X, Y, Z> = crate::foreign::Quad<Z, W, X, Y>;
+}
+
+#[derive(FuncMap)]
+struct Test<T>(reexport::Quad<i32, T, i32, i32>);
+
+fn main() {}
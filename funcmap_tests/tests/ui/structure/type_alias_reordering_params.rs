@@ -0,0 +1,11 @@
+use funcmap::FuncMap;
+
+#[derive(FuncMap)]
+struct Baz<S, T>(S, T);
+
+type Bar<T, U> = Baz<U, T>;
+
+#[derive(FuncMap)]
+struct Test<T>(Bar<T, i32>);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use funcmap::TryFuncMap;
+
+#[derive(TryFuncMap)]
+struct Baz<S, T>(S, T);
+
+type Bar<T, U> = Baz<U, T>;
+
+#[derive(TryFuncMap)]
+struct Test<T>(Option<Bar<T, i32>>);
+
+fn main() {}
@@ -105,6 +105,37 @@ fn enum_variant_with_named_fields_is_mapped() {
     );
 }
 
+#[test]
+fn enum_variants_with_fields_of_same_type_are_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    enum Test<T> {
+        First((T, i32), Vec<T>),
+        Second { value: (T, i32), values: Vec<T> },
+        Third((T, i32)),
+    }
+
+    let src = Test::First((T1, 42), vec![T1]);
+    let dst = src.func_map(|_| T2);
+    assert_eq!(dst, Test::First((T2, 42), vec![T2]));
+
+    let src = Test::Second {
+        value: (T1, 42),
+        values: vec![T1],
+    };
+    let dst = src.func_map(|_| T2);
+    assert_eq!(
+        dst,
+        Test::Second {
+            value: (T2, 42),
+            values: vec![T2],
+        }
+    );
+
+    let src = Test::Third((T1, 42));
+    let dst = src.func_map(|_| T2);
+    assert_eq!(dst, Test::Third((T2, 42)));
+}
+
 #[derive(Debug, PartialEq)]
 struct T1;
 
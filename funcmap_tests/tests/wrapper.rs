@@ -0,0 +1,107 @@
+use funcmap::{FuncMap, TryFuncMap};
+
+use std::convert::TryFrom;
+
+mod units {
+    #[derive(Debug, PartialEq)]
+    pub struct Seconds<T> {
+        value: T,
+    }
+
+    impl<T> Seconds<T> {
+        pub fn new(value: T) -> Self {
+            Self { value }
+        }
+
+        pub fn into_value(self) -> T {
+            self.value
+        }
+    }
+
+    funcmap::impl_funcmap_wrapper!(Seconds {
+        unwrap: Seconds::into_value,
+        wrap: Seconds::new,
+    });
+}
+
+#[derive(Debug, PartialEq)]
+struct Meters<T>(T);
+
+funcmap::impl_funcmap_wrapper!(Meters {
+    unwrap: |meters| meters.0,
+    wrap: Meters
+});
+
+#[derive(Debug, PartialEq)]
+struct Ratio<T>(T);
+
+impl<T: Copy> Ratio<T> {
+    fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+funcmap::impl_funcmap_wrapper!(Ratio {
+    unwrap: |ratio| ratio.0,
+    wrap: Ratio::new,
+} where B: Copy);
+
+#[test]
+fn wrapper_with_private_field_is_mapped_via_functions() {
+    let src = units::Seconds::new(90_u32);
+    let dst = src.func_map(f64::from);
+
+    assert_eq!(dst, units::Seconds::new(90.0));
+}
+
+#[test]
+fn wrapper_is_mapped_via_closure_and_constructor() {
+    let src = Meters(1);
+    let dst = src.func_map(|v| v * 1000);
+
+    assert_eq!(dst, Meters(1000));
+}
+
+#[test]
+fn wrapper_with_bounds_is_mapped() {
+    let src = Ratio::new(1_u8);
+    let dst = src.func_map(u64::from);
+
+    assert_eq!(dst, Ratio::new(1_u64));
+}
+
+#[test]
+fn wrapper_is_try_mapped() {
+    let src = Meters(300_u64);
+    let dst = src.try_func_map(u8::try_from);
+
+    assert!(dst.is_err());
+
+    let src = Ratio::new(42_u64);
+    let dst = src.try_func_map(u8::try_from);
+
+    assert_eq!(dst, Ok(Ratio::new(42_u8)));
+}
+
+#[test]
+fn field_of_wrapper_type_is_mapped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        timeout: units::Seconds<T>,
+        distances: Vec<Meters<T>>,
+    }
+
+    let src = Test {
+        timeout: units::Seconds::new(1),
+        distances: vec![Meters(2), Meters(3)],
+    };
+    let dst = src.func_map(|v| v * 10);
+
+    assert_eq!(
+        dst,
+        Test {
+            timeout: units::Seconds::new(10),
+            distances: vec![Meters(20), Meters(30)],
+        }
+    );
+}
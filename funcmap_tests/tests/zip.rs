@@ -0,0 +1,99 @@
+use funcmap::{once_per_leaf, FuncMap, FuncMapZip, TypeParam};
+
+#[test]
+fn values_are_zipped_with_iterator_in_order() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T> {
+        value: T,
+        values: Vec<T>,
+        other: i32,
+    }
+
+    let src = Test {
+        value: 'a',
+        values: vec!['b', 'c'],
+        other: 42,
+    };
+    let dst = src.func_map_zip_with(vec![1, 2, 3], |c, i| (i, c));
+
+    assert_eq!(
+        dst,
+        Test {
+            value: (1, 'a'),
+            values: vec![(2, 'b'), (3, 'c')],
+            other: 42,
+        }
+    );
+}
+
+#[test]
+fn values_are_replaced_with_items_of_iterator() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<T>(T, Option<T>, T);
+
+    let src = Test((), Some(()), ());
+    let dst = src.func_map_zip(vec![
+        String::from("x"),
+        String::from("y"),
+        String::from("z"),
+    ]);
+
+    assert_eq!(
+        dst,
+        Test(
+            String::from("x"),
+            Some(String::from("y")),
+            String::from("z")
+        )
+    );
+}
+
+#[test]
+fn surplus_items_are_left_unconsumed() {
+    let mut iter = 1..;
+
+    let dst = [(), ()].func_map_zip(&mut iter);
+
+    assert_eq!(dst, [1, 2]);
+    assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+#[should_panic = "iterator yielded fewer items than there are values to map"]
+fn zipping_with_too_short_iterator_panics() {
+    let _ = [1, 2, 3].func_map_zip(vec![4, 5]);
+}
+
+#[test]
+fn values_of_non_default_param_are_zipped() {
+    #[derive(FuncMap, Debug, PartialEq)]
+    struct Test<S, T>(S, T, T);
+
+    let src = Test(0, 'a', 'b');
+    let dst = FuncMapZip::<_, _, TypeParam<1>>::func_map_zip_with(src, 10.., |c, i| (i, c));
+
+    assert_eq!(dst, Test(0, (10, 'a'), (11, 'b')));
+}
+
+#[test]
+fn each_closure_is_applied_once_in_order() {
+    let first = String::from("first");
+    let second = String::from("second");
+
+    let closures: Vec<Box<dyn FnOnce(i32) -> String>> = vec![
+        Box::new(move |v| format!("{first}{v}")),
+        Box::new(move |v| format!("{second}{v}")),
+    ];
+
+    let dst = [1, 2].func_map(once_per_leaf(closures));
+
+    assert_eq!(dst, [String::from("first1"), String::from("second2")]);
+}
+
+#[test]
+#[should_panic = "closure was called more often than there are closures to apply"]
+fn calling_once_per_leaf_closure_too_often_panics() {
+    let closures = vec![|v: i32| v + 1];
+
+    let _ = [1, 2].func_map(once_per_leaf(closures));
+}
@@ -0,0 +1,280 @@
+//! Development tasks for the `funcmap` workspace
+//!
+//! Run `cargo xtask <task>` from anywhere within the workspace, where `<task>`
+//! is one of the following:
+//! - `feature-matrix`: builds `funcmap` with every single one of its features,
+//!   without the `std` feature unless it is the one being built, and tests it
+//!   with each of them in addition to the `std` feature. Features are read
+//!   from the output of `cargo metadata`, so newly added features are picked
+//!   up automatically. Also tests `funcmap_codegen` without the `nightly`
+//!   feature, which is always enabled within the workspace.
+//! - `minimal-versions`: tests the workspace with the minimal versions of all
+//!   dependencies allowed by the manifests. This requires a nightly toolchain
+//!   and is skipped if none is installed. The `Cargo.lock` file is restored
+//!   afterwards.
+//! - `all`: runs all of the above.
+//!
+//! All commands are run even if some of them fail, and the failed ones are
+//! listed at the end.
+
+#![deny(missing_copy_implementations)]
+#![deny(missing_debug_implementations)]
+#![deny(unused_crate_dependencies)]
+#![deny(unused_extern_crates)]
+#![deny(unused_lifetimes)]
+#![deny(unused_qualifications)]
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+use serde_json::Value;
+
+const USAGE: &str = "\
+Usage: cargo xtask <task>
+
+Tasks:
+    feature-matrix      Build and test `funcmap` with every single one of its features
+    minimal-versions    Test the workspace with minimal dependency versions (requires nightly)
+    all                 Run all of the above
+";
+
+fn main() -> ExitCode {
+    let task = env::args().nth(1);
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask should be located within the workspace")
+        .to_path_buf();
+
+    let mut runner = Runner::new(root);
+
+    let result = match task.as_deref() {
+        Some("feature-matrix") => feature_matrix(&mut runner),
+        Some("minimal-versions") => minimal_versions(&mut runner),
+        Some("all") => feature_matrix(&mut runner).and_then(|()| minimal_versions(&mut runner)),
+        _ => {
+            eprint!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(err) = result {
+        eprintln!("\nerror: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    runner.finish()
+}
+
+/// Builds `funcmap` with every single one of its features and tests it with
+/// each of them in addition to the `std` feature
+///
+/// Without the `std` feature, only building is possible, as the tests of
+/// `funcmap` depend on the standard library.
+///
+/// `funcmap_codegen` is tested on its own as well, as testing the workspace
+/// always enables its `nightly` feature through `funcmap_tests`.
+fn feature_matrix(runner: &mut Runner) -> io::Result<()> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--no-deps", "--format-version", "1"])
+        .current_dir(&runner.root)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            "failed to run `cargo metadata`",
+        ));
+    }
+
+    let features = features(&output.stdout, "funcmap")?;
+
+    runner.cargo(&["test", "--package", "funcmap_codegen"]);
+    runner.cargo(&["test", "--package", "funcmap"]);
+    runner.cargo(&["build", "--package", "funcmap", "--no-default-features"]);
+
+    for feature in features {
+        let features_with_std = if feature == "std" {
+            feature
+        } else {
+            runner.cargo(&[
+                "build",
+                "--package",
+                "funcmap",
+                "--no-default-features",
+                "--features",
+                &feature,
+            ]);
+
+            format!("std,{feature}")
+        };
+
+        runner.cargo(&[
+            "test",
+            "--package",
+            "funcmap",
+            "--no-default-features",
+            "--features",
+            &features_with_std,
+        ]);
+    }
+
+    runner.cargo(&["test", "--package", "funcmap", "--all-features"]);
+
+    Ok(())
+}
+
+/// Tests the workspace with the minimal versions of all dependencies allowed
+/// by the manifests, restoring the `Cargo.lock` file afterwards
+///
+/// As in CI, UI tests are skipped, since their expected output may depend on
+/// the versions of the dependencies.
+fn minimal_versions(runner: &mut Runner) -> io::Result<()> {
+    let nightly_available = Command::new("cargo")
+        .args(["+nightly", "--version"])
+        .output()
+        .map_or(false, |output| output.status.success());
+
+    if !nightly_available {
+        eprintln!("\nSkipping minimal versions, as no nightly toolchain is installed");
+        return Ok(());
+    }
+
+    let lockfile = runner.root.join("Cargo.lock");
+    let original_lockfile = fs::read(&lockfile).ok();
+
+    if runner.cargo(&["+nightly", "update", "-Z", "minimal-versions"]) {
+        runner.cargo(&["test", "--workspace", "--", "--skip", "ui"]);
+    }
+
+    match original_lockfile {
+        Some(original_lockfile) => fs::write(&lockfile, original_lockfile),
+        None => fs::remove_file(&lockfile),
+    }
+}
+
+/// Determines the features of a package from the output of
+/// `cargo metadata --format-version 1`, except `default`
+///
+/// These include the optional dependencies not referred to via `dep:` syntax,
+/// which are features implicitly.
+fn features(metadata: &[u8], package: &str) -> io::Result<Vec<String>> {
+    let metadata: Value = serde_json::from_slice(metadata)?;
+
+    let features = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|p| p["name"] == package)
+        .and_then(|p| p["features"].as_object())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("no features of package `{package}` found in metadata"),
+            )
+        })?;
+
+    Ok(features
+        .keys()
+        .filter(|feature| *feature != "default")
+        .cloned()
+        .collect())
+}
+
+/// Runner for `cargo` commands within the workspace, keeping track of the
+/// failed ones
+#[derive(Debug)]
+struct Runner {
+    root: PathBuf,
+    failed: Vec<String>,
+}
+
+impl Runner {
+    /// Creates a new [`Runner`] running commands within the workspace located
+    /// at `root`
+    fn new(root: PathBuf) -> Self {
+        Self {
+            root,
+            failed: Vec::new(),
+        }
+    }
+
+    /// Runs `cargo` with the given arguments, returning whether it succeeded
+    fn cargo(&mut self, args: &[&str]) -> bool {
+        let command = format!("cargo {}", args.join(" "));
+        eprintln!("\n$ {command}");
+
+        let success = Command::new("cargo")
+            .args(args)
+            .current_dir(&self.root)
+            .status()
+            .map_or(false, |status| status.success());
+
+        if !success {
+            self.failed.push(command);
+        }
+
+        success
+    }
+
+    /// Reports the failed commands, returning [`ExitCode::FAILURE`] if there
+    /// are any
+    fn finish(self) -> ExitCode {
+        if self.failed.is_empty() {
+            eprintln!("\nAll commands succeeded");
+            return ExitCode::SUCCESS;
+        }
+
+        eprintln!("\nFailed commands:");
+
+        for command in &self.failed {
+            eprintln!("    {command}");
+        }
+
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn features_are_read_from_metadata() {
+        let metadata = br#"{
+            "packages": [
+                {
+                    "name": "other",
+                    "features": { "other": [] }
+                },
+                {
+                    "name": "test",
+                    "features": {
+                        "alloc": [],
+                        "default": ["std"],
+                        "heapless": ["dep:heapless"],
+                        "json": ["alloc", "dep:serde_json"],
+                        "std": ["alloc"]
+                    }
+                }
+            ]
+        }"#;
+
+        assert_eq!(
+            features(metadata, "test").unwrap(),
+            ["alloc", "heapless", "json", "std"]
+        );
+    }
+
+    #[test]
+    fn missing_package_is_an_error() {
+        let metadata = br#"{ "packages": [] }"#;
+
+        assert!(features(metadata, "test").is_err());
+    }
+}